@@ -0,0 +1,22 @@
+from fizzbuzz import fizzbuzz
+
+
+def test_plain_numbers():
+    assert fizzbuzz(1) == "1"
+    assert fizzbuzz(2) == "2"
+    assert fizzbuzz(7) == "7"
+
+
+def test_multiples_of_three():
+    assert fizzbuzz(3) == "Fizz"
+    assert fizzbuzz(9) == "Fizz"
+
+
+def test_multiples_of_five():
+    assert fizzbuzz(5) == "Buzz"
+    assert fizzbuzz(20) == "Buzz"
+
+
+def test_multiples_of_fifteen():
+    assert fizzbuzz(15) == "FizzBuzz"
+    assert fizzbuzz(45) == "FizzBuzz"
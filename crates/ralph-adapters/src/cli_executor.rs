@@ -11,6 +11,7 @@ use nix::sys::signal::{Signal, kill};
 #[cfg(unix)]
 use nix::unistd::Pid;
 use std::io::Write;
+use std::path::PathBuf;
 use std::process::Stdio;
 use std::time::Duration;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
@@ -34,12 +35,23 @@ pub struct ExecutionResult {
 #[derive(Debug)]
 pub struct CliExecutor {
     backend: CliBackend,
+    workspace_root: Option<PathBuf>,
 }
 
 impl CliExecutor {
     /// Creates a new executor with the given backend.
     pub fn new(backend: CliBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            workspace_root: None,
+        }
+    }
+
+    /// Runs the backend in `root` instead of the current directory.
+    #[must_use]
+    pub fn with_workspace_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.workspace_root = Some(root.into());
+        self
     }
 
     /// Executes a prompt and streams output to the provided writer.
@@ -66,9 +78,12 @@ impl CliExecutor {
         command.stdout(Stdio::piped());
         command.stderr(Stdio::piped());
 
-        // Set working directory to current directory (mirrors PTY executor behavior)
-        // Use fallback to "." if current_dir fails (e.g., E2E test workspaces)
-        let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+        // Set working directory to the workspace root, or the current directory
+        // (mirrors PTY executor behavior). Use fallback to "." if current_dir
+        // fails (e.g., E2E test workspaces)
+        let cwd = self.workspace_root.clone().unwrap_or_else(|| {
+            std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
+        });
         command.current_dir(&cwd);
 
         // Apply backend-specific environment variables (e.g., Agent Teams env var)
@@ -262,6 +277,25 @@ mod tests {
         assert!(result.output.contains("hello world"));
     }
 
+    #[tokio::test]
+    async fn test_execute_in_workspace_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = CliBackend {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "pwd".to_string()],
+            prompt_mode: PromptMode::Arg,
+            prompt_flag: None,
+            output_format: OutputFormat::Text,
+            env_vars: vec![],
+        };
+
+        let executor = CliExecutor::new(backend).with_workspace_root(dir.path());
+        let result = executor.execute_capture("").await.unwrap();
+
+        let expected = dir.path().canonicalize().unwrap();
+        assert_eq!(result.output.trim(), expected.to_string_lossy());
+    }
+
     #[tokio::test]
     async fn test_execute_stdin() {
         // Use cat to test stdin mode
@@ -23,7 +23,7 @@ use crate::cli_backend::{CliBackend, OutputFormat};
 use crate::pi_stream::{PiSessionState, PiStreamParser, dispatch_pi_stream_event};
 use crate::stream_handler::{SessionResult, StreamHandler};
 #[cfg(unix)]
-use nix::sys::signal::{Signal, kill};
+use nix::sys::signal::{Signal, kill, killpg};
 #[cfg(unix)]
 use nix::unistd::Pid;
 use portable_pty::{CommandBuilder, PtyPair, PtySize, native_pty_system};
@@ -53,6 +53,11 @@ pub struct PtyExecutionResult {
     pub exit_code: Option<i32>,
     /// How the process was terminated.
     pub termination: TerminationType,
+    /// Session cost reported by the backend's result event, if any.
+    ///
+    /// Only set by [`PtyExecutor::run_observe_streaming`] for stream-json
+    /// backends that report usage.
+    pub total_cost_usd: Option<f64>,
 }
 
 /// How the PTY process was terminated.
@@ -570,6 +575,10 @@ impl PtyExecutor {
         mut interrupt_rx: tokio::sync::watch::Receiver<bool>,
         handler: &mut H,
     ) -> io::Result<PtyExecutionResult> {
+        // Capture the session cost from on_complete while forwarding everything
+        let mut cost_tracker = SessionCostTracker::new(handler);
+        let handler = &mut cost_tracker;
+
         // Check output format to decide parsing strategy
         let output_format = self.backend.output_format;
 
@@ -884,13 +893,15 @@ impl PtyExecutor {
                 }
 
                 // Pass extracted_text for event parsing from NDJSON
-                return Ok(build_result(
+                let mut result = build_result(
                     &output,
                     status.success(),
                     Some(exit_code),
                     final_termination,
                     extracted_text,
-                ));
+                );
+                result.total_cost_usd = handler.total_cost_usd;
+                return Ok(result);
             }
         }
 
@@ -933,13 +944,15 @@ impl PtyExecutor {
         }
 
         // Pass extracted_text for event parsing from NDJSON
-        Ok(build_result(
+        let mut result = build_result(
             &output,
             success,
             exit_code,
             final_termination,
             extracted_text,
-        ))
+        );
+        result.total_cost_usd = handler.total_cost_usd;
+        Ok(result)
     }
 
     /// Runs in interactive mode (bidirectional I/O).
@@ -1395,7 +1408,11 @@ impl PtyExecutor {
         ))
     }
 
-    /// Terminates the child process.
+    /// Terminates the child process and anything it spawned.
+    ///
+    /// The PTY child leads its own session, so on Unix the signals go to its
+    /// whole process group; otherwise tools the agent started (shells, test
+    /// runners) would outlive it.
     ///
     /// If `graceful` is true, sends SIGTERM and waits up to 5 seconds before SIGKILL.
     /// If `graceful` is false, sends SIGKILL immediately.
@@ -1425,9 +1442,16 @@ impl PtyExecutor {
             None => return Ok(()), // Already exited
         };
 
+        // Fall back to the child alone if its group can't be signalled
+        let signal_tree = |signal| {
+            if killpg(pid, signal).is_err() {
+                let _ = kill(pid, signal);
+            }
+        };
+
         if graceful {
             debug!(pid = %pid, "Sending SIGTERM");
-            let _ = kill(pid, Signal::SIGTERM);
+            signal_tree(Signal::SIGTERM);
 
             // Wait up to 5 seconds for graceful exit (reduced from 5s for better UX)
             let grace_period = Duration::from_secs(2);
@@ -1450,7 +1474,7 @@ impl PtyExecutor {
         }
 
         debug!(pid = %pid, "Sending SIGKILL");
-        let _ = kill(pid, Signal::SIGKILL);
+        signal_tree(Signal::SIGKILL);
         Ok(())
     }
 
@@ -1657,6 +1681,46 @@ fn build_result(
         success,
         exit_code,
         termination,
+        total_cost_usd: None,
+    }
+}
+
+/// Forwards stream events to an inner handler, remembering the session cost
+/// reported through `on_complete`.
+struct SessionCostTracker<'a, H> {
+    inner: &'a mut H,
+    total_cost_usd: Option<f64>,
+}
+
+impl<'a, H: StreamHandler> SessionCostTracker<'a, H> {
+    fn new(inner: &'a mut H) -> Self {
+        Self {
+            inner,
+            total_cost_usd: None,
+        }
+    }
+}
+
+impl<H: StreamHandler> StreamHandler for SessionCostTracker<'_, H> {
+    fn on_text(&mut self, text: &str) {
+        self.inner.on_text(text);
+    }
+
+    fn on_tool_call(&mut self, name: &str, id: &str, input: &serde_json::Value) {
+        self.inner.on_tool_call(name, id, input);
+    }
+
+    fn on_tool_result(&mut self, id: &str, output: &str) {
+        self.inner.on_tool_result(id, output);
+    }
+
+    fn on_error(&mut self, error: &str) {
+        self.inner.on_error(error);
+    }
+
+    fn on_complete(&mut self, result: &SessionResult) {
+        self.total_cost_usd = Some(result.total_cost_usd);
+        self.inner.on_complete(result);
     }
 }
 
@@ -1848,6 +1912,7 @@ mod tests {
             success: true,
             exit_code: Some(0),
             termination: TerminationType::Natural,
+            total_cost_usd: None,
         };
 
         assert!(
@@ -1934,6 +1999,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_session_cost_tracker_records_cost_and_forwards() {
+        let mut inner = CapturingHandler::default();
+        let mut tracker = SessionCostTracker::new(&mut inner);
+        assert!(tracker.total_cost_usd.is_none());
+
+        tracker.on_text("hi");
+        tracker.on_complete(&SessionResult {
+            duration_ms: 10,
+            total_cost_usd: 0.25,
+            num_turns: 1,
+            is_error: false,
+        });
+
+        assert!((tracker.total_cost_usd.unwrap() - 0.25).abs() < f64::EPSILON);
+        assert_eq!(inner.texts, vec!["hi".to_string()]);
+        assert_eq!(inner.completions.len(), 1);
+    }
+
     #[test]
     fn test_dispatch_stream_event_routes_text_and_tool_calls() {
         let mut handler = CapturingHandler::default();
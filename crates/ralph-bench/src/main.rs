@@ -9,16 +9,19 @@
 //! - Metrics collection for benchmark comparison
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
 use clap::{Parser, Subcommand, ValueEnum};
 use ralph_adapters::{CliBackend, CliExecutor, detect_backend};
 use ralph_core::{
-    CleanupPolicy, CliCapture, EventLoop, PlayerConfig, RalphConfig, ReplayMode, SessionPlayer,
-    TaskSuite, TerminationReason, WorkspaceManager,
+    CleanupPolicy, CliCapture, EventLoop, LOOP_ITERATION_TOPIC, LoopContext, PlayerConfig,
+    RalphConfig, Record, ReplayMode, SessionPlayer, SessionRecorder, TaskSuite, TerminationReason,
+    WorkspaceManager,
 };
 use ralph_proto::FrameCapture;
 use std::fs::{self, File};
 use std::io::{self, BufReader, BufWriter};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
@@ -38,7 +41,7 @@ struct Args {
 enum Commands {
     /// Run benchmark tasks
     Run {
-        /// Path to tasks.json file
+        /// Path to a tasks.json/tasks.yaml file or a directory of task files
         tasks: PathBuf,
 
         /// Record session to JSONL file (single task mode)
@@ -68,6 +71,25 @@ enum Commands {
         /// Number of workspaces to keep when using rotate policy
         #[arg(long, default_value = "5")]
         keep_last_n: usize,
+
+        /// Run each task N times in fresh workspaces and report summary stats
+        #[arg(long, default_value = "1", value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+
+        /// Re-drive the loop from recorded events instead of calling a backend
+        /// (reads <DIR>/<task>/events.jsonl)
+        #[arg(long, value_name = "DIR", conflicts_with = "record_ux")]
+        replay_from_events: Option<PathBuf>,
+
+        /// Exit non-zero if any task needs more iterations than expected
+        /// (beyond --iteration-tolerance)
+        #[arg(long)]
+        fail_on_regression: bool,
+
+        /// Extra iterations over expected_iterations allowed before a task
+        /// counts as a regression
+        #[arg(long, default_value = "0")]
+        iteration_tolerance: u32,
     },
 
     /// Replay a recorded session
@@ -87,11 +109,21 @@ enum Commands {
         #[arg(long)]
         step: bool,
 
+        /// Keyboard controls: space pauses, arrows skip a record, N+Enter seeks
+        #[arg(long, conflicts_with = "step")]
+        interactive: bool,
+
         /// Filter to specific event types (comma-separated prefixes)
         #[arg(long)]
         filter: Option<String>,
     },
 
+    /// Check that every line of a recorded session parses
+    Validate {
+        /// Path to session JSONL file
+        session: PathBuf,
+    },
+
     /// List recorded sessions or workspaces
     List {
         /// What to list: sessions, workspaces
@@ -101,6 +133,23 @@ enum Commands {
         /// Directory to search
         #[arg(short, long)]
         dir: Option<PathBuf>,
+
+        /// Remove workspaces older than this age (e.g. 7d, 12h, 30m)
+        #[arg(long, value_parser = parse_age)]
+        prune: Option<Duration>,
+    },
+
+    /// Compare two benchmark results files task by task
+    Compare {
+        /// Results JSON from the baseline run
+        baseline: PathBuf,
+
+        /// Results JSON from the candidate run
+        candidate: PathBuf,
+
+        /// Print the comparison as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -149,6 +198,10 @@ async fn main() -> Result<()> {
             task,
             cleanup,
             keep_last_n,
+            repeat,
+            replay_from_events,
+            fail_on_regression,
+            iteration_tolerance,
         } => {
             cmd_run(
                 tasks,
@@ -159,6 +212,10 @@ async fn main() -> Result<()> {
                 task,
                 cleanup,
                 keep_last_n,
+                repeat,
+                replay_from_events,
+                fail_on_regression,
+                iteration_tolerance,
             )
             .await
         }
@@ -167,9 +224,16 @@ async fn main() -> Result<()> {
             ux_mode,
             speed,
             step,
+            interactive,
             filter,
-        } => cmd_replay(session, ux_mode, speed, step, filter),
-        Commands::List { what, dir } => cmd_list(what, dir),
+        } => cmd_replay(session, ux_mode, speed, step, interactive, filter),
+        Commands::Validate { session } => cmd_validate(session),
+        Commands::List { what, dir, prune } => cmd_list(what, dir, prune),
+        Commands::Compare {
+            baseline,
+            candidate,
+            json,
+        } => cmd_compare(baseline, candidate, json),
     }
 }
 
@@ -183,6 +247,10 @@ async fn cmd_run(
     task_filter: Option<String>,
     cleanup_policy: String,
     keep_last_n: usize,
+    repeat: u32,
+    replay_from_events: Option<PathBuf>,
+    fail_on_regression: bool,
+    iteration_tolerance: u32,
 ) -> Result<()> {
     // Load task suite
     let suite = TaskSuite::from_file(&tasks_path)
@@ -210,11 +278,8 @@ async fn cmd_run(
     let base_dir = std::env::temp_dir();
     let manager = WorkspaceManager::new(&base_dir, policy);
 
-    // Get tasks directory (parent of tasks.json)
-    let tasks_dir = tasks_path
-        .parent()
-        .map(|p| p.to_path_buf())
-        .unwrap_or_else(|| PathBuf::from("."));
+    // Get tasks directory (task file paths are relative to it)
+    let tasks_dir = TaskSuite::tasks_dir(&tasks_path).to_path_buf();
 
     // Ensure record directory exists if specified
     if let Some(ref dir) = record_dir {
@@ -225,88 +290,93 @@ async fn cmd_run(
     // Run each task
     let mut results = Vec::new();
     for task in tasks_to_run {
-        info!("Running task: {}", task.name);
-
-        // Create workspace
-        let workspace = manager
-            .create_workspace(task)
-            .with_context(|| format!("Failed to create workspace for task '{}'", task.name))?;
-
-        // Setup workspace with task files
-        workspace
-            .setup(task, &tasks_dir)
-            .with_context(|| format!("Failed to setup workspace for task '{}'", task.name))?;
-
-        info!("Workspace created at: {}", workspace.path().display());
-
-        // Determine recording output
-        let record_path = if let Some(ref dir) = record_dir {
-            Some(dir.join(format!("{}.jsonl", task.name)))
-        } else {
-            record.clone()
-        };
-
-        // Track timing
-        let task_start = std::time::Instant::now();
-
-        // Run the orchestration loop for this task
-        let (iterations, termination_reason) =
-            run_task_loop(task, &workspace, record_path.as_ref(), record_ux)
-                .await
-                .with_context(|| format!("Failed to run task '{}'", task.name))?;
-
-        // Run verification command (this works even without full EventLoop integration)
-        let verification_result = workspace
-            .run_verification(&task.verification)
-            .with_context(|| format!("Failed to run verification for task '{}'", task.name))?;
+        let mut runs = Vec::new();
+        for run in 1..=repeat {
+            if repeat > 1 {
+                info!("Running task: {} (run {}/{})", task.name, run, repeat);
+            } else {
+                info!("Running task: {}", task.name);
+            }
 
-        if verification_result.passed {
-            info!(
-                "Task '{}' verification: {}",
-                task.name,
-                verification_result.summary()
+            // Determine recording output
+            let suffix = run_suffix(run, repeat);
+            let record_path = if let Some(ref dir) = record_dir {
+                Some(dir.join(format!("{}{}.jsonl", task.name, suffix)))
+            } else {
+                record.as_ref().map(|path| with_run_suffix(path, &suffix))
+            };
+
+            let replay_path = replay_from_events
+                .as_ref()
+                .map(|dir| dir.join(&task.name).join("events.jsonl"));
+
+            runs.push(
+                run_task_once(
+                    task,
+                    &manager,
+                    &tasks_dir,
+                    record_path.as_ref(),
+                    record_ux,
+                    replay_path.as_deref(),
+                )
+                .await?,
             );
-        } else {
-            tracing::warn!(
-                "Task '{}' verification: {}\nstderr: {}",
+        }
+
+        let result = TaskResult::from_runs(task.name.clone(), task.expected_iterations, runs)
+            .with_expected_termination(task)
+            .with_iteration_tolerance(iteration_tolerance);
+        if let Some(summary) = result.iteration_summary() {
+            if result.iteration_regression {
+                warn!(
+                    "Task '{}' iterations: {} — over tolerance of {}",
+                    task.name, summary, iteration_tolerance
+                );
+            } else {
+                info!("Task '{}' iterations: {}", task.name, summary);
+            }
+        }
+        if result.termination_mismatch {
+            warn!(
+                "Task '{}' terminated with {} but expected {}",
                 task.name,
-                verification_result.summary(),
-                verification_result.stderr.trim()
+                result
+                    .termination_reason
+                    .as_ref()
+                    .map_or("no loop run", TerminationReason::name),
+                result
+                    .expected_termination
+                    .as_ref()
+                    .map_or("?", TerminationReason::name)
             );
         }
-
-        let duration_secs = task_start.elapsed().as_secs_f64();
-
-        // Apply cleanup policy based on verification result
-        let mut workspace = workspace;
-        let cleaned_up = manager
-            .apply_cleanup(&mut workspace, verification_result.passed)
-            .with_context(|| format!("Failed to cleanup workspace for task '{}'", task.name))?;
-
-        if !cleaned_up {
+        if let Some(ref stats) = result.stats {
             info!(
-                "Workspace retained for debugging: {}",
-                workspace.path().display()
+                "Task '{}': {}/{} passed, duration mean {:.1}s (min {:.1}s, max {:.1}s), iterations mean {:.1}",
+                task.name,
+                stats.passed,
+                stats.runs,
+                stats.duration_mean_secs,
+                stats.duration_min_secs,
+                stats.duration_max_secs,
+                stats.iterations_mean
             );
         }
-
-        // Record task result
-        results.push(TaskResult::new(
-            task.name.clone(),
-            iterations,
-            task.expected_iterations,
-            duration_secs,
-            termination_reason,
-            verification_result.passed,
-            workspace.path().to_string_lossy().to_string(),
-        ));
+        results.push(result);
     }
 
+    let regressed: Vec<_> = results
+        .iter()
+        .filter(|r| r.iteration_regression)
+        .map(|r| r.name.clone())
+        .collect();
+
     // Write results if output specified
     if let Some(output_path) = output {
+        let now = Utc::now();
         let results_json = BenchmarkResults {
-            run_id: format!("bench-{}", chrono_timestamp()),
-            timestamp: chrono_timestamp(),
+            run_id: format!("bench-{}", chrono_timestamp(&now)),
+            timestamp: now.to_rfc3339_opts(SecondsFormat::Secs, true),
             tasks: results,
         };
 
@@ -318,31 +388,127 @@ async fn cmd_run(
         info!("Results written to: {:?}", output_path);
     }
 
+    if fail_on_regression && !regressed.is_empty() {
+        anyhow::bail!(
+            "{} task(s) exceeded expected iterations by more than {}: {}",
+            regressed.len(),
+            iteration_tolerance,
+            regressed.join(", ")
+        );
+    }
+
     Ok(())
 }
 
+/// Run one task once in a fresh workspace, verify it, and apply the cleanup policy
+async fn run_task_once(
+    task: &ralph_core::TaskDefinition,
+    manager: &WorkspaceManager,
+    tasks_dir: &std::path::Path,
+    record_path: Option<&PathBuf>,
+    record_ux: bool,
+    replay_path: Option<&std::path::Path>,
+) -> Result<TaskRun> {
+    // Create workspace
+    let workspace = manager
+        .create_workspace(task)
+        .with_context(|| format!("Failed to create workspace for task '{}'", task.name))?;
+
+    // Setup workspace with task files
+    workspace
+        .setup(task, tasks_dir)
+        .with_context(|| format!("Failed to setup workspace for task '{}'", task.name))?;
+
+    info!("Workspace created at: {}", workspace.path().display());
+
+    // Track timing
+    let task_start = std::time::Instant::now();
+
+    // Run the orchestration loop for this task, live or from a recording
+    let (iterations, termination_reason) = match replay_path {
+        Some(events_path) => replay_task_loop(task, &workspace, events_path, record_path),
+        None => run_task_loop(task, &workspace, record_path, record_ux).await,
+    }
+    .with_context(|| format!("Failed to run task '{}'", task.name))?;
+
+    // Run verification command against the workspace the loop left behind
+    let verification_result = workspace
+        .run_verification(&task.verification)
+        .with_context(|| format!("Failed to run verification for task '{}'", task.name))?;
+
+    if verification_result.passed {
+        info!(
+            "Task '{}' verification: {}",
+            task.name,
+            verification_result.summary()
+        );
+    } else {
+        tracing::warn!(
+            "Task '{}' verification: {}\nstderr: {}",
+            task.name,
+            verification_result.summary(),
+            verification_result.stderr.trim()
+        );
+    }
+
+    let duration_secs = task_start.elapsed().as_secs_f64();
+
+    // Apply cleanup policy based on verification result
+    let mut workspace = workspace;
+    let cleaned_up = manager
+        .apply_cleanup(&mut workspace, verification_result.passed)
+        .with_context(|| format!("Failed to cleanup workspace for task '{}'", task.name))?;
+
+    if !cleaned_up {
+        info!(
+            "Workspace retained for debugging: {}",
+            workspace.path().display()
+        );
+    }
+
+    Ok(TaskRun {
+        iterations,
+        duration_secs,
+        termination_reason,
+        verification_passed: verification_result.passed,
+        workspace_path: workspace.path().to_string_lossy().to_string(),
+    })
+}
+
+/// File name suffix for one of `repeat` runs (empty for a single run)
+fn run_suffix(run: u32, repeat: u32) -> String {
+    if repeat > 1 {
+        format!("-run{}", run)
+    } else {
+        String::new()
+    }
+}
+
+/// Insert a run suffix before the file extension
+fn with_run_suffix(path: &std::path::Path, suffix: &str) -> PathBuf {
+    if suffix.is_empty() {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{}{}.{}", stem, suffix, ext.to_string_lossy()),
+        None => format!("{}{}", stem, suffix),
+    };
+    path.with_file_name(name)
+}
+
 /// Run the orchestration loop for a single benchmark task.
 ///
-/// Returns (iterations, termination_reason) tuple.
+/// Returns (iterations, termination_reason) tuple. The reason is `None` if
+/// no backend was available and the loop never ran.
 async fn run_task_loop(
     task: &ralph_core::TaskDefinition,
     workspace: &ralph_core::TaskWorkspace,
     record_path: Option<&PathBuf>,
     record_ux: bool,
-) -> Result<(u32, String)> {
-    use ralph_core::{Record, SessionRecorder};
-    use std::sync::Arc;
-
-    // Read the prompt file from the workspace (it was copied there during setup)
-    let prompt_path = workspace.path().join("PROMPT.md");
-    let prompt_content = std::fs::read_to_string(&prompt_path)
-        .with_context(|| format!("Failed to read prompt file: {:?}", prompt_path))?;
-
-    // Build config for this task from task definition
-    let mut config = RalphConfig::default();
-    config.event_loop.max_iterations = task.max_iterations;
-    config.event_loop.completion_promise = task.completion_promise.clone();
-    config.event_loop.max_runtime_seconds = task.timeout_seconds;
+) -> Result<(u32, Option<TerminationReason>)> {
+    let prompt_content = read_task_prompt(workspace)?;
+    let mut config = task_config(task, workspace);
 
     // Auto-detect backend
     let priority = config.get_agent_priority();
@@ -356,40 +522,22 @@ async fn run_task_loop(
             config.cli.backend = backend_name;
         }
         Err(e) => {
-            // If no backend available, return NotRun
+            // If no backend available, the loop never runs
             warn!("No backend available: {}", e);
-            return Ok((0, "NoBackend".to_string()));
+            return Ok((0, None));
         }
     }
 
     // Initialize event loop
-    let mut event_loop = EventLoop::new(config.clone());
+    let mut event_loop = task_event_loop(&config, workspace);
     event_loop.initialize(&prompt_content);
 
-    // Create CLI executor
+    // Create CLI executor; the agent runs inside the workspace
     let backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
-    let executor = CliExecutor::new(backend);
+    let executor = CliExecutor::new(backend).with_workspace_root(workspace.path());
 
     // Setup session recording if requested
-    let recorder: Option<Arc<SessionRecorder<BufWriter<File>>>> =
-        if let Some(record_path) = record_path {
-            let file = File::create(record_path)
-                .with_context(|| format!("Failed to create recording file: {:?}", record_path))?;
-            let recorder = Arc::new(SessionRecorder::new(BufWriter::new(file)));
-            recorder.record_meta(Record::meta_loop_start(
-                &config.event_loop.prompt_file,
-                config.event_loop.max_iterations,
-                Some("cli"),
-            ));
-
-            // Wire observer to EventBus so events are recorded
-            let observer = SessionRecorder::make_observer(Arc::clone(&recorder));
-            event_loop.add_observer(observer);
-
-            Some(recorder)
-        } else {
-            None
-        };
+    let recorder = attach_recorder(&mut event_loop, &config, record_path, "cli")?;
 
     // Determine if we should capture UX events (requires both flag and recorder)
     let should_capture_ux = record_ux && recorder.is_some();
@@ -399,9 +547,7 @@ async fn run_task_loop(
         task.name, config.event_loop.max_iterations
     );
 
-    // Change to workspace directory for execution
-    let original_dir = std::env::current_dir()?;
-    std::env::set_current_dir(workspace.path())?;
+    let task_start = std::time::Instant::now();
 
     // Main orchestration loop
     let termination_reason: TerminationReason;
@@ -458,9 +604,12 @@ async fn run_task_loop(
         };
 
         // Execute the prompt (capture output but don't print to stdout)
-        // Get per-adapter timeout from config
-        let timeout_secs = config.adapter_settings(&config.cli.backend).timeout;
-        let timeout = Some(Duration::from_secs(timeout_secs));
+        // Per-adapter timeout, capped by what is left of the task's timeout
+        let timeout = Some(execution_timeout(
+            config.adapter_settings(&config.cli.backend).timeout,
+            task.timeout_seconds,
+            task_start.elapsed(),
+        ));
 
         // Execute with optional UX capture
         let result = if should_capture_ux {
@@ -502,37 +651,224 @@ async fn run_task_loop(
         }
     }
 
-    // Restore original directory
-    std::env::set_current_dir(original_dir)?;
-
     let state = event_loop.state();
     let iterations = state.iteration;
-    let reason_str = format_termination_reason(&termination_reason);
-
     info!(
         "Task '{}' completed: {} iterations, reason: {}",
-        task.name, iterations, reason_str
+        task.name, iterations, termination_reason
     );
 
-    Ok((iterations, reason_str))
+    Ok((iterations, Some(termination_reason)))
+}
+
+/// Reads the task prompt from the workspace (it was copied there during setup)
+fn read_task_prompt(workspace: &ralph_core::TaskWorkspace) -> Result<String> {
+    let prompt_path = workspace.path().join("PROMPT.md");
+    fs::read_to_string(&prompt_path)
+        .with_context(|| format!("Failed to read prompt file: {:?}", prompt_path))
+}
+
+/// Builds the loop config for a task from its definition, rooted at the
+/// task's workspace
+fn task_config(
+    task: &ralph_core::TaskDefinition,
+    workspace: &ralph_core::TaskWorkspace,
+) -> RalphConfig {
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = workspace.path().to_path_buf();
+    config.event_loop.max_iterations = task.max_iterations;
+    config.event_loop.completion_promise = task.completion_promise.clone();
+    config.event_loop.max_runtime_seconds = task.timeout_seconds;
+    config
+}
+
+/// Creates the event loop for a task, resolving `.ralph/` state against the
+/// workspace rather than the process working directory
+fn task_event_loop(config: &RalphConfig, workspace: &ralph_core::TaskWorkspace) -> EventLoop {
+    let context = LoopContext::primary(workspace.path().to_path_buf());
+    EventLoop::with_context(config.clone(), context)
+}
+
+/// Creates a session recorder at `record_path` (if given) and wires it to the
+/// loop's EventBus so published events are recorded.
+fn attach_recorder(
+    event_loop: &mut EventLoop,
+    config: &RalphConfig,
+    record_path: Option<&PathBuf>,
+    adapter: &str,
+) -> Result<Option<Arc<SessionRecorder<BufWriter<File>>>>> {
+    let Some(record_path) = record_path else {
+        return Ok(None);
+    };
+
+    let file = File::create(record_path)
+        .with_context(|| format!("Failed to create recording file: {:?}", record_path))?;
+    let recorder = Arc::new(SessionRecorder::new(BufWriter::new(file)));
+    recorder.record_meta(Record::meta_loop_start(
+        &config.event_loop.prompt_file,
+        config.event_loop.max_iterations,
+        Some(adapter),
+    ));
+
+    let observer = SessionRecorder::make_observer(Arc::clone(&recorder));
+    event_loop.add_observer(observer);
+
+    Ok(Some(recorder))
 }
 
-/// Format a TerminationReason into a human-readable string for results output.
-fn format_termination_reason(reason: &TerminationReason) -> String {
-    match reason {
-        TerminationReason::CompletionPromise => "CompletionPromise".to_string(),
-        TerminationReason::MaxIterations => "MaxIterations".to_string(),
-        TerminationReason::MaxRuntime => "MaxRuntime".to_string(),
-        TerminationReason::MaxCost => "MaxCost".to_string(),
-        TerminationReason::ConsecutiveFailures => "ConsecutiveFailures".to_string(),
-        TerminationReason::LoopThrashing => "LoopThrashing".to_string(),
-        TerminationReason::LoopStale => "LoopStale".to_string(),
-        TerminationReason::ValidationFailure => "ValidationFailure".to_string(),
-        TerminationReason::Stopped => "Stopped".to_string(),
-        TerminationReason::Interrupted => "Interrupted".to_string(),
-        TerminationReason::RestartRequested => "RestartRequested".to_string(),
-        TerminationReason::Cancelled => "Cancelled".to_string(),
+/// Re-drives the orchestration loop for a task from a recorded `events.jsonl`.
+///
+/// No backend is called: each iteration appends the next recorded chunk (see
+/// [`split_recorded_iterations`]) to the workspace's `.ralph/events.jsonl` and
+/// reads it back through `process_events_from_jsonl`, in the same order the
+/// live runner does. The loop ends when a termination condition is hit or
+/// the recording runs out, so results depend only on the recording.
+///
+/// Returns (iterations, termination_reason) tuple.
+fn replay_task_loop(
+    task: &ralph_core::TaskDefinition,
+    workspace: &ralph_core::TaskWorkspace,
+    events_path: &std::path::Path,
+    record_path: Option<&PathBuf>,
+) -> Result<(u32, Option<TerminationReason>)> {
+    let recorded = fs::read_to_string(events_path)
+        .with_context(|| format!("Failed to read recorded events: {:?}", events_path))?;
+    let mut recorded_iterations = split_recorded_iterations(&recorded).into_iter();
+
+    let prompt_content = read_task_prompt(workspace)?;
+    let config = task_config(task, workspace);
+
+    info!(
+        "Replaying task '{}' from {:?} ({} recorded iterations)",
+        task.name,
+        events_path,
+        recorded_iterations.len()
+    );
+
+    let context = LoopContext::primary(workspace.path().to_path_buf());
+    let replay_events = context.events_path();
+    fs::create_dir_all(context.ralph_dir()).context("Failed to create .ralph directory")?;
+    File::create(&replay_events)
+        .with_context(|| format!("Failed to create {:?}", replay_events))?;
+
+    let mut event_loop = EventLoop::with_context(config.clone(), context);
+    event_loop.initialize(&prompt_content);
+    let _recorder = attach_recorder(&mut event_loop, &config, record_path, "replay")?;
+
+    let mut consecutive_fallbacks: u32 = 0;
+    const MAX_FALLBACK_ATTEMPTS: u32 = 3;
+
+    let termination_reason = loop {
+        if let Some(reason) = event_loop.check_termination() {
+            break reason;
+        }
+
+        let hat_id = match event_loop.next_hat() {
+            Some(id) => {
+                consecutive_fallbacks = 0;
+                id.clone()
+            }
+            None => {
+                consecutive_fallbacks += 1;
+                if consecutive_fallbacks <= MAX_FALLBACK_ATTEMPTS
+                    && event_loop.inject_fallback_event()
+                {
+                    continue;
+                }
+                warn!("No hats with pending events, terminating replay");
+                break TerminationReason::Stopped;
+            }
+        };
+
+        let Some(chunk) = recorded_iterations.next() else {
+            info!("Recorded events exhausted, terminating replay");
+            break TerminationReason::Stopped;
+        };
+
+        // Building the prompt consumes the hat's pending events, as it would live
+        let _ = event_loop.build_prompt(&hat_id);
+
+        let mut events_file = fs::OpenOptions::new()
+            .append(true)
+            .open(&replay_events)
+            .with_context(|| format!("Failed to open {:?}", replay_events))?;
+        io::Write::write_all(&mut events_file, chunk.as_bytes())
+            .with_context(|| format!("Failed to write {:?}", replay_events))?;
+
+        if let Some(reason) = event_loop.process_output(&hat_id, "", true) {
+            break reason;
+        }
+
+        let wrote_events = event_loop
+            .process_events_from_jsonl()
+            .context("Failed to read replayed events")?;
+        if !wrote_events {
+            let active_hats = event_loop.state().last_active_hat_ids.clone();
+            for active_hat_id in &active_hats {
+                event_loop.check_default_publishes(active_hat_id);
+                if event_loop.has_pending_events() {
+                    break;
+                }
+            }
+        }
+
+        if let Some(reason) = event_loop.check_cancellation_event() {
+            break reason;
+        }
+        if let Some(reason) = event_loop.check_completion_event() {
+            break reason;
+        }
+    };
+
+    let iterations = event_loop.state().iteration;
+    info!(
+        "Task '{}' replayed: {} iterations, reason: {}",
+        task.name, iterations, termination_reason
+    );
+
+    Ok((iterations, Some(termination_reason)))
+}
+
+/// Splits a recorded `events.jsonl` into the lines written during each
+/// iteration, using the runner's `loop.iteration` records as boundaries.
+///
+/// A recording without boundary records replays as a single iteration.
+fn split_recorded_iterations(contents: &str) -> Vec<String> {
+    let mut iterations = Vec::new();
+    let mut current = String::new();
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        current.push_str(line);
+        current.push('\n');
+
+        let is_boundary = serde_json::from_str::<serde_json::Value>(line)
+            .ok()
+            .is_some_and(|record| record["topic"] == LOOP_ITERATION_TOPIC);
+        if is_boundary {
+            iterations.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.is_empty() {
+        iterations.push(current);
     }
+
+    iterations
+}
+
+/// Timeout for one agent execution: the adapter timeout, capped by the time
+/// left before the task's own timeout (0 = no task timeout).
+fn execution_timeout(
+    adapter_timeout_secs: u64,
+    task_timeout_secs: u64,
+    elapsed: Duration,
+) -> Duration {
+    let adapter_timeout = Duration::from_secs(adapter_timeout_secs);
+    if task_timeout_secs == 0 {
+        return adapter_timeout;
+    }
+    let remaining = Duration::from_secs(task_timeout_secs).saturating_sub(elapsed);
+    adapter_timeout.min(remaining)
 }
 
 /// Replay a recorded session
@@ -541,6 +877,7 @@ fn cmd_replay(
     ux_mode: UxMode,
     speed: f32,
     step: bool,
+    interactive: bool,
     filter: Option<String>,
 ) -> Result<()> {
     // Open session file
@@ -549,7 +886,7 @@ fn cmd_replay(
 
     // Create player
     let mut player = SessionPlayer::from_reader(BufReader::new(file))
-        .with_context(|| "Failed to parse session file")?;
+        .with_context(|| format!("Failed to parse session file: {:?}", session_path))?;
 
     info!(
         "Loaded {} records from {:?}",
@@ -562,6 +899,7 @@ fn cmd_replay(
     config.replay_mode = ux_mode.into();
     config.speed = speed;
     config.step_mode = step;
+    config.interactive = interactive;
 
     if let Some(f) = filter {
         config.event_filter = f.split(',').map(|s| s.trim().to_string()).collect();
@@ -578,10 +916,55 @@ fn cmd_replay(
     Ok(())
 }
 
+/// Validate a recorded session, reporting every line that fails to parse
+fn cmd_validate(session_path: PathBuf) -> Result<()> {
+    let file = File::open(&session_path)
+        .with_context(|| format!("Failed to open session file: {:?}", session_path))?;
+
+    let validation = SessionPlayer::validate(BufReader::new(file))
+        .with_context(|| format!("Failed to read session file: {:?}", session_path))?;
+
+    println!("{} valid records", validation.valid_records);
+    for invalid in &validation.invalid_lines {
+        println!("  {}", invalid);
+    }
+
+    if !validation.is_valid() {
+        anyhow::bail!(
+            "{} invalid line(s) in {:?}",
+            validation.invalid_lines.len(),
+            session_path
+        );
+    }
+    Ok(())
+}
+
+/// Parses an age such as `7d`, `12h`, `30m` or `45s` into a duration.
+fn parse_age(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s.len() - s.chars().last().map_or(0, char::len_utf8);
+    let (value, unit) = s.split_at(split);
+    let invalid = || format!("invalid age '{}': expected e.g. 7d, 12h, 30m", s);
+    let value: u64 = value.parse().map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        "d" => 24 * 60 * 60,
+        "h" => 60 * 60,
+        "m" => 60,
+        "s" => 1,
+        _ => return Err(format!("invalid age unit in '{}': use d, h, m or s", s)),
+    };
+    let secs = value.checked_mul(unit_secs).ok_or_else(invalid)?;
+    Ok(Duration::from_secs(secs))
+}
+
 /// List sessions or workspaces
-fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
+fn cmd_list(what: ListTarget, dir: Option<PathBuf>, prune: Option<Duration>) -> Result<()> {
     let search_dir = dir.unwrap_or_else(|| PathBuf::from("."));
 
+    if prune.is_some() && what != ListTarget::Workspaces {
+        anyhow::bail!("--prune only applies to workspaces");
+    }
+
     match what {
         ListTarget::Sessions => {
             // List .jsonl files
@@ -616,6 +999,10 @@ fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
         ListTarget::Workspaces => {
             // List ralph-bench-* directories
             let manager = WorkspaceManager::new(&search_dir, CleanupPolicy::Never);
+            if let Some(max_age) = prune {
+                let pruned = manager.prune_older_than(max_age)?;
+                println!("Pruned {} stale workspace(s)", pruned);
+            }
             let workspaces = manager.list_workspaces()?;
 
             if workspaces.is_empty() {
@@ -637,8 +1024,208 @@ fn cmd_list(what: ListTarget, dir: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
-/// Task execution result
+/// Compare a candidate benchmark run against a baseline.
+///
+/// Fails if any task that passed in the baseline fails in the candidate.
+fn cmd_compare(baseline_path: PathBuf, candidate_path: PathBuf, json: bool) -> Result<()> {
+    let baseline = load_results(&baseline_path)?;
+    let candidate = load_results(&candidate_path)?;
+    let comparisons = compare_results(&baseline, &candidate);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&comparisons)?);
+    } else {
+        print_comparison(&comparisons);
+    }
+
+    let regressed = comparisons
+        .iter()
+        .filter(|c| c.transition == Transition::Regressed)
+        .count();
+    if regressed > 0 {
+        anyhow::bail!("{} task(s) regressed", regressed);
+    }
+    Ok(())
+}
+
+/// Load a results file written by `ralph-bench run --output`.
+fn load_results(path: &std::path::Path) -> Result<BenchmarkResults> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open results file: {:?}", path))?;
+    serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("Failed to parse results file: {:?}", path))
+}
+
+/// How a task's verification outcome changed between two runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Transition {
+    /// Passed in the baseline, failed in the candidate
+    Regressed,
+    /// Failed in the baseline, passed in the candidate
+    Fixed,
+    StillPassing,
+    StillFailing,
+    /// Only in the candidate
+    Added,
+    /// Only in the baseline
+    Removed,
+}
+
+impl Transition {
+    fn from_outcomes(baseline: Option<bool>, candidate: Option<bool>) -> Self {
+        match (baseline, candidate) {
+            (Some(true), Some(false)) => Self::Regressed,
+            (Some(false), Some(true)) => Self::Fixed,
+            (Some(true), Some(true)) => Self::StillPassing,
+            (Some(false), Some(false)) => Self::StillFailing,
+            (None, _) => Self::Added,
+            (Some(_), None) => Self::Removed,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Regressed => "regressed",
+            Self::Fixed => "fixed",
+            Self::StillPassing => "passing",
+            Self::StillFailing => "failing",
+            Self::Added => "added",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+/// One task's baseline vs candidate comparison
 #[derive(Debug, serde::Serialize)]
+struct TaskComparison {
+    name: String,
+    baseline_iterations: Option<u32>,
+    candidate_iterations: Option<u32>,
+    /// Candidate minus baseline iterations; `None` unless both runs have the task.
+    iteration_delta: Option<i64>,
+    /// Candidate minus baseline duration; `None` unless both runs have the task.
+    duration_delta_secs: Option<f64>,
+    transition: Transition,
+}
+
+/// Pair up tasks by name, in baseline order followed by candidate-only tasks.
+fn compare_results(
+    baseline: &BenchmarkResults,
+    candidate: &BenchmarkResults,
+) -> Vec<TaskComparison> {
+    let names = baseline.tasks.iter().map(|t| t.name.as_str()).chain(
+        candidate
+            .tasks
+            .iter()
+            .map(|t| t.name.as_str())
+            .filter(|name| find_task(baseline, name).is_none()),
+    );
+
+    names
+        .map(|name| {
+            let before = find_task(baseline, name);
+            let after = find_task(candidate, name);
+            let both = before.zip(after);
+            TaskComparison {
+                name: name.to_string(),
+                baseline_iterations: before.map(|t| t.iterations),
+                candidate_iterations: after.map(|t| t.iterations),
+                iteration_delta: both
+                    .map(|(b, a)| i64::from(a.iterations) - i64::from(b.iterations)),
+                duration_delta_secs: both.map(|(b, a)| a.duration_secs - b.duration_secs),
+                transition: Transition::from_outcomes(
+                    before.map(|t| t.verification_passed),
+                    after.map(|t| t.verification_passed),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Find a task result by name
+fn find_task<'a>(results: &'a BenchmarkResults, name: &str) -> Option<&'a TaskResult> {
+    results.tasks.iter().find(|t| t.name == name)
+}
+
+/// Print a comparison table
+fn print_comparison(comparisons: &[TaskComparison]) {
+    let width = comparisons
+        .iter()
+        .map(|c| c.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("TASK".len());
+
+    println!(
+        "{:<width$}  {:>10}  {:>12}  STATUS",
+        "TASK", "ITERATIONS", "DURATION"
+    );
+    for c in comparisons {
+        let iterations = c
+            .iteration_delta
+            .map(|d| format!("{:+}", d))
+            .unwrap_or_else(|| "-".to_string());
+        let duration = c
+            .duration_delta_secs
+            .map(|d| format!("{:+.1}s", d))
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{:<width$}  {:>10}  {:>12}  {}",
+            c.name,
+            iterations,
+            duration,
+            c.transition.as_str()
+        );
+    }
+}
+
+/// Outcome of one run of a task
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct TaskRun {
+    iterations: u32,
+    duration_secs: f64,
+    termination_reason: Option<TerminationReason>,
+    verification_passed: bool,
+    workspace_path: String,
+}
+
+/// Summary over repeated runs of a task
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct RunStats {
+    runs: usize,
+    passed: usize,
+    duration_mean_secs: f64,
+    duration_min_secs: f64,
+    duration_max_secs: f64,
+    iterations_mean: f64,
+}
+
+impl RunStats {
+    /// Summarize runs; `None` if there are none.
+    fn from_runs(runs: &[TaskRun]) -> Option<Self> {
+        if runs.is_empty() {
+            return None;
+        }
+        let count = runs.len() as f64;
+        let durations = runs.iter().map(|r| r.duration_secs);
+        Some(Self {
+            runs: runs.len(),
+            passed: runs.iter().filter(|r| r.verification_passed).count(),
+            duration_mean_secs: durations.clone().sum::<f64>() / count,
+            duration_min_secs: durations.clone().fold(f64::INFINITY, f64::min),
+            duration_max_secs: durations.fold(f64::NEG_INFINITY, f64::max),
+            iterations_mean: runs.iter().map(|r| f64::from(r.iterations)).sum::<f64>() / count,
+        })
+    }
+}
+
+/// Task execution result
+///
+/// For a repeated task (`--repeat`), the top-level fields summarize all runs:
+/// mean iterations (rounded) and duration, `verification_passed` only if every
+/// run passed, and the last run's termination reason and workspace.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct TaskResult {
     name: String,
     iterations: u32,
@@ -646,10 +1233,26 @@ struct TaskResult {
     /// Difference between actual and expected iterations (iterations - expected).
     /// Positive means more iterations than expected, negative means fewer.
     iteration_delta: Option<i32>,
+    /// True if `iteration_delta` exceeds the run's `--iteration-tolerance`.
+    #[serde(default)]
+    iteration_regression: bool,
     duration_secs: f64,
-    termination_reason: String,
+    /// `None` if the loop never ran (no backend available).
+    termination_reason: Option<TerminationReason>,
+    /// Termination reason the task declared as expected, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expected_termination: Option<TerminationReason>,
+    /// True if any run ended with a reason other than `expected_termination`.
+    #[serde(default)]
+    termination_mismatch: bool,
     verification_passed: bool,
     workspace_path: String,
+    /// Summary stats when the task ran more than once.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stats: Option<RunStats>,
+    /// Individual runs when the task ran more than once.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    runs: Vec<TaskRun>,
 }
 
 impl TaskResult {
@@ -659,7 +1262,7 @@ impl TaskResult {
         iterations: u32,
         expected_iterations: Option<u32>,
         duration_secs: f64,
-        termination_reason: String,
+        termination_reason: Option<TerminationReason>,
         verification_passed: bool,
         workspace_path: String,
     ) -> Self {
@@ -671,88 +1274,415 @@ impl TaskResult {
             iterations,
             expected_iterations,
             iteration_delta,
+            iteration_regression: false,
             duration_secs,
             termination_reason,
+            expected_termination: None,
+            termination_mismatch: false,
             verification_passed,
             workspace_path,
+            stats: None,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Record the task's expected termination reason and whether any run
+    /// ended differently.
+    fn with_expected_termination(mut self, task: &ralph_core::TaskDefinition) -> Self {
+        self.termination_mismatch = if self.runs.is_empty() {
+            task.termination_mismatch(self.termination_reason.as_ref())
+        } else {
+            self.runs
+                .iter()
+                .any(|run| task.termination_mismatch(run.termination_reason.as_ref()))
+        };
+        self.expected_termination = task.expected_termination.clone();
+        self
+    }
+
+    /// Mark the task as a regression if it took more than `tolerance`
+    /// iterations over `expected_iterations`.
+    fn with_iteration_tolerance(mut self, tolerance: u32) -> Self {
+        self.iteration_regression = self
+            .iteration_delta
+            .is_some_and(|delta| i64::from(delta) > i64::from(tolerance));
+        self
+    }
+
+    /// `expected N, got M (Δ+K)` when the iterations differ from the
+    /// expectation; `None` if they match or no expectation was set.
+    fn iteration_summary(&self) -> Option<String> {
+        let expected = self.expected_iterations?;
+        let delta = self.iteration_delta.filter(|&delta| delta != 0)?;
+        Some(format!(
+            "expected {}, got {} (Δ{:+})",
+            expected, self.iterations, delta
+        ))
+    }
+
+    /// Create a TaskResult from one or more runs of the same task.
+    fn from_runs(name: String, expected_iterations: Option<u32>, runs: Vec<TaskRun>) -> Self {
+        let Some(stats) = RunStats::from_runs(&runs).filter(|stats| stats.runs > 1) else {
+            let run = runs.into_iter().next().expect("at least one run");
+            return Self::new(
+                name,
+                run.iterations,
+                expected_iterations,
+                run.duration_secs,
+                run.termination_reason,
+                run.verification_passed,
+                run.workspace_path,
+            );
+        };
+
+        let last = runs.last().expect("at least one run").clone();
+        Self {
+            stats: Some(stats.clone()),
+            runs,
+            ..Self::new(
+                name,
+                stats.iterations_mean.round() as u32,
+                expected_iterations,
+                stats.duration_mean_secs,
+                last.termination_reason,
+                stats.passed == stats.runs,
+                last.workspace_path,
+            )
         }
     }
 }
 
 /// Benchmark results output
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct BenchmarkResults {
     run_id: String,
+    /// When the run finished, as an RFC 3339 UTC timestamp.
     timestamp: String,
     tasks: Vec<TaskResult>,
 }
 
-/// Generate a timestamp string
-fn chrono_timestamp() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
+/// Format a time as a compact `YYYYMMDD-HHMMSS` run identifier
+fn chrono_timestamp(at: &DateTime<Utc>) -> String {
+    at.format("%Y%m%d-%H%M%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chrono_timestamp_format() {
+        let ts = chrono_timestamp(&Utc::now());
+        // Should be YYYYMMDD-HHMMSS format (15 characters)
+        assert_eq!(ts.len(), 15);
+        assert_eq!(&ts[8..9], "-");
 
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default();
+        let leap_day = DateTime::parse_from_rfc3339("2024-02-29T23:59:58Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(chrono_timestamp(&leap_day), "20240229-235958");
+    }
 
-    // Format: YYYYMMDD-HHMMSS
-    let secs = now.as_secs();
-    let days = secs / 86400;
-    let time_of_day = secs % 86400;
-    let hours = time_of_day / 3600;
-    let minutes = (time_of_day % 3600) / 60;
-    let seconds = time_of_day % 60;
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("7d"), Ok(Duration::from_hours(7 * 24)));
+        assert_eq!(parse_age("12h"), Ok(Duration::from_hours(12)));
+        assert_eq!(parse_age("30m"), Ok(Duration::from_mins(30)));
+        assert_eq!(parse_age("45s"), Ok(Duration::from_secs(45)));
+        assert!(parse_age("7").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("7w").is_err());
+        assert!(parse_age("7é").is_err());
+        assert!(
+            parse_age(&format!("{}d", u64::MAX))
+                .unwrap_err()
+                .starts_with("invalid age '")
+        );
+    }
 
-    // Approximate date calculation (not accounting for leap years perfectly)
-    let mut year = 1970;
-    let mut remaining_days = days;
+    #[test]
+    fn test_execution_timeout_capped_by_task_timeout() {
+        // Plenty of task time left: the adapter timeout applies
+        assert_eq!(
+            execution_timeout(90, 300, Duration::from_secs(10)),
+            Duration::from_secs(90)
+        );
+        // Less task time left than the adapter allows
+        assert_eq!(
+            execution_timeout(90, 300, Duration::from_secs(280)),
+            Duration::from_secs(20)
+        );
+        // Task timeout already exceeded
+        assert_eq!(
+            execution_timeout(90, 300, Duration::from_secs(400)),
+            Duration::ZERO
+        );
+        // No task timeout
+        assert_eq!(
+            execution_timeout(90, 0, Duration::from_secs(400)),
+            Duration::from_secs(90)
+        );
+    }
 
-    loop {
-        let days_in_year = if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-            366
-        } else {
-            365
-        };
-        if remaining_days < days_in_year {
-            break;
+    fn results(tasks: &[(&str, u32, f64, bool)]) -> BenchmarkResults {
+        BenchmarkResults {
+            run_id: "bench-test".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            tasks: tasks
+                .iter()
+                .map(|&(name, iterations, duration_secs, passed)| {
+                    TaskResult::new(
+                        name.to_string(),
+                        iterations,
+                        None,
+                        duration_secs,
+                        Some(TerminationReason::CompletionPromise),
+                        passed,
+                        String::new(),
+                    )
+                })
+                .collect(),
         }
-        remaining_days -= days_in_year;
-        year += 1;
     }
 
-    let days_in_months = if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
+    #[test]
+    fn test_compare_results_reports_deltas_and_transitions() {
+        let baseline = results(&[
+            ("hello", 3, 10.0, true),
+            ("fizzbuzz", 5, 20.0, false),
+            ("dropped", 1, 1.0, true),
+        ]);
+        let candidate = results(&[
+            ("fizzbuzz", 4, 15.5, true),
+            ("hello", 4, 12.5, false),
+            ("new", 2, 3.0, true),
+        ]);
+
+        let comparisons = compare_results(&baseline, &candidate);
+
+        let summary: Vec<_> = comparisons
+            .iter()
+            .map(|c| (c.name.as_str(), c.iteration_delta, c.transition))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("hello", Some(1), Transition::Regressed),
+                ("fizzbuzz", Some(-1), Transition::Fixed),
+                ("dropped", None, Transition::Removed),
+                ("new", None, Transition::Added),
+            ]
+        );
+        assert!((comparisons[0].duration_delta_secs.unwrap() - 2.5).abs() < f64::EPSILON);
+        assert!((comparisons[1].duration_delta_secs.unwrap() + 4.5).abs() < f64::EPSILON);
+    }
 
-    let mut month = 1;
-    for days_in_month in days_in_months {
-        if remaining_days < days_in_month {
-            break;
+    #[test]
+    fn test_compare_results_json_round_trip() {
+        let baseline = results(&[("hello", 3, 10.0, true)]);
+        let json = serde_json::to_string(&baseline).unwrap();
+        let loaded: BenchmarkResults = serde_json::from_str(&json).unwrap();
+
+        let comparisons = compare_results(&loaded, &baseline);
+        assert_eq!(comparisons[0].transition, Transition::StillPassing);
+
+        let value = serde_json::to_value(&comparisons).unwrap();
+        assert_eq!(value[0]["transition"], "still_passing");
+        assert_eq!(value[0]["iteration_delta"], 0);
+    }
+
+    fn run(iterations: u32, duration_secs: f64, passed: bool) -> TaskRun {
+        TaskRun {
+            iterations,
+            duration_secs,
+            termination_reason: Some(TerminationReason::CompletionPromise),
+            verification_passed: passed,
+            workspace_path: format!("/tmp/ws-{}", iterations),
         }
-        remaining_days -= days_in_month;
-        month += 1;
     }
 
-    let day = remaining_days + 1;
+    #[test]
+    fn test_task_result_from_repeated_runs() {
+        let result = TaskResult::from_runs(
+            "hello".to_string(),
+            Some(3),
+            vec![run(2, 10.0, true), run(4, 14.0, false), run(3, 12.0, true)],
+        );
+
+        let stats = result.stats.as_ref().unwrap();
+        assert_eq!((stats.passed, stats.runs), (2, 3));
+        assert!((stats.duration_mean_secs - 12.0).abs() < f64::EPSILON);
+        assert!((stats.duration_min_secs - 10.0).abs() < f64::EPSILON);
+        assert!((stats.duration_max_secs - 14.0).abs() < f64::EPSILON);
+        assert!((stats.iterations_mean - 3.0).abs() < f64::EPSILON);
+
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.iteration_delta, Some(0));
+        assert!(!result.verification_passed, "one run failed");
+        assert_eq!(result.workspace_path, "/tmp/ws-3");
+        assert_eq!(result.runs.len(), 3);
+    }
 
-    format!(
-        "{:04}{:02}{:02}-{:02}{:02}{:02}",
-        year, month, day, hours, minutes, seconds
-    )
-}
+    #[test]
+    fn test_iteration_delta_surfaced_in_summary_and_json() {
+        let over = TaskResult::from_runs("slow".to_string(), Some(3), vec![run(5, 10.0, true)]);
+        assert_eq!(over.iteration_delta, Some(2));
+        assert_eq!(
+            over.iteration_summary().as_deref(),
+            Some("expected 3, got 5 (Δ+2)")
+        );
+
+        let under = TaskResult::from_runs("fast".to_string(), Some(3), vec![run(2, 10.0, true)]);
+        assert_eq!(
+            under.iteration_summary().as_deref(),
+            Some("expected 3, got 2 (Δ-1)")
+        );
+
+        let exact = TaskResult::from_runs("exact".to_string(), Some(3), vec![run(3, 10.0, true)]);
+        assert!(exact.iteration_summary().is_none());
+        let unset = TaskResult::from_runs("unset".to_string(), None, vec![run(3, 10.0, true)]);
+        assert!(unset.iteration_summary().is_none());
+
+        let json = serde_json::to_value(over.with_iteration_tolerance(0)).unwrap();
+        assert_eq!(json["iteration_delta"], 2);
+        assert_eq!(json["iteration_regression"], true);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_iteration_tolerance_marks_regressions() {
+        let result = |iterations| {
+            TaskResult::from_runs("t".to_string(), Some(3), vec![run(iterations, 1.0, true)])
+        };
+
+        assert!(!result(3).with_iteration_tolerance(0).iteration_regression);
+        assert!(!result(2).with_iteration_tolerance(0).iteration_regression);
+        assert!(result(4).with_iteration_tolerance(0).iteration_regression);
+        assert!(!result(4).with_iteration_tolerance(1).iteration_regression);
+        assert!(result(5).with_iteration_tolerance(1).iteration_regression);
+
+        let no_expectation = TaskResult::from_runs("t".to_string(), None, vec![run(9, 1.0, true)]);
+        assert!(
+            !no_expectation
+                .with_iteration_tolerance(0)
+                .iteration_regression
+        );
+    }
 
     #[test]
-    fn test_chrono_timestamp_format() {
-        let ts = chrono_timestamp();
-        // Should be YYYYMMDD-HHMMSS format (15 characters)
-        assert_eq!(ts.len(), 15);
-        assert_eq!(&ts[8..9], "-");
+    fn test_task_result_from_single_run_has_no_stats() {
+        let result = TaskResult::from_runs("hello".to_string(), None, vec![run(2, 10.0, true)]);
+
+        assert!(result.stats.is_none());
+        assert!(result.runs.is_empty());
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("stats").is_none());
+        assert!(json.get("runs").is_none());
+    }
+
+    #[test]
+    fn test_task_result_flags_termination_mismatch() {
+        let task = ralph_core::TaskDefinition::builder("hello", "PROMPT.md", "DONE")
+            .verification_command("true")
+            .expected_termination(TerminationReason::CompletionPromise)
+            .build();
+
+        // Verification passed, but the loop hit max iterations
+        let mut hit_limit = run(5, 10.0, true);
+        hit_limit.termination_reason = Some(TerminationReason::MaxIterations);
+        let result = TaskResult::from_runs("hello".to_string(), None, vec![hit_limit.clone()])
+            .with_expected_termination(&task);
+        assert!(result.verification_passed);
+        assert!(result.termination_mismatch);
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["expected_termination"], "completion_promise");
+        assert_eq!(json["termination_mismatch"], true);
+
+        let result = TaskResult::from_runs("hello".to_string(), None, vec![run(2, 10.0, true)])
+            .with_expected_termination(&task);
+        assert!(!result.termination_mismatch);
+
+        // One mismatching run out of several is enough, even if the last matched
+        let result = TaskResult::from_runs(
+            "hello".to_string(),
+            None,
+            vec![hit_limit, run(2, 10.0, true)],
+        )
+        .with_expected_termination(&task);
+        assert!(result.termination_mismatch);
+    }
+
+    #[test]
+    fn test_record_paths_get_run_suffix() {
+        assert_eq!(run_suffix(1, 1), "");
+        assert_eq!(run_suffix(2, 5), "-run2");
+        assert_eq!(
+            with_run_suffix(std::path::Path::new("out/session.jsonl"), "-run2"),
+            PathBuf::from("out/session-run2.jsonl")
+        );
+        assert_eq!(
+            with_run_suffix(std::path::Path::new("session.jsonl"), ""),
+            PathBuf::from("session.jsonl")
+        );
+    }
+
+    #[test]
+    fn test_split_recorded_iterations_on_loop_iteration_records() {
+        let recorded = concat!(
+            r#"{"topic":"build.task","payload":"a","ts":"t"}"#,
+            "\n",
+            r#"{"ts":"t","iteration":1,"hat":"ralph","topic":"loop.iteration","payload":"{}"}"#,
+            "\n\n",
+            r#"{"topic":"build.done","payload":"b","ts":"t"}"#,
+            "\n",
+        );
+
+        let iterations = split_recorded_iterations(recorded);
+        assert_eq!(iterations.len(), 2);
+        assert!(iterations[0].contains("build.task"));
+        assert!(iterations[0].ends_with("\"payload\":\"{}\"}\n"));
+        assert_eq!(
+            iterations[1],
+            "{\"topic\":\"build.done\",\"payload\":\"b\",\"ts\":\"t\"}\n"
+        );
+
+        assert!(split_recorded_iterations("").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_from_recorded_events_is_deterministic() {
+        let fixtures =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/replay");
+        let base = tempfile::tempdir().unwrap();
+        let manager = WorkspaceManager::new(base.path(), CleanupPolicy::Always);
+        let task = ralph_core::TaskDefinition::builder(
+            "hello-world",
+            "hello-world/PROMPT.md",
+            "TASK_COMPLETE",
+        )
+        .verification_command("grep -q TASK_COMPLETE .ralph/events.jsonl")
+        .max_iterations(5)
+        .build();
+        let events_path = fixtures.join("hello-world/events.jsonl");
+        let cwd = std::env::current_dir().unwrap();
+
+        let first = run_task_once(&task, &manager, &fixtures, None, false, Some(&events_path))
+            .await
+            .unwrap();
+        let second = run_task_once(&task, &manager, &fixtures, None, false, Some(&events_path))
+            .await
+            .unwrap();
+
+        assert_eq!(first.iterations, 2);
+        assert_eq!(
+            first.termination_reason,
+            Some(TerminationReason::CompletionPromise)
+        );
+        assert!(first.verification_passed);
+        assert_eq!(first.iterations, second.iterations);
+        assert_eq!(first.termination_reason, second.termination_reason);
+        assert_eq!(first.verification_passed, second.verification_passed);
+        // Replay works on absolute workspace paths; the process cwd is untouched
+        assert_eq!(std::env::current_dir().unwrap(), cwd);
     }
 
     #[test]
@@ -0,0 +1,41 @@
+//! Embeds build metadata for `ralph version --verbose`.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let git_sha = command_output("git", &["rev-parse", "--short=12", "HEAD"]);
+    if let Some(git_dir) = command_output("git", &["rev-parse", "--absolute-git-dir"]) {
+        // Rebuild when HEAD moves to a new commit or branch
+        let git_dir = PathBuf::from(git_dir);
+        println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+        println!("cargo:rerun-if-changed={}", git_dir.join("refs").display());
+    }
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]);
+
+    emit("RALPH_BUILD_GIT_SHA", git_sha);
+    emit("RALPH_BUILD_RUSTC_VERSION", rustc_version);
+    emit("RALPH_BUILD_TARGET", env::var("TARGET").ok());
+    emit("RALPH_BUILD_PROFILE", env::var("PROFILE").ok());
+}
+
+fn emit(key: &str, value: Option<String>) {
+    let value = value.unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env={key}={value}");
+}
+
+/// Runs `program args...` and returns its trimmed stdout if it succeeds.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (!stdout.is_empty()).then(|| stdout.to_string())
+}
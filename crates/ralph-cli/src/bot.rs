@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 use tracing::warn;
 
 use crate::ConfigSource;
+use ralph_core::TelegramParseMode;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // CLI STRUCTS
@@ -544,7 +545,12 @@ async fn run_daemon(
     }
 
     // Build the adapter
-    let adapter = ralph_telegram::TelegramDaemon::new(token, chat_id);
+    let parse_mode = config_path
+        .as_ref()
+        .map(|path| load_config_parse_mode_from(path))
+        .unwrap_or_default();
+    let adapter = ralph_telegram::TelegramDaemon::new(token, chat_id)
+        .with_parse_mode(telegram_parse_mode(parse_mode));
 
     // Build the start_loop callback — wraps our CLI loop runner
     let start_loop: ralph_proto::StartLoopFn = Box::new(move |prompt: String| {
@@ -910,6 +916,36 @@ fn load_config_bot_token_from(path: &Path) -> Option<String> {
         .map(String::from)
 }
 
+/// Read `RObot.telegram.parse_mode` from a config file, defaulting to HTML.
+fn load_config_parse_mode_from(path: &Path) -> TelegramParseMode {
+    let Some(value) = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+        .and_then(|config| {
+            config
+                .get("RObot")
+                .or_else(|| config.get("robot"))
+                .and_then(|r| r.get("telegram"))
+                .and_then(|t| t.get("parse_mode"))
+                .cloned()
+        })
+    else {
+        return TelegramParseMode::default();
+    };
+    serde_yaml::from_value(value).unwrap_or_else(|e| {
+        warn!(error = %e, "Invalid RObot.telegram.parse_mode; using html");
+        TelegramParseMode::default()
+    })
+}
+
+/// Maps the configured parse mode to the Telegram crate's.
+pub(crate) fn telegram_parse_mode(mode: TelegramParseMode) -> ralph_telegram::ParseMode {
+    match mode {
+        TelegramParseMode::Html => ralph_telegram::ParseMode::Html,
+        TelegramParseMode::MarkdownV2 => ralph_telegram::ParseMode::MarkdownV2,
+    }
+}
+
 /// Read bot token from ralph.yml (legacy).
 fn load_config_bot_token() -> Option<String> {
     load_config_bot_token_from(Path::new("ralph.yml"))
@@ -1344,6 +1380,24 @@ mod tests {
         assert_eq!(token.as_deref(), Some("token-123"));
     }
 
+    #[test]
+    fn test_load_config_parse_mode_from_reads_mode() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("custom.yml");
+        let yaml = "RObot:\n  telegram:\n    bot_token: t\n    parse_mode: markdown_v2\n";
+        std::fs::write(&config_path, yaml).unwrap();
+        assert_eq!(
+            load_config_parse_mode_from(&config_path),
+            TelegramParseMode::MarkdownV2
+        );
+
+        std::fs::write(&config_path, "RObot:\n  telegram:\n    bot_token: t\n").unwrap();
+        assert_eq!(
+            load_config_parse_mode_from(&config_path),
+            TelegramParseMode::Html
+        );
+    }
+
     #[test]
     fn test_load_config_bot_token_from_reads_lowercase_robot() {
         let temp_dir = tempfile::tempdir().unwrap();
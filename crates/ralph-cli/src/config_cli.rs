@@ -0,0 +1,66 @@
+//! CLI commands for the `ralph config` namespace.
+//!
+//! Subcommands:
+//! - `migrate`: Rewrite a v1 flat config into v2 nested form
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use ralph_core::config_migration::migrate_v1_to_v2;
+use std::path::PathBuf;
+
+/// Configuration file commands.
+#[derive(Parser, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Convert v1 flat fields to v2 nested structure and report what moved
+    Migrate(MigrateArgs),
+}
+
+/// Arguments for `ralph config migrate`.
+#[derive(Parser, Debug)]
+pub struct MigrateArgs {
+    /// Config file to migrate
+    #[arg(long = "in", value_name = "FILE", default_value = "ralph.yml")]
+    pub input: PathBuf,
+
+    /// Where to write the v2 config (default: stdout)
+    #[arg(long = "out", value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+/// Execute a config command.
+pub fn execute(args: ConfigArgs) -> Result<()> {
+    match args.command {
+        ConfigCommands::Migrate(args) => migrate(&args),
+    }
+}
+
+fn migrate(args: &MigrateArgs) -> Result<()> {
+    let content = std::fs::read_to_string(&args.input)
+        .with_context(|| format!("Failed to read {}", args.input.display()))?;
+    let migration = migrate_v1_to_v2(&content)
+        .with_context(|| format!("Failed to migrate {}", args.input.display()))?;
+
+    match &args.output {
+        Some(path) => std::fs::write(path, &migration.yaml)
+            .with_context(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{}", migration.yaml),
+    }
+
+    // The report goes to stderr so stdout stays a clean YAML document.
+    if migration.changes.is_empty() {
+        eprintln!("{} is already in v2 format", args.input.display());
+    } else {
+        eprintln!("Migrated {}:", args.input.display());
+        for change in &migration.changes {
+            eprintln!("  - {change}");
+        }
+    }
+
+    Ok(())
+}
@@ -4,8 +4,9 @@
 //! iteration separators, termination messages, event tables,
 //! and other terminal UI elements.
 
-use ralph_core::{EventRecord, TerminationReason};
+use ralph_core::{EventRecord, EventSeverity, EventStats, NameCount, TerminationReason};
 use ralph_proto::HatId;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -147,10 +148,12 @@ pub fn print_termination(
         TerminationReason::LoopThrashing => (RED, "?", "Loop thrashing detected"),
         TerminationReason::LoopStale => (RED, "?", "Stale loop detected"),
         TerminationReason::ValidationFailure => (RED, "?", "Too many malformed JSONL events"),
+        TerminationReason::HookFailed => (RED, "?", "Iteration hook failed"),
         TerminationReason::Stopped => (CYAN, "?", "Manually stopped"),
         TerminationReason::Interrupted => (YELLOW, "?", "Interrupted by signal"),
         TerminationReason::RestartRequested => (CYAN, "↻", "Restarting by human request"),
         TerminationReason::Cancelled => (CYAN, "⏹", "Cancelled gracefully"),
+        TerminationReason::SingleIteration => (CYAN, "1", "Single iteration completed"),
     };
 
     let separator = "-".repeat(58);
@@ -161,6 +164,7 @@ pub fn print_termination(
             "{BOLD}|{RESET} {color}{BOLD}{icon}{RESET} Loop terminated: {color}{label}{RESET}"
         );
         println!("{BOLD}+{separator}+{RESET}");
+        println!("{BOLD}|{RESET}   Reason:      {CYAN}{reason}{RESET}");
         println!(
             "{BOLD}|{RESET}   Iterations:  {CYAN}{}{RESET}",
             state.iteration
@@ -180,6 +184,7 @@ pub fn print_termination(
         println!("\n+{}+", "-".repeat(58));
         println!("| {icon} Loop terminated: {label}");
         println!("+{}+", "-".repeat(58));
+        println!("|   Reason:      {reason}");
         println!("|   Iterations:  {}", state.iteration);
         println!("|   Elapsed:     {:.1}s", state.elapsed().as_secs_f64());
         if state.cumulative_cost > 0.0 {
@@ -207,92 +212,254 @@ pub fn get_topic_color(topic: &str) -> &'static str {
     }
 }
 
-/// Prints a table of event records.
-pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
+/// An event record tagged with the log file it was read from.
+#[derive(Debug, Serialize)]
+pub struct SourcedRecord {
+    #[serde(flatten)]
+    pub record: EventRecord,
+
+    /// Short name of the source log; `None` when only one log is shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Prints a table of event records, with a Source column if any record has one.
+pub fn print_events_table(records: &[SourcedRecord], use_colors: bool) {
+    use colors::*;
+
+    let show_source = records.iter().any(|r| r.source.is_some());
+    print_events_table_header(show_source, use_colors);
+    for (i, record) in records.iter().enumerate() {
+        print_events_table_row(i + 1, record, show_source, use_colors);
+    }
+
+    // Footer
+    if use_colors {
+        println!("\n{DIM}Total: {} events{RESET}", records.len());
+    } else {
+        println!("\nTotal: {} events", records.len());
+    }
+}
+
+/// Prints the `ralph events --stats` summary.
+pub fn print_event_stats(stats: &EventStats, use_colors: bool) {
+    use colors::*;
+
+    let iterations = match (stats.min_iteration, stats.max_iteration) {
+        (Some(min), Some(max)) => format!("{min}-{max}"),
+        _ => "-".to_string(),
+    };
+    let heading = |title: &str| {
+        if use_colors {
+            println!("\n{BOLD}{title}{RESET}");
+        } else {
+            println!("\n{title}");
+        }
+    };
+    let counts = |counts: &[NameCount]| {
+        for entry in counts {
+            let name = if entry.name.is_empty() {
+                "-"
+            } else {
+                &entry.name
+            };
+            println!("  {:>6}  {name}", entry.count);
+        }
+    };
+
+    let cost = format!("${:.4}", stats.total_cost);
+    let duration = format!("{:.1}s", stats.total_duration_ms as f64 / 1000.0);
+    if use_colors {
+        println!("{BOLD}Total events:{RESET} {CYAN}{}{RESET}", stats.total);
+        println!("{BOLD}Iterations:{RESET}   {CYAN}{iterations}{RESET}");
+        println!("{BOLD}Total cost:{RESET}   {CYAN}{cost}{RESET}");
+        println!("{BOLD}Duration:{RESET}     {CYAN}{duration}{RESET}");
+    } else {
+        println!("Total events: {}", stats.total);
+        println!("Iterations:   {iterations}");
+        println!("Total cost:   {cost}");
+        println!("Duration:     {duration}");
+    }
+
+    heading(&format!("Top {} topics", EventStats::TOP_TOPICS));
+    counts(&stats.top_topics);
+    heading("By topic");
+    counts(&stats.by_topic);
+    heading("By hat");
+    counts(&stats.by_hat);
+}
+
+/// Prints the column header of the events table.
+pub fn print_events_table_header(show_source: bool, use_colors: bool) {
     use colors::*;
 
-    // Header
+    let (source, source_rule) = if show_source {
+        (" Source       |", "--------------+")
+    } else {
+        ("", "")
+    };
     if use_colors {
         println!(
-            "{BOLD}{DIM}  # | Time     | Iteration | Hat           | Topic              | Triggered      | Payload{RESET}"
+            "{BOLD}{DIM}  # |{source} Time     | Iteration | Hat           | Topic              | Sev   | Triggered      | Payload{RESET}"
         );
         println!(
-            "{DIM}----+----------+-----------+---------------+--------------------+----------------+-----------------{RESET}"
+            "{DIM}----+{source_rule}----------+-----------+---------------+--------------------+-------+----------------+-----------------{RESET}"
         );
     } else {
+        let source_rule = source_rule.replace('+', "|");
         println!(
-            "  # | Time     | Iteration | Hat           | Topic              | Triggered      | Payload"
+            "  # |{source} Time     | Iteration | Hat           | Topic              | Sev   | Triggered      | Payload"
         );
         println!(
-            "----|----------|-----------|---------------|--------------------|-----------------|-----------------"
+            "----|{source_rule}----------|-----------|---------------|--------------------|-------|-----------------|-----------------"
         );
     }
+}
 
-    for (i, record) in records.iter().enumerate() {
-        let topic_color = get_topic_color(&record.topic);
-        let triggered = record.triggered.as_deref().unwrap_or("-");
-        let payload_one_line = record.payload.replace('\n', " ");
-        let payload_preview = if payload_one_line.len() > 40 {
-            truncate_prefix_bytes(&payload_one_line, 40)
-        } else {
-            payload_one_line
-        };
+/// Prints one row of the events table; `number` is the 1-based row number.
+pub fn print_events_table_row(
+    number: usize,
+    sourced: &SourcedRecord,
+    show_source: bool,
+    use_colors: bool,
+) {
+    use colors::*;
 
-        // Extract time portion (HH:MM:SS) from ISO 8601 timestamp
-        let time = record
-            .ts
-            .find('T')
-            .and_then(|t_pos| {
-                let after_t = &record.ts[t_pos + 1..];
-                // Find end of time (before timezone indicator or end of string)
-                let end = after_t
-                    .find(|c| c == 'Z' || c == '+' || c == '-')
-                    .unwrap_or(after_t.len());
-                let time_str = &after_t[..end];
-                // Take only HH:MM:SS (usually ASCII), but still ensure we slice on a valid UTF-8
-                // boundary for robustness. Otherwise, an unexpected `ts` (e.g. CJK/emoji) can make
-                // `&s[..N]` panic.
-                let mut boundary = time_str.len().min(8);
-                while boundary > 0 && !time_str.is_char_boundary(boundary) {
-                    boundary -= 1;
-                }
-                Some(&time_str[..boundary])
-            })
-            .unwrap_or("-");
+    let record = &sourced.record;
+    let source = if show_source {
+        format!(
+            " {:<12} |",
+            truncate(sourced.source.as_deref().unwrap_or("-"), 12)
+        )
+    } else {
+        String::new()
+    };
+    let topic_color = get_topic_color(&record.topic);
+    let severity = record.severity();
+    let severity_color = match severity {
+        EventSeverity::Error => RED,
+        EventSeverity::Warn => YELLOW,
+        EventSeverity::Info => DIM,
+    };
+    let triggered = record.triggered.as_deref().unwrap_or("-");
+    let payload_one_line = record.payload.replace('\n', " ");
+    let payload_preview = if payload_one_line.len() > 40 {
+        truncate_prefix_bytes(&payload_one_line, 40)
+    } else {
+        payload_one_line
+    };
 
-        if use_colors {
-            println!(
-                "{DIM}{:>3}{RESET} | {:<8} | {:>9} | {:<13} | {topic_color}{:<18}{RESET} | {:<14} | {DIM}{}{RESET}",
-                i + 1,
-                time,
-                record.iteration,
-                truncate(&record.hat, 13),
-                truncate(&record.topic, 18),
-                truncate(triggered, 14),
-                payload_preview
-            );
-        } else {
-            println!(
-                "{:>3} | {:<8} | {:>9} | {:<13} | {:<18} | {:<14} | {}",
-                i + 1,
-                time,
-                record.iteration,
-                truncate(&record.hat, 13),
-                truncate(&record.topic, 18),
-                truncate(triggered, 14),
-                payload_preview
-            );
-        }
+    // Extract time portion (HH:MM:SS) from ISO 8601 timestamp
+    let time = record
+        .ts
+        .find('T')
+        .and_then(|t_pos| {
+            let after_t = &record.ts[t_pos + 1..];
+            // Find end of time (before timezone indicator or end of string)
+            let end = after_t
+                .find(|c| c == 'Z' || c == '+' || c == '-')
+                .unwrap_or(after_t.len());
+            let time_str = &after_t[..end];
+            // Take only HH:MM:SS (usually ASCII), but still ensure we slice on a valid UTF-8
+            // boundary for robustness. Otherwise, an unexpected `ts` (e.g. CJK/emoji) can make
+            // `&s[..N]` panic.
+            let mut boundary = time_str.len().min(8);
+            while boundary > 0 && !time_str.is_char_boundary(boundary) {
+                boundary -= 1;
+            }
+            Some(&time_str[..boundary])
+        })
+        .unwrap_or("-");
+
+    if use_colors {
+        println!(
+            "{DIM}{:>3}{RESET} |{source} {:<8} | {:>9} | {:<13} | {topic_color}{:<18}{RESET} | {severity_color}{:<5}{RESET} | {:<14} | {DIM}{}{RESET}",
+            number,
+            time,
+            record.iteration,
+            truncate(&record.hat, 13),
+            truncate(&record.topic, 18),
+            severity.as_str(),
+            truncate(triggered, 14),
+            payload_preview
+        );
+    } else {
+        println!(
+            "{:>3} |{source} {:<8} | {:>9} | {:<13} | {:<18} | {:<5} | {:<14} | {}",
+            number,
+            time,
+            record.iteration,
+            truncate(&record.hat, 13),
+            truncate(&record.topic, 18),
+            severity.as_str(),
+            truncate(triggered, 14),
+            payload_preview
+        );
     }
+}
 
-    // Footer
+/// Prints serialized JSON, syntax-highlighting keys, strings, numbers, and
+/// literals when colors are enabled.
+pub fn print_json(json: &str, use_colors: bool) {
     if use_colors {
-        println!("\n{DIM}Total: {} events{RESET}", records.len());
+        println!("{}", colorize_json(json));
     } else {
-        println!("\nTotal: {} events", records.len());
+        println!("{json}");
     }
 }
 
+/// Adds ANSI colors to serialized JSON without changing its layout.
+pub fn colorize_json(json: &str) -> String {
+    use colors::*;
+
+    let mut out = String::with_capacity(json.len() * 2);
+    let mut chars = json.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '"' => {
+                let mut end = json.len();
+                let mut escaped = false;
+                for (i, c) in chars.by_ref() {
+                    match c {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => {
+                            end = i + 1;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                let is_key = json[end..].trim_start().starts_with(':');
+                let color = if is_key { CYAN } else { GREEN };
+                out.push_str(color);
+                out.push_str(&json[start..end]);
+                out.push_str(RESET);
+            }
+            '-' | '0'..='9' | 't' | 'f' | 'n' => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let color = if c.is_ascii_alphabetic() {
+                    MAGENTA
+                } else {
+                    YELLOW
+                };
+                out.push_str(color);
+                out.push_str(&json[start..end]);
+                out.push_str(RESET);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
 /// Builds a map of event topics to hat display information for the TUI.
 ///
 /// This allows the TUI to dynamically resolve which hat should be displayed
@@ -321,6 +488,47 @@ mod tests {
     use super::*;
     use ralph_core::RalphConfig;
 
+    #[test]
+    fn test_colorize_json_highlights_tokens() {
+        use colors::*;
+        let json = r#"{"topic": "build.done", "iteration": -1.5e3, "ok": true, "x": null}"#;
+        let colored = colorize_json(json);
+
+        assert!(colored.contains(&format!("{CYAN}\"topic\"{RESET}")));
+        assert!(colored.contains(&format!("{GREEN}\"build.done\"{RESET}")));
+        assert!(colored.contains(&format!("{YELLOW}-1.5e3{RESET}")));
+        assert!(colored.contains(&format!("{MAGENTA}true{RESET}")));
+        assert!(colored.contains(&format!("{MAGENTA}null{RESET}")));
+    }
+
+    /// Removes `ESC[...m` sequences.
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut rest = s;
+        while let Some(i) = rest.find('\x1b') {
+            out.push_str(&rest[..i]);
+            rest = &rest[i..];
+            rest = &rest[rest.find('m').map_or(rest.len(), |m| m + 1)..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    #[test]
+    fn test_colorize_json_preserves_text_with_escapes() {
+        let json = serde_json::to_string_pretty(&serde_json::json!({
+            "payload": "say \"hi\": \\ done",
+            "nested": [1, {"k": "v"}],
+        }))
+        .unwrap();
+        let colored = colorize_json(&json);
+
+        assert_eq!(strip_ansi(&colored), json);
+        // The quoted colon inside the payload doesn't make it a key.
+        let payload = r#""say \"hi\": \\ done""#;
+        assert!(colored.contains(&format!("{}{payload}", colors::GREEN)));
+    }
+
     #[test]
     fn test_format_elapsed_seconds_only() {
         let d = Duration::from_secs(45);
@@ -375,9 +583,18 @@ mod tests {
             triggered: None,
             payload,
             blocked_count: None,
+            severity: None,
+            cost: None,
+            duration_ms: None,
         };
 
-        print_events_table(&[record], false);
+        print_events_table(
+            &[SourcedRecord {
+                record,
+                source: None,
+            }],
+            false,
+        );
     }
 
     #[test]
@@ -392,9 +609,18 @@ mod tests {
             triggered: None,
             payload: "ok".to_string(),
             blocked_count: None,
+            severity: None,
+            cost: None,
+            duration_ms: None,
         };
 
-        print_events_table(&[record], false);
+        print_events_table(
+            &[SourcedRecord {
+                record,
+                source: None,
+            }],
+            false,
+        );
     }
 
     #[test]
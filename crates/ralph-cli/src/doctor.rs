@@ -2,7 +2,7 @@
 
 use anyhow::Result;
 use clap::Parser;
-use ralph_adapters::{CliBackend, DEFAULT_PRIORITY};
+use ralph_adapters::CliBackend;
 use ralph_core::{CheckResult, CheckStatus, ConfigError, HatBackend, PreflightReport, RalphConfig};
 use std::collections::HashSet;
 use std::env;
@@ -24,19 +24,28 @@ pub async fn execute(
     let source_label = crate::preflight::config_source_label(config_sources);
     let config = crate::preflight::load_config_for_preflight(config_sources).await?;
 
+    // Backends and paths get doctor-specific checks; the preflight `paths`
+    // check would create missing directories instead of reporting them.
     let runner = ralph_core::PreflightRunner::default_checks();
-    let preflight_report = runner.run_all(&config).await;
+    let selected: Vec<String> = runner
+        .check_names()
+        .into_iter()
+        .filter(|name| !matches!(*name, "backend" | "paths"))
+        .map(String::from)
+        .collect();
+    let preflight_report = runner.run_selected(&config, &selected).await;
 
     let mut config_check = None;
     let mut other_checks = Vec::new();
     for check in preflight_report.checks {
         match check.name.as_str() {
             "config" => config_check = Some(check),
-            "backend" => {}
             _ => other_checks.push(check),
         }
     }
 
+    let backends = backend_statuses(&config, command_version, command_exists);
+
     let mut checks = Vec::new();
     if let Some(check) = config_check {
         checks.push(check);
@@ -44,15 +53,27 @@ pub async fn execute(
 
     checks.push(hat_collection_check(&config));
 
-    let backend_checks = backend_checks(&config, command_version_ok, command_exists);
+    // Reuse the table's probes rather than running `--version` twice
+    let version_ok = |command: &str| {
+        backends
+            .iter()
+            .find(|status| status.command == command)
+            .map_or_else(
+                || command_version_ok(command),
+                |status| status.version.is_some(),
+            )
+    };
+    let backend_checks = backend_checks(&config, version_ok, command_exists);
     checks.extend(backend_checks);
 
     let auth_backends = auth_backend_names(&config);
     checks.push(auth_hint_check(&auth_backends, |key| env::var(key).ok()));
 
+    checks.push(prompt_paths_check(&config));
     checks.extend(other_checks);
 
     let report = report_from_checks(checks);
+    print_backend_table(&backends);
     print_human_report(&report, &source_label, use_colors);
 
     if report.failures > 0 {
@@ -86,7 +107,8 @@ where
 
     match config.cli.backend.trim() {
         "auto" => {
-            for backend in DEFAULT_PRIORITY {
+            let priority = enabled_priority(config);
+            for backend in &priority {
                 let command = command_for_backend(backend);
                 push_backend_check(
                     &mut checks,
@@ -109,7 +131,7 @@ where
                 CheckResult::fail(
                     "backend:auto",
                     "No supported backend found",
-                    format!("Checked: {}", DEFAULT_PRIORITY.join(", ")),
+                    format!("Checked: {}", priority.join(", ")),
                 )
             };
             checks.push(summary);
@@ -327,8 +349,8 @@ fn auth_backend_names(config: &RalphConfig) -> Vec<String> {
 
     match config.cli.backend.trim() {
         "auto" => {
-            for backend in DEFAULT_PRIORITY {
-                names.insert((*backend).to_string());
+            for backend in enabled_priority(config) {
+                names.insert(backend.to_string());
             }
         }
         "custom" => {
@@ -376,7 +398,79 @@ fn auth_env_vars(backend: &str) -> Option<Vec<&'static str>> {
     }
 }
 
-fn command_for_backend(backend: &str) -> String {
+/// Availability of one backend from the configured agent priority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BackendStatus {
+    name: String,
+    command: String,
+    installed: bool,
+    version: Option<String>,
+    enabled: bool,
+}
+
+/// Probes every backend in `agent_priority`, in order, the way auto-detection
+/// would see them.
+fn backend_statuses<F, G>(
+    config: &RalphConfig,
+    command_version: F,
+    command_exists: G,
+) -> Vec<BackendStatus>
+where
+    F: Fn(&str) -> Option<String>,
+    G: Fn(&str) -> bool,
+{
+    config
+        .get_agent_priority()
+        .into_iter()
+        .map(|backend| {
+            let command = command_for_backend(backend);
+            let version = command_version(&command);
+            BackendStatus {
+                name: backend.to_string(),
+                installed: version.is_some() || command_exists(&command),
+                enabled: config.adapter_settings(backend).enabled,
+                version,
+                command,
+            }
+        })
+        .collect()
+}
+
+/// Agent priority with adapters disabled in config removed, matching
+/// `detect_backend`.
+fn enabled_priority(config: &RalphConfig) -> Vec<&str> {
+    config
+        .get_agent_priority()
+        .into_iter()
+        .filter(|backend| config.adapter_settings(backend).enabled)
+        .collect()
+}
+
+/// Warns when the prompt file or specs directory is missing. Unlike the
+/// preflight `paths` check, nothing is created.
+fn prompt_paths_check(config: &RalphConfig) -> CheckResult {
+    let mut missing = Vec::new();
+
+    if config.event_loop.prompt.is_none() {
+        let prompt_path = config.core.resolve_path(&config.event_loop.prompt_file);
+        if !prompt_path.is_file() {
+            missing.push(format!("prompt_file: {}", prompt_path.display()));
+        }
+    }
+
+    let specs_path = config.core.resolve_path(&config.core.specs_dir);
+    if !specs_path.is_dir() {
+        missing.push(format!("specs_dir: {}", specs_path.display()));
+    }
+
+    if missing.is_empty() {
+        CheckResult::pass("paths", "Prompt file and specs directory found")
+    } else {
+        CheckResult::warn("paths", "Configured paths missing", missing.join("\n"))
+    }
+}
+
+pub(crate) fn command_for_backend(backend: &str) -> String {
     CliBackend::from_name(backend)
         .map(|backend| backend.command)
         .unwrap_or_else(|_| backend.to_string())
@@ -451,6 +545,22 @@ fn command_version_ok(command: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Returns the first line printed by `<command> --version`, if it succeeds.
+pub(crate) fn command_version(command: &str) -> Option<String> {
+    let output = Command::new(command).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let version = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("unknown")
+        .to_string();
+    Some(version)
+}
+
 fn command_exists(command: &str) -> bool {
     let path = Path::new(command);
     if path.components().count() > 1 {
@@ -509,6 +619,34 @@ fn report_from_checks(checks: Vec<CheckResult>) -> PreflightReport {
     }
 }
 
+fn print_backend_table(backends: &[BackendStatus]) {
+    if backends.is_empty() {
+        return;
+    }
+
+    let name_width = backends
+        .iter()
+        .map(|status| status.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("BACKEND".len());
+
+    println!(
+        "{:<name_width$}  {:<9}  {:<7}  VERSION",
+        "BACKEND", "INSTALLED", "ENABLED"
+    );
+    for status in backends {
+        println!(
+            "{:<name_width$}  {:<9}  {:<7}  {}",
+            status.name,
+            if status.installed { "yes" } else { "no" },
+            if status.enabled { "yes" } else { "no" },
+            status.version.as_deref().unwrap_or("-")
+        );
+    }
+    println!();
+}
+
 fn print_human_report(report: &PreflightReport, source: &str, use_colors: bool) {
     use crate::display::colors;
 
@@ -655,6 +793,101 @@ mod tests {
         assert_eq!(claude.status, CheckStatus::Fail);
     }
 
+    #[test]
+    fn backend_checks_auto_uses_enabled_agent_priority() {
+        let mut config = RalphConfig::default();
+        config.cli.backend = "auto".to_string();
+        config.agent_priority = vec!["gemini".to_string(), "claude".to_string()];
+        config.adapters.claude.enabled = false;
+
+        let checks = backend_checks(&config, |_| false, |_| false);
+        let names: Vec<_> = checks.iter().map(|check| check.name.as_str()).collect();
+
+        assert_eq!(names, vec!["backend:gemini", "backend:auto"]);
+        assert_eq!(
+            checks[1].message.as_deref(),
+            Some("Checked: gemini"),
+            "disabled adapters are not probed"
+        );
+    }
+
+    #[test]
+    fn backend_statuses_follow_priority_with_version_and_enabled() {
+        let mut config = RalphConfig::default();
+        config.agent_priority = vec![
+            "kiro".to_string(),
+            "claude".to_string(),
+            "gemini".to_string(),
+        ];
+        config.adapters.gemini.enabled = false;
+
+        let statuses = backend_statuses(
+            &config,
+            |cmd| (cmd == "claude").then(|| "2.1.0 (Claude Code)".to_string()),
+            |cmd| cmd == "kiro-cli",
+        );
+
+        assert_eq!(
+            statuses,
+            vec![
+                BackendStatus {
+                    name: "kiro".to_string(),
+                    command: "kiro-cli".to_string(),
+                    installed: true,
+                    version: None,
+                    enabled: true,
+                },
+                BackendStatus {
+                    name: "claude".to_string(),
+                    command: "claude".to_string(),
+                    installed: true,
+                    version: Some("2.1.0 (Claude Code)".to_string()),
+                    enabled: true,
+                },
+                BackendStatus {
+                    name: "gemini".to_string(),
+                    command: "gemini".to_string(),
+                    installed: false,
+                    version: None,
+                    enabled: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn prompt_paths_check_warns_on_missing_paths() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp.path().to_path_buf();
+
+        let check = prompt_paths_check(&config);
+        assert_eq!(check.status, CheckStatus::Warn);
+        let message = check.message.unwrap_or_default();
+        assert!(message.contains("prompt_file"));
+        assert!(message.contains("specs_dir"));
+        assert!(
+            !temp.path().join(&config.core.specs_dir).exists(),
+            "doctor must not create missing paths"
+        );
+
+        std::fs::write(temp.path().join(&config.event_loop.prompt_file), "task").unwrap();
+        std::fs::create_dir_all(temp.path().join(&config.core.specs_dir)).unwrap();
+        assert_eq!(prompt_paths_check(&config).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn prompt_paths_check_skips_prompt_file_for_inline_prompt() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp.path().to_path_buf();
+        config.event_loop.prompt = Some("inline".to_string());
+
+        let message = prompt_paths_check(&config).message.unwrap_or_default();
+        assert!(!message.contains("prompt_file"));
+        assert!(message.contains("specs_dir"));
+    }
+
     #[test]
     fn auth_hint_warns_when_env_missing() {
         let backends = vec!["codex".to_string(), "gemini".to_string()];
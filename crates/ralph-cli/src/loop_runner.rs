@@ -10,9 +10,10 @@ use ralph_adapters::{
     PrettyStreamHandler, PtyConfig, PtyExecutor, QuietStreamHandler, TuiStreamHandler,
 };
 use ralph_core::{
-    CompletionAction, EventLogger, EventLoop, EventParser, EventRecord, LoopCompletionHandler,
-    LoopContext, LoopHistory, LoopRegistry, MergeQueue, RalphConfig, Record, SessionRecorder,
-    SummaryWriter, TerminationReason,
+    AsyncEventLogger, CompletionAction, EventLogger, EventLoop, EventLoopConfig, EventParser,
+    LOOP_ITERATION_TOPIC, LoopCompletionHandler, LoopContext, LoopHistory, LoopRegistry,
+    MEMORY_INJECTED_TOPIC, MergeQueue, ParsedEvents, RalphConfig, Record, RunSummary,
+    SessionRecorder, SummaryWriter, TerminationReason,
 };
 use ralph_proto::{Event, HatId};
 use ralph_tui::Tui;
@@ -22,7 +23,7 @@ use std::io::{BufWriter, IsTerminal, stdin, stdout};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
 use crate::display::{build_tui_hat_map, print_iteration_separator, print_termination};
@@ -34,6 +35,8 @@ pub(crate) struct ExecutionOutcome {
     pub output: String,
     pub success: bool,
     pub termination: Option<TerminationReason>,
+    /// Cost the backend reported for this execution, if it reports usage.
+    pub cost_usd: Option<f64>,
 }
 
 /// Core loop implementation supporting both fresh start and continue modes.
@@ -81,13 +84,15 @@ pub async fn run_loop_impl(
 
     // Set up interrupt channel for signal handling
     // Per spec:
-    // - SIGINT (Ctrl+C): Immediately terminate child process (SIGTERM -> 5s grace -> SIGKILL), exit with code 130
-    // - SIGTERM: Same as SIGINT
+    // - SIGINT (Ctrl+C): Immediately abandon the running agent, write the summary, exit with code 130
+    // - SIGTERM: Finish the current iteration, then stop with TerminationReason::Stopped
+    //   (a second SIGTERM interrupts immediately, like SIGINT)
     // - SIGHUP: Same as SIGINT
     //
     // Use watch channel for interrupt notification so we can race execution vs interrupt
     // Note: Signal handlers are spawned AFTER TUI initialization to avoid deadlock
     let (interrupt_tx, interrupt_rx) = tokio::sync::watch::channel(false);
+    let graceful_stop = GracefulStop::default();
 
     // Resolve prompt content with precedence:
     // 1. CLI -p (inline text)
@@ -190,8 +195,16 @@ pub async fn run_loop_impl(
             None
         };
 
-    // Initialize event logger for debugging (uses context for path resolution)
-    let mut event_logger = EventLogger::from_context(&ctx);
+    // Create backend from config - TUI mode uses the same backend as non-TUI
+    // The TUI is an observation layer that displays output, not a different mode
+    let mut backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
+
+    // Initialize event logger for debugging (uses context for path resolution).
+    // Records are written on a background writer so the loop never waits on disk;
+    // every exit path below flushes it.
+    let event_logger = EventLogger::from_context(&ctx)
+        .with_max_payload_len(config.event_loop.max_payload_len)
+        .with_async();
 
     // Log initial event (use configured starting_event or default to task.start/task.resume)
     let default_start_topic = if resume { "task.resume" } else { "task.start" };
@@ -203,15 +216,11 @@ pub async fn run_loop_impl(
     let start_triggered = "planner"; // Default triggered hat for backward compat
     let start_event = Event::new(start_topic, &prompt_content);
     let start_record =
-        EventRecord::new(0, "loop", &start_event, Some(&HatId::new(start_triggered)));
-    if let Err(e) = event_logger.log(&start_record) {
+        event_logger.record(0, "loop", &start_event, Some(&HatId::new(start_triggered)));
+    if let Err(e) = event_logger.log(start_record) {
         warn!("Failed to log start event: {}", e);
     }
 
-    // Create backend from config - TUI mode uses the same backend as non-TUI
-    // The TUI is an observation layer that displays output, not a different mode
-    let mut backend = CliBackend::from_config(&config.cli).map_err(|e| anyhow::Error::new(e))?;
-
     // Append custom args from CLI if provided (e.g., `ralph run -b opencode -- --model="some-model"`)
     if !custom_args.is_empty() {
         backend.args.extend(custom_args);
@@ -272,6 +281,30 @@ pub async fn run_loop_impl(
         (None, None, None)
     };
 
+    // --explain: trace routing decisions to stderr, or to a trace file when the TUI
+    // owns the terminal. Published events are explained via a bus observer.
+    let explain_sink = if config.explain {
+        let sink = if enable_tui {
+            match ExplainSink::file(&ctx.ralph_dir().join("explain.log")) {
+                Ok(sink) => sink,
+                Err(e) => {
+                    flush_event_log(&event_logger).await;
+                    return Err(e);
+                }
+            }
+        } else {
+            ExplainSink::Stderr
+        };
+        let observer_sink = sink.clone();
+        let registry = event_loop.registry().clone();
+        event_loop.add_observer(move |event| {
+            observer_sink.write(&[explain_published_event(&registry, event)]);
+        });
+        Some(sink)
+    } else {
+        None
+    };
+
     // Give TUI task time to initialize (enter alternate screen, enable raw mode)
     // before the main loop starts doing work
     if tui_handle.is_some() {
@@ -287,33 +320,40 @@ pub async fn run_loop_impl(
     // (TUI must enter raw mode and create EventStream before signal handlers are registered)
 
     // Spawn task to listen for SIGINT (Ctrl+C)
-    let interrupt_tx_sigint = interrupt_tx.clone();
-    let robot_shutdown_sigint = robot_shutdown.clone();
-    tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            debug!("Interrupt received (SIGINT), terminating immediately...");
-            if let Some(ref flag) = robot_shutdown_sigint {
-                flag.store(true, std::sync::atomic::Ordering::Relaxed);
+    spawn_interrupt_on(
+        async {
+            if tokio::signal::ctrl_c().await.is_err() {
+                std::future::pending::<()>().await;
             }
-            let _ = interrupt_tx_sigint.send(true);
-        }
-    });
+            debug!("Interrupt received (SIGINT), terminating immediately...");
+        },
+        interrupt_tx.clone(),
+        robot_shutdown.clone(),
+    );
 
     // Spawn task to listen for SIGTERM (Unix only)
     #[cfg(unix)]
     {
         let interrupt_tx_sigterm = interrupt_tx.clone();
         let robot_shutdown_sigterm = robot_shutdown.clone();
+        let graceful_stop_sigterm = graceful_stop.clone();
         tokio::spawn(async move {
             let mut sigterm =
                 tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
                     .expect("Failed to register SIGTERM handler");
-            sigterm.recv().await;
-            debug!("SIGTERM received, terminating immediately...");
-            if let Some(ref flag) = robot_shutdown_sigterm {
-                flag.store(true, std::sync::atomic::Ordering::Relaxed);
+            while sigterm.recv().await.is_some() {
+                // Unblock a pending wait_for_response() so the iteration can finish
+                if let Some(ref flag) = robot_shutdown_sigterm {
+                    flag.store(true, std::sync::atomic::Ordering::Relaxed);
+                }
+                if graceful_stop_sigterm.request() {
+                    info!("SIGTERM received, stopping after the current iteration...");
+                } else {
+                    debug!("Second SIGTERM received, terminating immediately...");
+                    let _ = interrupt_tx_sigterm.send(true);
+                    break;
+                }
             }
-            let _ = interrupt_tx_sigterm.send(true);
         });
     }
 
@@ -392,14 +432,22 @@ pub async fn run_loop_impl(
         }
     }
 
+    // Hooks run in the workspace root; termination hooks after the loop is torn down
+    let hook_config = config.event_loop.clone();
+    let hook_dir = config.core.workspace_root.clone();
+    let summary_json = config.event_loop.summary_json.clone();
+
     // Helper closure to handle termination (writes summary, prints status, records history)
-    let handle_termination = |reason: &TerminationReason,
-                              state: &ralph_core::LoopState,
-                              scratchpad: &str,
-                              history: &Option<LoopHistory>,
-                              context: &Option<LoopContext>,
-                              auto_merge: bool,
-                              prompt: &str| {
+    let handle_termination = async |reason: &TerminationReason,
+                                    state: &ralph_core::LoopState,
+                                    scratchpad: &str,
+                                    history: &Option<LoopHistory>,
+                                    context: &Option<LoopContext>,
+                                    auto_merge: bool,
+                                    prompt: &str| {
+        // The terminate record is queued; get it on disk before anything reads the log
+        flush_event_log(&event_logger).await;
+
         // Per spec: Write summary file on termination
         let summary_writer = SummaryWriter::default();
         let scratchpad_path = std::path::Path::new(scratchpad);
@@ -416,29 +464,19 @@ pub async fn run_loop_impl(
         {
             warn!("Failed to write summary file: {}", e);
         }
+        if let Some(path) = &summary_json
+            && let Err(e) = RunSummary::new(reason, state).write_json(Path::new(path))
+        {
+            warn!("Failed to write JSON summary to {}: {}", path, e);
+        }
 
         // Record termination in history
         if let Some(hist) = history {
-            let reason_str = match reason {
-                TerminationReason::CompletionPromise => "completion_promise",
-                TerminationReason::MaxIterations => "max_iterations",
-                TerminationReason::MaxRuntime => "max_runtime",
-                TerminationReason::MaxCost => "max_cost",
-                TerminationReason::ConsecutiveFailures => "consecutive_failures",
-                TerminationReason::LoopThrashing => "loop_thrashing",
-                TerminationReason::LoopStale => "loop_stale",
-                TerminationReason::ValidationFailure => "validation_failure",
-                TerminationReason::Stopped => "stopped",
-                TerminationReason::Interrupted => "interrupted",
-                TerminationReason::RestartRequested => "restart_requested",
-                TerminationReason::Cancelled => "cancelled",
-            };
-
             if matches!(reason, TerminationReason::Interrupted) {
                 if let Err(e) = hist.record_terminated("SIGTERM") {
                     warn!("Failed to record termination in history: {}", e);
                 }
-            } else if let Err(e) = hist.record_completed(reason_str) {
+            } else if let Err(e) = hist.record_completed(reason.name()) {
                 warn!("Failed to record completion in history: {}", e);
             }
         }
@@ -497,11 +535,13 @@ pub async fn run_loop_impl(
                     TerminationReason::LoopThrashing => "loop thrashing detected",
                     TerminationReason::LoopStale => "stale loop detected",
                     TerminationReason::ValidationFailure => "validation failure",
+                    TerminationReason::HookFailed => "iteration hook failed",
                     TerminationReason::Stopped => "manually stopped",
                     TerminationReason::Interrupted => "interrupted by signal",
                     TerminationReason::CompletionPromise => unreachable!(),
                     TerminationReason::RestartRequested => "restart requested",
                     TerminationReason::Cancelled => "cancelled by human",
+                    TerminationReason::SingleIteration => "stopped after one iteration",
                 };
                 if let Err(e) = queue.mark_needs_review(loop_id, reason_str) {
                     warn!(loop_id = %loop_id, error = %e, "Failed to mark merge as needs-review");
@@ -580,34 +620,27 @@ pub async fn run_loop_impl(
             }
         }
 
+        ralph_core::hooks::run_termination_hook(&hook_config, reason, state, &hook_dir).await;
+
         // Print termination info to console (skip in TUI mode - TUI handles display)
         if !enable_tui {
             print_termination(reason, state, use_colors);
         }
     };
 
+    // A pause signal can only belong to this run once it has started
+    event_loop.clear_stale_pause();
+
     // Main orchestration loop
     loop {
         // Check for interrupt signal at start of each iteration
         // This catches TUI Ctrl+C (via interrupt_tx) before printing iteration separator
         if *interrupt_rx.borrow() {
-            #[cfg(unix)]
-            {
-                use nix::sys::signal::{Signal, killpg};
-                use nix::unistd::getpgrp;
-                let pgid = getpgrp();
-                debug!(
-                    "Interrupt detected at loop start, sending SIGTERM to process group {}",
-                    pgid
-                );
-                let _ = killpg(pgid, Signal::SIGTERM);
-                tokio::time::sleep(Duration::from_millis(250)).await;
-                let _ = killpg(pgid, Signal::SIGKILL);
-            }
+            debug!("Interrupt detected at loop start, stopping");
             let reason = TerminationReason::Interrupted;
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -619,12 +652,35 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
-            );
+            )
+            .await;
             // Signal TUI to exit immediately on interrupt
             let _ = terminated_tx.send(true);
             return Ok(reason);
         }
 
+        // SIGTERM lets the in-flight iteration finish; stop before starting the next one
+        if let Some(reason) = graceful_stop.termination_reason() {
+            let terminate_event = event_loop.publish_terminate_event(&reason);
+            log_terminate_event(
+                &event_logger,
+                event_loop.state().iteration,
+                &terminate_event,
+            );
+            handle_termination(
+                &reason,
+                event_loop.state(),
+                &config.core.scratchpad,
+                &loop_history,
+                &loop_context,
+                auto_merge,
+                &prompt_content,
+            )
+            .await;
+            let _ = terminated_tx.send(true);
+            return Ok(reason);
+        }
+
         // Drain next-loop guidance queue and write as human.guidance events.
         // These will be picked up by process_events_from_jsonl() during build_prompt().
         if let Some(ref queue) = guidance_next_queue {
@@ -682,7 +738,7 @@ pub async fn run_loop_impl(
             // Per spec: Publish loop.terminate event to observers
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -694,7 +750,8 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
-            );
+            )
+            .await;
             // Wait for user to exit TUI (press 'q') on natural completion
             if let Some(handle) = tui_handle.take() {
                 let _ = handle.await;
@@ -702,6 +759,16 @@ pub async fn run_loop_impl(
             return Ok(reason);
         }
 
+        let bus = event_loop.bus();
+        debug!(
+            pending = bus.pending_count(),
+            by_hat = ?bus
+                .hats_with_pending()
+                .map(|id| (id.as_str(), bus.pending_for(id).len()))
+                .collect::<Vec<_>>(),
+            "Event bus queue"
+        );
+
         // Get next hat to execute, with fallback recovery if no pending events
         let hat_id = match event_loop.next_hat() {
             Some(id) => {
@@ -723,7 +790,7 @@ pub async fn run_loop_impl(
                     let reason = TerminationReason::Stopped;
                     let terminate_event = event_loop.publish_terminate_event(&reason);
                     log_terminate_event(
-                        &mut event_logger,
+                        &event_logger,
                         event_loop.state().iteration,
                         &terminate_event,
                     );
@@ -735,7 +802,8 @@ pub async fn run_loop_impl(
                         &loop_context,
                         auto_merge,
                         &prompt_content,
-                    );
+                    )
+                    .await;
                     // Wait for user to exit TUI (press 'q') on natural completion
                     if let Some(handle) = tui_handle.take() {
                         let _ = handle.await;
@@ -758,7 +826,7 @@ pub async fn run_loop_impl(
                 // Per spec: Publish loop.terminate event to observers
                 let terminate_event = event_loop.publish_terminate_event(&reason);
                 log_terminate_event(
-                    &mut event_logger,
+                    &event_logger,
                     event_loop.state().iteration,
                     &terminate_event,
                 );
@@ -770,7 +838,8 @@ pub async fn run_loop_impl(
                     &loop_context,
                     auto_merge,
                     &prompt_content,
-                );
+                )
+                .await;
                 // Wait for user to exit TUI (press 'q') on natural completion
                 if let Some(handle) = tui_handle.take() {
                     let _ = handle.await;
@@ -780,6 +849,8 @@ pub async fn run_loop_impl(
         };
 
         let iteration = event_loop.state().iteration + 1;
+        let iteration_start = Instant::now();
+        let cost_before = event_loop.state().cumulative_cost;
 
         // Determine which hat to display in iteration separator
         // When Ralph is coordinating (hat_id == "ralph"), show the active hat being worked on
@@ -820,6 +891,14 @@ pub async fn run_loop_impl(
             iteration, config.event_loop.max_iterations, hat_id
         );
 
+        if let Some(sink) = &explain_sink
+            && let Some(lines) = event_loop.explain_routing()
+        {
+            let mut traced = vec![format!("iteration {iteration}:")];
+            traced.extend(lines.into_iter().map(|line| format!("  {line}")));
+            sink.write(&traced);
+        }
+
         // Build prompt for this hat
         let prompt = match event_loop.build_prompt(&hat_id) {
             Some(p) => p,
@@ -828,6 +907,44 @@ pub async fn run_loop_impl(
                 continue;
             }
         };
+        log_injected_memories(
+            &event_logger,
+            iteration,
+            &display_hat,
+            &event_loop.take_injected_memory_ids(),
+        );
+
+        let hook_env = ralph_core::hooks::iteration_env(iteration, &display_hat);
+        if let Some(reason) = ralph_core::hooks::check_iteration_hook(
+            &config.event_loop,
+            "pre_iteration_command",
+            config.event_loop.pre_iteration_command.as_deref(),
+            &hook_dir,
+            &hook_env,
+        )
+        .await
+        {
+            let terminate_event = event_loop.publish_terminate_event(&reason);
+            log_terminate_event(
+                &event_logger,
+                event_loop.state().iteration,
+                &terminate_event,
+            );
+            handle_termination(
+                &reason,
+                event_loop.state(),
+                &config.core.scratchpad,
+                &loop_history,
+                &loop_context,
+                auto_merge,
+                &prompt_content,
+            )
+            .await;
+            if let Some(handle) = tui_handle.take() {
+                let _ = handle.await;
+            }
+            return Ok(reason);
+        }
 
         // In verbose mode, print the full prompt before execution
         if verbosity == Verbosity::Verbose {
@@ -961,41 +1078,63 @@ pub async fn run_loop_impl(
                     output: result.output,
                     success: result.success,
                     termination: None,
+                    cost_usd: None,
                 })
             }
         };
 
-        let outcome = tokio::select! {
-            result = execute_future => result?,
-            _ = interrupt_rx_clone.changed() => {
-                // Immediately terminate children via process group signal
-                #[cfg(unix)]
-                {
-                    use nix::sys::signal::{killpg, Signal};
-                    use nix::unistd::getpgrp;
-                    let pgid = getpgrp();
-                    debug!("Sending SIGTERM to process group {}", pgid);
-                    let _ = killpg(pgid, Signal::SIGTERM);
-
-                    // Wait briefly for graceful exit, then SIGKILL
-                    tokio::time::sleep(Duration::from_millis(250)).await;
-                    let _ = killpg(pgid, Signal::SIGKILL);
-                }
+        tokio::pin!(execute_future);
+        let finished = tokio::select! {
+            result = &mut execute_future => Some(result),
+            _ = interrupt_rx_clone.changed() => None,
+        };
+
+        let outcome = match finished {
+            Some(Ok(outcome)) => outcome,
+            Some(Err(e)) => {
+                flush_event_log(&event_logger).await;
+                return Err(e);
+            }
+            None => {
+                // The executor sees the same interrupt and terminates the agent's
+                // process group; give it a bounded window to do so. The agent
+                // runs in its own session, so signalling our own process group
+                // would only reach this process and kill it before the summary
+                // below is written.
+                let _ = tokio::time::timeout(AGENT_SHUTDOWN_GRACE, &mut execute_future).await;
 
                 let reason = TerminationReason::Interrupted;
                 let terminate_event = event_loop.publish_terminate_event(&reason);
-                log_terminate_event(&mut event_logger, event_loop.state().iteration, &terminate_event);
-                handle_termination(&reason, event_loop.state(), &config.core.scratchpad, &loop_history, &loop_context, auto_merge, &prompt_content);
+                log_terminate_event(
+                    &event_logger,
+                    event_loop.state().iteration,
+                    &terminate_event,
+                );
+                handle_termination(
+                    &reason,
+                    event_loop.state(),
+                    &config.core.scratchpad,
+                    &loop_history,
+                    &loop_context,
+                    auto_merge,
+                    &prompt_content,
+                )
+                .await;
                 // Signal TUI to exit immediately on interrupt
                 let _ = terminated_tx.send(true);
                 return Ok(reason);
             }
         };
 
+        // Record reported spend so max_cost_usd and the summary see it
+        if let Some(cost) = outcome.cost_usd {
+            event_loop.add_cost(cost);
+        }
+
         if let Some(reason) = outcome.termination {
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -1007,7 +1146,8 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
-            );
+            )
+            .await;
             // Wait for user to exit TUI (press 'q') on natural completion
             if let Some(handle) = tui_handle.take() {
                 let _ = handle.await;
@@ -1025,16 +1165,43 @@ pub async fn run_loop_impl(
         }
 
         // Log events from output before processing
-        log_events_from_output(
-            &mut event_logger,
+        let malformed = log_events_from_output(
+            &event_logger,
             iteration,
             &hat_id,
             &output,
             event_loop.registry(),
+            &event_loop.config().event_loop,
+            event_loop.state(),
         );
+        event_loop.record_malformed_output_events(malformed);
 
         // Process output
-        if let Some(reason) = event_loop.process_output(&hat_id, &output, success) {
+        let termination = event_loop.process_output(&hat_id, &output, success);
+        log_iteration_usage(
+            &event_logger,
+            iteration,
+            &hat_id,
+            event_loop.state().cumulative_cost - cost_before,
+            iteration_start.elapsed(),
+        );
+
+        // The post hook runs once this iteration's events are on disk. A strict
+        // failure ends the loop only after the events have been routed, and any
+        // other termination reason takes precedence.
+        flush_event_log(&event_logger).await;
+        let mut hook_env = hook_env;
+        hook_env.push(("RALPH_ITERATION_SUCCESS", success.to_string()));
+        let hook_failure = ralph_core::hooks::check_iteration_hook(
+            &config.event_loop,
+            "post_iteration_command",
+            config.event_loop.post_iteration_command.as_deref(),
+            &hook_dir,
+            &hook_env,
+        )
+        .await;
+
+        if let Some(reason) = termination {
             // Per spec: Log "All done! {promise} detected." when completion promise found
             if reason == TerminationReason::CompletionPromise {
                 info!(
@@ -1045,7 +1212,7 @@ pub async fn run_loop_impl(
             // Per spec: Publish loop.terminate event to observers
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -1057,7 +1224,8 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
-            );
+            )
+            .await;
             // Wait for user to exit TUI (press 'q') on natural completion
             if let Some(handle) = tui_handle.take() {
                 let _ = handle.await;
@@ -1070,11 +1238,21 @@ pub async fn run_loop_impl(
             warn!(error = %e, "Failed to check planning session responses");
         }
 
-        // Read events from JSONL that agent may have written
+        // Read events from JSONL that agent may have written. A running
+        // verification command is killed on interrupt; the check at the top of
+        // the loop then terminates.
+        // Stdout events logged above must be on disk before the reader routes them.
+        flush_event_log(&event_logger).await;
+        let mut interrupt_rx_verify = interrupt_rx.clone();
+        let processed = tokio::select! {
+            result = event_loop.process_events_from_jsonl_async() => Some(result),
+            Ok(_) = interrupt_rx_verify.wait_for(|interrupted| *interrupted) => None,
+        };
+        let Some(processed) = processed else {
+            continue;
+        };
         let agent_wrote_events = matches!(
-            event_loop
-                .process_events_from_jsonl()
-                .inspect_err(|e| warn!(error = %e, "Failed to read events from JSONL")),
+            processed.inspect_err(|e| warn!(error = %e, "Failed to read events from JSONL")),
             Ok(true)
         );
 
@@ -1095,7 +1273,7 @@ pub async fn run_loop_impl(
 
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -1107,7 +1285,8 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
-            );
+            )
+            .await;
             if let Some(handle) = tui_handle.take() {
                 let _ = handle.await;
             }
@@ -1122,7 +1301,7 @@ pub async fn run_loop_impl(
 
             let terminate_event = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
-                &mut event_logger,
+                &event_logger,
                 event_loop.state().iteration,
                 &terminate_event,
             );
@@ -1134,13 +1313,43 @@ pub async fn run_loop_impl(
                 &loop_context,
                 auto_merge,
                 &prompt_content,
+            )
+            .await;
+            if let Some(handle) = tui_handle.take() {
+                let _ = handle.await;
+            }
+            return Ok(reason);
+        }
+
+        if let Some(reason) = hook_failure {
+            let terminate_event = event_loop.publish_terminate_event(&reason);
+            log_terminate_event(
+                &event_logger,
+                event_loop.state().iteration,
+                &terminate_event,
             );
+            handle_termination(
+                &reason,
+                event_loop.state(),
+                &config.core.scratchpad,
+                &loop_history,
+                &loop_context,
+                auto_merge,
+                &prompt_content,
+            )
+            .await;
             if let Some(handle) = tui_handle.take() {
                 let _ = handle.await;
             }
             return Ok(reason);
         }
 
+        // --once terminates at the loop top now that this iteration's events
+        // and completion have been handled; no cooldown or backoff first
+        if config.event_loop.single_iteration {
+            continue;
+        }
+
         // Precheck validation: Warn if no pending events after processing output
         // Per EventLoop doc: "Use has_pending_events after process_output to detect
         // if the LLM failed to publish an event."
@@ -1164,6 +1373,70 @@ pub async fn run_loop_impl(
             );
             tokio::time::sleep(Duration::from_secs(cooldown)).await;
         }
+
+        // Back off after failed iterations so transient conditions (rate
+        // limits, flaky services) can recover; an interrupt cuts it short
+        let backoff = event_loop.failure_backoff();
+        if !backoff.is_zero() {
+            info!(
+                consecutive_failures = event_loop.state().consecutive_failures,
+                delay_seconds = backoff.as_secs(),
+                "Backing off after failed iteration"
+            );
+            let mut interrupt_rx_backoff = interrupt_rx.clone();
+            tokio::select! {
+                () = tokio::time::sleep(backoff) => {}
+                _ = interrupt_rx_backoff.changed() => {}
+            }
+        }
+
+        // Telegram /pause holds the loop here until /resume, /stop, or
+        // /restart; stop signals end the wait and terminate at the loop top
+        if event_loop.pause_requested() {
+            info!("Loop paused via Telegram; send /resume to continue");
+            let mut interrupt_rx_pause = interrupt_rx.clone();
+            loop {
+                match pause_state(&event_loop, *interrupt_rx_pause.borrow(), &graceful_stop) {
+                    PauseState::Paused => {}
+                    PauseState::Resumed => {
+                        info!("Loop resumed");
+                        break;
+                    }
+                    PauseState::Stopping => {
+                        info!("Stop requested while paused");
+                        break;
+                    }
+                }
+                tokio::select! {
+                    () = tokio::time::sleep(PAUSE_POLL_INTERVAL) => {}
+                    _ = interrupt_rx_pause.changed() => {}
+                }
+            }
+        }
+    }
+}
+
+/// What a loop held by a Telegram `/pause` should do next.
+#[derive(Debug, PartialEq, Eq)]
+enum PauseState {
+    Paused,
+    Resumed,
+    /// An interrupt, graceful stop, or `ralph loops stop` / `ralph cancel`
+    /// signal arrived; the top of the loop turns it into a termination.
+    Stopping,
+}
+
+fn pause_state(
+    event_loop: &EventLoop,
+    interrupted: bool,
+    graceful_stop: &GracefulStop,
+) -> PauseState {
+    if interrupted || graceful_stop.termination_reason().is_some() || event_loop.stop_requested() {
+        PauseState::Stopping
+    } else if event_loop.pause_requested() {
+        PauseState::Paused
+    } else {
+        PauseState::Resumed
     }
 }
 
@@ -1361,6 +1634,7 @@ async fn execute_pty(
                 output: output_for_parsing,
                 success: pty_result.success,
                 termination,
+                cost_usd: pty_result.total_cost_usd,
             })
         }
         Err(e) => {
@@ -1371,19 +1645,142 @@ async fn execute_pty(
     }
 }
 
+/// How long an interrupted iteration may take to terminate its agent (the
+/// executor allows a 2s SIGTERM grace period before SIGKILL).
+const AGENT_SHUTDOWN_GRACE: Duration = Duration::from_secs(3);
+
+/// How often a paused loop checks whether it has been resumed.
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Raises the interrupt once `signal` resolves (Ctrl+C in production).
+///
+/// Also unblocks a pending robot `wait_for_response()`, so the main loop can
+/// reach its termination path, log `loop.terminate` and write the summary.
+fn spawn_interrupt_on(
+    signal: impl std::future::Future<Output = ()> + Send + 'static,
+    interrupt_tx: tokio::sync::watch::Sender<bool>,
+    robot_shutdown: Option<Arc<std::sync::atomic::AtomicBool>>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        signal.await;
+        if let Some(ref flag) = robot_shutdown {
+            flag.store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+        let _ = interrupt_tx.send(true);
+    })
+}
+
+/// Graceful stop request shared between the SIGTERM handler and the main loop.
+///
+/// Unlike an interrupt, a stop request never cancels the running agent: the
+/// loop checks it before starting each iteration.
+///
+/// SIGINT deliberately stays an immediate interrupt: Ctrl+C at a terminal
+/// means "stop now", while supervisors (systemd, Kubernetes, `ralph loops
+/// stop`) send SIGTERM and get the graceful path.
+#[derive(Clone, Default)]
+struct GracefulStop(Arc<std::sync::atomic::AtomicBool>);
+
+impl GracefulStop {
+    /// Requests a stop. Returns `false` if one was already requested, meaning
+    /// the caller should escalate to an immediate interrupt.
+    fn request(&self) -> bool {
+        !self.0.swap(true, std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Returns `Stopped` once a stop has been requested.
+    fn termination_reason(&self) -> Option<TerminationReason> {
+        self.0
+            .load(std::sync::atomic::Ordering::SeqCst)
+            .then_some(TerminationReason::Stopped)
+    }
+}
+
+/// Destination for `--explain` routing traces.
+#[derive(Clone)]
+enum ExplainSink {
+    Stderr,
+    File(Arc<std::sync::Mutex<File>>),
+}
+
+impl ExplainSink {
+    fn file(path: &Path) -> Result<Self> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open explain trace file: {:?}", path))?;
+        info!("Routing explanations will be written to {:?}", path);
+        Ok(Self::File(Arc::new(std::sync::Mutex::new(file))))
+    }
+
+    fn write(&self, lines: &[String]) {
+        match self {
+            Self::Stderr => {
+                for line in lines {
+                    eprintln!("[explain] {line}");
+                }
+            }
+            Self::File(file) => {
+                use std::io::Write;
+                if let Ok(mut file) = file.lock() {
+                    for line in lines {
+                        let _ = writeln!(file, "[explain] {line}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Describes which hat a published event will trigger and why.
+fn explain_published_event(registry: &ralph_core::HatRegistry, event: &Event) -> String {
+    if event.topic.as_str().starts_with("human.") {
+        return format!("published {} -> human queue", event.topic);
+    }
+    if let Some(target) = &event.target {
+        return format!("published {} -> direct target {}", event.topic, target);
+    }
+    match registry.find_subscription(event.topic.as_str()) {
+        Some((hat, pattern)) => format!(
+            "published {} -> triggers {} (matched subscription '{}')",
+            event.topic, hat.id, pattern
+        ),
+        None => format!(
+            "published {} -> no hat subscribes, Ralph handles it (fallback)",
+            event.topic
+        ),
+    }
+}
+
 /// Logs events parsed from output to the event history file.
 ///
 /// When an event has no subscriber (orphan), also logs an `event.orphaned`
 /// system event to help Ralph understand the misconfiguration.
+///
+/// `build.blocked` records carry a `blocked_count`: how many times that task
+/// has blocked so far in `state`, plus earlier blocks in this output.
+///
+/// Returns how many malformed `<event>` tags were found. Does nothing with
+/// `json_events_only`, where events come solely from the JSONL file.
 fn log_events_from_output(
-    logger: &mut EventLogger,
+    logger: &AsyncEventLogger,
     iteration: u32,
     hat_id: &HatId,
     output: &str,
     registry: &ralph_core::HatRegistry,
-) {
-    let parser = EventParser::new();
-    let events = parser.parse(output);
+    config: &EventLoopConfig,
+    state: &ralph_core::LoopState,
+) -> usize {
+    if config.json_events_only {
+        return 0;
+    }
+
+    let parser = EventParser::new()
+        .with_max_events(config.max_events_per_iteration)
+        .with_topic_aliases(config.topic_aliases.clone());
+    let ParsedEvents { events, malformed } = parser.parse_with_malformed(output);
+    let mut block_counts = state.task_block_counts.clone();
 
     for event in events {
         // Determine which hat will be triggered by this event
@@ -1425,33 +1822,91 @@ fn log_events_from_output(
             )
             .with_source(hat_id.clone());
 
-            let orphan_record = EventRecord::new(iteration, "loop", &orphan_event, None::<&HatId>);
-            if let Err(e) = logger.log(&orphan_record) {
+            let orphan_record = logger.record(iteration, "loop", &orphan_event, None::<&HatId>);
+            if let Err(e) = logger.log(orphan_record) {
                 warn!("Failed to log event.orphaned: {}", e);
             }
         }
 
-        let record = EventRecord::new(iteration, hat_id.to_string(), &event, triggered);
+        let mut record = logger.record(iteration, hat_id.to_string(), &event, triggered);
+        if event.topic.as_str() == "build.blocked" {
+            let count = block_counts
+                .entry(EventLoop::extract_task_id(&event.payload))
+                .or_insert(0);
+            *count += 1;
+            record = record.with_blocked_count(*count);
+        }
 
-        if let Err(e) = logger.log(&record) {
+        if let Err(e) = logger.log(record) {
             warn!("Failed to log event {}: {}", event.topic, e);
         }
     }
+
+    malformed
+}
+
+/// Waits for queued event records to reach disk, warning if any write failed.
+async fn flush_event_log(logger: &AsyncEventLogger) {
+    if let Err(e) = logger.flush().await {
+        warn!(path = %logger.path().display(), error = %e, "Failed to write event log");
+    }
 }
 
 /// Logs the loop.terminate system event to the event history.
 ///
 /// Per spec: loop.terminate is an observer-only event published on loop exit.
-fn log_terminate_event(logger: &mut EventLogger, iteration: u32, event: &Event) {
+fn log_terminate_event(logger: &AsyncEventLogger, iteration: u32, event: &Event) {
     // loop.terminate is published by the orchestrator, not a hat
     // No hat can trigger on it (it's observer-only)
-    let record = EventRecord::new(iteration, "loop", event, None::<&HatId>);
+    let record = logger.record(iteration, "loop", event, None::<&HatId>);
 
-    if let Err(e) = logger.log(&record) {
+    if let Err(e) = logger.log(record) {
         warn!("Failed to log loop.terminate event: {}", e);
     }
 }
 
+/// Logs a `memory.injected` event listing the memories injected into the prompt.
+///
+/// Observer-only, like `loop.terminate`: the event loop skips it when reading
+/// events back. Nothing is logged when no memories were injected.
+fn log_injected_memories(logger: &AsyncEventLogger, iteration: u32, hat: &HatId, ids: &[String]) {
+    if ids.is_empty() {
+        return;
+    }
+
+    let event = Event::new(MEMORY_INJECTED_TOPIC, ids.join(", "));
+    let record = logger.record(iteration, hat.to_string(), &event, None::<&HatId>);
+
+    if let Err(e) = logger.log(record) {
+        warn!("Failed to log memory.injected event: {}", e);
+    }
+}
+
+/// Logs a `loop.iteration` record with the iteration's cost and duration.
+///
+/// Observer-only, like `memory.injected`: the event loop skips it when
+/// reading events back. The same values go in the payload (as JSON) and in
+/// the record's `cost` and `duration_ms` fields, which `ralph events --stats`
+/// sums.
+fn log_iteration_usage(
+    logger: &AsyncEventLogger,
+    iteration: u32,
+    hat: &HatId,
+    cost: f64,
+    duration: Duration,
+) {
+    let duration_ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+    let payload = serde_json::json!({ "cost": cost, "duration_ms": duration_ms });
+    let event = Event::new(LOOP_ITERATION_TOPIC, payload.to_string());
+    let record = logger
+        .record(iteration, hat.to_string(), &event, None::<&HatId>)
+        .with_usage(cost, duration_ms);
+
+    if let Err(e) = logger.log(record) {
+        warn!("Failed to log loop.iteration event: {}", e);
+    }
+}
+
 /// Gets the last commit info (short SHA and subject) for the summary file.
 fn get_last_commit_info_with_cmd(git_cmd: &OsStr) -> Option<String> {
     let output = Command::new(git_cmd)
@@ -1817,6 +2272,8 @@ fn create_robot_service(
 
     match ralph_telegram::TelegramService::new(workspace_root, bot_token, timeout_secs, loop_id) {
         Ok(service) => {
+            let service =
+                service.with_parse_mode(crate::bot::telegram_parse_mode(config.robot.parse_mode()));
             if let Err(e) = service.start() {
                 warn!(error = %e, "Failed to start robot service");
                 return None;
@@ -1839,8 +2296,8 @@ fn create_robot_service(
 mod tests {
     use super::*;
     use crate::test_support::CwdGuard;
-    use ralph_core::HatRegistry;
     use ralph_core::planning_session::{ConversationEntry, ConversationType};
+    use ralph_core::{EventRecord, HatRegistry};
     use ralph_proto::{Hat, Topic};
     use std::ffi::OsStr;
     use std::sync::Arc;
@@ -2156,11 +2613,160 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_interrupt_raised_when_shutdown_token_fires() {
+        let (token_tx, token_rx) = tokio::sync::oneshot::channel::<()>();
+        let (interrupt_tx, mut interrupt_rx) = tokio::sync::watch::channel(false);
+        let robot_shutdown = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let handle = spawn_interrupt_on(
+            async {
+                let _ = token_rx.await;
+            },
+            interrupt_tx,
+            Some(Arc::clone(&robot_shutdown)),
+        );
+        assert!(!*interrupt_rx.borrow());
+
+        token_tx.send(()).unwrap();
+        interrupt_rx.changed().await.unwrap();
+        handle.await.unwrap();
+
+        assert!(*interrupt_rx.borrow());
+        assert!(robot_shutdown.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    // The loop-level behaviour is covered end to end by
+    // `test_run_sigterm_finishes_in_flight_iteration` in tests/integration_run.rs.
     #[test]
-    fn test_log_events_from_output_records_orphan_event() {
+    fn test_graceful_stop_second_request_escalates() {
+        let stop = GracefulStop::default();
+        let handler = stop.clone();
+        assert_eq!(stop.termination_reason(), None);
+
+        assert!(handler.request(), "first SIGTERM requests a graceful stop");
+        assert_eq!(stop.termination_reason(), Some(TerminationReason::Stopped));
+        assert!(
+            !handler.request(),
+            "second SIGTERM escalates to an interrupt"
+        );
+    }
+
+    /// A paused loop in a temp workspace with the pause signal set.
+    fn paused_event_loop() -> (tempfile::TempDir, EventLoop) {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp_dir.path().to_path_buf();
+        let ralph_dir = temp_dir.path().join(".ralph");
+        fs::create_dir_all(&ralph_dir).unwrap();
+        fs::write(ralph_dir.join("pause-requested"), "").unwrap();
+        (temp_dir, EventLoop::new(config))
+    }
+
+    #[test]
+    fn test_pause_ends_for_stop_and_cancel_signals() {
+        for signal in ["stop-requested", "cancel-requested"] {
+            let (temp_dir, event_loop) = paused_event_loop();
+            let no_stop = GracefulStop::default();
+            assert_eq!(
+                pause_state(&event_loop, false, &no_stop),
+                PauseState::Paused
+            );
+
+            fs::write(temp_dir.path().join(".ralph").join(signal), "").unwrap();
+            assert_eq!(
+                pause_state(&event_loop, false, &no_stop),
+                PauseState::Stopping,
+                "{signal}"
+            );
+            assert!(event_loop.check_termination().is_some(), "{signal}");
+        }
+    }
+
+    #[test]
+    fn test_pause_ends_for_graceful_stop_interrupt_and_resume() {
+        let (temp_dir, event_loop) = paused_event_loop();
+        let stop = GracefulStop::default();
+        assert_eq!(pause_state(&event_loop, true, &stop), PauseState::Stopping);
+
+        stop.request();
+        assert_eq!(pause_state(&event_loop, false, &stop), PauseState::Stopping);
+
+        fs::remove_file(temp_dir.path().join(".ralph/pause-requested")).unwrap();
+        assert_eq!(
+            pause_state(&event_loop, false, &GracefulStop::default()),
+            PauseState::Resumed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_log_injected_memories_records_ids() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
         let log_path = temp_dir.path().join("events.jsonl");
-        let mut logger = EventLogger::new(&log_path);
+        let logger = EventLogger::new(&log_path).with_async();
+        let hat_id = HatId::new("builder");
+
+        log_injected_memories(&logger, 3, &hat_id, &[]);
+        logger.flush().await.unwrap();
+        assert!(!log_path.exists(), "nothing injected, nothing logged");
+
+        let ids = vec![
+            "mem-1737372000-a1b2".to_string(),
+            "mem-1737372100-c3d4".to_string(),
+        ];
+        log_injected_memories(&logger, 3, &hat_id, &ids);
+
+        logger.flush().await.unwrap();
+        let content = std::fs::read_to_string(&log_path).expect("read events");
+        let records: Vec<EventRecord> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("record"))
+            .collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "memory.injected");
+        assert_eq!(records[0].iteration, 3);
+        assert_eq!(records[0].hat, "builder");
+        assert_eq!(
+            records[0].payload,
+            "mem-1737372000-a1b2, mem-1737372100-c3d4"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_log_iteration_usage_records_cost_and_duration() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let logger = EventLogger::new(&log_path).with_async();
+        let hat_id = HatId::new("builder");
+
+        log_iteration_usage(&logger, 1, &hat_id, 0.25, Duration::from_millis(1500));
+        log_iteration_usage(&logger, 2, &hat_id, 0.5, Duration::from_secs(2));
+
+        logger.flush().await.unwrap();
+        let content = std::fs::read_to_string(&log_path).expect("read events");
+        let records: Vec<EventRecord> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("record"))
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].topic, LOOP_ITERATION_TOPIC);
+        assert_eq!(records[0].hat, "builder");
+        assert_eq!(records[0].cost, Some(0.25));
+        assert_eq!(records[0].duration_ms, Some(1500));
+        let payload: serde_json::Value =
+            serde_json::from_str(&records[1].payload).expect("JSON payload");
+        assert_eq!(payload["duration_ms"], 2000);
+
+        let stats = ralph_core::EventStats::from_records(&records);
+        assert!((stats.total_cost - 0.75).abs() < f64::EPSILON);
+        assert_eq!(stats.total_duration_ms, 3500);
+    }
+
+    #[tokio::test]
+    async fn test_log_events_from_output_records_orphan_event() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let logger = EventLogger::new(&log_path).with_async();
 
         let mut registry = HatRegistry::new();
         let mut hat = Hat::new("planner", "Planner");
@@ -2171,8 +2777,21 @@ mod tests {
 <event topic=\"unknown.event\">oops</event>";
         let hat_id = HatId::new("tester");
 
-        log_events_from_output(&mut logger, 1, &hat_id, output, &registry);
+        let config = EventLoopConfig {
+            max_events_per_iteration: 0,
+            ..EventLoopConfig::default()
+        };
+        log_events_from_output(
+            &logger,
+            1,
+            &hat_id,
+            output,
+            &registry,
+            &config,
+            &ralph_core::LoopState::new(),
+        );
 
+        logger.flush().await.unwrap();
         let content = std::fs::read_to_string(&log_path).expect("read events");
         let records: Vec<EventRecord> = content
             .lines()
@@ -2192,15 +2811,153 @@ mod tests {
         assert_eq!(triggered.as_deref(), Some("planner"));
     }
 
+    #[tokio::test]
+    async fn test_log_events_from_output_logs_canonical_topic_for_alias() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let logger = EventLogger::new(&log_path).with_async();
+
+        let mut registry = HatRegistry::new();
+        let mut hat = Hat::new("reviewer", "Reviewer");
+        hat.subscriptions.push(Topic::new("work.done"));
+        registry.register(hat);
+
+        let config = EventLoopConfig {
+            topic_aliases: std::collections::HashMap::from([(
+                "work_finished".to_string(),
+                "work.done".to_string(),
+            )]),
+            ..EventLoopConfig::default()
+        };
+        log_events_from_output(
+            &logger,
+            1,
+            &HatId::new("builder"),
+            "<event topic=\"work_finished\">done</event>",
+            &registry,
+            &config,
+            &ralph_core::LoopState::new(),
+        );
+
+        logger.flush().await.unwrap();
+        let content = std::fs::read_to_string(&log_path).expect("read events");
+        let records: Vec<EventRecord> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("record"))
+            .collect();
+        assert_eq!(records.len(), 1, "alias should not be logged as orphaned");
+        assert_eq!(records[0].topic, "work.done");
+        assert_eq!(records[0].triggered.as_deref(), Some("reviewer"));
+    }
+
+    #[tokio::test]
+    async fn test_log_events_from_output_ignores_xml_in_json_events_only_mode() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let logger = EventLogger::new(&log_path).with_async();
+        let config = EventLoopConfig {
+            json_events_only: true,
+            ..EventLoopConfig::default()
+        };
+
+        let output = "<event topic=\"build.done\">tests: pass</event>";
+        log_events_from_output(
+            &logger,
+            1,
+            &HatId::new("builder"),
+            output,
+            &HatRegistry::new(),
+            &config,
+            &ralph_core::LoopState::new(),
+        );
+
+        logger.flush().await.unwrap();
+
+        assert!(!log_path.exists(), "stdout events should not be logged");
+    }
+
+    #[tokio::test]
+    async fn test_log_events_from_output_counts_blocks_per_task() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let logger = EventLogger::new(&log_path).with_async();
+        let mut state = ralph_core::LoopState::new();
+        let hat_id = HatId::new("builder");
+        let registry = HatRegistry::new();
+        let config = EventLoopConfig::default();
+
+        let output = "<event topic=\"build.blocked\">Fix bug\nCan't compile</event>\n\
+<event topic=\"build.blocked\">Other task</event>\n\
+<event topic=\"build.blocked\">Fix bug\nStill stuck</event>";
+        log_events_from_output(&logger, 1, &hat_id, output, &registry, &config, &state);
+
+        // Blocks the loop already counted carry over to the next iteration
+        state.task_block_counts.insert("Fix bug".to_string(), 2);
+        let output = "<event topic=\"build.blocked\">Fix bug\nThird time</event>";
+        log_events_from_output(&logger, 2, &hat_id, output, &registry, &config, &state);
+
+        logger.flush().await.unwrap();
+
+        let counts: Vec<(String, Option<u32>)> = std::fs::read_to_string(&log_path)
+            .expect("read events")
+            .lines()
+            .map(|line| serde_json::from_str::<EventRecord>(line).expect("record"))
+            .filter(|record| record.topic == "build.blocked")
+            .map(|record| {
+                let task = record
+                    .payload
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                (task, record.blocked_count)
+            })
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                ("Fix bug".to_string(), Some(1)),
+                ("Other task".to_string(), Some(1)),
+                ("Fix bug".to_string(), Some(2)),
+                ("Fix bug".to_string(), Some(3)),
+            ]
+        );
+    }
+
     #[test]
-    fn test_log_terminate_event_writes_record() {
+    fn test_explain_published_event_names_triggered_hat() {
+        let config: RalphConfig = serde_yaml::from_str(
+            r#"
+hats:
+  reviewer:
+    name: "Reviewer"
+    description: "Reviews"
+    triggers: ["build.*"]
+"#,
+        )
+        .unwrap();
+        let registry = ralph_core::HatRegistry::from_config(&config);
+
+        let line = explain_published_event(&registry, &Event::new("build.done", ""));
+        assert_eq!(
+            line,
+            "published build.done -> triggers reviewer (matched subscription 'build.*')"
+        );
+
+        let orphan = explain_published_event(&registry, &Event::new("deploy.done", ""));
+        assert!(orphan.contains("no hat subscribes"));
+    }
+
+    #[tokio::test]
+    async fn test_log_terminate_event_writes_record() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
         let log_path = temp_dir.path().join("events.jsonl");
-        let mut logger = EventLogger::new(&log_path);
+        let logger = EventLogger::new(&log_path).with_async();
 
         let event = Event::new("loop.terminate", "done");
-        log_terminate_event(&mut logger, 7, &event);
+        log_terminate_event(&logger, 7, &event);
 
+        logger.flush().await.unwrap();
         let content = std::fs::read_to_string(&log_path).expect("read events");
         let records: Vec<EventRecord> = content
             .lines()
@@ -12,6 +12,8 @@
 //! - `prune`: Clean up stale loops
 //! - `attach`: Open shell in worktree
 //! - `diff`: Show changes from merge-base
+//!
+//! Also hosts the top-level `ralph cancel` command.
 
 use std::path::PathBuf;
 use std::process::Command;
@@ -20,7 +22,9 @@ use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
 
 use ralph_core::worktree::{list_ralph_worktrees, remove_worktree};
-use ralph_core::{LoopRegistry, MergeButtonState, MergeQueue, MergeState, merge_button_state};
+use ralph_core::{
+    LockMetadata, LoopRegistry, MergeButtonState, MergeQueue, MergeState, merge_button_state,
+};
 
 /// Manage parallel loops.
 #[derive(Parser, Debug)]
@@ -126,6 +130,14 @@ pub struct StopArgs {
     pub force: bool,
 }
 
+/// Arguments for `ralph cancel`.
+#[derive(Parser, Debug)]
+pub struct CancelArgs {
+    /// Loop ID (group-id). If omitted, cancels the active primary loop.
+    #[arg(long)]
+    pub loop_id: Option<String>,
+}
+
 #[derive(Parser, Debug)]
 pub struct AttachArgs {
     /// Loop ID
@@ -688,20 +700,19 @@ fn discard_loop(args: DiscardArgs) -> Result<()> {
     Ok(())
 }
 
-/// Stop a running loop.
-fn stop_loop(args: StopArgs) -> Result<()> {
+/// Resolves a running loop to its ID, workspace root and lock metadata.
+///
+/// With no ID, targets the primary loop in the current directory.
+fn resolve_running_loop(loop_id: Option<&str>) -> Result<(String, PathBuf, LockMetadata)> {
     use ralph_core::LoopLock;
 
     let cwd = std::env::current_dir()?;
-    let (loop_id, worktree_path) = match args.loop_id.as_deref() {
+    let (loop_id, worktree_path) = match loop_id {
         Some(id) => resolve_loop(&cwd, id)?,
         None => ("(primary)".to_string(), None),
     };
 
-    let target_root = worktree_path
-        .as_ref()
-        .map(PathBuf::from)
-        .unwrap_or_else(|| cwd.clone());
+    let target_root = worktree_path.map(PathBuf::from).unwrap_or(cwd);
 
     let metadata = LoopLock::read_existing(&target_root)?
         .context("Cannot determine active loop - it may have already stopped")?;
@@ -714,6 +725,13 @@ fn stop_loop(args: StopArgs) -> Result<()> {
         );
     }
 
+    Ok((loop_id, target_root, metadata))
+}
+
+/// Stop a running loop.
+fn stop_loop(args: StopArgs) -> Result<()> {
+    let (loop_id, target_root, metadata) = resolve_running_loop(args.loop_id.as_deref())?;
+
     if args.force {
         // Force-stop with SIGKILL for immediate termination.
         #[cfg(unix)]
@@ -752,6 +770,28 @@ fn stop_loop(args: StopArgs) -> Result<()> {
     Ok(())
 }
 
+/// Cancel a running loop (`ralph cancel`).
+///
+/// Writes `.ralph/cancel-requested`, which the loop checks at the next
+/// iteration boundary and terminates with `TerminationReason::Stopped`.
+pub fn cancel_loop(args: CancelArgs) -> Result<()> {
+    let (loop_id, target_root, metadata) = resolve_running_loop(args.loop_id.as_deref())?;
+
+    let cancel_path = target_root.join(".ralph/cancel-requested");
+    if let Some(parent) = cancel_path.parent() {
+        std::fs::create_dir_all(parent)
+            .context("Failed to create .ralph directory for cancel signal")?;
+    }
+    std::fs::write(&cancel_path, "").context("Failed to write cancel signal")?;
+
+    println!(
+        "Cancel requested for loop '{}' (PID {}). The loop will cancel at the next iteration boundary.",
+        loop_id, metadata.pid
+    );
+
+    Ok(())
+}
+
 /// Prune stale loops.
 fn prune_stale() -> Result<()> {
     let cwd = std::env::current_dir()?;
@@ -1381,6 +1421,30 @@ mod tests {
         assert!(temp_dir.path().join(".ralph/stop-requested").exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_cancel_loop_writes_cancel_requested_file() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let _lock = LoopLock::try_acquire(temp_dir.path(), "test prompt").expect("lock");
+
+        cancel_loop(CancelArgs { loop_id: None }).expect("cancel loop");
+
+        assert!(temp_dir.path().join(".ralph/cancel-requested").exists());
+        assert!(!temp_dir.path().join(".ralph/stop-requested").exists());
+    }
+
+    #[test]
+    fn test_cancel_loop_without_running_loop_fails() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let err = cancel_loop(CancelArgs { loop_id: None }).unwrap_err();
+        assert!(err.to_string().contains("Cannot determine active loop"));
+        assert!(!temp_dir.path().join(".ralph/cancel-requested").exists());
+    }
+
     #[test]
     fn test_attach_to_loop_requires_worktree() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
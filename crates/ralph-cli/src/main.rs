@@ -13,6 +13,7 @@
 //! - Work item tracking via `ralph task`
 
 mod bot;
+mod config_cli;
 mod display;
 mod doctor;
 mod hats;
@@ -29,16 +30,21 @@ mod task_cli;
 #[cfg(test)]
 mod test_support;
 mod tools;
+mod version;
 mod web;
 
 use anyhow::{Context, Result};
-use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use ralph_adapters::detect_backend;
 use ralph_core::{
-    CheckStatus, EventHistory, LockError, LoopContext, LoopEntry, LoopLock, LoopRegistry,
-    PreflightReport, PreflightRunner, RalphConfig, TerminationReason,
+    CheckStatus, EventHistory, EventParser, EventRecord, EventSeverity, EventStats, EventTail,
+    LockError, LoopContext, LoopEntry, LoopLock, LoopRegistry, PreflightReport, PreflightRunner,
+    RalphConfig, TerminationReason,
+    dry_run::DryRunReport,
     worktree::{WorktreeConfig, create_worktree, ensure_gitignore, remove_worktree},
 };
+use std::collections::HashMap;
 use std::fs;
 use std::io::{IsTerminal, Write, stdout};
 use std::path::{Path, PathBuf};
@@ -222,6 +228,7 @@ pub enum OutputFormat {
 }
 
 // Re-export colors and truncate from display module for use in this file
+use display::SourcedRecord;
 use display::colors;
 use display::truncate;
 
@@ -239,6 +246,14 @@ pub enum ConfigSource {
 }
 
 impl ConfigSource {
+    /// Resolves a relative file path against `base`; other sources are unchanged.
+    fn resolved_against(self, base: &Path) -> Self {
+        match self {
+            ConfigSource::File(path) if path.is_relative() => ConfigSource::File(base.join(path)),
+            other => other,
+        }
+    }
+
     /// Parse a config source string into its variant.
     ///
     /// Format:
@@ -363,6 +378,24 @@ pub(crate) fn load_config_with_overrides(
     Ok(config)
 }
 
+/// Loads a config file, rejecting unknown keys when `strict` is set.
+fn parse_config_file(path: &Path, strict: bool) -> Result<RalphConfig, ralph_core::ConfigError> {
+    if strict {
+        RalphConfig::from_file_strict(path)
+    } else {
+        RalphConfig::from_file(path)
+    }
+}
+
+/// Parses config YAML, rejecting unknown keys when `strict` is set.
+fn parse_config_yaml(content: &str, strict: bool) -> Result<RalphConfig, ralph_core::ConfigError> {
+    if strict {
+        RalphConfig::parse_yaml_strict(content)
+    } else {
+        RalphConfig::parse_yaml(content)
+    }
+}
+
 /// Ralph Orchestrator - Multi-agent orchestration framework
 #[derive(Parser, Debug)]
 #[command(name = "ralph", version, about)]
@@ -412,6 +445,9 @@ enum Commands {
     /// Initialize a new ralph.yml configuration file
     Init(InitArgs),
 
+    /// Inspect and upgrade configuration files
+    Config(config_cli::ConfigArgs),
+
     /// Clean up Ralph artifacts (.agent/ directory)
     Clean(CleanArgs),
 
@@ -433,6 +469,9 @@ enum Commands {
     /// Manage parallel loops
     Loops(loops::LoopsArgs),
 
+    /// Cancel a running loop at its next iteration boundary
+    Cancel(loops::CancelArgs),
+
     /// Manage configured hats
     Hats(hats::HatsArgs),
 
@@ -444,6 +483,10 @@ enum Commands {
 
     /// Generate shell completions
     Completions(CompletionsArgs),
+
+    /// Show version and build information (`--verbose` adds build metadata
+    /// and detected backend CLI versions)
+    Version(version::VersionArgs),
 }
 
 /// Arguments for the init subcommand.
@@ -487,6 +530,10 @@ struct RunArgs {
     #[arg(long)]
     max_iterations: Option<u32>,
 
+    /// Cap activations for every hat without its own max_activations
+    #[arg(long, value_name = "N")]
+    max_iterations_per_hat: Option<u32>,
+
     /// Override completion promise
     #[arg(long)]
     completion_promise: Option<String>,
@@ -495,6 +542,33 @@ struct RunArgs {
     #[arg(long)]
     dry_run: bool,
 
+    /// Print the fully assembled first-iteration and coordinator prompts,
+    /// then exit without invoking any backend
+    #[arg(long, conflicts_with = "dry_run")]
+    show_prompt: bool,
+
+    /// With --dry-run: also check hat reachability, closed cycles, and prompt
+    /// token estimates, and exit non-zero if any issue is found.
+    #[arg(long, requires = "dry_run")]
+    strict: bool,
+
+    /// Fail on config keys Ralph does not recognize (e.g. typos) instead of
+    /// silently ignoring them
+    #[arg(long)]
+    strict_config: bool,
+
+    /// Per-prompt token budget checked by --dry-run --strict (0 = unlimited)
+    #[arg(long, value_name = "TOKENS", default_value_t = ralph_core::dry_run::DEFAULT_PROMPT_TOKEN_BUDGET)]
+    token_budget: usize,
+
+    /// Workspace root directory for the whole run (default: current directory).
+    /// Prompt files, config paths inside the config, `.ralph/` state, and git
+    /// operations all resolve against it. An explicit -c/--config path stays
+    /// relative to where ralph was invoked; the default `ralph.yml` is read
+    /// from the workspace.
+    #[arg(long, value_name = "DIR")]
+    workspace: Option<PathBuf>,
+
     /// Continue from existing scratchpad (resume interrupted loop).
     /// Use this when a previous run was interrupted and you want to
     /// continue from where it left off.
@@ -555,6 +629,26 @@ struct RunArgs {
     #[arg(long, value_name = "FILE")]
     record_session: Option<PathBuf>,
 
+    /// Trace routing decisions each iteration: pending events, the selected hat
+    /// and matched subscription, and which hat each published event triggers.
+    /// Written to stderr, or `.ralph/explain.log` when the TUI is active.
+    #[arg(long)]
+    explain: bool,
+
+    /// Read events only from the JSONL events file and ignore `<event>` tags
+    /// in agent output. Overrides event_loop.json_events_only from config.
+    #[arg(long)]
+    json_events_only: bool,
+
+    /// Run exactly one hat execution and stop, ignoring max_iterations.
+    #[arg(long)]
+    once: bool,
+
+    /// Write a JSON summary (reason, iteration, elapsed_secs, cumulative_cost)
+    /// to this path when the loop terminates
+    #[arg(long, value_name = "PATH")]
+    summary_json: Option<PathBuf>,
+
     /// Custom backend command and arguments (use after --)
     #[arg(last = true)]
     custom_args: Vec<String>,
@@ -610,17 +704,59 @@ struct EventsArgs {
     #[arg(long)]
     iteration: Option<u32>,
 
+    /// Show only events from this iteration onward (inclusive)
+    #[arg(long, value_name = "N")]
+    from: Option<u32>,
+
+    /// Show only events up to this iteration (inclusive)
+    #[arg(long, value_name = "N")]
+    to: Option<u32>,
+
+    /// Filter by the hat that published the event (e.g., "builder")
+    #[arg(long)]
+    hat: Option<String>,
+
+    /// Show only events at or above this severity (info, warn, error)
+    #[arg(long, value_name = "LEVEL")]
+    severity: Option<EventSeverity>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     format: OutputFormat,
 
-    /// Path to events file (default: auto-detects current run)
-    #[arg(long)]
-    file: Option<PathBuf>,
+    /// Path to events file (default: auto-detects current run). Repeat to
+    /// merge several logs into one timeline.
+    #[arg(long, action = ArgAction::Append)]
+    file: Vec<PathBuf>,
 
     /// Clear the event history
     #[arg(long)]
     clear: bool,
+
+    /// Keep running and print new events as they are appended (Ctrl+C to stop)
+    #[arg(short = 'f', long, conflicts_with = "clear")]
+    follow: bool,
+
+    /// Follow, and exit 0 once an event whose topic matches this pattern
+    /// (e.g. "build.done", "build.*") is logged, even if other filters hide
+    /// it. Existing history counts too unless --since-now is given
+    #[arg(long, value_name = "TOPIC", conflicts_with = "clear")]
+    follow_and_exit_on: Option<String>,
+
+    /// With --follow or --follow-and-exit-on, skip existing history and only
+    /// show events appended from now on
+    #[arg(long, conflicts_with_all = ["clear", "stats", "last"])]
+    since_now: bool,
+
+    /// With --follow-and-exit-on, exit non-zero if no matching event arrives
+    /// within this many seconds
+    #[arg(long, value_name = "SECS", requires = "follow_and_exit_on")]
+    wait_timeout: Option<u64>,
+
+    /// Print aggregate counts (per topic, per hat, iteration range) instead of
+    /// individual events
+    #[arg(long, conflicts_with_all = ["clear", "follow", "follow_and_exit_on"])]
+    stats: bool,
 }
 
 /// Arguments for the clean subcommand.
@@ -722,6 +858,15 @@ struct CompletionsArgs {
     shell: clap_complete::Shell,
 }
 
+/// Whether -c/--config was passed, as opposed to the `ralph.yml` default.
+fn config_is_explicit(matches: &ArgMatches) -> bool {
+    let source = matches
+        .subcommand()
+        .and_then(|(_, sub)| sub.value_source("config"))
+        .or_else(|| matches.value_source("config"));
+    source != Some(ValueSource::DefaultValue)
+}
+
 fn completions_command(args: CompletionsArgs) -> Result<()> {
     use clap_complete::generate;
 
@@ -736,7 +881,8 @@ async fn main() -> Result<()> {
     // This prevents the terminal from being left in raw mode or alternate screen
     install_panic_hook();
 
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // Detect if TUI mode is requested - TUI owns the terminal, so logs must not go to stdout
     // TUI is enabled by default unless --no-tui is specified or --autonomous is used
@@ -827,9 +973,27 @@ async fn main() -> Result<()> {
     }
 
     // Parse all config sources from CLI
-    let config_sources: Vec<ConfigSource> =
+    let mut config_sources: Vec<ConfigSource> =
         cli.config.iter().map(|s| ConfigSource::parse(s)).collect();
 
+    // `run --workspace` switches directory before loading config; explicit
+    // -c paths and --summary-json stay relative to where ralph was invoked
+    if let Some(Commands::Run(args)) = &mut cli.command
+        && args.workspace.is_some()
+    {
+        let invocation_dir = std::env::current_dir().context("Failed to read current directory")?;
+        if config_is_explicit(&matches) {
+            config_sources = config_sources
+                .into_iter()
+                .map(|source| source.resolved_against(&invocation_dir))
+                .collect();
+        }
+        args.summary_json = args
+            .summary_json
+            .take()
+            .map(|path| invocation_dir.join(path));
+    }
+
     match cli.command {
         Some(Commands::Run(args)) => {
             run_command(&config_sources, cli.verbose, cli.color, args).await
@@ -844,15 +1008,17 @@ async fn main() -> Result<()> {
         Some(Commands::Resume(args)) => {
             resume_command(&config_sources, cli.verbose, cli.color, args).await
         }
-        Some(Commands::Events(args)) => events_command(cli.color, args),
+        Some(Commands::Events(args)) => events_command(cli.color, args).await,
         Some(Commands::Init(args)) => init_command(cli.color, args),
+        Some(Commands::Config(args)) => config_cli::execute(args),
         Some(Commands::Clean(args)) => clean_command(&config_sources, cli.color, args),
-        Some(Commands::Emit(args)) => emit_command(cli.color, args),
+        Some(Commands::Emit(args)) => emit_command(&config_sources, cli.color, args),
         Some(Commands::Plan(args)) => plan_command(&config_sources, cli.color, args),
         Some(Commands::CodeTask(args)) => code_task_command(&config_sources, cli.color, args),
         Some(Commands::Task(args)) => code_task_command(&config_sources, cli.color, args),
         Some(Commands::Tools(args)) => tools::execute(args, cli.color.should_use_colors()).await,
         Some(Commands::Loops(args)) => loops::execute(args, cli.color.should_use_colors()),
+        Some(Commands::Cancel(args)) => loops::cancel_loop(args),
         Some(Commands::Hats(args)) => {
             hats::execute(&config_sources, args, cli.color.should_use_colors())
         }
@@ -861,6 +1027,7 @@ async fn main() -> Result<()> {
             bot::execute(args, &config_sources, cli.color.should_use_colors()).await
         }
         Some(Commands::Completions(args)) => completions_command(args),
+        Some(Commands::Version(args)) => version::execute(args, cli.verbose),
         None => {
             // Default to run with TUI enabled (new default behavior)
             let args = RunArgs {
@@ -868,8 +1035,14 @@ async fn main() -> Result<()> {
                 prompt_file: None,
                 backend: None,
                 max_iterations: None,
+                max_iterations_per_hat: None,
                 completion_promise: None,
                 dry_run: false,
+                show_prompt: false,
+                strict: false,
+                strict_config: false,
+                token_budget: ralph_core::dry_run::DEFAULT_PROMPT_TOKEN_BUDGET,
+                workspace: None,
                 continue_mode: false,
                 no_tui: false, // TUI enabled by default
                 autonomous: false,
@@ -880,6 +1053,10 @@ async fn main() -> Result<()> {
                 verbose: false,
                 quiet: false,
                 record_session: None,
+                explain: false,
+                json_events_only: false,
+                once: false,
+                summary_json: None,
                 custom_args: Vec::new(),
             };
             run_command(&config_sources, cli.verbose, cli.color, args).await
@@ -1042,12 +1219,63 @@ fn print_preflight_summary(
     }
 }
 
+fn print_prompt_previews(previews: &[ralph_core::dry_run::PromptPreview]) {
+    for (i, preview) in previews.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("═══ Prompt for hat: {} ═══", preview.hat);
+        println!();
+        println!("{}", preview.text);
+    }
+}
+
+fn print_dry_run_report(report: &DryRunReport) {
+    println!("  Entry topic: {}", report.entry_topic);
+    if !report.reachable_hats.is_empty() {
+        println!("  Reachable hats: {}", report.reachable_hats.join(", "));
+    }
+    for estimate in &report.prompts {
+        println!(
+            "  Prompt tokens ({}): ~{}",
+            estimate.prompt, estimate.tokens
+        );
+    }
+    if report.is_clean() {
+        println!("  Strict checks: passed");
+    } else {
+        println!("  Strict checks: {} issue(s)", report.issues.len());
+        for issue in &report.issues {
+            println!("  ✗ {issue}");
+        }
+    }
+}
+
 async fn run_command(
     config_sources: &[ConfigSource],
     verbose: bool,
     color_mode: ColorMode,
     args: RunArgs,
 ) -> Result<()> {
+    // Switch into the workspace before anything resolves relative paths
+    if let Some(workspace) = &args.workspace {
+        let workspace_root = workspace
+            .canonicalize()
+            .with_context(|| format!("Invalid workspace path: {}", workspace.display()))?;
+        if !workspace_root.is_dir() {
+            anyhow::bail!(
+                "Workspace path is not a directory: {}",
+                workspace_root.display()
+            );
+        }
+        std::env::set_current_dir(&workspace_root).with_context(|| {
+            format!(
+                "Failed to enter workspace directory: {}",
+                workspace_root.display()
+            )
+        })?;
+    }
+
     // Partition sources: file/builtin/remote sources vs overrides
     let (primary_sources, overrides): (Vec<_>, Vec<_>) = config_sources
         .iter()
@@ -1063,7 +1291,7 @@ async fn run_command(
         match source {
             ConfigSource::File(path) => {
                 if path.exists() {
-                    RalphConfig::from_file(path)
+                    parse_config_file(path, args.strict_config)
                         .with_context(|| format!("Failed to load config from {:?}", path))?
                 } else {
                     warn!("Config file {:?} not found, using defaults", path);
@@ -1079,7 +1307,7 @@ async fn run_command(
                         available
                     )
                 })?;
-                RalphConfig::parse_yaml(preset.content)
+                parse_config_yaml(preset.content, args.strict_config)
                     .with_context(|| format!("Failed to parse builtin preset '{}'", name))?
             }
             ConfigSource::Remote(url) => {
@@ -1101,7 +1329,7 @@ async fn run_command(
                     .await
                     .with_context(|| format!("Failed to read config content from {}", url))?;
 
-                RalphConfig::parse_yaml(&content)
+                parse_config_yaml(&content, args.strict_config)
                     .with_context(|| format!("Failed to parse config from {}", url))?
             }
             ConfigSource::Override { .. } => unreachable!("Partitioned out overrides"),
@@ -1110,7 +1338,7 @@ async fn run_command(
         // Only overrides specified - load default ralph.yml as base
         let default_path = PathBuf::from("ralph.yml");
         if default_path.exists() {
-            RalphConfig::from_file(&default_path)
+            parse_config_file(&default_path, args.strict_config)
                 .with_context(|| "Failed to load config from ralph.yml")?
         } else {
             warn!("Config file ralph.yml not found, using defaults");
@@ -1160,12 +1388,27 @@ async fn run_command(
     if let Some(max_iter) = args.max_iterations {
         config.event_loop.max_iterations = max_iter;
     }
+    if let Some(max) = args.max_iterations_per_hat {
+        config.event_loop.max_iterations_per_hat = Some(max);
+    }
     if let Some(promise) = args.completion_promise {
         config.event_loop.completion_promise = promise;
     }
     if verbose {
         config.verbose = true;
     }
+    if args.explain {
+        config.explain = true;
+    }
+    if args.json_events_only {
+        config.event_loop.json_events_only = true;
+    }
+    if args.once {
+        config.event_loop.single_iteration = true;
+    }
+    if let Some(path) = args.summary_json {
+        config.event_loop.summary_json = Some(path.to_string_lossy().into_owned());
+    }
 
     // Apply execution mode overrides per spec
     // TUI is enabled by default (unless --no-tui is specified)
@@ -1193,6 +1436,19 @@ async fn run_command(
         eprintln!("{warning}");
     }
 
+    // Install configured secret redaction (patterns already checked by validate())
+    if let Ok(redactor) = ralph_core::redaction::Redactor::new(&config.core.redact_patterns) {
+        ralph_core::redaction::install(redactor);
+    }
+
+    // Show prompts before backend detection so no backend is touched
+    if args.show_prompt {
+        let previews = ralph_core::dry_run::preview_prompts(&config)
+            .map_err(|e| anyhow::anyhow!("Prompt unavailable: {e}"))?;
+        print_prompt_previews(&previews);
+        return Ok(());
+    }
+
     // Handle auto-detection if backend is "auto"
     if config.cli.backend == "auto" {
         let priority = config.get_agent_priority();
@@ -1265,6 +1521,13 @@ async fn run_command(
         if let Some(report) = preflight_report.as_ref() {
             print_preflight_summary(report, preflight_verbose, "  Preflight: ", true);
         }
+        if args.strict {
+            let report = ralph_core::dry_run::analyze(&config, args.token_budget);
+            print_dry_run_report(&report);
+            if !report.is_clean() {
+                anyhow::bail!("Strict dry run found {} issue(s)", report.issues.len());
+            }
+        }
         return Ok(());
     }
 
@@ -1573,6 +1836,11 @@ async fn resume_command(
         eprintln!("{warning}");
     }
 
+    // Install configured secret redaction (patterns already checked by validate())
+    if let Ok(redactor) = ralph_core::redaction::Redactor::new(&config.core.redact_patterns) {
+        ralph_core::redaction::install(redactor);
+    }
+
     // Handle auto-detection if backend is "auto"
     if config.cli.backend == "auto" {
         let priority = config.get_agent_priority();
@@ -1704,21 +1972,38 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
     Ok(())
 }
 
-fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
+async fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     let use_colors = color_mode.should_use_colors();
 
+    let from = args.from.unwrap_or(0);
+    let to = args.to.unwrap_or(u32::MAX);
+    if from > to {
+        anyhow::bail!("--from ({from}) must not be greater than --to ({to})");
+    }
+
     // Read events path from marker file, fall back to default if marker doesn't exist
     // This ensures `ralph events` reads from the same events file as the active run
-    let history = match args.file {
-        Some(path) => EventHistory::new(path),
-        None => fs::read_to_string(".ralph/current-events")
-            .map(|s| EventHistory::new(s.trim()))
-            .unwrap_or_else(|_| EventHistory::default_path()),
+    let mut logs: Vec<EventLog> = if args.file.is_empty() {
+        vec![EventLog::new(
+            fs::read_to_string(".ralph/current-events")
+                .map(|s| PathBuf::from(s.trim()))
+                .unwrap_or_else(|_| PathBuf::from(ralph_core::EventLogger::DEFAULT_PATH)),
+            None,
+        )]
+    } else {
+        let labels = source_labels(&args.file);
+        args.file
+            .iter()
+            .zip(labels)
+            .map(|(path, label)| EventLog::new(path.clone(), label))
+            .collect()
     };
 
     // Handle clear command
     if args.clear {
-        history.clear()?;
+        for log in &logs {
+            log.history.clear()?;
+        }
         if use_colors {
             println!("{}✓{} Event history cleared", colors::GREEN, colors::RESET);
         } else {
@@ -1727,7 +2012,25 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
         return Ok(());
     }
 
-    if !history.exists() {
+    // With several logs, a missing one is skipped rather than fatal.
+    if logs.len() > 1 {
+        logs.retain(|log| {
+            let exists = log.history.exists();
+            if !exists {
+                eprintln!(
+                    "Warning: events file {} not found, skipping",
+                    log.path.display()
+                );
+            }
+            exists
+        });
+    }
+
+    let follow = args.follow || args.follow_and_exit_on.is_some();
+    if args.since_now && !follow {
+        anyhow::bail!("--since-now requires --follow or --follow-and-exit-on");
+    }
+    if !logs.iter().any(|log| log.history.exists()) && !follow {
         if use_colors {
             println!(
                 "{}No event history found.{} Run `ralph` to generate events.",
@@ -1741,22 +2044,63 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     }
 
     // Read and filter events
-    let mut records = history.read_all()?;
+    let matches = |r: &EventRecord| {
+        args.topic.as_ref().is_none_or(|topic| r.topic == *topic)
+            && args
+                .iteration
+                .is_none_or(|iteration| r.iteration == iteration)
+            && (from..=to).contains(&r.iteration)
+            && args.hat.as_ref().is_none_or(|hat| r.hat == *hat)
+            && args.severity.is_none_or(|min| r.severity() >= min)
+    };
+    let mut records = Vec::new();
+    for log in &mut logs {
+        records.extend(log.read_new()?);
+    }
+    sort_by_timestamp(&mut records);
+    if args.since_now {
+        // The logs are now positioned at their ends
+        records.clear();
+    }
 
-    // Apply filters in sequence
-    if let Some(ref topic) = args.topic {
-        records.retain(|r| r.topic == *topic);
+    // Apply 'last' filter after other filters (to get last N of filtered results).
+    // Earlier records are dropped, but non-matching ones after the cut are kept
+    // for --follow-and-exit-on, which watches every record.
+    if let Some(n) = args.last {
+        let matching: Vec<usize> = records
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| matches(&r.record))
+            .map(|(i, _)| i)
+            .collect();
+        if matching.len() > n {
+            let start = matching
+                .get(matching.len() - n)
+                .copied()
+                .unwrap_or(records.len());
+            records.drain(..start);
+        }
     }
 
-    if let Some(iteration) = args.iteration {
-        records.retain(|r| r.iteration == iteration);
+    if follow {
+        let exit_on = args.follow_and_exit_on.map(|topic| FollowExit {
+            topic: ralph_proto::Topic::new(topic),
+            timeout: args.wait_timeout.map(std::time::Duration::from_secs),
+        });
+        return follow_events(logs, records, matches, args.format, use_colors, exit_on).await;
     }
+    records.retain(|r| matches(&r.record));
 
-    // Apply 'last' filter after other filters (to get last N of filtered results)
-    if let Some(n) = args.last
-        && records.len() > n
-    {
-        records = records.into_iter().rev().take(n).rev().collect();
+    if args.stats {
+        let records: Vec<EventRecord> = records.into_iter().map(|r| r.record).collect();
+        let stats = EventStats::from_records(&records);
+        match args.format {
+            OutputFormat::Json => {
+                display::print_json(&serde_json::to_string_pretty(&stats)?, use_colors);
+            }
+            OutputFormat::Table => display::print_event_stats(&stats, use_colors),
+        }
+        return Ok(());
     }
 
     if records.is_empty() {
@@ -1771,7 +2115,7 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     match args.format {
         OutputFormat::Json => {
             let json = serde_json::to_string_pretty(&records)?;
-            println!("{json}");
+            display::print_json(&json, use_colors);
         }
         OutputFormat::Table => {
             display::print_events_table(&records, use_colors);
@@ -1781,6 +2125,186 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     Ok(())
 }
 
+/// One events file being read by `ralph events`.
+struct EventLog {
+    path: PathBuf,
+    history: EventHistory,
+    /// Source label shown when several logs are merged.
+    label: Option<String>,
+    /// Position in the current file, kept across rotations for `--follow`.
+    tail: EventTail,
+    /// Whether rotated copies have been read (only on the first read).
+    read_rotated: bool,
+}
+
+impl EventLog {
+    fn new(path: PathBuf, label: Option<String>) -> Self {
+        Self {
+            history: EventHistory::new(&path),
+            tail: EventTail::new(&path),
+            path,
+            label,
+            read_rotated: false,
+        }
+    }
+
+    /// Reads records appended since the last call.
+    fn read_new(&mut self) -> Result<Vec<SourcedRecord>> {
+        let mut records = Vec::new();
+        if !self.read_rotated {
+            records = self.history.read_rotated()?;
+            self.read_rotated = true;
+        }
+        records.extend(self.tail.read_new()?);
+        Ok(records
+            .into_iter()
+            .map(|record| SourcedRecord {
+                record,
+                source: self.label.clone(),
+            })
+            .collect())
+    }
+}
+
+/// Short labels for the Source column: the file name, or the parent directory
+/// plus file name when several logs share a name (e.g. worktree `events.jsonl`).
+/// A single log gets no label.
+fn source_labels(paths: &[PathBuf]) -> Vec<Option<String>> {
+    if paths.len() < 2 {
+        return vec![None; paths.len()];
+    }
+
+    let file_name = |p: &PathBuf| {
+        p.file_name().map_or_else(
+            || p.display().to_string(),
+            |n| n.to_string_lossy().into_owned(),
+        )
+    };
+    let names: Vec<String> = paths.iter().map(file_name).collect();
+    paths
+        .iter()
+        .zip(&names)
+        .map(|(path, name)| {
+            if names.iter().filter(|n| *n == name).count() == 1 {
+                return Some(name.clone());
+            }
+            let parent = path
+                .parent()
+                .and_then(Path::file_name)
+                .map(|p| p.to_string_lossy().into_owned());
+            Some(parent.map_or_else(|| name.clone(), |p| format!("{p}/{name}")))
+        })
+        .collect()
+}
+
+/// Stable sort by timestamp. RFC 3339 timestamps are compared as instants;
+/// unparseable ones sort after them in string order.
+fn sort_by_timestamp(records: &mut [SourcedRecord]) {
+    records.sort_by_key(|r| {
+        let parsed = chrono::DateTime::parse_from_rfc3339(&r.record.ts).ok();
+        (parsed.is_none(), parsed, r.record.ts.clone())
+    });
+}
+
+/// Exit condition for `ralph events --follow-and-exit-on`.
+struct FollowExit {
+    /// Topic pattern that ends the follow once a printed event matches it.
+    topic: ralph_proto::Topic,
+    /// Give up with an error if nothing matches within this long.
+    timeout: Option<std::time::Duration>,
+}
+
+/// Prints `initial`, then polls the events files and prints matching records
+/// as they are appended until Ctrl+C.
+///
+/// With `exit_on`, returns as soon as a printed record's topic matches it
+/// (existing history included, unless `--since-now` emptied `initial`), or
+/// fails once its timeout elapses.
+///
+/// JSON output switches to one object per line so the stream stays parseable.
+async fn follow_events(
+    mut logs: Vec<EventLog>,
+    initial: Vec<SourcedRecord>,
+    matches: impl Fn(&EventRecord) -> bool,
+    format: OutputFormat,
+    use_colors: bool,
+    exit_on: Option<FollowExit>,
+) -> Result<()> {
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+    let show_source = logs.iter().any(|log| log.label.is_some());
+    let mut printed = 0;
+    // Prints the records passing `matches` up to the first one matching
+    // `exit_on`; returns whether it was found. The exit topic is checked
+    // before filtering, so filters that hide it cannot keep the command waiting.
+    let mut print = |records: &[SourcedRecord]| -> Result<bool> {
+        let mut found = false;
+        for record in records {
+            if matches(&record.record) {
+                printed += 1;
+                match format {
+                    OutputFormat::Json => {
+                        display::print_json(&serde_json::to_string(record)?, use_colors);
+                    }
+                    OutputFormat::Table => {
+                        display::print_events_table_row(printed, record, show_source, use_colors);
+                    }
+                }
+            }
+            if exit_on
+                .as_ref()
+                .is_some_and(|exit| exit.topic.matches_str(&record.record.topic))
+            {
+                found = true;
+                break;
+            }
+        }
+        stdout().flush()?;
+        Ok(found)
+    };
+
+    if matches!(format, OutputFormat::Table) {
+        display::print_events_table_header(show_source, use_colors);
+    }
+    if print(&initial)? {
+        return Ok(());
+    }
+
+    let deadline = exit_on
+        .as_ref()
+        .and_then(|exit| exit.timeout)
+        .map(|timeout| tokio::time::Instant::now() + timeout);
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        tokio::select! {
+            _ = &mut ctrl_c => return Ok(()),
+            () = tokio::time::sleep(POLL_INTERVAL) => {}
+        }
+
+        // The tail drains a rotated file before moving on, and restarts from
+        // the top if a file was cleared or recreated.
+        let mut records = Vec::new();
+        for log in &mut logs {
+            records.extend(log.read_new()?);
+        }
+        sort_by_timestamp(&mut records);
+        if print(&records)? {
+            return Ok(());
+        }
+
+        if let (Some(deadline), Some(exit)) = (deadline, &exit_on)
+            && tokio::time::Instant::now() >= deadline
+        {
+            anyhow::bail!(
+                "Timed out after {}s waiting for an event matching '{}'",
+                exit.timeout.unwrap_or_default().as_secs(),
+                exit.topic
+            );
+        }
+    }
+}
+
 fn clean_command(
     config_sources: &[ConfigSource],
     color_mode: ColorMode,
@@ -1878,9 +2402,39 @@ fn clean_command(
 ///
 /// Events are written to the path specified in `.ralph/current-events` marker file
 /// (created by `ralph run`), or falls back to `.ralph/events.jsonl` if no marker exists.
-fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
+/// Reads `event_loop.topic_aliases` from the config file, so `ralph emit`
+/// logs the canonical topic. A missing or unreadable config (or a builtin or
+/// remote source) means no aliases; emitting must not fail because of it.
+fn emit_topic_aliases(config_sources: &[ConfigSource]) -> HashMap<String, String> {
+    let path = match config_sources
+        .iter()
+        .find(|s| !matches!(s, ConfigSource::Override { .. }))
+    {
+        Some(ConfigSource::File(path)) => path.clone(),
+        Some(_) => return HashMap::new(),
+        None => PathBuf::from("ralph.yml"),
+    };
+    if !path.exists() {
+        return HashMap::new();
+    }
+    RalphConfig::from_file(&path)
+        .map(|config| config.event_loop.topic_aliases)
+        .unwrap_or_default()
+}
+
+fn emit_command(
+    config_sources: &[ConfigSource],
+    color_mode: ColorMode,
+    args: EmitArgs,
+) -> Result<()> {
     let use_colors = color_mode.should_use_colors();
 
+    // Aliases are logged as their canonical topic
+    let aliases = emit_topic_aliases(config_sources);
+    let topic = EventParser::resolve_topic_alias(&args.topic, &aliases)
+        .unwrap_or(&args.topic)
+        .to_string();
+
     // Generate timestamp if not provided
     let ts = args.ts.unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
 
@@ -1896,7 +2450,7 @@ fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
     // Build the event record
     // We use serde_json directly to ensure proper escaping
     let record = serde_json::json!({
-        "topic": args.topic,
+        "topic": topic,
         "payload": if args.json && !payload.is_empty() {
             // Parse and embed as object
             serde_json::from_str::<serde_json::Value>(&payload)?
@@ -1939,10 +2493,10 @@ fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
             "{}✓{} Event emitted: {}",
             colors::GREEN,
             colors::RESET,
-            args.topic
+            topic
         );
     } else {
-        println!("Event emitted: {}", args.topic);
+        println!("Event emitted: {}", topic);
     }
 
     Ok(())
@@ -2347,6 +2901,80 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_sort_by_timestamp_orders_mixed_timestamps() {
+        let sourced = |ts: &str| SourcedRecord {
+            record: EventRecord {
+                ts: ts.to_string(),
+                iteration: 1,
+                hat: "hat".to_string(),
+                topic: "task.start".to_string(),
+                triggered: None,
+                payload: String::new(),
+                blocked_count: None,
+                severity: None,
+                cost: None,
+                duration_ms: None,
+            },
+            source: None,
+        };
+        let mut records: Vec<_> = [
+            "later",
+            "2026-01-23T02:00:00+02:00",
+            "garbage",
+            "2026-01-23T01:00:00Z",
+        ]
+        .into_iter()
+        .map(sourced)
+        .collect();
+
+        sort_by_timestamp(&mut records);
+
+        let order: Vec<_> = records.iter().map(|r| r.record.ts.as_str()).collect();
+        assert_eq!(
+            order,
+            [
+                "2026-01-23T02:00:00+02:00",
+                "2026-01-23T01:00:00Z",
+                "garbage",
+                "later"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_is_explicit_detects_default() {
+        let matches = Cli::command()
+            .try_get_matches_from(["ralph", "run", "--workspace", "ws"])
+            .unwrap();
+        assert!(!config_is_explicit(&matches));
+
+        for argv in [
+            ["ralph", "run", "-c", "ralph.yml"],
+            ["ralph", "-c", "ralph.yml", "run"],
+        ] {
+            let matches = Cli::command().try_get_matches_from(argv).unwrap();
+            assert!(config_is_explicit(&matches), "{argv:?}");
+        }
+    }
+
+    #[test]
+    fn test_config_source_resolved_against_base() {
+        let base = Path::new("/invoked/here");
+        assert!(matches!(
+            ConfigSource::parse("configs/ralph.yml").resolved_against(base),
+            ConfigSource::File(path) if path == Path::new("/invoked/here/configs/ralph.yml")
+        ));
+        assert!(matches!(
+            ConfigSource::parse("/abs/ralph.yml").resolved_against(base),
+            ConfigSource::File(path) if path == Path::new("/abs/ralph.yml")
+        ));
+        assert!(matches!(
+            ConfigSource::parse("builtin:feature").resolved_against(base),
+            ConfigSource::Builtin(name) if name == "feature"
+        ));
+    }
+
     #[test]
     fn test_doctor_parses_command() {
         let cli = Cli::try_parse_from(["ralph", "doctor"]).expect("CLI parse failed");
@@ -2354,6 +2982,21 @@ mod tests {
         assert!(matches!(cli.command, Some(Commands::Doctor(_))));
     }
 
+    #[test]
+    fn test_events_parses_severity_filter() {
+        let cli = Cli::try_parse_from(["ralph", "events", "--severity", "error"])
+            .expect("CLI parse failed");
+
+        match cli.command {
+            Some(Commands::Events(args)) => {
+                assert_eq!(args.severity, Some(EventSeverity::Error));
+            }
+            other => panic!("expected events command, got {other:?}"),
+        }
+
+        assert!(Cli::try_parse_from(["ralph", "events", "--severity", "fatal"]).is_err());
+    }
+
     #[test]
     fn test_tutorial_parses_command() {
         let cli = Cli::try_parse_from(["ralph", "tutorial"]).expect("CLI parse failed");
@@ -2812,6 +3455,43 @@ core:
         assert_eq!(actual_root, expected_root);
     }
 
+    #[test]
+    fn test_emit_logs_alias_as_canonical_topic() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+        std::fs::write(
+            temp_dir.path().join("ralph.yml"),
+            "event_loop:\n  topic_aliases:\n    work_finished: work.done\n",
+        )
+        .unwrap();
+
+        let args = EmitArgs {
+            topic: "work_finished".to_string(),
+            payload: "Implemented the parser".to_string(),
+            json: false,
+            ts: None,
+            file: PathBuf::from("events.jsonl"),
+        };
+        emit_command(
+            &[ConfigSource::File(PathBuf::from("ralph.yml"))],
+            ColorMode::Never,
+            args,
+        )
+        .unwrap();
+
+        let written = std::fs::read_to_string(temp_dir.path().join("events.jsonl")).unwrap();
+        let record: serde_json::Value = serde_json::from_str(written.trim()).unwrap();
+        assert_eq!(record["topic"], "work.done");
+    }
+
+    #[test]
+    fn test_emit_topic_aliases_tolerates_missing_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let sources = [ConfigSource::File(temp_dir.path().join("ralph.yml"))];
+        assert!(emit_topic_aliases(&sources).is_empty());
+        assert!(emit_topic_aliases(&[ConfigSource::Builtin("feature".to_string())]).is_empty());
+    }
+
     #[test]
     fn test_load_config_with_overrides_only_overrides_uses_defaults() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -2892,8 +3572,14 @@ core:
             backend: Some("claude".to_string()),
             prompt_file: None,
             max_iterations: None,
+            max_iterations_per_hat: None,
             completion_promise: None,
             dry_run: false,
+            show_prompt: false,
+            strict: false,
+            strict_config: false,
+            token_budget: ralph_core::dry_run::DEFAULT_PROMPT_TOKEN_BUDGET,
+            workspace: None,
             continue_mode: false,
             no_tui: true,
             autonomous: false,
@@ -2904,6 +3590,10 @@ core:
             verbose: false,
             quiet: false,
             record_session: None,
+            explain: false,
+            json_events_only: false,
+            once: false,
+            summary_json: None,
             custom_args: Vec::new(),
         }
     }
@@ -2935,4 +3625,147 @@ core:
             .await
             .expect("dry run should succeed");
     }
+
+    #[tokio::test]
+    async fn test_run_command_show_prompt_skips_backend() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let mut args = default_run_args();
+        args.show_prompt = true;
+        // Would fail auto-detection or execution if a backend were touched
+        args.backend = Some("auto".to_string());
+        args.prompt_text = Some("Show me the prompt".to_string());
+
+        run_command(&[], false, ColorMode::Never, args)
+            .await
+            .expect("show-prompt should succeed without a backend");
+        assert!(!temp_dir.path().join(".ralph/loop.lock").exists());
+    }
+
+    #[tokio::test]
+    async fn test_run_command_show_prompt_missing_prompt_file_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let mut args = default_run_args();
+        args.show_prompt = true;
+        args.prompt_file = Some(PathBuf::from("MISSING.md"));
+
+        let err = run_command(&[], false, ColorMode::Never, args)
+            .await
+            .expect_err("missing prompt file should error");
+        assert!(err.to_string().contains("MISSING.md"));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_workspace_resolves_paths_in_workspace() {
+        let caller_dir = tempfile::tempdir().unwrap();
+        let workspace_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(caller_dir.path());
+        std::fs::write(
+            workspace_dir.path().join("ralph.yml"),
+            "event_loop:\n  max_iterations: 7\n",
+        )
+        .unwrap();
+        std::fs::write(workspace_dir.path().join("TASK.md"), "Do the thing").unwrap();
+
+        let mut args = default_run_args();
+        args.dry_run = true;
+        args.strict = true;
+        args.workspace = Some(workspace_dir.path().to_path_buf());
+        args.prompt_file = Some(PathBuf::from("TASK.md"));
+
+        let sources = vec![ConfigSource::File(PathBuf::from("ralph.yml"))];
+        run_command(&sources, false, ColorMode::Never, args)
+            .await
+            .expect("ralph.yml and TASK.md should resolve inside the workspace");
+
+        assert_eq!(
+            std::env::current_dir().unwrap(),
+            std::fs::canonicalize(workspace_dir.path()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_command_workspace_must_exist() {
+        let caller_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(caller_dir.path());
+
+        let mut args = default_run_args();
+        args.dry_run = true;
+        args.workspace = Some(caller_dir.path().join("missing"));
+
+        let err = run_command(&[], false, ColorMode::Never, args)
+            .await
+            .expect_err("missing workspace should be rejected");
+        assert!(err.to_string().contains("Invalid workspace path"));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_strict_dry_run_fails_on_unreachable_hat() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+        let config_path = temp_dir.path().join("ralph.yml");
+        std::fs::write(
+            &config_path,
+            r#"
+event_loop:
+  starting_event: "build.start"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds the feature"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+  auditor:
+    name: "Auditor"
+    description: "Never triggered by anything"
+    triggers: ["audit.request"]
+    publishes: ["audit.done"]
+"#,
+        )
+        .unwrap();
+
+        let mut args = default_run_args();
+        args.dry_run = true;
+        args.strict = true;
+        args.prompt_text = Some("Test inline prompt".to_string());
+
+        let sources = vec![ConfigSource::File(config_path)];
+        let err = run_command(&sources, false, ColorMode::Never, args)
+            .await
+            .expect_err("strict dry run should reject an unreachable hat");
+        assert!(err.to_string().contains("Strict dry run found 1 issue"));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_strict_config_rejects_unknown_key() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+        let config_path = temp_dir.path().join("ralph.yml");
+        std::fs::write(&config_path, "event_loop:\n  max_iteration: 5\n").unwrap();
+
+        let mut args = default_run_args();
+        args.dry_run = true;
+        args.prompt_text = Some("Test inline prompt".to_string());
+
+        let sources = vec![ConfigSource::File(config_path)];
+        run_command(&sources, false, ColorMode::Never, args)
+            .await
+            .expect("lenient parsing ignores unknown keys");
+
+        let mut args = default_run_args();
+        args.dry_run = true;
+        args.strict_config = true;
+        args.prompt_text = Some("Test inline prompt".to_string());
+
+        let err = run_command(&sources, false, ColorMode::Never, args)
+            .await
+            .expect_err("strict config should reject the typo");
+        assert!(
+            format!("{err:#}").contains("Unknown config key(s): event_loop.max_iteration"),
+            "{err:#}"
+        );
+    }
 }
@@ -4,15 +4,21 @@
 //! - `add`: Store a new memory
 //! - `list`: List all memories
 //! - `show`: Show a single memory by ID
-//! - `delete`: Delete a memory by ID
+//! - `delete` (alias `remove`): Delete a memory by ID
+//! - `clear`: Remove all memories
 //! - `search`: Find memories by query
 //! - `prime`: Output memories for context injection
 //! - `init`: Initialize memories file
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
-use ralph_core::{MarkdownMemoryStore, Memory, MemoryType};
-use std::path::PathBuf;
+use ralph_core::{
+    MarkdownMemoryStore, MemoriesFilter, Memory, MemoryMatch, MemoryPruneFilter,
+    MemorySearchFilter, MemoryType, cap_memories, filter_memories, format_memories_within_budget,
+    select_within_budget,
+};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// ANSI color codes for terminal output.
 mod colors {
@@ -20,6 +26,7 @@ mod colors {
     pub const BOLD: &str = "\x1b[1m";
     pub const DIM: &str = "\x1b[2m";
     pub const GREEN: &str = "\x1b[32m";
+    pub const YELLOW: &str = "\x1b[33m";
     pub const CYAN: &str = "\x1b[36m";
 }
 
@@ -92,8 +99,15 @@ pub enum MemoryCommands {
     Show(ShowArgs),
 
     /// Delete a memory by ID
+    #[command(visible_alias = "remove")]
     Delete(DeleteArgs),
 
+    /// Remove all memories
+    Clear(ClearArgs),
+
+    /// Remove stale memories by age and/or tag
+    Prune(PruneArgs),
+
     /// Find memories by query
     Search(SearchArgs),
 
@@ -118,6 +132,10 @@ pub struct AddArgs {
     #[arg(long)]
     pub tags: Option<String>,
 
+    /// Store the memory even if one with the same content already exists
+    #[arg(long)]
+    pub force: bool,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -157,6 +175,34 @@ pub struct DeleteArgs {
     pub id: String,
 }
 
+/// Arguments for the `memory clear` command.
+#[derive(Parser, Debug)]
+pub struct ClearArgs {
+    /// Skip confirmation prompt
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
+/// Arguments for the `memory prune` command.
+#[derive(Parser, Debug)]
+pub struct PruneArgs {
+    /// Remove memories created more than this many days ago
+    #[arg(long, value_name = "DAYS", required_unless_present = "tags")]
+    pub older_than: Option<u32>,
+
+    /// Remove memories with any of these tags (comma-separated)
+    #[arg(long)]
+    pub tags: Option<String>,
+
+    /// List the memories that would be removed without removing them
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
 /// Arguments for the `memory search` command.
 #[derive(Parser, Debug)]
 pub struct SearchArgs {
@@ -167,12 +213,20 @@ pub struct SearchArgs {
     #[arg(short = 't', long)]
     pub r#type: Option<MemoryType>,
 
-    /// Filter by tags (comma-separated, OR logic)
+    /// Require all of these tags (comma-separated, AND logic)
     #[arg(long)]
     pub tags: Option<String>,
 
-    /// Show all results (no limit)
+    /// Require any of these tags (comma-separated, OR logic)
     #[arg(long)]
+    pub any_tags: Option<String>,
+
+    /// Maximum number of results to show
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+
+    /// Show all results (no limit)
+    #[arg(long, conflicts_with = "limit")]
     pub all: bool,
 
     /// Output format
@@ -199,6 +253,10 @@ pub struct PrimeArgs {
     #[arg(long)]
     pub recent: Option<u32>,
 
+    /// Maximum number of memories to output, most recent first (0 = unlimited)
+    #[arg(long)]
+    pub max_memories: Option<usize>,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Markdown)]
     pub format: OutputFormat,
@@ -222,7 +280,11 @@ pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
         MemoryCommands::List(list_args) => list_command(&store, list_args, use_colors),
         MemoryCommands::Show(show_args) => show_command(&store, show_args, use_colors),
         MemoryCommands::Delete(delete_args) => delete_command(&store, delete_args, use_colors),
-        MemoryCommands::Search(search_args) => search_command(&store, search_args, use_colors),
+        MemoryCommands::Clear(clear_args) => clear_command(&store, clear_args, use_colors),
+        MemoryCommands::Prune(prune_args) => prune_command(&store, prune_args, use_colors),
+        MemoryCommands::Search(search_args) => {
+            search_command(&store, &root, search_args, use_colors)
+        }
         MemoryCommands::Prime(prime_args) => prime_command(&store, prime_args),
         MemoryCommands::Init(init_args) => init_command(&store, init_args, use_colors),
     }
@@ -239,7 +301,31 @@ fn add_command(store: &MarkdownMemoryStore, args: AddArgs, use_colors: bool) ->
     let memory = Memory::new(args.r#type, args.content, tags);
     let id = memory.id.clone();
 
-    store.append(&memory).context("Failed to store memory")?;
+    // Skip exact/near-duplicate content unless --force is given
+    if args.force {
+        store.append(&memory).context("Failed to store memory")?;
+    } else if let Some(existing) = store
+        .append_unique(&memory)
+        .context("Failed to store memory")?
+    {
+        match args.format {
+            OutputFormat::Quiet => println!("{}", existing.id),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&existing)?),
+            _ => {
+                if use_colors {
+                    println!(
+                        "{}Duplicate of {}{} (use --force to store anyway)",
+                        colors::YELLOW,
+                        existing.id,
+                        colors::RESET
+                    );
+                } else {
+                    println!("Duplicate of {} (use --force to store anyway)", existing.id);
+                }
+            }
+        }
+        return Ok(());
+    }
 
     // Output based on format
     match args.format {
@@ -332,36 +418,107 @@ fn delete_command(store: &MarkdownMemoryStore, args: DeleteArgs, use_colors: boo
     }
 }
 
-fn search_command(store: &MarkdownMemoryStore, args: SearchArgs, use_colors: bool) -> Result<()> {
-    let all_memories = store.load().context("Failed to load memories")?;
-    let total_count = all_memories.len();
-    let mut memories = all_memories;
+fn clear_command(store: &MarkdownMemoryStore, args: ClearArgs, use_colors: bool) -> Result<()> {
+    if !args.yes {
+        eprintln!(
+            "This will permanently remove all memories from {}.",
+            store.path().display()
+        );
+        eprint!("Continue? [y/N] ");
+        io::stderr().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let removed = store.clear().context("Failed to clear memories")?;
+
+    if use_colors {
+        println!(
+            "{}🗑️  Cleared {} memories{}",
+            colors::GREEN,
+            removed,
+            colors::RESET
+        );
+    } else {
+        println!("Cleared {} memories", removed);
+    }
+    Ok(())
+}
+
+fn prune_command(store: &MarkdownMemoryStore, args: PruneArgs, use_colors: bool) -> Result<()> {
+    let filter = MemoryPruneFilter {
+        older_than_days: args.older_than,
+        tags: args.tags.as_deref().map(split_tags).unwrap_or_default(),
+    };
 
-    // Filter by query if provided
-    if let Some(ref query) = args.query {
-        memories.retain(|m| m.matches_query(query));
+    let pruned = if args.dry_run {
+        let mut candidates = store.load().context("Failed to load memories")?;
+        candidates.retain(|m| filter.matches(m));
+        candidates
+    } else {
+        store.prune(&filter).context("Failed to prune memories")?
+    };
+
+    if matches!(args.format, OutputFormat::Json | OutputFormat::Quiet) {
+        output_memories(&pruned, args.format, use_colors);
+        return Ok(());
     }
 
-    // Filter by type if specified
-    if let Some(memory_type) = args.r#type {
-        memories.retain(|m| m.memory_type == memory_type);
+    if !pruned.is_empty() {
+        output_memories(&pruned, args.format, use_colors);
     }
 
-    // Filter by tags if specified
-    if let Some(ref tags_str) = args.tags {
-        let tags: Vec<String> = tags_str.split(',').map(|s| s.trim().to_string()).collect();
-        memories.retain(|m| m.has_any_tag(&tags));
+    let summary = if args.dry_run {
+        format!("{} memories would be pruned (dry run)", pruned.len())
+    } else {
+        format!("Pruned {} memories", pruned.len())
+    };
+    if use_colors {
+        println!("{}🗑️  {}{}", colors::GREEN, summary, colors::RESET);
+    } else {
+        println!("{}", summary);
     }
+    Ok(())
+}
 
-    let match_count = memories.len();
-    let truncated = !args.all && match_count > 10;
+fn search_command(
+    store: &MarkdownMemoryStore,
+    root: &Path,
+    args: SearchArgs,
+    use_colors: bool,
+) -> Result<()> {
+    let total_count = store.load().context("Failed to load memories")?.len();
+
+    let filter = MemorySearchFilter {
+        memory_type: args.r#type,
+        all_tags: args.tags.as_deref().map(split_tags).unwrap_or_default(),
+        any_tags: args.any_tags.as_deref().map(split_tags).unwrap_or_default(),
+    };
+    let semantic = args
+        .query
+        .as_deref()
+        .and_then(|query| semantic_search(store, root, query, &filter));
+    let mut results = match semantic {
+        Some(results) => results,
+        None => store
+            .search_filtered(args.query.as_deref(), &filter)
+            .context("Failed to search memories")?,
+    };
+
+    let match_count = results.len();
+    let truncated = !args.all && match_count > args.limit;
 
     // Limit results unless --all is specified
     if truncated {
-        memories.truncate(10);
+        results.truncate(args.limit);
     }
 
-    if memories.is_empty() {
+    if results.is_empty() {
         if use_colors {
             println!(
                 "\n{}No matching memories found in {} total memories.{}",
@@ -405,21 +562,28 @@ fn search_command(store: &MarkdownMemoryStore, args: SearchArgs, use_colors: boo
         }
     }
 
-    output_memories(&memories, args.format, use_colors);
+    let filtered = filter != MemorySearchFilter::default();
+    if args.query.is_some() || filtered {
+        output_scored_memories(&results, args.format, use_colors);
+    } else {
+        let memories: Vec<Memory> = results.into_iter().map(|r| r.memory).collect();
+        output_memories(&memories, args.format, use_colors);
+    }
 
     // Show truncation hint (only for table format)
     if truncated && args.format == OutputFormat::Table {
         if use_colors {
             println!(
-                "{}Showing 10 of {} matches • Use --all to see all results{}\n",
+                "{}Showing {} of {} matches • Use --all to see all results{}\n",
                 colors::DIM,
+                args.limit,
                 match_count,
                 colors::RESET
             );
         } else {
             println!(
-                "Showing 10 of {} matches • Use --all to see all results\n",
-                match_count
+                "Showing {} of {} matches • Use --all to see all results\n",
+                args.limit, match_count
             );
         }
     }
@@ -427,57 +591,43 @@ fn search_command(store: &MarkdownMemoryStore, args: SearchArgs, use_colors: boo
     Ok(())
 }
 
-fn prime_command(store: &MarkdownMemoryStore, args: PrimeArgs) -> Result<()> {
-    let mut memories = store.load().context("Failed to load memories")?;
-
-    // Filter by types if specified
-    if let Some(ref types_str) = args.r#type {
-        let types: Vec<MemoryType> = types_str
-            .split(',')
-            .filter_map(|s| s.trim().parse().ok())
-            .collect();
-        if !types.is_empty() {
-            memories.retain(|m| types.contains(&m.memory_type));
-        }
-    }
-
-    // Filter by tags if specified
-    if let Some(ref tags_str) = args.tags {
-        let tags: Vec<String> = tags_str.split(',').map(|s| s.trim().to_string()).collect();
-        memories.retain(|m| m.has_any_tag(&tags));
-    }
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',').map(|s| s.trim().to_string()).collect()
+}
 
-    // Filter by recent days if specified
-    if let Some(days) = args.recent {
-        let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(days));
-        let cutoff_str = cutoff.format("%Y-%m-%d").to_string();
-        memories.retain(|m| m.created >= cutoff_str);
-    }
+fn prime_command(store: &MarkdownMemoryStore, args: PrimeArgs) -> Result<()> {
+    let split = |list: &Option<String>| -> Vec<String> {
+        list.iter()
+            .flat_map(|s| s.split(','))
+            .map(|s| s.trim().to_string())
+            .collect()
+    };
+    let filter = MemoriesFilter {
+        types: split(&args.r#type),
+        tags: split(&args.tags),
+        recent: args.recent.unwrap_or(0),
+    };
+    let memories = cap_memories(
+        filter_memories(store.load().context("Failed to load memories")?, &filter),
+        args.max_memories.unwrap_or(0),
+    );
 
     if memories.is_empty() {
         return Ok(());
     }
 
-    // Generate output
+    // Generate output, fitted to the budget the same way auto-injection does
+    let budget = args.budget.unwrap_or(0);
     let output = match args.format {
-        OutputFormat::Json => serde_json::to_string_pretty(&memories)?,
-        OutputFormat::Markdown | OutputFormat::Table | OutputFormat::Quiet => {
-            format_memories_as_markdown(&memories)
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(&select_within_budget(memories, budget, &filter.tags))?
         }
-    };
-
-    // Apply budget if specified
-    let final_output = if let Some(budget) = args.budget {
-        if budget > 0 {
-            truncate_to_budget(&output, budget)
-        } else {
-            output
+        OutputFormat::Markdown | OutputFormat::Table | OutputFormat::Quiet => {
+            format_memories_within_budget(memories, budget, &filter.tags)
         }
-    } else {
-        output
     };
 
-    print!("{}", final_output);
+    print!("{}", output);
     Ok(())
 }
 
@@ -531,6 +681,144 @@ fn output_memories(memories: &[Memory], format: OutputFormat, use_colors: bool)
     }
 }
 
+/// A memory with its search relevance and matched tags, for JSON output.
+#[derive(serde::Serialize)]
+struct ScoredMemory<'a> {
+    #[serde(flatten)]
+    memory: &'a Memory,
+    score: Option<usize>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    matched_tags: &'a [String],
+}
+
+/// Ranks filter matches by embedding similarity to `query`, using the
+/// `memories.embedding_command` from `<root>/ralph.yml`.
+///
+/// Returns `None` when no embedding command is configured, so the caller
+/// falls back to lexical search. Results carry no lexical score.
+#[cfg(feature = "semantic-memory")]
+fn semantic_search(
+    store: &MarkdownMemoryStore,
+    root: &Path,
+    query: &str,
+    filter: &MemorySearchFilter,
+) -> Option<Vec<MemoryMatch>> {
+    let config = ralph_core::RalphConfig::from_file(root.join("ralph.yml")).ok()?;
+    let embedder = ralph_core::CommandEmbedder::from_config(&config.memories, root)?;
+    let ranked = store.search_semantic(query, &embedder).ok()?;
+    let mut matches = store.search_filtered(None, filter).ok()?;
+    Some(
+        ranked
+            .into_iter()
+            .filter_map(|(memory, _)| {
+                let index = matches.iter().position(|m| m.memory.id == memory.id)?;
+                Some(matches.swap_remove(index))
+            })
+            .collect(),
+    )
+}
+
+#[cfg(not(feature = "semantic-memory"))]
+fn semantic_search(
+    _store: &MarkdownMemoryStore,
+    _root: &Path,
+    _query: &str,
+    _filter: &MemorySearchFilter,
+) -> Option<Vec<MemoryMatch>> {
+    None
+}
+
+fn output_scored_memories(results: &[MemoryMatch], format: OutputFormat, use_colors: bool) {
+    match format {
+        OutputFormat::Json => {
+            let scored: Vec<_> = results
+                .iter()
+                .map(|r| ScoredMemory {
+                    memory: &r.memory,
+                    score: r.score,
+                    matched_tags: &r.matched_tags,
+                })
+                .collect();
+            let json = serde_json::to_string_pretty(&scored).unwrap_or_default();
+            println!("{}", json);
+        }
+        OutputFormat::Table => print_scored_table(results, use_colors),
+        OutputFormat::Markdown | OutputFormat::Quiet => {
+            let memories: Vec<Memory> = results.iter().map(|r| r.memory.clone()).collect();
+            output_memories(&memories, format, use_colors);
+        }
+    }
+}
+
+/// Joins a memory's tags, highlighting the ones that matched the search
+/// (bold yellow with colors, `*tag*` without).
+fn highlight_tags(memory: &Memory, matched: &[String], use_colors: bool) -> String {
+    use colors::*;
+
+    memory
+        .tags
+        .iter()
+        .map(|tag| match (matched.contains(tag), use_colors) {
+            (false, _) => tag.clone(),
+            (true, true) => format!("{BOLD}{YELLOW}{tag}{RESET}"),
+            (true, false) => format!("*{tag}*"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_scored_table(results: &[MemoryMatch], use_colors: bool) {
+    use colors::*;
+
+    if use_colors {
+        println!("\n{BOLD}  # │ ID                  │ Score │ Summary{RESET}");
+        println!(
+            "{DIM}────┼─────────────────────┼───────┼────────────────────────────────────────{RESET}"
+        );
+    } else {
+        println!("\n  # | ID                  | Score | Summary");
+        println!("----|---------------------|-------|----------------------------------------");
+    }
+
+    for (i, result) in results.iter().enumerate() {
+        let memory = &result.memory;
+        let score = result
+            .score
+            .map_or_else(|| "-".to_string(), |s| s.to_string());
+        let summary = truncate_str(&memory.content.replace('\n', " "), 50);
+
+        if use_colors {
+            println!(
+                "{DIM}{:>3}{RESET} │ {CYAN}{:<19}{RESET} │ {:>5} │ {}",
+                i + 1,
+                memory.id,
+                score,
+                summary
+            );
+        } else {
+            println!(
+                "{:>3} | {:<19} | {:>5} | {}",
+                i + 1,
+                memory.id,
+                score,
+                summary
+            );
+        }
+        if !memory.tags.is_empty() {
+            let tags = highlight_tags(memory, &result.matched_tags, use_colors);
+            if use_colors {
+                println!(
+                    "    {DIM}│{RESET} {:<19} {DIM}│{RESET}       {DIM}│{RESET} tags: {tags}",
+                    ""
+                );
+            } else {
+                println!("    | {:<19} |       | tags: {tags}", "");
+            }
+        }
+    }
+    println!();
+}
+
 fn output_memory(memory: &Memory, format: OutputFormat, use_colors: bool) {
     match format {
         OutputFormat::Json => {
@@ -710,43 +998,12 @@ fn format_memories_as_markdown(memories: &[Memory]) -> String {
     output
 }
 
-/// Truncate content to approximately fit within a token budget.
-///
-/// Uses a simple heuristic of ~4 characters per token.
-fn truncate_to_budget(content: &str, budget: usize) -> String {
-    // Rough estimate: 4 chars per token
-    let char_budget = budget * 4;
-
-    if content.len() <= char_budget {
-        return content.to_string();
-    }
-
-    // Find a good break point (end of a memory block)
-    let truncated = &content[..char_budget];
-
-    // Try to find the last complete memory block (ends with -->)
-    if let Some(last_complete) = truncated.rfind("-->") {
-        let end = last_complete + 3;
-        // Find the next newline after -->
-        let final_end = truncated[end..].find('\n').map_or(end, |n| end + n + 1);
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            &content[..final_end],
-            budget
-        )
-    } else {
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            truncated, budget
-        )
-    }
-}
-
 fn truncate_str(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
     } else {
-        format!("{}…", &s[..max_len - 1])
+        let kept: String = s.chars().take(max_len - 1).collect();
+        format!("{}…", kept)
     }
 }
 
@@ -754,6 +1011,7 @@ fn truncate_str(s: &str, max_len: usize) -> String {
 mod tests {
     use super::*;
     use chrono::{Duration, NaiveDate};
+    use ralph_core::truncate_to_budget;
 
     fn fixed_today() -> NaiveDate {
         NaiveDate::from_ymd_opt(2026, 1, 31).expect("valid date")
@@ -842,6 +1100,106 @@ mod tests {
         assert!(truncated.contains("truncated: budget 1 tokens exceeded"));
     }
 
+    #[test]
+    fn highlight_tags_marks_only_matched_tags() {
+        let memory = Memory::new(
+            MemoryType::Fix,
+            "Start docker compose".to_string(),
+            split_tags("docker,database"),
+        );
+
+        assert_eq!(
+            highlight_tags(&memory, &["database".to_string()], false),
+            "docker, *database*"
+        );
+        assert_eq!(highlight_tags(&memory, &[], false), "docker, database");
+    }
+
+    #[test]
+    fn search_tag_filters_combine_with_query() {
+        let temp_dir = tempfile::TempDir::new().expect("temp dir");
+        let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+        for (content, tags) in [
+            ("Database pool size is 10", "database,docker"),
+            ("Database migrations run on boot", "database"),
+            ("Docker compose brings up the database", "docker,infra"),
+            ("Auth tokens expire daily", "auth,docker"),
+        ] {
+            store
+                .append(&Memory::new(
+                    MemoryType::Pattern,
+                    content.to_string(),
+                    split_tags(tags),
+                ))
+                .expect("append");
+        }
+        let search = |all: Option<&str>, any: Option<&str>| {
+            let filter = MemorySearchFilter {
+                memory_type: None,
+                all_tags: all.map(split_tags).unwrap_or_default(),
+                any_tags: any.map(split_tags).unwrap_or_default(),
+            };
+            let results = store
+                .search_filtered(Some("database"), &filter)
+                .expect("search");
+            let mut contents: Vec<_> = results.into_iter().map(|r| r.memory.content).collect();
+            contents.sort();
+            contents
+        };
+
+        assert_eq!(
+            search(Some("database,docker"), None),
+            vec!["Database pool size is 10"]
+        );
+        assert_eq!(
+            search(None, Some("infra,auth")),
+            vec!["Docker compose brings up the database"]
+        );
+        assert_eq!(
+            search(Some("docker"), Some("database,infra")),
+            vec![
+                "Database pool size is 10",
+                "Docker compose brings up the database"
+            ]
+        );
+    }
+
+    #[cfg(feature = "semantic-memory")]
+    #[test]
+    fn semantic_search_ranks_filter_matches_by_similarity() {
+        let temp_dir = tempfile::TempDir::new().expect("temp dir");
+        let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+        for (content, tags) in [
+            ("Postgres holds the orders", "infra"),
+            ("Compose starts the containers", "infra"),
+            ("SQL lives in db/", "docs"),
+        ] {
+            store
+                .append(&Memory::new(
+                    MemoryType::Pattern,
+                    content.to_string(),
+                    split_tags(tags),
+                ))
+                .expect("append");
+        }
+        let filter = MemorySearchFilter {
+            memory_type: None,
+            all_tags: vec!["infra".to_string()],
+            any_tags: vec![],
+        };
+        assert!(semantic_search(&store, temp_dir.path(), "storage", &filter).is_none());
+
+        std::fs::write(
+            temp_dir.path().join("ralph.yml"),
+            "memories:\n  embedding_command: \"if grep -qiE 'postgres|sql|storage'; then echo '[1, 0]'; else echo '[0, 1]'; fi\"\n",
+        )
+        .expect("write config");
+        let results = semantic_search(&store, temp_dir.path(), "storage", &filter).expect("ranked");
+
+        let contents: Vec<_> = results.into_iter().map(|r| r.memory.content).collect();
+        assert_eq!(contents, vec!["Postgres holds the orders"]);
+    }
+
     #[test]
     fn truncate_str_handles_short_and_long_values() {
         assert_eq!(truncate_str("short", 10), "short");
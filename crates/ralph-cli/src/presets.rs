@@ -198,6 +198,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_presets_have_no_unknown_keys() {
+        for preset in list_presets() {
+            let result = ralph_core::RalphConfig::parse_yaml_strict(preset.content);
+            assert!(
+                result.is_ok(),
+                "Preset '{}' should pass --strict-config: {}",
+                preset.name,
+                result.err().map(|e| e.to_string()).unwrap_or_default()
+            );
+        }
+    }
+
     #[test]
     fn test_preset_names_returns_all_names() {
         let names = preset_names();
@@ -0,0 +1,50 @@
+//! CLI command for `ralph version`.
+//!
+//! Prints the crate version. With the global `--verbose` flag, also prints
+//! build metadata embedded by `build.rs` and the versions of any backend CLIs
+//! found on PATH, for pasting into bug reports.
+
+use anyhow::Result;
+use clap::Parser;
+use ralph_adapters::DEFAULT_PRIORITY;
+
+use crate::doctor::{command_for_backend, command_version};
+
+/// Show version and build information.
+#[derive(Parser, Debug)]
+pub struct VersionArgs {}
+
+/// Build metadata embedded at compile time.
+const BUILD_INFO: &[(&str, &str)] = &[
+    ("commit", env!("RALPH_BUILD_GIT_SHA")),
+    ("rustc", env!("RALPH_BUILD_RUSTC_VERSION")),
+    ("target", env!("RALPH_BUILD_TARGET")),
+    ("profile", env!("RALPH_BUILD_PROFILE")),
+];
+
+pub fn execute(_args: VersionArgs, verbose: bool) -> Result<()> {
+    println!("ralph {}", env!("CARGO_PKG_VERSION"));
+    if !verbose {
+        return Ok(());
+    }
+
+    println!();
+    for (label, value) in BUILD_INFO {
+        println!("{:<8} {}", format!("{label}:"), value);
+    }
+
+    println!();
+    println!("Backends:");
+    let name_width = DEFAULT_PRIORITY
+        .iter()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0);
+    for backend in DEFAULT_PRIORITY {
+        let command = command_for_backend(backend);
+        let version = command_version(&command).unwrap_or_else(|| "not found".to_string());
+        println!("  {backend:<name_width$}  {version}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+//! Integration tests for `ralph events` filters.
+
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Writes six events, iterations 1-6, alternating reviewer/builder hats.
+fn write_history(dir: &std::path::Path) {
+    let lines: Vec<String> = (1..=6)
+        .map(|i| {
+            let hat = if i % 2 == 0 { "builder" } else { "reviewer" };
+            serde_json::json!({
+                "ts": format!("2026-01-01T10:00:0{i}Z"),
+                "iteration": i,
+                "hat": hat,
+                "topic": "work.done",
+            })
+            .to_string()
+        })
+        .collect();
+    std::fs::write(dir.join("events.jsonl"), lines.join("\n") + "\n").unwrap();
+}
+
+/// Runs `ralph events` with `filters` and returns the printed iterations.
+fn event_iterations(dir: &std::path::Path, filters: &[&str]) -> Vec<u64> {
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["events", "--file", "events.jsonl", "--format", "json"])
+        .args(filters)
+        .current_dir(dir)
+        .output()
+        .expect("run ralph events");
+
+    assert!(output.status.success(), "{output:?}");
+    let records: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    records
+        .iter()
+        .map(|r| r["iteration"].as_u64().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_hat_filter_applies_before_last() {
+    let temp_dir = TempDir::new().unwrap();
+    write_history(temp_dir.path());
+
+    let iterations = event_iterations(temp_dir.path(), &["--hat", "builder", "--last", "2"]);
+
+    assert_eq!(iterations, vec![4, 6]);
+}
+
+#[test]
+fn test_iteration_range_composes_with_hat() {
+    let temp_dir = TempDir::new().unwrap();
+    write_history(temp_dir.path());
+
+    let iterations = event_iterations(
+        temp_dir.path(),
+        &["--from", "2", "--to", "5", "--hat", "reviewer"],
+    );
+
+    assert_eq!(iterations, vec![3, 5]);
+}
+
+#[test]
+fn test_iteration_range_rejects_reversed_bounds() {
+    let temp_dir = TempDir::new().unwrap();
+    write_history(temp_dir.path());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args([
+            "events",
+            "--file",
+            "events.jsonl",
+            "--from",
+            "5",
+            "--to",
+            "2",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("run ralph events");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--from (5)"), "{stderr}");
+}
+
+#[test]
+fn test_stats_sums_iteration_usage() {
+    let temp_dir = TempDir::new().unwrap();
+    let lines: Vec<String> = [(1, 0.25, 1500), (2, 0.5, 2000)]
+        .iter()
+        .map(|(i, cost, duration_ms)| {
+            serde_json::json!({
+                "ts": format!("2026-01-01T10:00:0{i}Z"),
+                "iteration": i,
+                "hat": "builder",
+                "topic": "loop.iteration",
+                "cost": cost,
+                "duration_ms": duration_ms,
+            })
+            .to_string()
+        })
+        .collect();
+    std::fs::write(
+        temp_dir.path().join("events.jsonl"),
+        lines.join("\n") + "\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args([
+            "events",
+            "--file",
+            "events.jsonl",
+            "--stats",
+            "--format",
+            "json",
+        ])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("run ralph events");
+
+    assert!(output.status.success(), "{output:?}");
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!((stats["total_cost"].as_f64().unwrap() - 0.75).abs() < f64::EPSILON);
+    assert_eq!(stats["total_duration_ms"], 3500);
+}
+
+/// Runs `ralph events --format json` with the given color mode.
+fn events_json(dir: &std::path::Path, color: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["--color", color, "events", "--file", "events.jsonl"])
+        .args(["--format", "json"])
+        .current_dir(dir)
+        .output()
+        .expect("run ralph events");
+
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_json_output_has_no_ansi_codes_without_colors() {
+    let temp_dir = TempDir::new().unwrap();
+    write_history(temp_dir.path());
+
+    // Piped stdout is not a TTY, so `auto` stays plain too.
+    for color in ["never", "auto"] {
+        let stdout = events_json(temp_dir.path(), color);
+        assert!(!stdout.contains('\x1b'), "--color {color}: {stdout}");
+        let records: Vec<serde_json::Value> = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(records.len(), 6);
+    }
+}
+
+#[test]
+fn test_json_output_is_highlighted_with_colors() {
+    let temp_dir = TempDir::new().unwrap();
+    write_history(temp_dir.path());
+
+    let stdout = events_json(temp_dir.path(), "always");
+
+    assert!(stdout.contains("\x1b[36m\"topic\"\x1b[0m"), "{stdout}");
+    assert!(stdout.contains("\x1b[32m\"work.done\"\x1b[0m"), "{stdout}");
+}
+
+#[test]
+fn test_severity_filter_shows_severity_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let lines = [
+        r#"{"ts":"2026-01-01T10:00:01Z","iteration":1,"hat":"builder","topic":"build.done"}"#,
+        r#"{"ts":"2026-01-01T10:00:02Z","iteration":2,"hat":"builder","topic":"build.blocked"}"#,
+        r#"{"ts":"2026-01-01T10:00:03Z","iteration":3,"hat":"ralph","topic":"hat.exhausted"}"#,
+    ];
+    std::fs::write(
+        temp_dir.path().join("events.jsonl"),
+        lines.join("\n") + "\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args(["events", "--file", "events.jsonl", "--severity", "warn"])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("run ralph events");
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("| Sev   |"), "{stdout}");
+    assert!(!stdout.contains("build.done"), "{stdout}");
+    let row = |topic: &str| stdout.lines().find(|l| l.contains(topic)).unwrap();
+    assert!(row("build.blocked").contains("| error |"), "{stdout}");
+    assert!(row("hat.exhausted").contains("| warn  |"), "{stdout}");
+}
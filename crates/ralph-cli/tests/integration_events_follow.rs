@@ -0,0 +1,239 @@
+//! Integration tests for `ralph events --follow`.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use tempfile::TempDir;
+
+fn ralph_bin() -> &'static str {
+    env!("CARGO_BIN_EXE_ralph")
+}
+
+fn emit(dir: &std::path::Path, topic: &str, payload: &str) {
+    let status = Command::new(ralph_bin())
+        .args(["emit", topic, payload])
+        .current_dir(dir)
+        .stdout(Stdio::null())
+        .status()
+        .expect("run ralph emit");
+    assert!(status.success());
+}
+
+#[test]
+fn test_follow_prints_new_matching_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    emit(dir, "build.done", "before follow");
+
+    let mut child = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow",
+            "--topic",
+            "build.done",
+            "--format",
+            "json",
+        ])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn ralph events --follow");
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let next_line = || rx.recv_timeout(Duration::from_secs(10));
+
+    let first = next_line().expect("existing event printed");
+    assert!(first.contains("before follow"), "{first}");
+
+    emit(dir, "review.done", "filtered out");
+    emit(dir, "build.done", "after follow");
+
+    let second = next_line().expect("appended event printed");
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(second.contains("after follow"), "{second}");
+    let record: serde_json::Value = serde_json::from_str(&second).unwrap();
+    assert_eq!(record["topic"], "build.done");
+}
+
+#[test]
+fn test_follow_and_exit_on_exits_cleanly_when_topic_appears() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    emit(dir, "build.task", "started");
+
+    let mut child = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow-and-exit-on",
+            "build.done",
+            "--wait-timeout",
+            "30",
+            "--format",
+            "json",
+        ])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn ralph events --follow-and-exit-on");
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let first = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("existing event printed");
+    assert!(first.contains("build.task"), "{first}");
+
+    emit(dir, "review.done", "not yet");
+    emit(dir, "build.done", "milestone");
+    emit(dir, "build.task", "after milestone");
+
+    let status = child.wait().unwrap();
+    assert!(status.success(), "{status}");
+
+    let lines: Vec<String> = rx.iter().collect();
+    let last = lines.last().expect("milestone printed");
+    let record: serde_json::Value = serde_json::from_str(last).unwrap();
+    assert_eq!(record["topic"], "build.done");
+}
+
+#[test]
+fn test_follow_and_exit_on_fails_after_wait_timeout() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    emit(dir, "build.task", "started");
+
+    let output = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow-and-exit-on",
+            "deploy.*",
+            "--wait-timeout",
+            "1",
+            "--topic",
+            "review.done",
+        ])
+        .current_dir(dir)
+        .output()
+        .expect("run ralph events --follow-and-exit-on");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Timed out after 1s waiting for an event matching 'deploy.*'"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_follow_and_exit_on_exits_when_filters_hide_the_exit_topic() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    emit(dir, "build.done", "shown");
+
+    let mut child = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow-and-exit-on",
+            "loop.done",
+            "--topic",
+            "build.done",
+            "--wait-timeout",
+            "30",
+            "--format",
+            "json",
+        ])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn ralph events --follow-and-exit-on --topic");
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let first = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("existing event printed");
+    assert!(first.contains("shown"), "{first}");
+
+    emit(dir, "loop.done", "finished");
+
+    let start = std::time::Instant::now();
+    let status = child.wait().unwrap();
+    assert!(status.success(), "{status}");
+    assert!(start.elapsed() < Duration::from_secs(20));
+
+    // The exit topic ends the follow but is still subject to --topic
+    let lines: Vec<String> = rx.iter().collect();
+    assert!(lines.is_empty(), "{lines:?}");
+}
+
+#[test]
+fn test_follow_and_exit_on_since_now_ignores_existing_history() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    emit(dir, "build.done", "old milestone");
+
+    let mut child = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--follow-and-exit-on",
+            "build.done",
+            "--since-now",
+            "--wait-timeout",
+            "30",
+        ])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn ralph events --follow-and-exit-on --since-now");
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    // The table header is printed once existing history has been skipped
+    rx.recv_timeout(Duration::from_secs(10))
+        .expect("table header printed");
+
+    emit(dir, "build.done", "new milestone");
+
+    let status = child.wait().unwrap();
+    assert!(status.success(), "{status}");
+    let output = rx.iter().collect::<Vec<_>>().join("\n");
+    assert!(output.contains("new milestone"), "{output}");
+    assert!(!output.contains("old milestone"), "{output}");
+}
@@ -0,0 +1,96 @@
+//! Integration tests for merging several logs with repeated `ralph events --file`.
+
+use std::process::Command;
+use tempfile::TempDir;
+
+fn ralph_bin() -> &'static str {
+    env!("CARGO_BIN_EXE_ralph")
+}
+
+fn write_log(path: &std::path::Path, events: &[(&str, &str)]) {
+    let lines: Vec<String> = events
+        .iter()
+        .map(|(ts, payload)| {
+            serde_json::json!({ "ts": ts, "topic": "build.done", "payload": payload }).to_string()
+        })
+        .collect();
+    std::fs::write(path, lines.join("\n") + "\n").unwrap();
+}
+
+#[test]
+fn test_multiple_files_are_merged_by_timestamp() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    write_log(
+        &dir.join("a.jsonl"),
+        &[
+            ("2026-01-01T10:00:00Z", "a1"),
+            ("2026-01-01T10:00:02Z", "a2"),
+        ],
+    );
+    // Different offset, same instant ordering as UTC.
+    write_log(
+        &dir.join("b.jsonl"),
+        &[
+            ("2026-01-01T11:00:01+01:00", "b1"),
+            ("2026-01-01T10:00:03Z", "b2"),
+        ],
+    );
+
+    let output = Command::new(ralph_bin())
+        .args([
+            "events",
+            "--file",
+            "a.jsonl",
+            "--file",
+            "missing.jsonl",
+            "--file",
+            "b.jsonl",
+            "--format",
+            "json",
+        ])
+        .current_dir(dir)
+        .output()
+        .expect("run ralph events");
+
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("missing.jsonl not found"), "{stderr}");
+
+    let records: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let merged: Vec<(&str, &str)> = records
+        .iter()
+        .map(|r| {
+            (
+                r["payload"].as_str().unwrap(),
+                r["source"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        merged,
+        vec![
+            ("a1", "a.jsonl"),
+            ("b1", "b.jsonl"),
+            ("a2", "a.jsonl"),
+            ("b2", "b.jsonl"),
+        ]
+    );
+}
+
+#[test]
+fn test_single_file_has_no_source_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    write_log(&dir.join("a.jsonl"), &[("2026-01-01T10:00:00Z", "a1")]);
+
+    let output = Command::new(ralph_bin())
+        .args(["events", "--file", "a.jsonl", "--format", "json"])
+        .current_dir(dir)
+        .output()
+        .expect("run ralph events");
+
+    let records: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(records.len(), 1);
+    assert!(records[0].get("source").is_none());
+}
@@ -220,6 +220,75 @@ fn test_memory_add_json_format() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_memory_add_skips_exact_duplicate() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let id = ralph_memory_ok(
+        temp_path,
+        &["add", "Use barrel exports", "--format", "quiet"],
+    );
+    let stdout = ralph_memory_ok(temp_path, &["add", "Use barrel exports"]);
+
+    assert!(
+        stdout.contains(&format!("Duplicate of {}", id.trim())),
+        "Should report the existing memory: {}",
+        stdout
+    );
+    let content = std::fs::read_to_string(temp_path.join(".ralph/agent/memories.md"))?;
+    assert_eq!(content.matches("Use barrel exports").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_add_skips_whitespace_and_case_duplicate() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let id = ralph_memory_ok(
+        temp_path,
+        &["add", "Run cargo test before pushing", "--format", "quiet"],
+    );
+    let stdout = ralph_memory_ok(
+        temp_path,
+        &[
+            "add",
+            "  run   cargo test\nBEFORE pushing ",
+            "--format",
+            "quiet",
+        ],
+    );
+
+    assert_eq!(stdout.trim(), id.trim(), "Should print the existing ID");
+    let content = std::fs::read_to_string(temp_path.join(".ralph/agent/memories.md"))?;
+    assert_eq!(content.matches("### mem-").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_add_force_stores_duplicate() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let first = ralph_memory_ok(
+        temp_path,
+        &["add", "Use barrel exports", "--format", "quiet"],
+    );
+    let second = ralph_memory_ok(
+        temp_path,
+        &["add", "Use barrel exports", "--force", "--format", "quiet"],
+    );
+
+    assert_ne!(first.trim(), second.trim(), "Should create a new memory");
+    let content = std::fs::read_to_string(temp_path.join(".ralph/agent/memories.md"))?;
+    assert_eq!(content.matches("Use barrel exports").count(), 2);
+
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // List Command Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -438,6 +507,145 @@ fn test_memory_delete_not_found() -> Result<()> {
     Ok(())
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Clear Command Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_memory_clear_without_confirmation_aborts_on_stderr() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    ralph_memory_ok(temp_path, &["add", "keep me"]);
+
+    // stdin is empty, so the prompt is declined
+    let output = ralph_memory(temp_path, &["clear"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty(), "{:?}", output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Continue? [y/N] "), "{stderr}");
+    assert!(stderr.contains("Aborted."), "{stderr}");
+
+    let content = fs::read_to_string(temp_path.join(".ralph/agent/memories.md"))?;
+    assert!(content.contains("keep me"));
+
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Prune Command Tests
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Memories file with entries from 2020 (stale) and 2099 (never stale).
+const MIXED_AGE_MEMORIES: &str = r"# Memories
+
+## Patterns
+
+### mem-1577836800-0001
+> Old build uses make
+<!-- tags: build, legacy | created: 2020-01-01 -->
+
+### mem-4070908800-0002
+> Build uses cargo
+<!-- tags: build | created: 2099-01-01 -->
+
+## Decisions
+
+## Fixes
+
+### mem-1577836800-0003
+> Old flaky test workaround
+<!-- tags: testing | created: 2020-01-01 -->
+
+### mem-4070908800-0004
+> Retired API note
+<!-- tags: legacy | created: 2099-01-01 -->
+
+## Context
+";
+
+fn write_mixed_age_memories(temp_path: &std::path::Path) -> Result<std::path::PathBuf> {
+    let path = temp_path.join(".ralph/agent/memories.md");
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, MIXED_AGE_MEMORIES)?;
+    Ok(path)
+}
+
+#[test]
+fn test_memory_prune_older_than_removes_stale_entries() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    let path = write_mixed_age_memories(temp_path)?;
+
+    let stdout = ralph_memory_ok(temp_path, &["prune", "--older-than", "365"]);
+    assert!(stdout.contains("Pruned 2 memories"), "{stdout}");
+
+    let content = fs::read_to_string(&path)?;
+    assert!(!content.contains("mem-1577836800-0001"));
+    assert!(!content.contains("mem-1577836800-0003"));
+    assert!(content.contains("mem-4070908800-0002"));
+    assert!(content.contains("mem-4070908800-0004"));
+    for section in ["## Patterns", "## Decisions", "## Fixes", "## Context"] {
+        assert!(content.contains(section), "missing {section}: {content}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_prune_combines_age_and_tags() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    let path = write_mixed_age_memories(temp_path)?;
+
+    let stdout = ralph_memory_ok(
+        temp_path,
+        &[
+            "prune",
+            "--older-than",
+            "365",
+            "--tags",
+            "legacy",
+            "--format",
+            "quiet",
+        ],
+    );
+    assert_eq!(stdout.trim(), "mem-1577836800-0001");
+
+    let stdout = ralph_memory_ok(temp_path, &["prune", "--tags", "legacy"]);
+    assert!(stdout.contains("Pruned 1 memories"), "{stdout}");
+    assert!(!fs::read_to_string(&path)?.contains("Retired API note"));
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_prune_dry_run_keeps_file() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    let path = write_mixed_age_memories(temp_path)?;
+
+    let stdout = ralph_memory_ok(temp_path, &["prune", "--older-than", "365", "--dry-run"]);
+    assert!(stdout.contains("Old build uses make"), "{stdout}");
+    assert!(stdout.contains("Old flaky test workaround"), "{stdout}");
+    assert!(!stdout.contains("Build uses cargo"), "{stdout}");
+    assert!(stdout.contains("2 memories would be pruned"), "{stdout}");
+    assert_eq!(fs::read_to_string(&path)?, MIXED_AGE_MEMORIES);
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_prune_requires_criteria() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    write_mixed_age_memories(temp_path)?;
+
+    let output = ralph_memory(temp_path, &["prune"]);
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Search Command Tests
 // ─────────────────────────────────────────────────────────────────────────────
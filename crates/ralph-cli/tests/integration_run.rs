@@ -58,3 +58,603 @@ fn test_run_continue_requires_scratchpad() {
         "stderr: {stderr}"
     );
 }
+
+#[test]
+fn test_run_iteration_hooks_run_once_per_iteration() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        r#"
+event_loop:
+  max_iterations: 3
+  max_runtime_seconds: 30
+  pre_iteration_command: "echo \"pre $RALPH_ITERATION $RALPH_HAT\" >> hooks.log"
+  post_iteration_command: "echo \"post $RALPH_ITERATION $RALPH_ITERATION_SUCCESS\" >> hooks.log"
+
+cli:
+  backend: "custom"
+  command: "true"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+    )
+    .expect("write config");
+
+    let output = run_ralph(
+        temp_path,
+        &[
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+        ],
+    );
+
+    let log = std::fs::read_to_string(temp_path.join("hooks.log")).unwrap_or_else(|_| {
+        panic!(
+            "hooks never ran: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "pre 1 ralph",
+            "post 1 true",
+            "pre 2 ralph",
+            "post 2 true",
+            "pre 3 ralph",
+            "post 3 true",
+        ],
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_strict_post_hook_failure_keeps_iteration_events() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    let script = temp_path.join("mock-agent.sh");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\necho '<event topic=\"build.done\">tests: pass</event>'\n",
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+event_loop:
+  max_iterations: 3
+  max_runtime_seconds: 30
+  post_iteration_command: "false"
+  hooks_strict: true
+
+cli:
+  backend: "custom"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let output = run_ralph(
+        temp_path,
+        &[
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--summary-json",
+            "summary.json",
+        ],
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(1), "stderr: {stderr}");
+    let summary = std::fs::read_to_string(temp_path.join("summary.json")).expect("summary");
+    let summary: serde_json::Value = serde_json::from_str(&summary).expect("parse summary");
+    assert_eq!(summary["reason"], "hook_failed", "summary: {summary}");
+    assert_eq!(summary["iteration"], 1, "summary: {summary}");
+
+    // The failed hook does not discard what the agent emitted
+    let marker =
+        std::fs::read_to_string(temp_path.join(".ralph/current-events")).expect("events marker");
+    let events = std::fs::read_to_string(temp_path.join(marker.trim())).expect("read events log");
+    let topics: Vec<String> = events
+        .lines()
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line).expect("record");
+            record["topic"].as_str().expect("topic").to_string()
+        })
+        .collect();
+    assert!(
+        topics.iter().any(|t| t == "build.done"),
+        "topics: {topics:?}"
+    );
+    assert_eq!(topics.last().map(String::as_str), Some("loop.terminate"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_routes_stdout_events_logged_in_the_background() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    // Mock agent that only completes through events printed to stdout, so the
+    // loop must have written them to the events log before reading them back.
+    // The burst ahead of LOOP_COMPLETE keeps the background writer busy.
+    let script = temp_path.join("mock-agent.sh");
+    std::fs::write(
+        &script,
+        r#"#!/bin/sh
+for i in $(seq 1 50); do echo "<event topic=\"work.progress\">step $i</event>"; done
+echo '<event topic="LOOP_COMPLETE">all done</event>'
+"#,
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+event_loop:
+  max_iterations: 3
+  max_runtime_seconds: 30
+  max_events_per_iteration: 0
+
+cli:
+  backend: "custom"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let output = run_ralph(
+        temp_path,
+        &[
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--summary-json",
+            "summary.json",
+        ],
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(0), "stderr: {stderr}");
+    let summary = std::fs::read_to_string(temp_path.join("summary.json")).expect("summary");
+    let summary: serde_json::Value = serde_json::from_str(&summary).expect("parse summary");
+    assert_eq!(
+        summary["reason"], "completion_promise",
+        "summary: {summary}"
+    );
+    assert_eq!(summary["iteration"], 1, "summary: {summary}");
+
+    // Every record the loop queued reached disk, ending with loop.terminate
+    let marker =
+        std::fs::read_to_string(temp_path.join(".ralph/current-events")).expect("events marker");
+    let events = std::fs::read_to_string(temp_path.join(marker.trim())).expect("read events log");
+    let topics: Vec<String> = events
+        .lines()
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line).expect("record");
+            record["topic"].as_str().expect("topic").to_string()
+        })
+        .collect();
+    assert_eq!(topics.first().map(String::as_str), Some("task.start"));
+    assert!(
+        topics.iter().any(|t| t == "LOOP_COMPLETE"),
+        "topics: {topics:?}"
+    );
+    assert_eq!(topics.last().map(String::as_str), Some("loop.terminate"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_stops_when_reported_cost_exceeds_max_cost() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    // Mock Claude backend: each call reports a higher session cost
+    // (0.1, 0.2, 0.3, ...), so spend crosses $0.50 on the third iteration.
+    let counter = temp_path.join("calls");
+    let script = temp_path.join("mock-claude.sh");
+    std::fs::write(
+        &script,
+        format!(
+            r#"#!/bin/sh
+n=$(( $(cat "{counter}" 2>/dev/null || echo 0) + 1 ))
+echo "$n" > "{counter}"
+echo '{{"type":"result","duration_ms":5,"total_cost_usd":0.'"$n"',"num_turns":1,"is_error":false}}'
+"#,
+            counter = counter.display()
+        ),
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+event_loop:
+  max_iterations: 10
+  max_runtime_seconds: 60
+  max_cost_usd: 0.5
+
+cli:
+  backend: "claude"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let output = run_ralph(
+        temp_path,
+        &[
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--summary-json",
+            "summary.json",
+        ],
+    );
+
+    let summary = std::fs::read_to_string(temp_path.join("summary.json")).unwrap_or_else(|_| {
+        panic!(
+            "summary not written: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+    });
+    let summary: serde_json::Value = serde_json::from_str(&summary).expect("parse summary");
+
+    assert_eq!(summary["reason"], "max_cost", "summary: {summary}");
+    assert_eq!(summary["iteration"], 3, "summary: {summary}");
+    let cost = summary["cumulative_cost"]
+        .as_f64()
+        .expect("cumulative_cost");
+    assert!((cost - 0.6).abs() < 1e-9, "summary: {summary}");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_summary_json_is_relative_to_invocation_dir_with_workspace() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+    let workspace = temp_path.join("ws");
+    std::fs::create_dir(&workspace).expect("create workspace");
+
+    let script = temp_path.join("mock-claude.sh");
+    std::fs::write(
+        &script,
+        "#!/bin/sh
+exit 0
+",
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+cli:
+  backend: "claude"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let output = run_ralph(
+        temp_path,
+        &[
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--once",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--workspace",
+            "ws",
+            "--summary-json",
+            "summary.json",
+        ],
+    );
+
+    assert!(
+        temp_path.join("summary.json").exists(),
+        "summary not written to the invocation dir: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!workspace.join("summary.json").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_interrupt_writes_summary_and_stops_agent() {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::{Duration, Instant};
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    // Mock backend that starts a long-running tool and never finishes
+    let tool_pid = temp_path.join("tool.pid");
+    let script = temp_path.join("mock-claude.sh");
+    std::fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\nsleep 30 &\necho $! > \"{}\"\nwait\n",
+            tool_pid.display()
+        ),
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+event_loop:
+  max_iterations: 10
+  max_runtime_seconds: 60
+
+cli:
+  backend: "claude"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args([
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--summary-json",
+            "summary.json",
+        ])
+        .current_dir(temp_path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("spawn ralph");
+
+    let wait_for = |what: &str, done: &mut dyn FnMut() -> bool| {
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting for {what}");
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    };
+
+    let mut tool = None;
+    wait_for("the agent to start", &mut || {
+        tool = std::fs::read_to_string(&tool_pid)
+            .ok()
+            .and_then(|pid| pid.trim().parse::<i32>().ok());
+        tool.is_some()
+    });
+    let tool = Pid::from_raw(tool.unwrap());
+
+    let ralph = Pid::from_raw(i32::try_from(child.id()).expect("pid"));
+    kill(ralph, Signal::SIGINT).expect("send SIGINT");
+
+    let mut status = None;
+    wait_for("ralph to exit", &mut || {
+        status = child.try_wait().expect("wait for ralph");
+        status.is_some()
+    });
+    assert_eq!(status.unwrap().code(), Some(130));
+
+    let summary = std::fs::read_to_string(temp_path.join("summary.json")).expect("summary");
+    let summary: serde_json::Value = serde_json::from_str(&summary).expect("parse summary");
+    assert_eq!(summary["reason"], "interrupted", "summary: {summary}");
+
+    let events_marker =
+        std::fs::read_to_string(temp_path.join(".ralph/current-events")).expect("events marker");
+    let events = std::fs::read_to_string(temp_path.join(events_marker.trim())).expect("events");
+    assert!(
+        events
+            .lines()
+            .any(|line| line.contains("\"loop.terminate\"")),
+        "events: {events}"
+    );
+
+    // The tool the agent started went down with it
+    wait_for("the agent's tool to exit", &mut || {
+        kill(tool, None).is_err()
+            || std::fs::read_to_string(format!("/proc/{tool}/stat"))
+                .is_ok_and(|stat| stat.contains(") Z "))
+    });
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_sigterm_finishes_in_flight_iteration() {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::{Duration, Instant};
+
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    // Mock backend that records each iteration and blocks until released
+    let script = temp_path.join("mock-claude.sh");
+    std::fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho start >> \"{dir}/starts\"\nwhile [ ! -f \"{dir}/release\" ]; do sleep 0.05; done\necho finish >> \"{dir}/finishes\"\n",
+            dir = temp_path.display()
+        ),
+    )
+    .expect("write mock backend");
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+        .expect("chmod mock backend");
+
+    std::fs::write(
+        temp_path.join("ralph.yml"),
+        format!(
+            r#"
+event_loop:
+  max_iterations: 10
+  max_runtime_seconds: 60
+
+cli:
+  backend: "claude"
+  command: "{}"
+
+memories:
+  enabled: false
+
+tasks:
+  enabled: false
+"#,
+            script.display()
+        ),
+    )
+    .expect("write config");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .args([
+            "run",
+            "--skip-preflight",
+            "--no-tui",
+            "--prompt",
+            "hello world",
+            "--config",
+            "ralph.yml",
+            "--summary-json",
+            "summary.json",
+        ])
+        .current_dir(temp_path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("spawn ralph");
+
+    let wait_for = |what: &str, done: &mut dyn FnMut() -> bool| {
+        let deadline = Instant::now() + Duration::from_secs(20);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting for {what}");
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    };
+    let lines = |name: &str| {
+        std::fs::read_to_string(temp_path.join(name))
+            .map(|content| content.lines().count())
+            .unwrap_or(0)
+    };
+
+    wait_for("the first iteration to start", &mut || lines("starts") == 1);
+    let ralph = Pid::from_raw(i32::try_from(child.id()).expect("pid"));
+    kill(ralph, Signal::SIGTERM).expect("send SIGTERM");
+
+    // The agent is still running; let it finish on its own
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(lines("finishes"), 0, "SIGTERM must not cancel the agent");
+    std::fs::write(temp_path.join("release"), "").expect("release agent");
+
+    let mut status = None;
+    wait_for("ralph to exit", &mut || {
+        status = child.try_wait().expect("wait for ralph");
+        status.is_some()
+    });
+    assert_eq!(status.unwrap().code(), Some(130));
+    assert_eq!(lines("finishes"), 1, "the in-flight iteration completed");
+    assert_eq!(lines("starts"), 1, "no further iteration started");
+
+    let summary = std::fs::read_to_string(temp_path.join("summary.json")).expect("summary");
+    let summary: serde_json::Value = serde_json::from_str(&summary).expect("parse summary");
+    assert_eq!(summary["reason"], "stopped", "summary: {summary}");
+    assert_eq!(summary["iteration"], 1, "summary: {summary}");
+}
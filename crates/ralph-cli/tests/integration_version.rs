@@ -0,0 +1,49 @@
+//! Integration tests for `ralph version` CLI command.
+
+use std::process::Command;
+
+fn ralph_version(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("version")
+        .args(args)
+        .output()
+        .expect("Failed to execute ralph version command");
+    assert!(
+        output.status.success(),
+        "version failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_version_prints_crate_version() {
+    let stdout = ralph_version(&[]);
+
+    assert_eq!(
+        stdout.trim(),
+        format!("ralph {}", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn test_version_verbose_includes_build_metadata_and_backends() {
+    let stdout = ralph_version(&["--verbose"]);
+
+    assert!(
+        stdout.starts_with(&format!("ralph {}\n", env!("CARGO_PKG_VERSION"))),
+        "Should lead with the crate version: {}",
+        stdout
+    );
+    for label in ["commit:", "rustc:", "target:", "profile:", "Backends:"] {
+        assert!(stdout.contains(label), "Missing {label}: {stdout}");
+    }
+    assert!(
+        stdout.contains("rustc "),
+        "Should embed rustc version: {stdout}"
+    );
+    assert!(
+        stdout.contains("  claude"),
+        "Should list backends: {stdout}"
+    );
+}
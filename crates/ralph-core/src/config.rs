@@ -5,7 +5,7 @@
 
 use ralph_proto::Topic;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use tracing::debug;
 
@@ -17,6 +17,10 @@ use tracing::debug;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(clippy::struct_excessive_bools)] // Configuration struct with multiple feature flags
 pub struct RalphConfig {
+    /// Short summary of what this config is for (informational only).
+    #[serde(default)]
+    pub description: Option<String>,
+
     /// Event loop configuration (v2 nested style).
     #[serde(default)]
     pub event_loop: EventLoopConfig,
@@ -80,6 +84,10 @@ pub struct RalphConfig {
     #[serde(default)]
     pub verbose: bool,
 
+    /// Trace routing decisions each iteration (set by `ralph run --explain`).
+    #[serde(default)]
+    pub explain: bool,
+
     /// Archive prompts after completion (DEFERRED: warn if enabled).
     #[serde(default)]
     pub archive_prompts: bool,
@@ -143,6 +151,7 @@ fn default_true() -> bool {
 impl Default for RalphConfig {
     fn default() -> Self {
         Self {
+            description: None,
             event_loop: EventLoopConfig::default(),
             cli: CliConfig::default(),
             core: CoreConfig::default(),
@@ -158,6 +167,7 @@ impl Default for RalphConfig {
             max_cost: None,
             // Feature flags
             verbose: false,
+            explain: false,
             archive_prompts: false,
             enable_metrics: false,
             // Dropped fields
@@ -245,6 +255,14 @@ impl RalphConfig {
         Self::parse_yaml(&content)
     }
 
+    /// Loads configuration from a YAML file, rejecting unknown keys.
+    pub fn from_file_strict(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path_ref = path.as_ref();
+        debug!(path = %path_ref.display(), "Loading configuration from file (strict)");
+        let content = std::fs::read_to_string(path_ref)?;
+        Self::parse_yaml_strict(&content)
+    }
+
     /// Parses configuration from a YAML string.
     pub fn parse_yaml(content: &str) -> Result<Self, ConfigError> {
         let config: Self = serde_yaml::from_str(content)?;
@@ -257,6 +275,25 @@ impl RalphConfig {
         Ok(config)
     }
 
+    /// Parses configuration, rejecting keys that no config field accepts.
+    ///
+    /// Lenient [`parse_yaml`](Self::parse_yaml) silently ignores unknown keys,
+    /// so a typo like `max_iteration` just falls back to the default.
+    pub fn parse_yaml_strict(content: &str) -> Result<Self, ConfigError> {
+        let mut keys = Vec::new();
+        let config: Self =
+            serde_ignored::deserialize(serde_yaml::Deserializer::from_str(content), |path| {
+                keys.push(path.to_string())
+            })?;
+        if keys.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError::UnknownKeys {
+                keys: keys.join(", "),
+            })
+        }
+    }
+
     /// Normalizes v1 flat fields into v2 nested structure.
     ///
     /// V1 flat fields take precedence over v2 nested fields when both are present.
@@ -346,12 +383,51 @@ impl RalphConfig {
         }
     }
 
+    /// Returns the v1 flat fields that are set, paired with the v2 field
+    /// `normalize()` maps each onto.
+    pub fn deprecated_v1_fields(&self) -> Vec<(&'static str, &'static str)> {
+        [
+            ("agent", "cli.backend", self.agent.is_some()),
+            (
+                "prompt_file",
+                "event_loop.prompt_file",
+                self.prompt_file.is_some(),
+            ),
+            (
+                "completion_promise",
+                "event_loop.completion_promise",
+                self.completion_promise.is_some(),
+            ),
+            (
+                "max_iterations",
+                "event_loop.max_iterations",
+                self.max_iterations.is_some(),
+            ),
+            (
+                "max_runtime",
+                "event_loop.max_runtime_seconds",
+                self.max_runtime.is_some(),
+            ),
+            (
+                "max_cost",
+                "event_loop.max_cost_usd",
+                self.max_cost.is_some(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, _, present)| *present)
+        .map(|(field, replacement, _)| (field, replacement))
+        .collect()
+    }
+
     /// Validates the configuration and returns warnings.
     ///
     /// This method checks for:
     /// - Deferred features that are enabled (archive_prompts, enable_metrics)
+    /// - Deprecated v1 flat fields (agent, max_iterations, ...)
     /// - Dropped fields that are present (max_tokens, retry_delay, tool_permissions)
     /// - Ambiguous trigger routing across custom hats
+    /// - Unreachable hats and dead-end topics when `starting_event` is set
     /// - Mutual exclusivity of prompt and prompt_file
     ///
     /// Returns a list of warnings that should be displayed to the user.
@@ -398,6 +474,16 @@ impl RalphConfig {
             });
         }
 
+        // Check for deprecated v1 flat fields
+        warnings.extend(
+            self.deprecated_v1_fields()
+                .into_iter()
+                .map(|(field, replacement)| ConfigWarning::DeprecatedField {
+                    field: field.to_string(),
+                    replacement: replacement.to_string(),
+                }),
+        );
+
         // Check for dropped fields
         if self.max_tokens.is_some() {
             warnings.push(ConfigWarning::DroppedField {
@@ -450,6 +536,17 @@ impl RalphConfig {
             }
         }
 
+        // Check for hat names that collide across distinct keys
+        check_duplicate_hat_names(&self.hats)?;
+
+        // Redaction patterns must compile before anything gets logged
+        if let Err((pattern, e)) = crate::redaction::Redactor::new(&self.core.redact_patterns) {
+            return Err(ConfigError::InvalidRedactPattern {
+                pattern,
+                message: e.to_string(),
+            });
+        }
+
         // Check for reserved triggers: task.start and task.resume are reserved for Ralph
         // Per design: Ralph coordinates first, then delegates to custom hats via events
         const RESERVED_TRIGGERS: &[&str] = &["task.start", "task.resume"];
@@ -482,6 +579,18 @@ impl RalphConfig {
             }
         }
 
+        // Check the hat graph for hats that never run and topics nobody consumes.
+        // Without a starting_event Ralph picks the entry hat itself, so only
+        // explicit pipelines are checked.
+        if let Some(starting_event) = &self.event_loop.starting_event
+            && !self.hats.is_empty()
+        {
+            warnings.extend(
+                crate::hat_registry::HatRegistry::from_config(self)
+                    .validate_topology(starting_event, &self.event_loop.completion_promise),
+            );
+        }
+
         Ok(warnings)
     }
 
@@ -523,6 +632,12 @@ pub enum ConfigWarning {
     DroppedField { field: String, reason: String },
     /// Field has an invalid value.
     InvalidValue { field: String, message: String },
+    /// V1 flat field that still works but should move to its v2 location.
+    DeprecatedField { field: String, replacement: String },
+    /// Hat whose triggers are never published, so it can never run.
+    UnreachableHat { hat: String, triggers: Vec<String> },
+    /// Topic a hat publishes that no hat subscribes to.
+    DeadEndTopic { hat: String, topic: String },
 }
 
 impl std::fmt::Display for ConfigWarning {
@@ -536,6 +651,19 @@ impl std::fmt::Display for ConfigWarning {
             ConfigWarning::DroppedField { field, reason } => {
                 write!(f, "Warning [{field}]: Field ignored - {reason}")
             }
+            ConfigWarning::DeprecatedField { field, replacement } => write!(
+                f,
+                "Warning [{field}]: Deprecated v1 field - use '{replacement}' instead (run `ralph config migrate`)"
+            ),
+            ConfigWarning::UnreachableHat { hat, triggers } => write!(
+                f,
+                "Warning [hats.{hat}]: Unreachable - nothing publishes its triggers ({})",
+                triggers.join(", ")
+            ),
+            ConfigWarning::DeadEndTopic { hat, topic } => write!(
+                f,
+                "Warning [hats.{hat}.publishes]: No hat subscribes to '{topic}' (falls back to Ralph)"
+            ),
         }
     }
 }
@@ -565,15 +693,40 @@ pub struct EventLoopConfig {
     /// Maximum cost in USD before stopping.
     pub max_cost_usd: Option<f64>,
 
+    /// Deprecated git checkpoint frequency. Accepted so existing configs and
+    /// presets still parse (including under `--strict-config`), but ignored.
+    #[serde(default)]
+    pub checkpoint_interval: Option<u32>,
+
     /// Stop after this many consecutive failures.
     #[serde(default = "default_max_failures")]
     pub max_consecutive_failures: u32,
 
+    /// Maximum activations for every hat in a single loop run, unless the hat
+    /// sets its own `max_activations`. Exhausted hats publish
+    /// `<hat_id>.exhausted` instead of running again. `None` means unlimited.
+    #[serde(default)]
+    pub max_iterations_per_hat: Option<u32>,
+
+    /// Stop as a stale loop once the same topic is emitted this many times
+    /// in a row, e.g. two hats bouncing `build.task`/`build.blocked` (0 = never).
+    #[serde(default = "default_max_consecutive_same_topic")]
+    pub max_consecutive_same_topic: u32,
+
     /// Delay in seconds before starting the next iteration.
     /// Skipped when the next iteration is triggered by a human event.
     #[serde(default)]
     pub cooldown_delay_seconds: u64,
 
+    /// Pause in seconds after a failed iteration, doubled for each further
+    /// consecutive failure and reset by a success (0 = no backoff).
+    #[serde(default)]
+    pub failure_backoff_seconds: u64,
+
+    /// Cap in seconds on the failure backoff.
+    #[serde(default = "default_max_failure_backoff_seconds")]
+    pub max_failure_backoff_seconds: u64,
+
     /// Starting hat for multi-hat mode (deprecated, use starting_event instead).
     pub starting_hat: Option<String>,
 
@@ -605,10 +758,19 @@ pub struct EventLoopConfig {
 
     /// Event topics that must have been seen before LOOP_COMPLETE is accepted.
     /// If any required event has not been seen during the loop's lifetime,
-    /// completion is rejected and a task.resume event is injected.
+    /// completion is rejected and a `completion.blocked` event listing the missing
+    /// topics is published so the loop continues.
     #[serde(default)]
     pub required_events: Vec<String>,
 
+    /// Evidence topic (typically `build.done`) that must have been accepted
+    /// before LOOP_COMPLETE is honored. `build.done` is only accepted when its
+    /// backpressure evidence passes, so this holds completion until tests have
+    /// been run and reported. A premature completion is rejected with a
+    /// corrective task.resume and the loop continues. Unset disables the check.
+    #[serde(default)]
+    pub completion_evidence_topic: Option<String>,
+
     /// Event topic that triggers graceful early termination WITHOUT chain validation.
     /// Use this for human rejection, timeout escalation, or other abort paths.
     /// Defaults to "" (disabled). Set to "loop.cancel" to enable.
@@ -620,6 +782,99 @@ pub struct EventLoopConfig {
     /// `{hat_id}.scope_violation` diagnostic events. Defaults to false (permissive).
     #[serde(default)]
     pub enforce_hat_scope: bool,
+
+    /// Shell command that verifies a `build.done` claim (e.g. `cargo test`).
+    ///
+    /// When set, a `build.done` the agent writes to the events file is not
+    /// trusted: once the iteration's events are read, the command runs in the
+    /// workspace root and the loop publishes `build.done` if it exits 0, or
+    /// `build.blocked` otherwise. Payload evidence parsing is skipped. Only
+    /// agent-written `build.done` triggers it; other completion topics do not.
+    #[serde(default)]
+    pub verification_command: Option<String>,
+
+    /// Seconds the verification command may run before it is killed and the
+    /// `build.done` is rejected as `build.blocked`. 0 disables the limit.
+    #[serde(default = "default_verification_timeout_seconds")]
+    pub verification_timeout_seconds: u64,
+
+    /// Maximum characters of failing verification output injected into the
+    /// next prompt (the tail is kept, where errors usually are). 0 disables.
+    #[serde(default = "default_verification_output_max_chars")]
+    pub verification_output_max_chars: usize,
+
+    /// Maximum events accepted from a single iteration. Extra events are
+    /// dropped for good (the events file has already been read past them)
+    /// with a warning giving the count, so runaway output cannot flood the
+    /// bus or the event log. The default of 200 leaves room for busy
+    /// iterations; 0 disables the cap.
+    #[serde(default = "default_max_events_per_iteration")]
+    pub max_events_per_iteration: usize,
+
+    /// Per-topic publish limits keyed by topic or pattern (e.g. `build.*`).
+    /// Once an agent has published a topic `max` times within
+    /// `window_seconds`, further events on it are dropped with a warning.
+    /// The most specific matching pattern applies. Unlike
+    /// `max_events_per_iteration`, this targets a single runaway topic.
+    #[serde(default)]
+    pub topic_rate_limits: BTreeMap<String, TopicRateLimitConfig>,
+
+    /// Alternate topic names mapped to their canonical topic, e.g.
+    /// `tests_passed: build.done`. Aliased events are rewritten before
+    /// routing and logging, so agents that drift from the exact topic names
+    /// in their instructions still trigger the right hat.
+    #[serde(default)]
+    pub topic_aliases: HashMap<String, String>,
+
+    /// Payload length at which events written to the event log are truncated.
+    /// 0 disables truncation.
+    #[serde(default = "default_max_payload_len")]
+    pub max_payload_len: usize,
+
+    /// Read events only from `.ralph/events.jsonl` and skip scanning agent
+    /// stdout for `<event>` tags. For backends that always publish with
+    /// `ralph emit`, this avoids wasted parsing and false positives from
+    /// quoted XML in output.
+    #[serde(default)]
+    pub json_events_only: bool,
+
+    /// Stop after one hat execution regardless of `max_iterations`
+    /// (set by `ralph run --once`).
+    #[serde(skip)]
+    pub single_iteration: bool,
+
+    /// Where to write a JSON run summary on termination
+    /// (set by `ralph run --summary-json`).
+    #[serde(skip)]
+    pub summary_json: Option<String>,
+
+    /// Shell command run when the loop ends with the completion promise.
+    ///
+    /// Runs in the workspace root with `RALPH_TERMINATION_REASON`,
+    /// `RALPH_SUCCESS`, `RALPH_ITERATIONS`, `RALPH_COST_USD`, and
+    /// `RALPH_ELAPSED_SECS` set. Its exit status is logged, never fatal.
+    #[serde(default)]
+    pub on_complete_command: Option<String>,
+
+    /// Shell command run when the loop ends for any other reason
+    /// (limits, failures, interrupts). Same environment as `on_complete_command`.
+    #[serde(default)]
+    pub on_failure_command: Option<String>,
+
+    /// Shell command run before each hat run, with `RALPH_ITERATION` and
+    /// `RALPH_HAT` set.
+    #[serde(default)]
+    pub pre_iteration_command: Option<String>,
+
+    /// Shell command run after each hat run, once its events are logged, with
+    /// `RALPH_ITERATION`, `RALPH_HAT`, and `RALPH_ITERATION_SUCCESS` set.
+    #[serde(default)]
+    pub post_iteration_command: Option<String>,
+
+    /// When true, a failing iteration hook stops the loop with
+    /// `TerminationReason::HookFailed` instead of only logging a warning.
+    #[serde(default)]
+    pub hooks_strict: bool,
 }
 
 fn default_prompt_file() -> String {
@@ -642,6 +897,30 @@ fn default_max_failures() -> u32 {
     5
 }
 
+fn default_max_failure_backoff_seconds() -> u64 {
+    300
+}
+
+fn default_max_consecutive_same_topic() -> u32 {
+    3
+}
+
+fn default_verification_timeout_seconds() -> u64 {
+    600
+}
+
+fn default_verification_output_max_chars() -> usize {
+    4000
+}
+
+fn default_max_events_per_iteration() -> usize {
+    200
+}
+
+fn default_max_payload_len() -> usize {
+    crate::event_logger::EventRecord::DEFAULT_MAX_PAYLOAD_LEN
+}
+
 impl Default for EventLoopConfig {
     fn default() -> Self {
         Self {
@@ -652,18 +931,48 @@ impl Default for EventLoopConfig {
             max_runtime_seconds: default_max_runtime(),
             max_cost_usd: None,
             max_consecutive_failures: default_max_failures(),
+            max_iterations_per_hat: None,
+            max_consecutive_same_topic: default_max_consecutive_same_topic(),
+            checkpoint_interval: None,
             cooldown_delay_seconds: 0,
+            failure_backoff_seconds: 0,
+            max_failure_backoff_seconds: default_max_failure_backoff_seconds(),
             starting_hat: None,
             starting_event: None,
             mutation_score_warn_threshold: None,
             persistent: false,
             required_events: Vec::new(),
+            completion_evidence_topic: None,
             cancellation_promise: String::new(),
             enforce_hat_scope: false,
+            verification_command: None,
+            verification_timeout_seconds: default_verification_timeout_seconds(),
+            verification_output_max_chars: default_verification_output_max_chars(),
+            max_events_per_iteration: default_max_events_per_iteration(),
+            topic_rate_limits: BTreeMap::new(),
+            topic_aliases: HashMap::new(),
+            max_payload_len: default_max_payload_len(),
+            json_events_only: false,
+            single_iteration: false,
+            summary_json: None,
+            on_complete_command: None,
+            on_failure_command: None,
+            pre_iteration_command: None,
+            post_iteration_command: None,
+            hooks_strict: false,
         }
     }
 }
 
+/// Rate limit for one topic pattern in `event_loop.topic_rate_limits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopicRateLimitConfig {
+    /// Publishes allowed per window.
+    pub max: u32,
+    /// Length of the sliding window in seconds (0 disables the limit).
+    pub window_seconds: u64,
+}
+
 /// Core paths and settings shared across all hats.
 ///
 /// Per spec: "Core behaviors (always injected, can customize paths)"
@@ -691,6 +1000,13 @@ pub struct CoreConfig {
     /// This is especially important for E2E tests that run in isolated workspaces.
     #[serde(skip)]
     pub workspace_root: std::path::PathBuf,
+
+    /// Extra regex patterns redacted to `***` in event logs and console output.
+    ///
+    /// Common credential formats (API keys, GitHub/Slack/AWS tokens, private
+    /// keys) are always redacted; these are added on top.
+    #[serde(default)]
+    pub redact_patterns: Vec<String>,
 }
 
 fn default_scratchpad() -> String {
@@ -722,6 +1038,7 @@ impl Default for CoreConfig {
                 .unwrap_or_else(|_| {
                     std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
                 }),
+            redact_patterns: Vec::new(),
         }
     }
 }
@@ -878,10 +1195,27 @@ pub struct MemoriesConfig {
 
     /// Maximum tokens to inject (0 = unlimited).
     ///
-    /// When set, memories are truncated to fit within this budget.
+    /// When set, the memories matching the most `filter.tags` (then the most
+    /// recent) are injected whole until the budget is reached; the rest are
+    /// omitted.
     #[serde(default)]
     pub budget: usize,
 
+    /// Maximum number of memories to inject (0 = unlimited).
+    ///
+    /// The most recent memories are kept. Applied alongside `budget`, so
+    /// whichever limit is smaller wins.
+    #[serde(default)]
+    pub max_injected: usize,
+
+    /// Shell command that embeds text for semantic memory ranking.
+    ///
+    /// Receives the text on stdin and prints a JSON array of numbers. Only
+    /// used by builds with the `semantic-memory` feature; without it (or when
+    /// the command fails) ranking stays lexical.
+    #[serde(default)]
+    pub embedding_command: Option<String>,
+
     /// Filter configuration for memory injection.
     #[serde(default)]
     pub filter: MemoriesFilter,
@@ -893,6 +1227,8 @@ impl Default for MemoriesConfig {
             enabled: true, // Memories enabled by default
             inject: InjectMode::Auto,
             budget: 0,
+            max_injected: 0,
+            embedding_command: None,
             filter: MemoriesFilter::default(),
         }
     }
@@ -1310,6 +1646,7 @@ impl HatConfig {
 ///   checkin_interval_seconds: 120  # Optional: send status every 2 min
 ///   telegram:
 ///     bot_token: "..."  # Or set RALPH_TELEGRAM_BOT_TOKEN env var
+///     parse_mode: markdown_v2  # Optional: html (default) or markdown_v2
 /// ```
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RobotConfig {
@@ -1332,6 +1669,14 @@ pub struct RobotConfig {
 }
 
 impl RobotConfig {
+    /// Formatting syntax for Telegram messages; HTML unless configured.
+    pub fn parse_mode(&self) -> TelegramParseMode {
+        self.telegram
+            .as_ref()
+            .map(|telegram| telegram.parse_mode)
+            .unwrap_or_default()
+    }
+
     /// Validates the RObot config. Returns an error if enabled but misconfigured.
     pub fn validate(&self) -> Result<(), ConfigError> {
         if !self.enabled {
@@ -1396,6 +1741,22 @@ impl RobotConfig {
 pub struct TelegramBotConfig {
     /// Bot token. Optional if `RALPH_TELEGRAM_BOT_TOKEN` env var is set.
     pub bot_token: Option<String>,
+
+    /// Formatting syntax for every message the bot sends.
+    #[serde(default)]
+    pub parse_mode: TelegramParseMode,
+}
+
+/// Formatting syntax for Telegram messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TelegramParseMode {
+    /// Telegram HTML.
+    #[default]
+    Html,
+    /// Telegram MarkdownV2.
+    #[serde(alias = "markdownv2")]
+    MarkdownV2,
 }
 
 /// Configuration errors.
@@ -1434,6 +1795,16 @@ pub enum ConfigError {
     )]
     ReservedTrigger { trigger: String, hat: String },
 
+    #[error(
+        "Invalid redact pattern '{pattern}': {message}\nFix: correct the regex in 'core.redact_patterns'.\nSee: docs/reference/troubleshooting.md#invalid-redact-pattern"
+    )]
+    InvalidRedactPattern { pattern: String, message: String },
+
+    #[error(
+        "Unknown config key(s): {keys}\nFix: check the spelling against docs/guide/configuration.md, or drop --strict-config to ignore unknown keys.\nSee: docs/reference/troubleshooting.md#unknown-config-keys"
+    )]
+    UnknownKeys { keys: String },
+
     #[error(
         "Hat '{hat}' is missing required 'description' field - add a short description of the hat's purpose.\nSee: docs/reference/troubleshooting.md#missing-hat-description"
     )]
@@ -1443,6 +1814,56 @@ pub enum ConfigError {
         "RObot config error: {field} - {hint}\nSee: docs/reference/troubleshooting.md#robot-config"
     )]
     RobotMissingField { field: String, hint: String },
+
+    #[error(
+        "Duplicate hat name: '{name}' is used by both '{hat1}' and '{hat2}'.\nFix: give each hat a unique name (names must not collide with other hats' keys either).\nSee: docs/reference/troubleshooting.md#duplicate-hat-name"
+    )]
+    DuplicateHatName {
+        name: String,
+        hat1: String,
+        hat2: String,
+    },
+}
+
+/// Detects hats whose names collide with another hat's name or key.
+///
+/// YAML parsing already rejects duplicate keys, but two distinct keys can
+/// still share a `name` (compared case-insensitively), which makes routing
+/// tables and logs ambiguous. Keys are checked in sorted order so the
+/// reported pair is deterministic.
+pub(crate) fn check_duplicate_hat_names(
+    hats: &HashMap<String, HatConfig>,
+) -> Result<(), ConfigError> {
+    let mut keys: Vec<&String> = hats.keys().collect();
+    keys.sort();
+
+    let mut seen: HashMap<String, &str> = keys
+        .iter()
+        .map(|key| (key.trim().to_lowercase(), key.as_str()))
+        .collect();
+
+    for key in keys {
+        let name = hats[key].name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let normalized = name.to_lowercase();
+        match seen.get(&normalized) {
+            Some(owner) if *owner != key.as_str() => {
+                return Err(ConfigError::DuplicateHatName {
+                    name: name.to_string(),
+                    hat1: (*owner).to_string(),
+                    hat2: key.clone(),
+                });
+            }
+            Some(_) => {}
+            None => {
+                seen.insert(normalized, key.as_str());
+            }
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1595,6 +2016,102 @@ adapters:
             .any(|w| matches!(w, ConfigWarning::DroppedField { field, .. } if field == "adapters.*.tool_permissions")));
     }
 
+    #[test]
+    fn test_validate_deprecated_v1_fields() {
+        let yaml = r"
+agent: gemini
+max_iterations: 25
+cli:
+  backend: claude
+";
+        let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        config.normalize();
+        let warnings = config.validate().unwrap();
+
+        let deprecated: Vec<String> = warnings
+            .iter()
+            .filter(|w| matches!(w, ConfigWarning::DeprecatedField { .. }))
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            deprecated,
+            vec![
+                "Warning [agent]: Deprecated v1 field - use 'cli.backend' instead (run `ralph config migrate`)",
+                "Warning [max_iterations]: Deprecated v1 field - use 'event_loop.max_iterations' instead (run `ralph config migrate`)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strict_parse_rejects_unknown_keys() {
+        let yaml = r#"
+max_iteration: 5
+event_loop:
+  max_iterations: 5
+  max_iteration: 10
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds"
+    triggers: ["build.start"]
+    trigger: "typo"
+"#;
+
+        // Lenient parsing ignores the typos.
+        assert!(RalphConfig::parse_yaml(yaml).is_ok());
+
+        let err = RalphConfig::parse_yaml_strict(yaml).unwrap_err();
+        match err {
+            ConfigError::UnknownKeys { keys } => assert_eq!(
+                keys,
+                "max_iteration, event_loop.max_iteration, hats.builder.trigger"
+            ),
+            other => panic!("expected UnknownKeys, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_strict_parse_accepts_known_keys() {
+        let yaml = r#"
+agent: claude
+_suppress_warnings: true
+cli:
+  backend: custom
+  command: "true"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds"
+    triggers: ["build.start"]
+    backend:
+      type: kiro
+      agent: builder
+RObot:
+  enabled: false
+"#;
+
+        assert!(RalphConfig::parse_yaml_strict(yaml).is_ok());
+    }
+
+    #[test]
+    fn test_strict_parse_accepts_description() {
+        let yaml = "description: \"Feature work with review\"\n";
+
+        let config = RalphConfig::parse_yaml_strict(yaml).unwrap();
+        assert_eq!(
+            config.description.as_deref(),
+            Some("Feature work with review")
+        );
+    }
+
+    #[test]
+    fn test_strict_parse_accepts_deprecated_checkpoint_interval() {
+        let yaml = "event_loop:\n  checkpoint_interval: 5\n";
+
+        let config = RalphConfig::parse_yaml_strict(yaml).unwrap();
+        assert_eq!(config.event_loop.checkpoint_interval, Some(5));
+    }
+
     #[test]
     fn test_suppress_warnings() {
         let yaml = r"
@@ -1671,6 +2188,55 @@ hats:
         );
     }
 
+    #[test]
+    fn test_topology_warnings_require_starting_event() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds code"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+        let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().unwrap().is_empty());
+
+        config.event_loop.starting_event = Some("build.task".to_string());
+        let warnings = config.validate().unwrap();
+        assert!(
+            matches!(
+                warnings.as_slice(),
+                [ConfigWarning::DeadEndTopic { hat, topic }] if hat == "builder" && topic == "build.done"
+            ),
+            "{warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_hat_name_rejected() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds code"
+    triggers: ["build.task"]
+  fixer:
+    name: "Builder"
+    description: "Fixes code"
+    triggers: ["fix.task"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = config.validate();
+
+        let err = result.unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::DuplicateHatName { name, hat1, hat2 }
+                if name == "Builder" && hat1 == "builder" && hat2 == "fixer"),
+            "Expected DuplicateHatName error for 'Builder', got: {:?}",
+            err
+        );
+    }
+
     #[test]
     fn test_unique_triggers_accepted() {
         // Valid config: each trigger maps to exactly one hat
@@ -2429,6 +2995,23 @@ RObot:
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_robot_config_parse_mode() {
+        let yaml = r#"
+RObot:
+  telegram:
+    bot_token: "123456:ABC-DEF"
+    parse_mode: markdown_v2
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.robot.parse_mode(), TelegramParseMode::MarkdownV2);
+
+        assert_eq!(
+            RalphConfig::default().robot.parse_mode(),
+            TelegramParseMode::Html
+        );
+    }
+
     #[test]
     fn test_robot_config_disabled_skips_validation() {
         // Disabled RObot config should pass validation even with missing fields
@@ -2492,6 +3075,7 @@ RObot:
             checkin_interval_seconds: None,
             telegram: Some(TelegramBotConfig {
                 bot_token: Some("config-token".to_string()),
+                parse_mode: TelegramParseMode::default(),
             }),
         };
 
@@ -2530,6 +3114,7 @@ RObot:
             checkin_interval_seconds: None,
             telegram: Some(TelegramBotConfig {
                 bot_token: Some("test-token".to_string()),
+                parse_mode: TelegramParseMode::default(),
             }),
         };
         assert!(robot.validate().is_ok());
@@ -2572,7 +3157,10 @@ RObot:
             enabled: true,
             timeout_seconds: Some(300),
             checkin_interval_seconds: None,
-            telegram: Some(TelegramBotConfig { bot_token: None }),
+            telegram: Some(TelegramBotConfig {
+                bot_token: None,
+                parse_mode: TelegramParseMode::default(),
+            }),
         };
         let result = robot.validate();
         assert!(result.is_err());
@@ -0,0 +1,194 @@
+//! Explicit v1 → v2 config migration behind `ralph config migrate`.
+//!
+//! [`RalphConfig::normalize`](crate::RalphConfig::normalize) maps v1 flat
+//! fields onto their v2 nested equivalents at load time. This module performs
+//! the same mapping on the YAML document itself so users can rewrite their
+//! config once and see exactly which fields moved. Other keys are kept as-is;
+//! YAML comments are not preserved in a rewritten file.
+
+use crate::config::{ConfigError, RalphConfig};
+use serde_yaml::{Mapping, Value};
+use std::fmt;
+
+/// V1 fields that have no v2 equivalent and are removed.
+const DROPPED_FIELDS: &[(&str, &str)] = &[
+    ("max_tokens", "token limits are controlled by the CLI tool"),
+    ("retry_delay", "retry logic is handled differently in v2"),
+];
+
+/// One change made while migrating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationChange {
+    /// A v1 field was moved to its v2 location.
+    Moved { from: String, to: String },
+    /// A v1 field was moved, replacing a v2 value that was already set.
+    Replaced { from: String, to: String },
+    /// A v1 field with no v2 equivalent was removed.
+    Dropped { field: String, reason: String },
+}
+
+impl fmt::Display for MigrationChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Moved { from, to } => write!(f, "moved {from} → {to}"),
+            Self::Replaced { from, to } => {
+                write!(f, "moved {from} → {to} (replaced existing {to})")
+            }
+            Self::Dropped { field, reason } => write!(f, "removed {field} ({reason})"),
+        }
+    }
+}
+
+/// Result of a migration: the rewritten YAML and what changed.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub yaml: String,
+    pub changes: Vec<MigrationChange>,
+}
+
+/// Rewrites a v1 (or mixed) config document into v2 nested form.
+///
+/// A config that is already v2 comes back unchanged with no changes listed.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a valid config or the output cannot
+/// be serialized.
+pub fn migrate_v1_to_v2(content: &str) -> Result<Migration, ConfigError> {
+    // Reject anything the loader would reject before touching it.
+    let config = RalphConfig::parse_yaml(content)?;
+
+    let mut doc: Value = serde_yaml::from_str(content)?;
+    let mut changes = Vec::new();
+
+    if let Some(root) = doc.as_mapping_mut() {
+        // Same mapping as `RalphConfig::normalize`, where v1 wins over v2.
+        for (from, to) in config.deprecated_v1_fields() {
+            let Some(value) = root.shift_remove(from) else {
+                continue;
+            };
+            let (section, key) = to.split_once('.').expect("v2 field is section.key");
+            let to = to.to_string();
+            let target = section_mut(root, section);
+            let change = if target.contains_key(key) {
+                MigrationChange::Replaced {
+                    from: from.to_string(),
+                    to,
+                }
+            } else {
+                MigrationChange::Moved {
+                    from: from.to_string(),
+                    to,
+                }
+            };
+            target.insert(Value::from(key), value);
+            changes.push(change);
+        }
+
+        for &(field, reason) in DROPPED_FIELDS {
+            if root.shift_remove(field).is_some() {
+                changes.push(MigrationChange::Dropped {
+                    field: field.to_string(),
+                    reason: reason.to_string(),
+                });
+            }
+        }
+    }
+
+    let yaml = if changes.is_empty() {
+        content.to_string()
+    } else {
+        serde_yaml::to_string(&doc)?
+    };
+
+    Ok(Migration { yaml, changes })
+}
+
+/// Returns the named top-level section, creating it if missing or not a mapping.
+fn section_mut<'a>(root: &'a mut Mapping, section: &str) -> &'a mut Mapping {
+    let entry = root
+        .entry(Value::from(section))
+        .or_insert_with(|| Value::Mapping(Mapping::new()));
+    if !entry.is_mapping() {
+        *entry = Value::Mapping(Mapping::new());
+    }
+    entry.as_mapping_mut().expect("section is a mapping")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrates_v1_fixture_to_v2() {
+        let v1 = r"
+agent: gemini
+prompt_file: TASK.md
+completion_promise: DONE
+max_iterations: 25
+max_runtime: 600
+max_cost: 5.0
+max_tokens: 4096
+event_loop:
+  max_iterations: 100
+core:
+  specs_dir: ./specs/
+";
+
+        let migration = migrate_v1_to_v2(v1).unwrap();
+
+        let expected = r"event_loop:
+  max_iterations: 25
+  prompt_file: TASK.md
+  completion_promise: DONE
+  max_runtime_seconds: 600
+  max_cost_usd: 5.0
+core:
+  specs_dir: ./specs/
+cli:
+  backend: gemini
+";
+        assert_eq!(migration.yaml, expected);
+        assert_eq!(
+            migration
+                .changes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "moved agent → cli.backend",
+                "moved prompt_file → event_loop.prompt_file",
+                "moved completion_promise → event_loop.completion_promise",
+                "moved max_iterations → event_loop.max_iterations (replaced existing event_loop.max_iterations)",
+                "moved max_runtime → event_loop.max_runtime_seconds",
+                "moved max_cost → event_loop.max_cost_usd",
+                "removed max_tokens (token limits are controlled by the CLI tool)",
+            ]
+        );
+
+        // The migrated file loads to the same effective settings.
+        let mut original = RalphConfig::parse_yaml(v1).unwrap();
+        original.normalize();
+        let migrated = RalphConfig::parse_yaml(&migration.yaml).unwrap();
+        assert_eq!(migrated.cli.backend, original.cli.backend);
+        assert_eq!(
+            migrated.event_loop.max_iterations,
+            original.event_loop.max_iterations
+        );
+        assert_eq!(
+            migrated.event_loop.prompt_file,
+            original.event_loop.prompt_file
+        );
+        assert_eq!(migrated.agent, None);
+    }
+
+    #[test]
+    fn test_v2_config_is_left_unchanged() {
+        let v2 = "# my config\ncli:\n  backend: claude\n";
+
+        let migration = migrate_v1_to_v2(v2).unwrap();
+
+        assert!(migration.changes.is_empty());
+        assert_eq!(migration.yaml, v2);
+    }
+}
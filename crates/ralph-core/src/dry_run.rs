@@ -0,0 +1,480 @@
+//! Static analysis behind `ralph run --dry-run --strict`.
+//!
+//! Combines config validation, hat reachability from the entry topic, closed
+//! cycle detection, and prompt token estimates into a single report so CI can
+//! reject a configuration before spending a real iteration on it. Also
+//! assembles the prompt previews printed by `ralph run --show-prompt`.
+
+use crate::config::ConfigWarning;
+use crate::{EventLoop, HatRegistry, RalphConfig};
+use ralph_proto::Hat;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+/// Default per-prompt token budget for strict dry runs.
+pub const DEFAULT_PROMPT_TOKEN_BUDGET: usize = 32_000;
+
+/// A problem found by the dry-run analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DryRunIssue {
+    /// `config.validate()` rejected the config or produced a warning.
+    ConfigWarning(String),
+    /// The prompt text or prompt file could not be loaded.
+    PromptUnavailable(String),
+    /// `starting_event` is set but no hat subscribes to it.
+    NoEntrySubscriber { topic: String },
+    /// No chain of hat publications from the entry topic reaches this hat.
+    UnreachableHat { hat: String, triggers: Vec<String> },
+    /// These hats only publish to each other, so control never returns to Ralph.
+    ClosedCycle { hats: Vec<String> },
+    /// A rendered prompt's estimated size exceeds the token budget.
+    PromptOverBudget {
+        prompt: String,
+        tokens: usize,
+        budget: usize,
+    },
+}
+
+impl fmt::Display for DryRunIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConfigWarning(message) => write!(f, "config warning: {message}"),
+            Self::PromptUnavailable(message) => write!(f, "prompt unavailable: {message}"),
+            Self::NoEntrySubscriber { topic } => {
+                write!(f, "starting_event '{topic}' has no hat subscribers")
+            }
+            Self::UnreachableHat { hat, triggers } => write!(
+                f,
+                "hat '{hat}' is unreachable from the entry topic (triggers: {})",
+                triggers.join(", ")
+            ),
+            Self::ClosedCycle { hats } => write!(
+                f,
+                "hats [{}] form a cycle with no exit back to Ralph",
+                hats.join(", ")
+            ),
+            Self::PromptOverBudget {
+                prompt,
+                tokens,
+                budget,
+            } => write!(
+                f,
+                "{prompt} prompt is ~{tokens} tokens, over the {budget} token budget"
+            ),
+        }
+    }
+}
+
+/// Estimated size of one rendered prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptEstimate {
+    /// `ralph` for the coordination prompt, otherwise the hat id.
+    pub prompt: String,
+    pub tokens: usize,
+}
+
+/// Result of a dry-run analysis.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunReport {
+    /// Topic the loop starts from (`starting_event` or `task.start`).
+    pub entry_topic: String,
+    /// Hat ids reachable from the entry topic, in registry order.
+    pub reachable_hats: Vec<String>,
+    pub prompts: Vec<PromptEstimate>,
+    pub token_budget: usize,
+    pub issues: Vec<DryRunIssue>,
+}
+
+impl DryRunReport {
+    /// Returns true if the analysis found nothing to report.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Rough token estimate (4 chars per token), matching memory budget truncation.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.len().div_ceil(4)
+}
+
+/// Analyzes an already-validated config without running any backend.
+pub fn analyze(config: &RalphConfig, token_budget: usize) -> DryRunReport {
+    let mut report = DryRunReport {
+        token_budget,
+        ..DryRunReport::default()
+    };
+
+    match config.validate() {
+        // Topology warnings are superseded by the reachability analysis below,
+        // which treats topics without subscribers as exits back to Ralph.
+        Ok(warnings) => report.issues.extend(
+            warnings
+                .iter()
+                .filter(|w| {
+                    !matches!(
+                        w,
+                        ConfigWarning::UnreachableHat { .. } | ConfigWarning::DeadEndTopic { .. }
+                    )
+                })
+                .map(|w| DryRunIssue::ConfigWarning(w.to_string())),
+        ),
+        // Callers normally validate first; record the error rather than panic.
+        Err(e) => report
+            .issues
+            .push(DryRunIssue::ConfigWarning(e.to_string())),
+    }
+
+    let registry = HatRegistry::from_config(config);
+    check_topology(config, &registry, &mut report);
+
+    match load_prompt(config) {
+        Ok(prompt) => estimate_prompts(config, &registry, &prompt, &mut report),
+        Err(message) => report.issues.push(DryRunIssue::PromptUnavailable(message)),
+    }
+
+    report
+}
+
+fn check_topology(config: &RalphConfig, registry: &HatRegistry, report: &mut DryRunReport) {
+    let starting_event = config.event_loop.starting_event.as_deref();
+    report.entry_topic = starting_event.unwrap_or("task.start").to_string();
+
+    if registry.is_empty() {
+        return;
+    }
+
+    let hats: Vec<&Hat> = registry.all().collect();
+    let subscribers_of = |topic: &str| -> Vec<usize> {
+        hats.iter()
+            .enumerate()
+            .filter(|(_, h)| h.is_subscribed_str(topic))
+            .map(|(i, _)| i)
+            .collect()
+    };
+
+    // Without a starting_event Ralph picks the first hat itself, so every hat
+    // is a potential entry point.
+    let mut frontier = subscribers_of(&report.entry_topic);
+    if frontier.is_empty() {
+        match starting_event {
+            Some(topic) => report.issues.push(DryRunIssue::NoEntrySubscriber {
+                topic: topic.to_string(),
+            }),
+            None => frontier = (0..hats.len()).collect(),
+        }
+    }
+
+    let mut reachable = BTreeSet::new();
+    while let Some(i) = frontier.pop() {
+        if !reachable.insert(i) {
+            continue;
+        }
+        for topic in &hats[i].publishes {
+            frontier.extend(subscribers_of(topic.as_str()));
+        }
+    }
+
+    for (i, hat) in hats.iter().enumerate() {
+        if reachable.contains(&i) {
+            report.reachable_hats.push(hat.id.to_string());
+        } else {
+            report.issues.push(DryRunIssue::UnreachableHat {
+                hat: hat.id.to_string(),
+                triggers: hat.subscriptions.iter().map(ToString::to_string).collect(),
+            });
+        }
+    }
+
+    // A hat can hand control back to Ralph if it publishes nothing, publishes
+    // the completion promise or an orphan topic, is instructed to emit the
+    // completion promise, or publishes to a hat that can.
+    let completion = config.event_loop.completion_promise.as_str();
+    let mut can_exit: Vec<bool> = hats
+        .iter()
+        .map(|h| {
+            h.publishes.is_empty()
+                || h.instructions.contains(completion)
+                || h.publishes
+                    .iter()
+                    .any(|t| t.as_str() == completion || subscribers_of(t.as_str()).is_empty())
+        })
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..hats.len() {
+            if !can_exit[i]
+                && hats[i]
+                    .publishes
+                    .iter()
+                    .any(|t| subscribers_of(t.as_str()).iter().any(|&j| can_exit[j]))
+            {
+                can_exit[i] = true;
+                changed = true;
+            }
+        }
+    }
+
+    let trapped: Vec<String> = reachable
+        .iter()
+        .filter(|&&i| !can_exit[i])
+        .map(|&i| hats[i].id.to_string())
+        .collect();
+    if !trapped.is_empty() {
+        report
+            .issues
+            .push(DryRunIssue::ClosedCycle { hats: trapped });
+    }
+}
+
+/// A fully assembled prompt and the hat it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptPreview {
+    /// Hat that executes with this prompt, e.g. `ralph (wearing builder)`.
+    pub hat: String,
+    pub text: String,
+}
+
+/// Assembles the prompt sent on the first iteration and the bare Hatless Ralph
+/// coordinator prompt, without invoking any backend.
+///
+/// The first prompt comes from [`EventLoop::build_prompt`] after publishing
+/// the starting event, so it includes the same scratchpad, memory, and task
+/// context a real run would inject.
+pub fn preview_prompts(config: &RalphConfig) -> Result<Vec<PromptPreview>, String> {
+    let prompt = load_prompt(config)?;
+    let mut event_loop = EventLoop::new(config.clone());
+    let coordinator = PromptPreview {
+        hat: "ralph (coordinator)".to_string(),
+        text: event_loop.build_ralph_prompt(&prompt),
+    };
+
+    let mut previews = Vec::new();
+    event_loop.initialize(&prompt);
+    if let Some(hat_id) = event_loop.next_hat().cloned()
+        && let Some(text) = event_loop.build_prompt(&hat_id)
+    {
+        let worn = &event_loop.state().last_active_hat_ids;
+        let hat = if worn.is_empty() {
+            hat_id.to_string()
+        } else {
+            let worn: Vec<&str> = worn.iter().map(|id| id.as_str()).collect();
+            format!("{hat_id} (wearing {})", worn.join(", "))
+        };
+        previews.push(PromptPreview { hat, text });
+    }
+    previews.push(coordinator);
+
+    Ok(previews)
+}
+
+fn load_prompt(config: &RalphConfig) -> Result<String, String> {
+    if let Some(inline) = &config.event_loop.prompt {
+        return Ok(inline.clone());
+    }
+
+    let path = Path::new(&config.event_loop.prompt_file);
+    let resolved = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        config.core.workspace_root.join(path)
+    };
+    std::fs::read_to_string(&resolved).map_err(|e| format!("{}: {e}", resolved.display()))
+}
+
+fn estimate_prompts(
+    config: &RalphConfig,
+    registry: &HatRegistry,
+    prompt: &str,
+    report: &mut DryRunReport,
+) {
+    let event_loop = EventLoop::new(config.clone());
+
+    let mut rendered = vec![("ralph".to_string(), event_loop.build_ralph_prompt(prompt))];
+    for hat in registry.all() {
+        if let Some(text) = event_loop.preview_hat_prompt(prompt, &hat.id) {
+            rendered.push((hat.id.to_string(), text));
+        }
+    }
+
+    for (name, text) in rendered {
+        let tokens = estimate_tokens(&text);
+        if report.token_budget > 0 && tokens > report.token_budget {
+            report.issues.push(DryRunIssue::PromptOverBudget {
+                prompt: name.clone(),
+                tokens,
+                budget: report.token_budget,
+            });
+        }
+        report.prompts.push(PromptEstimate {
+            prompt: name,
+            tokens,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_from(yaml: &str) -> RalphConfig {
+        let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        config.event_loop.prompt = Some("Build the thing".to_string());
+        config
+    }
+
+    #[test]
+    fn test_linear_topology_is_clean() {
+        let config = config_from(
+            r#"
+event_loop:
+  starting_event: "build.start"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builder hat"
+    triggers: ["build.start"]
+    publishes: ["review.request"]
+  reviewer:
+    name: "Reviewer"
+    description: "Reviewer hat"
+    triggers: ["review.request"]
+    publishes: ["review.done"]
+"#,
+        );
+
+        let report = analyze(&config, DEFAULT_PROMPT_TOKEN_BUDGET);
+
+        assert!(report.is_clean(), "unexpected issues: {:?}", report.issues);
+        assert_eq!(report.entry_topic, "build.start");
+        assert_eq!(report.reachable_hats, vec!["builder", "reviewer"]);
+        assert_eq!(report.prompts.len(), 3);
+        assert!(report.prompts.iter().all(|p| p.tokens > 0));
+    }
+
+    #[test]
+    fn test_preview_prompts_labels_first_hat_and_coordinator() {
+        let mut config = config_from(
+            r#"
+event_loop:
+  starting_event: "build.start"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builder hat"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+    instructions: "BUILDER-INSTRUCTIONS"
+"#,
+        );
+        let temp = tempfile::tempdir().unwrap();
+        config.core.workspace_root = temp.path().to_path_buf();
+
+        let previews = preview_prompts(&config).unwrap();
+
+        let hats: Vec<&str> = previews.iter().map(|p| p.hat.as_str()).collect();
+        assert_eq!(hats, vec!["ralph (wearing builder)", "ralph (coordinator)"]);
+        assert!(previews[0].text.contains("Build the thing"));
+        assert!(previews[0].text.contains("BUILDER-INSTRUCTIONS"));
+        assert!(!previews[1].text.contains("BUILDER-INSTRUCTIONS"));
+    }
+
+    #[test]
+    fn test_preview_prompts_solo_mode() {
+        let mut config = config_from("{}");
+        let temp = tempfile::tempdir().unwrap();
+        config.core.workspace_root = temp.path().to_path_buf();
+
+        let previews = preview_prompts(&config).unwrap();
+
+        let hats: Vec<&str> = previews.iter().map(|p| p.hat.as_str()).collect();
+        assert_eq!(hats, vec!["ralph", "ralph (coordinator)"]);
+        assert!(previews[0].text.contains("Build the thing"));
+    }
+
+    #[test]
+    fn test_unreachable_hat_is_reported() {
+        let config = config_from(
+            r#"
+event_loop:
+  starting_event: "build.start"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builder hat"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+  auditor:
+    name: "Auditor"
+    description: "Auditor hat"
+    triggers: ["audit.request"]
+    publishes: ["audit.done"]
+"#,
+        );
+
+        let report = analyze(&config, DEFAULT_PROMPT_TOKEN_BUDGET);
+
+        assert_eq!(report.reachable_hats, vec!["builder"]);
+        assert_eq!(
+            report.issues,
+            vec![DryRunIssue::UnreachableHat {
+                hat: "auditor".to_string(),
+                triggers: vec!["audit.request".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_closed_cycle_is_reported() {
+        let config = config_from(
+            r#"
+event_loop:
+  starting_event: "ping"
+hats:
+  pinger:
+    name: "Pinger"
+    description: "Pinger hat"
+    triggers: ["ping"]
+    publishes: ["pong"]
+  ponger:
+    name: "Ponger"
+    description: "Ponger hat"
+    triggers: ["pong"]
+    publishes: ["ping"]
+"#,
+        );
+
+        let report = analyze(&config, DEFAULT_PROMPT_TOKEN_BUDGET);
+
+        assert!(report.issues.contains(&DryRunIssue::ClosedCycle {
+            hats: vec!["pinger".to_string(), "ponger".to_string()],
+        }));
+    }
+
+    #[test]
+    fn test_prompt_over_budget_is_reported() {
+        let config = config_from("{}");
+
+        let report = analyze(&config, 10);
+
+        assert!(matches!(
+            report.issues.as_slice(),
+            [DryRunIssue::PromptOverBudget { prompt, budget: 10, .. }] if prompt == "ralph"
+        ));
+    }
+
+    #[test]
+    fn test_missing_prompt_file_is_reported() {
+        let mut config = config_from("{}");
+        config.event_loop.prompt = None;
+        config.event_loop.prompt_file = "/nonexistent/PROMPT.md".to_string();
+
+        let report = analyze(&config, DEFAULT_PROMPT_TOKEN_BUDGET);
+
+        assert!(matches!(
+            report.issues.as_slice(),
+            [DryRunIssue::PromptUnavailable(_)]
+        ));
+    }
+}
@@ -7,8 +7,9 @@ use crate::loop_context::LoopContext;
 use ralph_proto::{Event, HatId};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use tokio::sync::{mpsc, oneshot};
 use tracing::{debug, warn};
 
 /// Custom deserializer that accepts both String and structured JSON payloads.
@@ -42,6 +43,80 @@ where
         .unwrap_or_default())
 }
 
+/// Severity level of a logged event.
+///
+/// Derived from topic conventions so operators can surface problems in a
+/// large history without knowing every hat's vocabulary.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSeverity {
+    /// Normal progress events.
+    #[default]
+    Info,
+    /// Something degraded but the loop can continue (e.g., `*.exhausted`).
+    Warn,
+    /// Something failed or is stuck (e.g., `*.blocked`, `*.failed`).
+    Error,
+}
+
+impl EventSeverity {
+    /// Topic suffixes that indicate an error.
+    const ERROR_SUFFIXES: &'static [&'static str] =
+        &["blocked", "failed", "error", "abandoned", "malformed"];
+
+    /// Topic suffixes that indicate a warning.
+    const WARN_SUFFIXES: &'static [&'static str] =
+        &["exhausted", "orphaned", "timeout", "rejected", "warning"];
+
+    /// Derives the severity from a topic's last segment.
+    ///
+    /// - `*.blocked`, `*.failed`, `*.error`, `*.abandoned`, `*.malformed` → `Error`
+    /// - `*.exhausted`, `*.orphaned`, `*.timeout`, `*.rejected`, `*.warning` → `Warn`
+    /// - Everything else → `Info`
+    pub fn from_topic(topic: &str) -> Self {
+        let last = topic.rsplit('.').next().unwrap_or(topic);
+        if Self::ERROR_SUFFIXES.contains(&last) {
+            Self::Error
+        } else if Self::WARN_SUFFIXES.contains(&last) {
+            Self::Warn
+        } else {
+            Self::Info
+        }
+    }
+
+    /// Returns the severity as a lowercase string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl std::fmt::Display for EventSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for EventSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            other => Err(format!(
+                "invalid severity '{other}' (expected info, warn, or error)"
+            )),
+        }
+    }
+}
+
 /// A logged event record for debugging.
 ///
 /// Supports two schemas:
@@ -79,33 +154,66 @@ pub struct EventRecord {
     /// How many times this task has blocked (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blocked_count: Option<u32>,
+
+    /// Severity derived from the topic when logged (absent for agent-written events).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<EventSeverity>,
+
+    /// Cost in USD attributed to this record (set on `loop.iteration` records).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+
+    /// Wall-clock duration in milliseconds (set on `loop.iteration` records).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 impl EventRecord {
-    /// Maximum payload length before truncation.
-    const MAX_PAYLOAD_LEN: usize = 500;
+    /// Default maximum payload length before truncation.
+    pub const DEFAULT_MAX_PAYLOAD_LEN: usize = 500;
 
-    /// Creates a new event record.
+    /// Creates a new event record, truncating the payload at
+    /// [`DEFAULT_MAX_PAYLOAD_LEN`](Self::DEFAULT_MAX_PAYLOAD_LEN).
     pub fn new(
         iteration: u32,
         hat: impl Into<String>,
         event: &Event,
         triggered: Option<&HatId>,
     ) -> Self {
-        let payload = if event.payload.len() > Self::MAX_PAYLOAD_LEN {
-            // Find a valid UTF-8 char boundary at or before MAX_PAYLOAD_LEN.
+        Self::new_with_limit(
+            iteration,
+            hat,
+            event,
+            triggered,
+            Self::DEFAULT_MAX_PAYLOAD_LEN,
+        )
+    }
+
+    /// Creates a new event record, truncating the payload at `max_payload_len`
+    /// bytes (0 = no truncation).
+    pub fn new_with_limit(
+        iteration: u32,
+        hat: impl Into<String>,
+        event: &Event,
+        triggered: Option<&HatId>,
+        max_payload_len: usize,
+    ) -> Self {
+        // Redact before truncating so a secret can't survive as a partial match
+        let redacted = crate::redaction::redact(&event.payload);
+        let payload = if max_payload_len > 0 && redacted.len() > max_payload_len {
+            // Find a valid UTF-8 char boundary at or before max_payload_len.
             // We walk backwards from the limit until we find a char boundary.
-            let mut truncate_at = Self::MAX_PAYLOAD_LEN;
-            while truncate_at > 0 && !event.payload.is_char_boundary(truncate_at) {
+            let mut truncate_at = max_payload_len;
+            while truncate_at > 0 && !redacted.is_char_boundary(truncate_at) {
                 truncate_at -= 1;
             }
             format!(
                 "{}... [truncated, {} chars total]",
-                &event.payload[..truncate_at],
-                event.payload.chars().count()
+                &redacted[..truncate_at],
+                redacted.chars().count()
             )
         } else {
-            event.payload.clone()
+            redacted
         };
 
         Self {
@@ -116,39 +224,101 @@ impl EventRecord {
             triggered: triggered.map(|h| h.to_string()),
             payload,
             blocked_count: None,
+            severity: Some(EventSeverity::from_topic(event.topic.as_str())),
+            cost: None,
+            duration_ms: None,
         }
     }
 
+    /// Returns the record's severity, deriving it from the topic when not logged.
+    pub fn severity(&self) -> EventSeverity {
+        self.severity
+            .unwrap_or_else(|| EventSeverity::from_topic(&self.topic))
+    }
+
     /// Sets the blocked count for this record.
     pub fn with_blocked_count(mut self, count: u32) -> Self {
         self.blocked_count = Some(count);
         self
     }
+
+    /// Sets the cost and duration of the iteration this record summarizes.
+    pub fn with_usage(mut self, cost: f64, duration_ms: u64) -> Self {
+        self.cost = Some(cost);
+        self.duration_ms = Some(duration_ms);
+        self
+    }
+}
+
+/// Path of the `index`-th rotated copy of an events file (`events.jsonl.1`
+/// is the most recent).
+pub(crate) fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
 }
 
 /// Logger that writes events to a JSONL file.
+///
+/// Once the file reaches `max_bytes` it is rotated: `events.jsonl` becomes
+/// `events.jsonl.1`, older copies shift up, and anything beyond `keep` copies
+/// is deleted. [`EventHistory`] reads rotated copies transparently.
 pub struct EventLogger {
     /// Path to the events file.
     path: PathBuf,
 
     /// File handle for appending.
     file: Option<File>,
+
+    /// Size at which the file is rotated (0 = never).
+    max_bytes: u64,
+
+    /// Number of rotated copies to keep.
+    keep: usize,
+
+    /// Payload length at which records built by [`record`](Self::record)
+    /// are truncated (0 = never).
+    max_payload_len: usize,
 }
 
 impl EventLogger {
     /// Default path for the events file.
     pub const DEFAULT_PATH: &'static str = ".ralph/events.jsonl";
 
-    /// Creates a new event logger.
+    /// Default rotation size: 10 MB.
+    pub const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+    /// Default number of rotated copies kept.
+    pub const DEFAULT_KEEP: usize = 3;
+
+    /// Creates a new event logger with default rotation.
     ///
     /// The `.ralph/` directory is created if it doesn't exist.
     pub fn new(path: impl Into<PathBuf>) -> Self {
         Self {
             path: path.into(),
             file: None,
+            max_bytes: Self::DEFAULT_MAX_BYTES,
+            keep: Self::DEFAULT_KEEP,
+            max_payload_len: EventRecord::DEFAULT_MAX_PAYLOAD_LEN,
         }
     }
 
+    /// Sets the rotation size (0 disables rotation) and how many rotated
+    /// copies to keep.
+    pub fn with_rotation(mut self, max_bytes: u64, keep: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self.keep = keep;
+        self
+    }
+
+    /// Sets the payload length at which records are truncated (0 disables
+    /// truncation).
+    pub fn with_max_payload_len(mut self, max_payload_len: usize) -> Self {
+        self.max_payload_len = max_payload_len;
+        self
+    }
+
     /// Creates a logger with the default path.
     pub fn default_path() -> Self {
         Self::new(Self::DEFAULT_PATH)
@@ -187,12 +357,47 @@ impl EventLogger {
         Ok(self.file.as_mut().unwrap())
     }
 
+    /// Rotates the file if it has reached `max_bytes`.
+    ///
+    /// Checks the size on disk rather than tracking writes, since `ralph emit`
+    /// appends to the same file from other processes.
+    fn rotate_if_needed(&mut self) -> std::io::Result<()> {
+        if self.max_bytes == 0 {
+            return Ok(());
+        }
+        match fs::metadata(&self.path) {
+            Ok(meta) if meta.len() >= self.max_bytes => {}
+            _ => return Ok(()),
+        }
+
+        // The open handle follows the renamed file; reopen after rotating.
+        self.file = None;
+        if self.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let oldest = rotated_path(&self.path, self.keep);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for index in (1..self.keep).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        debug!(path = %self.path.display(), "Rotated events file");
+        Ok(())
+    }
+
     /// Logs an event record.
     ///
     /// Uses a single `write_all` call to ensure the JSON line is written atomically.
     /// This prevents corruption when multiple processes append to the same file
     /// concurrently (e.g., during parallel merge queue processing).
     pub fn log(&mut self, record: &EventRecord) -> std::io::Result<()> {
+        self.rotate_if_needed()?;
         let file = self.ensure_open()?;
         let mut json = serde_json::to_string(record)?;
         json.push('\n');
@@ -211,14 +416,142 @@ impl EventLogger {
         event: &Event,
         triggered: Option<&HatId>,
     ) -> std::io::Result<()> {
-        let record = EventRecord::new(iteration, hat, event, triggered);
+        let record = self.record(iteration, hat, event, triggered);
         self.log(&record)
     }
 
+    /// Builds a record using this logger's payload limit.
+    pub fn record(
+        &self,
+        iteration: u32,
+        hat: impl Into<String>,
+        event: &Event,
+        triggered: Option<&HatId>,
+    ) -> EventRecord {
+        EventRecord::new_with_limit(iteration, hat, event, triggered, self.max_payload_len)
+    }
+
     /// Returns the path to the log file.
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Moves this logger onto a dedicated writer so [`AsyncEventLogger::log`]
+    /// hands records off without waiting on disk I/O.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn with_async(self) -> AsyncEventLogger {
+        AsyncEventLogger::spawn(self)
+    }
+}
+
+/// Message sent to the writer behind an [`AsyncEventLogger`].
+enum WriterMessage {
+    Record(EventRecord),
+    Flush(oneshot::Sender<std::io::Result<()>>),
+}
+
+/// Non-blocking handle to an [`EventLogger`] running on a dedicated writer.
+///
+/// Records are written in the order they were logged. Write failures are
+/// logged as warnings and the first one is reported by the next
+/// [`flush`](Self::flush) or [`shutdown`](Self::shutdown). Because the write
+/// happens later, call `flush` before reading the file back.
+pub struct AsyncEventLogger {
+    tx: mpsc::UnboundedSender<WriterMessage>,
+    writer: tokio::task::JoinHandle<std::io::Result<()>>,
+    path: PathBuf,
+    max_payload_len: usize,
+}
+
+impl AsyncEventLogger {
+    fn spawn(mut logger: EventLogger) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let path = logger.path.clone();
+        let max_payload_len = logger.max_payload_len;
+
+        // The logger does blocking file I/O, so it runs on the blocking pool.
+        let writer = tokio::task::spawn_blocking(move || {
+            let mut pending_error = None;
+            while let Some(message) = rx.blocking_recv() {
+                match message {
+                    WriterMessage::Record(record) => {
+                        if let Err(e) = logger.log(&record) {
+                            warn!(path = %logger.path.display(), error = %e, "Failed to write event");
+                            pending_error.get_or_insert(e);
+                        }
+                    }
+                    WriterMessage::Flush(done) => {
+                        let _ = done.send(pending_error.take().map_or(Ok(()), Err));
+                    }
+                }
+            }
+            pending_error.map_or(Ok(()), Err)
+        });
+
+        Self {
+            tx,
+            writer,
+            path,
+            max_payload_len,
+        }
+    }
+
+    /// Queues a record for writing without blocking.
+    pub fn log(&self, record: EventRecord) -> std::io::Result<()> {
+        self.tx
+            .send(WriterMessage::Record(record))
+            .map_err(|_| writer_gone())
+    }
+
+    /// Convenience method to queue an event directly.
+    pub fn log_event(
+        &self,
+        iteration: u32,
+        hat: &str,
+        event: &Event,
+        triggered: Option<&HatId>,
+    ) -> std::io::Result<()> {
+        self.log(self.record(iteration, hat, event, triggered))
+    }
+
+    /// Builds a record using this logger's payload limit.
+    pub fn record(
+        &self,
+        iteration: u32,
+        hat: impl Into<String>,
+        event: &Event,
+        triggered: Option<&HatId>,
+    ) -> EventRecord {
+        EventRecord::new_with_limit(iteration, hat, event, triggered, self.max_payload_len)
+    }
+
+    /// Waits until every record queued so far has been written.
+    pub async fn flush(&self) -> std::io::Result<()> {
+        let (done_tx, done_rx) = oneshot::channel();
+        self.tx
+            .send(WriterMessage::Flush(done_tx))
+            .map_err(|_| writer_gone())?;
+        done_rx.await.map_err(|_| writer_gone())?
+    }
+
+    /// Writes all queued records and stops the writer.
+    pub async fn shutdown(self) -> std::io::Result<()> {
+        drop(self.tx);
+        self.writer.await.map_err(std::io::Error::other)?
+    }
+
+    /// Returns the path to the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+fn writer_gone() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::BrokenPipe,
+        "event log writer has stopped",
+    )
 }
 
 /// Reader for event history files.
@@ -250,29 +583,36 @@ impl EventHistory {
         self.path.exists()
     }
 
-    /// Reads all event records from the file.
-    pub fn read_all(&self) -> std::io::Result<Vec<EventRecord>> {
-        if !self.exists() {
-            return Ok(Vec::new());
+    /// Rotated copies of the file, oldest first.
+    fn rotated_files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        loop {
+            let path = rotated_path(&self.path, files.len() + 1);
+            if !path.exists() {
+                break;
+            }
+            files.push(path);
         }
+        files.reverse();
+        files
+    }
 
-        let file = File::open(&self.path)?;
-        let reader = BufReader::new(file);
+    /// Reads the records in rotated copies only, oldest first.
+    pub fn read_rotated(&self) -> std::io::Result<Vec<EventRecord>> {
         let mut records = Vec::new();
-
-        for (line_num, line) in reader.lines().enumerate() {
-            let line = line?;
-            if line.trim().is_empty() {
-                continue;
-            }
-            match serde_json::from_str(&line) {
-                Ok(record) => records.push(record),
-                Err(e) => {
-                    warn!(line = line_num + 1, error = %e, "Failed to parse event record");
-                }
-            }
+        for path in self.rotated_files() {
+            read_records(&path, &mut records)?;
         }
+        Ok(records)
+    }
 
+    /// Reads all event records, including rotated copies, in chronological
+    /// order.
+    pub fn read_all(&self) -> std::io::Result<Vec<EventRecord>> {
+        let mut records = self.read_rotated()?;
+        if self.exists() {
+            read_records(&self.path, &mut records)?;
+        }
         Ok(records)
     }
 
@@ -289,6 +629,35 @@ impl EventHistory {
         Ok(all.into_iter().filter(|r| r.topic == topic).collect())
     }
 
+    /// Reads events whose iteration is within `from..=to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` if `from > to`, or any error reading the file.
+    pub fn filter_by_iteration_range(
+        &self,
+        from: u32,
+        to: u32,
+    ) -> std::io::Result<Vec<EventRecord>> {
+        if from > to {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("invalid iteration range: from ({from}) is greater than to ({to})"),
+            ));
+        }
+        let all = self.read_all()?;
+        Ok(all
+            .into_iter()
+            .filter(|r| (from..=to).contains(&r.iteration))
+            .collect())
+    }
+
+    /// Reads events published while the given hat was active.
+    pub fn filter_by_hat(&self, hat: &str) -> std::io::Result<Vec<EventRecord>> {
+        let all = self.read_all()?;
+        Ok(all.into_iter().filter(|r| r.hat == hat).collect())
+    }
+
     /// Reads events filtered by iteration.
     pub fn filter_by_iteration(&self, iteration: u32) -> std::io::Result<Vec<EventRecord>> {
         let all = self.read_all()?;
@@ -298,8 +667,26 @@ impl EventHistory {
             .collect())
     }
 
-    /// Clears the event history file.
+    /// Reads complete records appended after byte `offset`, for tailing.
+    ///
+    /// Returns the records and the offset to resume from. A trailing line
+    /// without a newline is still being written and is left for the next call.
+    /// If the file is now shorter than `offset` it was cleared or recreated,
+    /// so reading restarts from the beginning. A missing file yields nothing
+    /// and offset 0.
+    pub fn read_since(&self, offset: u64) -> std::io::Result<(Vec<EventRecord>, u64)> {
+        match File::open(&self.path) {
+            Ok(mut file) => read_complete_records(&mut file, offset),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok((Vec::new(), 0)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Clears the event history file and its rotated copies.
     pub fn clear(&self) -> std::io::Result<()> {
+        for path in self.rotated_files() {
+            fs::remove_file(path)?;
+        }
         if self.exists() {
             fs::remove_file(&self.path)?;
         }
@@ -307,6 +694,216 @@ impl EventHistory {
     }
 }
 
+/// Follows an events file as it grows, for `ralph events --follow`.
+///
+/// Unlike [`EventHistory::read_since`], the file stays open between reads.
+/// When [`EventLogger`] rotates it, records appended after the last read are
+/// drained from the renamed copy before the tail moves on to the new file.
+pub struct EventTail {
+    path: PathBuf,
+    file: Option<File>,
+    offset: u64,
+}
+
+impl EventTail {
+    /// Creates a tail that starts at the beginning of `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            file: None,
+            offset: 0,
+        }
+    }
+
+    /// Reads complete records appended since the last call.
+    ///
+    /// A missing file yields nothing until it is created. A file cleared or
+    /// recreated in place is read again from the top.
+    pub fn read_new(&mut self) -> std::io::Result<Vec<EventRecord>> {
+        let mut records = Vec::new();
+        loop {
+            let file = match &mut self.file {
+                Some(file) => file,
+                None => match File::open(&self.path) {
+                    Ok(file) => {
+                        self.offset = 0;
+                        self.file.insert(file)
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(records),
+                    Err(e) => return Err(e),
+                },
+            };
+
+            let (new, offset) = read_complete_records(file, self.offset)?;
+            records.extend(new);
+            self.offset = offset;
+
+            if !self.replaced()? {
+                return Ok(records);
+            }
+
+            // Rotated or removed: take what was written before the switch,
+            // then continue with whatever now lives at the path.
+            if let Some(file) = &mut self.file {
+                records.extend(read_complete_records(file, self.offset)?.0);
+            }
+            self.file = None;
+        }
+    }
+
+    /// Whether the path no longer refers to the open file.
+    fn replaced(&self) -> std::io::Result<bool> {
+        let Some(file) = &self.file else {
+            return Ok(false);
+        };
+        let open = file.metadata()?;
+        match fs::metadata(&self.path) {
+            Ok(on_disk) => Ok(!is_same_file(&open, &on_disk)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Device and inode of a file, where the platform has them.
+#[cfg(unix)]
+pub(crate) fn file_id(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn file_id(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Without inode numbers, a file at the path that is shorter than the open
+/// one (which only grows) must be a different file.
+fn is_same_file(open: &fs::Metadata, on_disk: &fs::Metadata) -> bool {
+    match (file_id(open), file_id(on_disk)) {
+        (Some(a), Some(b)) => a == b,
+        _ => on_disk.len() >= open.len(),
+    }
+}
+
+/// Reads complete records after byte `offset` of `file`, returning them and
+/// the offset to resume from. A trailing line without a newline is left for
+/// the next call; a file shorter than `offset` is read from the top.
+fn read_complete_records(file: &mut File, offset: u64) -> std::io::Result<(Vec<EventRecord>, u64)> {
+    let start = if file.metadata()?.len() < offset {
+        0
+    } else {
+        offset
+    };
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    let Some(last_newline) = buf.iter().rposition(|&b| b == b'\n') else {
+        return Ok((Vec::new(), start));
+    };
+
+    let records = String::from_utf8_lossy(&buf[..last_newline])
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!(error = %e, "Failed to parse event record");
+                None
+            }
+        })
+        .collect();
+
+    Ok((records, start + last_newline as u64 + 1))
+}
+
+/// Appends the records in one JSONL file to `records`, skipping bad lines.
+fn read_records(path: &Path, records: &mut Vec<EventRecord>) -> std::io::Result<()> {
+    let reader = BufReader::new(File::open(path)?);
+    for (line_num, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                warn!(line = line_num + 1, error = %e, "Failed to parse event record");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A name with how many events carried it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NameCount {
+    pub name: String,
+    pub count: usize,
+}
+
+/// Aggregate counts over event records, shown by `ralph events --stats`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EventStats {
+    /// Number of records.
+    pub total: usize,
+    /// Events per topic, most frequent first.
+    pub by_topic: Vec<NameCount>,
+    /// Events per hat, most frequent first. Agent-written events without a
+    /// hat are counted under an empty name.
+    pub by_hat: Vec<NameCount>,
+    /// Lowest iteration seen, if any.
+    pub min_iteration: Option<u32>,
+    /// Highest iteration seen, if any.
+    pub max_iteration: Option<u32>,
+    /// The five most frequent topics.
+    pub top_topics: Vec<NameCount>,
+    /// Sum of `cost` over records that carry one (USD).
+    pub total_cost: f64,
+    /// Sum of `duration_ms` over records that carry one.
+    pub total_duration_ms: u64,
+}
+
+impl EventStats {
+    /// Number of topics listed in [`top_topics`](Self::top_topics).
+    pub const TOP_TOPICS: usize = 5;
+
+    /// Computes statistics over `records`.
+    pub fn from_records(records: &[EventRecord]) -> Self {
+        let by_topic = count_by(records.iter().map(|r| r.topic.as_str()));
+        let top_topics = by_topic.iter().take(Self::TOP_TOPICS).cloned().collect();
+
+        Self {
+            total: records.len(),
+            by_hat: count_by(records.iter().map(|r| r.hat.as_str())),
+            min_iteration: records.iter().map(|r| r.iteration).min(),
+            max_iteration: records.iter().map(|r| r.iteration).max(),
+            by_topic,
+            top_topics,
+            total_cost: records.iter().filter_map(|r| r.cost).sum(),
+            total_duration_ms: records.iter().filter_map(|r| r.duration_ms).sum(),
+        }
+    }
+}
+
+/// Counts occurrences, sorted by count descending then name ascending.
+fn count_by<'a>(names: impl Iterator<Item = &'a str>) -> Vec<NameCount> {
+    let mut counts = std::collections::HashMap::<&str, usize>::new();
+    for name in names {
+        *counts.entry(name).or_default() += 1;
+    }
+    let mut counts: Vec<NameCount> = counts
+        .into_iter()
+        .map(|(name, count)| NameCount {
+            name: name.to_string(),
+            count,
+        })
+        .collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    counts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,6 +943,47 @@ mod tests {
         assert_eq!(records[1].topic, "build.done");
     }
 
+    #[tokio::test]
+    async fn test_async_logger_writes_all_records_in_order() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let logger = EventLogger::new(&path).with_async();
+
+        for i in 0..200 {
+            let event = make_event(&format!("step.{i}"), "payload");
+            logger.log_event(i, "ralph", &event, None).unwrap();
+        }
+        logger.flush().await.unwrap();
+        assert_eq!(EventHistory::new(&path).read_all().unwrap().len(), 200);
+
+        logger
+            .log_event(200, "ralph", &make_event("step.200", "last"), None)
+            .unwrap();
+        logger.shutdown().await.unwrap();
+
+        let records = EventHistory::new(&path).read_all().unwrap();
+        let topics: Vec<String> = records.into_iter().map(|r| r.topic).collect();
+        let expected: Vec<String> = (0..=200).map(|i| format!("step.{i}")).collect();
+        assert_eq!(topics, expected);
+    }
+
+    #[tokio::test]
+    async fn test_async_logger_reports_write_errors_on_flush() {
+        let tmp = TempDir::new().unwrap();
+        // A directory where the file should be makes every write fail
+        let path = tmp.path().join("events.jsonl");
+        fs::create_dir_all(&path).unwrap();
+        let logger = EventLogger::new(&path).with_async();
+
+        logger
+            .log_event(1, "ralph", &make_event("task.start", "go"), None)
+            .unwrap();
+
+        assert!(logger.flush().await.is_err());
+        assert!(logger.flush().await.is_ok(), "error is reported once");
+        logger.shutdown().await.unwrap();
+    }
+
     #[test]
     fn test_read_last() {
         let tmp = TempDir::new().unwrap();
@@ -390,6 +1028,148 @@ mod tests {
         assert_eq!(blocked[0].iteration, 2);
     }
 
+    #[test]
+    fn test_filter_by_iteration_range() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path);
+        for iteration in 1..=5 {
+            logger
+                .log_event(iteration, "hat", &make_event("build.done", "x"), None)
+                .unwrap();
+        }
+
+        let history = EventHistory::new(&path);
+        let window: Vec<u32> = history
+            .filter_by_iteration_range(2, 4)
+            .unwrap()
+            .iter()
+            .map(|r| r.iteration)
+            .collect();
+        assert_eq!(window, vec![2, 3, 4]);
+
+        let single = history.filter_by_iteration_range(5, 5).unwrap();
+        assert_eq!(single.len(), 1);
+
+        // Range past the end of the history is empty, not an error
+        assert!(history.filter_by_iteration_range(6, 9).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_filter_by_iteration_range_rejects_reversed_bounds() {
+        let tmp = TempDir::new().unwrap();
+        let history = EventHistory::new(tmp.path().join("events.jsonl"));
+
+        let err = history.filter_by_iteration_range(4, 2).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("from (4)"), "{err}");
+    }
+
+    #[test]
+    fn test_filter_by_hat() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path);
+
+        logger
+            .log_event(1, "builder", &make_event("build.done", "a"), None)
+            .unwrap();
+        logger
+            .log_event(2, "reviewer", &make_event("review.done", "b"), None)
+            .unwrap();
+        logger
+            .log_event(3, "builder", &make_event("build.blocked", "c"), None)
+            .unwrap();
+
+        let history = EventHistory::new(&path);
+        let builder = history.filter_by_hat("builder").unwrap();
+
+        assert_eq!(builder.len(), 2);
+        assert_eq!(builder[0].iteration, 1);
+        assert_eq!(builder[1].iteration, 3);
+        assert!(history.filter_by_hat("planner").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_severity_from_topic() {
+        assert_eq!(
+            EventSeverity::from_topic("build.blocked"),
+            EventSeverity::Error
+        );
+        assert_eq!(
+            EventSeverity::from_topic("verify.failed"),
+            EventSeverity::Error
+        );
+        assert_eq!(
+            EventSeverity::from_topic("build.task.abandoned"),
+            EventSeverity::Error
+        );
+        assert_eq!(
+            EventSeverity::from_topic("builder.exhausted"),
+            EventSeverity::Warn
+        );
+        assert_eq!(EventSeverity::from_topic("build.done"), EventSeverity::Info);
+        assert_eq!(EventSeverity::from_topic("blocked"), EventSeverity::Error);
+        // Only the last segment counts
+        assert_eq!(
+            EventSeverity::from_topic("blocked.done"),
+            EventSeverity::Info
+        );
+    }
+
+    #[test]
+    fn test_severity_parse() {
+        assert_eq!("error".parse::<EventSeverity>(), Ok(EventSeverity::Error));
+        assert_eq!("WARN".parse::<EventSeverity>(), Ok(EventSeverity::Warn));
+        assert_eq!("warning".parse::<EventSeverity>(), Ok(EventSeverity::Warn));
+        assert!("fatal".parse::<EventSeverity>().is_err());
+    }
+
+    #[test]
+    fn test_record_severity_is_logged_or_derived() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path);
+        logger
+            .log_event(1, "hat", &make_event("build.done", "a"), None)
+            .unwrap();
+        logger
+            .log_event(2, "hat", &make_event("build.blocked", "b"), None)
+            .unwrap();
+        logger
+            .log_event(3, "hat", &make_event("builder.exhausted", "c"), None)
+            .unwrap();
+
+        // Agent-written event without a severity field is derived on read
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"verify.failed","payload":"tests red","ts":"2024-01-15T10:00:00Z"}}"#
+        )
+        .unwrap();
+
+        let history = EventHistory::new(&path);
+        let records = history.read_all().unwrap();
+        assert_eq!(records[1].severity, Some(EventSeverity::Error));
+        assert_eq!(records[3].severity, None);
+        assert_eq!(records[3].severity(), EventSeverity::Error);
+
+        let severities: Vec<_> = records.iter().map(EventRecord::severity).collect();
+        assert_eq!(
+            severities,
+            vec![
+                EventSeverity::Info,
+                EventSeverity::Error,
+                EventSeverity::Warn,
+                EventSeverity::Error
+            ]
+        );
+    }
+
     #[test]
     fn test_payload_truncation() {
         let long_payload = "x".repeat(1000);
@@ -400,6 +1180,43 @@ mod tests {
         assert!(record.payload.contains("[truncated"));
     }
 
+    #[test]
+    fn test_payload_custom_limit() {
+        let event = make_event("test", &"x".repeat(1000));
+        let record = EventRecord::new_with_limit(1, "hat", &event, None, 800);
+
+        assert_eq!(
+            record.payload,
+            format!("{}... [truncated, 1000 chars total]", "x".repeat(800))
+        );
+    }
+
+    #[test]
+    fn test_payload_limit_zero_disables_truncation() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let long_payload = "x".repeat(5000);
+        let mut logger = EventLogger::new(&path).with_max_payload_len(0);
+
+        logger
+            .log_event(1, "hat", &make_event("test", &long_payload), None)
+            .unwrap();
+
+        let records = EventHistory::new(&path).read_all().unwrap();
+        assert_eq!(records[0].payload, long_payload);
+    }
+
+    #[test]
+    fn test_payload_secrets_are_redacted() {
+        let event = make_event(
+            "build.done",
+            "tests: pass (used ANTHROPIC_API_KEY=sk-ant-REDACTED)",
+        );
+        let record = EventRecord::new(1, "hat", &event, None);
+
+        assert_eq!(record.payload, "tests: pass (used ANTHROPIC_API_KEY=***)");
+    }
+
     #[test]
     fn test_payload_truncation_with_multibyte_chars() {
         // Create a payload with multi-byte UTF-8 characters (✅ is 3 bytes)
@@ -417,6 +1234,47 @@ mod tests {
         for _ in record.payload.chars() {}
     }
 
+    #[test]
+    fn test_read_since_tails_appended_records() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let history = EventHistory::new(&path);
+        let mut logger = EventLogger::new(&path);
+
+        assert_eq!(history.read_since(0).unwrap().1, 0);
+
+        logger
+            .log_event(1, "hat", &make_event("first", "1"), None)
+            .unwrap();
+        let (records, offset) = history.read_since(0).unwrap();
+        assert_eq!(records.len(), 1);
+
+        logger
+            .log_event(2, "hat", &make_event("second", "2"), None)
+            .unwrap();
+        // A partially written line is not consumed yet.
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"topic\":")
+            .unwrap();
+        let (records, partial_offset) = history.read_since(offset).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "second");
+        assert_eq!(history.read_since(partial_offset).unwrap().0.len(), 0);
+
+        // Cleared and recreated: start over from the top.
+        history.clear().unwrap();
+        let mut logger = EventLogger::new(&path);
+        logger
+            .log_event(3, "hat", &make_event("third", "3"), None)
+            .unwrap();
+        let (records, _) = history.read_since(partial_offset).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "third");
+    }
+
     #[test]
     fn test_creates_parent_directory() {
         let tmp = TempDir::new().unwrap();
@@ -565,4 +1423,157 @@ mod tests {
         let parsed: serde_json::Value = serde_json::from_str(&records[2].payload).unwrap();
         assert_eq!(parsed["evidence"]["tests"], "pass");
     }
+
+    #[test]
+    fn test_event_stats_aggregates_records() {
+        let record = |iteration: u32, hat: &str, topic: &str| {
+            EventRecord::new(iteration, hat, &make_event(topic, ""), None::<&HatId>)
+        };
+        let topics = [
+            "build.done",
+            "build.done",
+            "build.done",
+            "review.done",
+            "review.done",
+            "a.one",
+            "b.two",
+            "c.three",
+            "d.four",
+        ];
+        let records: Vec<EventRecord> = topics
+            .iter()
+            .enumerate()
+            .map(|(i, topic)| {
+                let hat = if topic.starts_with("review") {
+                    "reviewer"
+                } else {
+                    "builder"
+                };
+                record(i as u32 + 2, hat, topic)
+            })
+            .collect();
+
+        let stats = EventStats::from_records(&records);
+
+        assert_eq!(stats.total, 9);
+        assert_eq!(stats.min_iteration, Some(2));
+        assert_eq!(stats.max_iteration, Some(10));
+        let names = |counts: &[NameCount]| -> Vec<(String, usize)> {
+            counts.iter().map(|c| (c.name.clone(), c.count)).collect()
+        };
+        assert_eq!(
+            names(&stats.by_hat),
+            vec![("builder".to_string(), 7), ("reviewer".to_string(), 2)]
+        );
+        assert_eq!(stats.by_topic.len(), 6);
+        assert_eq!(
+            names(&stats.top_topics),
+            vec![
+                ("build.done".to_string(), 3),
+                ("review.done".to_string(), 2),
+                ("a.one".to_string(), 1),
+                ("b.two".to_string(), 1),
+                ("c.three".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_stats_empty() {
+        let stats = EventStats::from_records(&[]);
+
+        assert_eq!(stats, EventStats::default());
+    }
+
+    #[test]
+    fn test_rotation_keeps_history_readable_in_order() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        // Every record is larger than the cap, so each log rotates the last
+        let mut logger = EventLogger::new(&path).with_rotation(10, 2);
+        for iteration in 1..=4 {
+            logger
+                .log_event(iteration, "hat", &make_event("build.done", "x"), None)
+                .unwrap();
+        }
+
+        assert!(path.exists());
+        assert!(tmp.path().join("events.jsonl.1").exists());
+        assert!(tmp.path().join("events.jsonl.2").exists());
+        assert!(!tmp.path().join("events.jsonl.3").exists());
+
+        // The oldest record fell off; the rest read oldest to newest
+        let history = EventHistory::new(&path);
+        let iterations: Vec<u32> = history
+            .read_all()
+            .unwrap()
+            .iter()
+            .map(|r| r.iteration)
+            .collect();
+        assert_eq!(iterations, vec![2, 3, 4]);
+
+        history.clear().unwrap();
+        assert!(!path.exists());
+        assert!(!tmp.path().join("events.jsonl.1").exists());
+        assert!(history.read_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_tail_drains_old_file_before_following_rotation() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        let mut tail = EventTail::new(&path);
+        assert!(tail.read_new().unwrap().is_empty());
+
+        let mut logger = EventLogger::new(&path).with_rotation(10, 2);
+        logger
+            .log_event(1, "hat", &make_event("first", "1"), None)
+            .unwrap();
+        let topics = |records: Vec<EventRecord>| -> Vec<String> {
+            records.into_iter().map(|r| r.topic).collect()
+        };
+        assert_eq!(topics(tail.read_new().unwrap()), vec!["first"]);
+
+        // Appended by another writer (e.g. `ralph emit`) after the last poll,
+        // then rotated away before the next one
+        EventLogger::new(&path)
+            .log_event(2, "hat", &make_event("late", "2"), None)
+            .unwrap();
+        logger
+            .log_event(3, "hat", &make_event("after_rotation", "3"), None)
+            .unwrap();
+        assert!(tmp.path().join("events.jsonl.1").exists());
+
+        assert_eq!(
+            topics(tail.read_new().unwrap()),
+            vec!["late", "after_rotation"]
+        );
+        assert!(tail.read_new().unwrap().is_empty());
+
+        // Cleared and recreated: start over from the top
+        EventHistory::new(&path).clear().unwrap();
+        assert!(tail.read_new().unwrap().is_empty());
+        let mut logger = EventLogger::new(&path);
+        logger
+            .log_event(4, "hat", &make_event("fresh", "4"), None)
+            .unwrap();
+        assert_eq!(topics(tail.read_new().unwrap()), vec!["fresh"]);
+    }
+
+    #[test]
+    fn test_rotation_disabled_with_zero_max_bytes() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut logger = EventLogger::new(&path).with_rotation(0, 2);
+        for iteration in 1..=3 {
+            logger
+                .log_event(iteration, "hat", &make_event("build.done", "x"), None)
+                .unwrap();
+        }
+
+        assert!(!tmp.path().join("events.jsonl.1").exists());
+        assert_eq!(EventHistory::new(&path).read_all().unwrap().len(), 3);
+    }
 }
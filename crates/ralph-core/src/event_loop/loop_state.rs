@@ -33,6 +33,8 @@ pub struct LoopState {
     pub abandoned_task_redispatches: u32,
     /// Consecutive malformed JSONL lines encountered (for validation backpressure).
     pub consecutive_malformed_events: u32,
+    /// Consecutive iterations whose output contained malformed `<event>` tags.
+    pub consecutive_malformed_output_iterations: u32,
     /// Whether a completion event has been observed in JSONL.
     pub completion_requested: bool,
 
@@ -61,6 +63,10 @@ pub struct LoopState {
 
     /// Set to true when a loop.cancel event is detected.
     pub cancellation_requested: bool,
+
+    /// Truncated output of the last failed verification command, injected
+    /// into the next prompt and then cleared.
+    pub verification_failure: Option<String>,
 }
 
 impl Default for LoopState {
@@ -77,6 +83,7 @@ impl Default for LoopState {
             abandoned_tasks: Vec::new(),
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
+            consecutive_malformed_output_iterations: 0,
             completion_requested: false,
             hat_activation_counts: HashMap::new(),
             exhausted_hats: HashSet::new(),
@@ -86,6 +93,7 @@ impl Default for LoopState {
             last_emitted_topic: None,
             consecutive_same_topic: 0,
             cancellation_requested: false,
+            verification_failure: None,
         }
     }
 }
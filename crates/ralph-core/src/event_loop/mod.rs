@@ -15,10 +15,17 @@ use crate::hat_registry::HatRegistry;
 use crate::hatless_ralph::HatlessRalph;
 use crate::instructions::InstructionBuilder;
 use crate::loop_context::LoopContext;
-use crate::memory_store::{MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget};
+use crate::memory_store::{
+    MarkdownMemoryStore, cap_memories, filter_memories, format_memories_within_budget,
+};
 use crate::skill_registry::SkillRegistry;
 use crate::text::floor_char_boundary;
-use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService};
+use crate::verifier::{VerificationResult, Verifier};
+use ralph_proto::{
+    CheckinContext, Event, EventBus, Hat, HatId, RobotService, TerminationContext, TopicRateLimit,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -26,7 +33,11 @@ use std::time::Duration;
 use tracing::{debug, info, warn};
 
 /// Reason the event loop terminated.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Serializes and displays as a stable snake_case name (`completion_promise`,
+/// `max_iterations`, ...) for logs, history, and benchmark results.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TerminationReason {
     /// Completion promise was detected in output.
     CompletionPromise,
@@ -40,10 +51,13 @@ pub enum TerminationReason {
     ConsecutiveFailures,
     /// Loop thrashing detected (repeated blocked events).
     LoopThrashing,
-    /// Stale loop detected (same topic emitted 3+ times consecutively).
+    /// Stale loop detected (same topic emitted `max_consecutive_same_topic`
+    /// times consecutively).
     LoopStale,
     /// Too many consecutive malformed JSONL lines in events file.
     ValidationFailure,
+    /// An iteration hook failed with `hooks_strict` enabled.
+    HookFailed,
     /// Manually stopped.
     Stopped,
     /// Interrupted by signal (SIGINT/SIGTERM).
@@ -52,42 +66,43 @@ pub enum TerminationReason {
     RestartRequested,
     /// Loop was cancelled gracefully via loop.cancel event (human rejection, timeout).
     Cancelled,
+    /// Stopped after one iteration, as requested by `ralph run --once`.
+    SingleIteration,
 }
 
 impl TerminationReason {
-    /// Returns the exit code for this termination reason per spec.
+    /// Returns the exit code for this termination reason.
     ///
-    /// Per spec "Loop Termination" section:
-    /// - 0: Completion promise detected (success)
-    /// - 1: Consecutive failures or unrecoverable error (failure)
+    /// - 0: Completion promise detected, cancelled, or `--once` (success)
+    /// - 1: Loop thrashing, stale loop, validation failure, or a strict hook failed
     /// - 2: Max iterations, max runtime, or max cost exceeded (limit)
-    /// - 130: User interrupt (SIGINT = 128 + 2)
+    /// - 3: Consecutive failures
+    /// - 4: Restart requested
+    /// - 130: Stopped or interrupted (SIGINT = 128 + 2)
     pub fn exit_code(&self) -> i32 {
         match self {
             TerminationReason::CompletionPromise => 0,
-            TerminationReason::ConsecutiveFailures
-            | TerminationReason::LoopThrashing
+            TerminationReason::LoopThrashing
             | TerminationReason::LoopStale
             | TerminationReason::ValidationFailure
-            | TerminationReason::Stopped => 1,
+            | TerminationReason::HookFailed => 1,
             TerminationReason::MaxIterations
             | TerminationReason::MaxRuntime
             | TerminationReason::MaxCost => 2,
-            TerminationReason::Interrupted => 130,
-            // Restart uses exit code 3 to signal the caller to exec-replace
-            TerminationReason::RestartRequested => 3,
-            // Cancelled is a clean exit (0) — the loop stopped intentionally
-            TerminationReason::Cancelled => 0,
+            TerminationReason::ConsecutiveFailures => 3,
+            // Stopped and interrupted both mean "a human ended the run"
+            TerminationReason::Stopped | TerminationReason::Interrupted => 130,
+            // Restart uses exit code 4 to signal the caller to exec-replace
+            TerminationReason::RestartRequested => 4,
+            // Cancelled and --once are clean exits (0) — the loop stopped intentionally
+            TerminationReason::Cancelled | TerminationReason::SingleIteration => 0,
         }
     }
 
-    /// Returns the reason string for use in loop.terminate event payload.
-    ///
-    /// Per spec event payload format:
-    /// `completed | max_iterations | max_runtime | consecutive_failures | interrupted | error`
-    pub fn as_str(&self) -> &'static str {
+    /// Returns the stable snake_case name, matching the serde representation.
+    pub fn name(&self) -> &'static str {
         match self {
-            TerminationReason::CompletionPromise => "completed",
+            TerminationReason::CompletionPromise => "completion_promise",
             TerminationReason::MaxIterations => "max_iterations",
             TerminationReason::MaxRuntime => "max_runtime",
             TerminationReason::MaxCost => "max_cost",
@@ -95,10 +110,26 @@ impl TerminationReason {
             TerminationReason::LoopThrashing => "loop_thrashing",
             TerminationReason::LoopStale => "loop_stale",
             TerminationReason::ValidationFailure => "validation_failure",
+            TerminationReason::HookFailed => "hook_failed",
             TerminationReason::Stopped => "stopped",
             TerminationReason::Interrupted => "interrupted",
             TerminationReason::RestartRequested => "restart_requested",
             TerminationReason::Cancelled => "cancelled",
+            TerminationReason::SingleIteration => "single_iteration",
+        }
+    }
+
+    /// Returns the reason string for use in loop.terminate event payload.
+    ///
+    /// Per spec event payload format:
+    /// `completed | max_iterations | max_runtime | consecutive_failures | interrupted | error`
+    ///
+    /// Identical to [`name`](Self::name) except that a completion promise is
+    /// reported as `completed`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TerminationReason::CompletionPromise => "completed",
+            other => other.name(),
         }
     }
 
@@ -108,6 +139,64 @@ impl TerminationReason {
     }
 }
 
+impl fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Sorts memories by embedding similarity to `query`, if an embedding
+/// command is configured (`semantic-memory` feature).
+///
+/// Returns `false`, leaving `memories` as they were, when semantic ranking is
+/// off or fails; injection then falls back to recency-based selection.
+#[cfg(feature = "semantic-memory")]
+fn rank_memories_semantically(
+    store: &MarkdownMemoryStore,
+    config: &crate::config::MemoriesConfig,
+    workspace_root: &std::path::Path,
+    memories: &mut [crate::memory::Memory],
+    query: &str,
+) -> bool {
+    let Some(embedder) = crate::CommandEmbedder::from_config(config, workspace_root) else {
+        return false;
+    };
+    store
+        .rank_semantic(memories, query, &embedder)
+        .inspect_err(|e| warn!(error = %e, "Semantic memory ranking failed"))
+        .is_ok()
+}
+
+#[cfg(not(feature = "semantic-memory"))]
+fn rank_memories_semantically(
+    _store: &MarkdownMemoryStore,
+    _config: &crate::config::MemoriesConfig,
+    _workspace_root: &std::path::Path,
+    _memories: &mut [crate::memory::Memory],
+    _query: &str,
+) -> bool {
+    false
+}
+
+/// Topic of the observer-only event recording which memories were injected
+/// into a prompt. Never routed to hats.
+pub const MEMORY_INJECTED_TOPIC: &str = "memory.injected";
+
+/// Topic of the observer-only record summarizing an iteration's cost and
+/// duration. Never routed to hats.
+pub const LOOP_ITERATION_TOPIC: &str = "loop.iteration";
+
+/// Topic published when `LOOP_COMPLETE` is refused because some
+/// `event_loop.required_events` haven't been seen yet. Routed to Ralph.
+pub const COMPLETION_BLOCKED_TOPIC: &str = "completion.blocked";
+
+/// Validated events from one JSONL read, waiting to be published.
+struct JsonlBatch {
+    events: Vec<Event>,
+    /// Indices of agent `build.done` events awaiting the verification command.
+    unverified: Vec<usize>,
+}
+
 /// The main event loop orchestrator.
 pub struct EventLoop {
     config: RalphConfig,
@@ -129,6 +218,8 @@ pub struct EventLoop {
     /// Robot service for human-in-the-loop communication.
     /// Injected externally when `human.enabled` is true and this is the primary loop.
     robot_service: Option<Box<dyn RobotService>>,
+    /// IDs of the memories injected by the most recent prompt build.
+    injected_memory_ids: Vec<String>,
 }
 
 impl EventLoop {
@@ -189,6 +280,7 @@ impl EventLoop {
         // Per spec: "Ralph runs when no hat triggered — Universal fallback for orphaned events"
         let ralph_hat = ralph_proto::Hat::new("ralph", "Ralph").subscribe("*"); // Subscribe to all events
         bus.register(ralph_hat);
+        apply_topic_rate_limits(&mut bus, &config.event_loop);
 
         if registry.is_empty() {
             debug!("Solo mode: Ralph is the only coordinator");
@@ -257,6 +349,7 @@ impl EventLoop {
             loop_context: Some(context),
             skill_registry,
             robot_service: None,
+            injected_memory_ids: Vec::new(),
         }
     }
 
@@ -282,6 +375,7 @@ impl EventLoop {
         // Per spec: "Ralph runs when no hat triggered — Universal fallback for orphaned events"
         let ralph_hat = ralph_proto::Hat::new("ralph", "Ralph").subscribe("*"); // Subscribe to all events
         bus.register(ralph_hat);
+        apply_topic_rate_limits(&mut bus, &config.event_loop);
 
         if registry.is_empty() {
             debug!("Solo mode: Ralph is the only coordinator");
@@ -347,6 +441,7 @@ impl EventLoop {
             loop_context: None,
             skill_registry,
             robot_service: None,
+            injected_memory_ids: Vec::new(),
         }
     }
 
@@ -387,6 +482,36 @@ impl EventLoop {
         &self.state
     }
 
+    /// Takes the IDs of the memories injected by the last [`build_prompt`](Self::build_prompt).
+    ///
+    /// Empty when nothing was injected (memories disabled, non-auto injection,
+    /// or no memories selected).
+    pub fn take_injected_memory_ids(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.injected_memory_ids)
+    }
+
+    /// Records the malformed `<event>` tags found in one iteration's output.
+    ///
+    /// Call once per iteration. Each iteration with malformed tags counts
+    /// once, however many tags it had, so prose or code samples quoting
+    /// `<event` fragments don't end the loop on their own. A clean iteration
+    /// resets the count.
+    pub fn record_malformed_output_events(&mut self, count: usize) {
+        if count == 0 {
+            self.state.consecutive_malformed_output_iterations = 0;
+            return;
+        }
+        self.state.consecutive_malformed_output_iterations = self
+            .state
+            .consecutive_malformed_output_iterations
+            .saturating_add(1);
+        warn!(
+            count,
+            consecutive_iterations = self.state.consecutive_malformed_output_iterations,
+            "Malformed <event> tags detected in output"
+        );
+    }
+
     /// Returns the configuration.
     pub fn config(&self) -> &RalphConfig {
         &self.config
@@ -462,8 +587,15 @@ impl EventLoop {
             return Some(TerminationReason::ValidationFailure);
         }
 
-        // Check for stale loop: same topic emitted 3+ times in a row
-        if self.state.consecutive_same_topic >= 3 {
+        // ...or too many consecutive iterations with malformed <event> tags
+        if self.state.consecutive_malformed_output_iterations >= 3 {
+            return Some(TerminationReason::ValidationFailure);
+        }
+
+        // Check for stale loop: same topic emitted too many times in a row
+        if cfg.max_consecutive_same_topic > 0
+            && self.state.consecutive_same_topic >= cfg.max_consecutive_same_topic
+        {
             warn!(
                 topic = self.state.last_emitted_topic.as_deref().unwrap_or("?"),
                 count = self.state.consecutive_same_topic,
@@ -480,6 +612,15 @@ impl EventLoop {
             return Some(TerminationReason::Stopped);
         }
 
+        // Check for cancel signal from `ralph cancel`; like a stop, it exits 130
+        let cancel_path =
+            std::path::Path::new(&self.config.core.workspace_root).join(".ralph/cancel-requested");
+        if cancel_path.exists() {
+            let _ = std::fs::remove_file(&cancel_path);
+            info!("Loop cancelled via cancel-requested signal");
+            return Some(TerminationReason::Stopped);
+        }
+
         // Check for restart signal from Telegram /restart command
         let restart_path =
             std::path::Path::new(&self.config.core.workspace_root).join(".ralph/restart-requested");
@@ -487,9 +628,51 @@ impl EventLoop {
             return Some(TerminationReason::RestartRequested);
         }
 
+        // --once ends the loop only when nothing more specific applies, so a
+        // failed or over-limit iteration still reports (and exits) as such
+        if cfg.single_iteration && self.state.iteration >= 1 {
+            return Some(TerminationReason::SingleIteration);
+        }
+
         None
     }
 
+    /// Returns true while a Telegram `/pause` is in effect.
+    ///
+    /// The runner holds the loop at the iteration boundary until the
+    /// `.ralph/pause-requested` signal file is removed (by `/resume`, `/stop`,
+    /// or `/restart`).
+    pub fn pause_requested(&self) -> bool {
+        self.config
+            .core
+            .workspace_root
+            .join(".ralph/pause-requested")
+            .exists()
+    }
+
+    /// Returns true while a stop or cancel signal file is waiting for
+    /// [`Self::check_termination`], so a paused loop can wake up for it.
+    pub fn stop_requested(&self) -> bool {
+        let ralph_dir = self.config.core.workspace_root.join(".ralph");
+        ralph_dir.join("stop-requested").exists() || ralph_dir.join("cancel-requested").exists()
+    }
+
+    /// Removes a pause signal left behind by an earlier run, so a new run
+    /// does not silently pause after its first iteration.
+    pub fn clear_stale_pause(&self) {
+        let pause_path = self
+            .config
+            .core
+            .workspace_root
+            .join(".ralph/pause-requested");
+        if pause_path.exists() {
+            match std::fs::remove_file(&pause_path) {
+                Ok(()) => info!("Removed stale pause request from a previous run"),
+                Err(e) => warn!(error = %e, "Failed to remove stale pause request"),
+            }
+        }
+    }
+
     /// Check if a loop.cancel event was detected.
     ///
     /// Unlike check_completion_event(), this does NOT validate required_events.
@@ -520,6 +703,39 @@ impl EventLoop {
             return None;
         }
 
+        // Backpressure: completion needs accepted evidence from this run
+        if let Some(topic) = self
+            .config
+            .event_loop
+            .completion_evidence_topic
+            .as_deref()
+            .filter(|t| !t.is_empty())
+            && !self.state.seen_topics.contains(topic)
+        {
+            warn!(
+                topic = %topic,
+                "Rejecting LOOP_COMPLETE: no {} with passing evidence this run",
+                topic
+            );
+            self.state.completion_requested = false;
+
+            self.diagnostics.log_orchestration(
+                self.state.iteration,
+                "loop",
+                crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
+                    reason: format!("completion without accepted {topic}"),
+                },
+            );
+
+            let resume_payload = format!(
+                "LOOP_COMPLETE rejected: no `{topic}` with passing evidence has been accepted \
+                 this run. Run and report tests before completing: emit `{topic}` with \
+                 'tests: pass', 'lint: pass', 'typecheck: pass', ... and then LOOP_COMPLETE."
+            );
+            self.bus.publish(Event::new("task.resume", resume_payload));
+            return None;
+        }
+
         // Event chain validation: check required events were seen
         let required = &self.config.event_loop.required_events;
         if !required.is_empty() {
@@ -531,14 +747,20 @@ impl EventLoop {
                 );
                 self.state.completion_requested = false;
 
-                // Inject task.resume so the loop continues
-                let resume_payload = format!(
-                    "LOOP_COMPLETE rejected: missing required events: {:?}. \
-                     The agent must complete all workflow phases before emitting LOOP_COMPLETE. \
-                     Use loop.cancel to abort the workflow instead.",
-                    missing
+                // Publish completion.blocked so the loop continues and the agent
+                // sees which phases are outstanding
+                let missing_list = missing
+                    .iter()
+                    .map(|topic| format!("- {topic}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let blocked_payload = format!(
+                    "LOOP_COMPLETE blocked: required events not seen yet:\n{missing_list}\n\
+                     Complete all workflow phases before emitting LOOP_COMPLETE. \
+                     Use loop.cancel to abort the workflow instead."
                 );
-                self.bus.publish(Event::new("task.resume", resume_payload));
+                self.bus
+                    .publish(Event::new(COMPLETION_BLOCKED_TOPIC, blocked_payload));
                 return None;
             }
         }
@@ -637,8 +859,14 @@ impl EventLoop {
     ///
     /// - Solo mode (no custom hats): Returns "ralph" if Ralph has pending events
     /// - Multi-hat mode (custom hats defined): Always returns "ralph" if ANY hat has pending events
+    ///
+    /// Events pending only for exhausted hats (see `max_activations`) do not
+    /// count: those hats are never selected again.
     pub fn next_hat(&self) -> Option<&HatId> {
-        let next = self.bus.next_hat_with_pending();
+        let next = self
+            .bus
+            .hats_with_pending()
+            .find(|id| !self.state.exhausted_hats.contains(*id));
 
         // If no pending hat events but human interactions are pending, route to Ralph.
         if next.is_none() && self.bus.has_human_pending() {
@@ -660,6 +888,54 @@ impl EventLoop {
         }
     }
 
+    /// Explains how the next iteration will be routed.
+    ///
+    /// Lists the pending events on the bus, which hat will be worn and the
+    /// subscription that selected it, and which hat executes the iteration.
+    /// Used by `ralph run --explain`. Returns `None` when nothing is pending.
+    pub fn explain_routing(&self) -> Option<Vec<String>> {
+        let executor = self.next_hat()?;
+        let mut lines = Vec::new();
+
+        for hat_id in self.bus.hat_ids() {
+            for event in self.bus.peek_pending(hat_id).into_iter().flatten() {
+                lines.push(format!("pending: {} -> queued for {}", event.topic, hat_id));
+            }
+        }
+        for event in self.bus.peek_human_pending() {
+            lines.push(format!("pending: {} -> human queue", event.topic));
+        }
+
+        // Mirrors get_active_hat_id(): the first pending event (in hat ID order)
+        // that a custom hat subscribes to decides which hat Ralph wears.
+        let selected = self.bus.hat_ids().find_map(|hat_id| {
+            let event = self.bus.peek_pending(hat_id)?.first()?;
+            self.registry
+                .find_subscription(event.topic.as_str())
+                .map(|(hat, pattern)| (hat, pattern, event.topic.as_str()))
+        });
+
+        match selected {
+            Some((hat, pattern, topic)) => lines.push(format!(
+                "selected hat: {} ({}) - '{}' matched subscription '{}' (hats checked in ID order)",
+                hat.id, hat.name, topic, pattern
+            )),
+            None => lines.push(
+                "selected hat: ralph - no custom hat subscribes to the pending topics (fallback)"
+                    .to_string(),
+            ),
+        }
+
+        let mode = if self.registry.is_empty() {
+            "solo mode"
+        } else {
+            "multi-hat mode: Ralph executes, custom hats define topology"
+        };
+        lines.push(format!("executor: {} ({})", executor, mode));
+
+        Some(lines)
+    }
+
     /// Checks if any hats have pending events.
     ///
     /// Use this after `process_output` to detect if the LLM failed to publish an event.
@@ -668,6 +944,26 @@ impl EventLoop {
         self.bus.next_hat_with_pending().is_some() || self.bus.has_human_pending()
     }
 
+    /// Pause before the next iteration after consecutive failures.
+    ///
+    /// `failure_backoff_seconds` after the first failure, doubling with each
+    /// further one up to `max_failure_backoff_seconds`. Zero when the last
+    /// iteration succeeded or backoff is disabled.
+    pub fn failure_backoff(&self) -> Duration {
+        let cfg = &self.config.event_loop;
+        let failures = self.state.consecutive_failures;
+        if cfg.failure_backoff_seconds == 0 || failures == 0 {
+            return Duration::ZERO;
+        }
+
+        let factor = 1u64.checked_shl(failures - 1).unwrap_or(u64::MAX);
+        let secs = cfg
+            .failure_backoff_seconds
+            .saturating_mul(factor)
+            .min(cfg.max_failure_backoff_seconds);
+        Duration::from_secs(secs)
+    }
+
     /// Checks if any pending events are human-related (human.response, human.guidance).
     ///
     /// Used to skip cooldown delays when a human event is next, since we don't
@@ -758,9 +1054,10 @@ impl EventLoop {
                 self.apply_robot_guidance();
 
                 // Build base prompt and prepend memories + scratchpad + ready tasks
-                let base_prompt = self.ralph.build_prompt(&events_context, &[]);
+                let mut base_prompt = self.ralph.build_prompt(&events_context, &[]);
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
+                self.inject_verification_failure(&mut base_prompt);
+                let with_skills = self.prepend_auto_inject_skills(base_prompt, &events_context);
                 let with_scratchpad = self.prepend_scratchpad(with_skills);
                 let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
@@ -838,9 +1135,10 @@ impl EventLoop {
                         .collect::<Vec<_>>()
                 );
 
-                // Inject stale-topic warning when the same topic has been emitted 2+ times
-                // consecutively. This warns the agent before the loop terminates at count >= 3.
-                if self.state.consecutive_same_topic >= 2 {
+                // Inject stale-topic warning one emission before the loop would
+                // terminate as stale.
+                let stale_limit = self.config.event_loop.max_consecutive_same_topic;
+                if stale_limit > 1 && self.state.consecutive_same_topic + 1 >= stale_limit {
                     let stale_topic = self
                         .state
                         .last_emitted_topic
@@ -862,7 +1160,8 @@ impl EventLoop {
 
                 // Clear guidance after active_hats references are no longer needed
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
+                self.inject_verification_failure(&mut base_prompt);
+                let with_skills = self.prepend_auto_inject_skills(base_prompt, &events_context);
                 let with_scratchpad = self.prepend_scratchpad(with_skills);
                 let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
@@ -987,11 +1286,14 @@ impl EventLoop {
     /// 1. Memory data + ralph-tools skill (special case: loads memory data from store, applies budget)
     /// 2. RObot interaction skill (gated by `robot.enabled`)
     /// 3. Other auto-inject skills from the registry (wrapped in XML tags)
-    fn prepend_auto_inject_skills(&self, prompt: String) -> String {
+    ///
+    /// `events_context` is the formatted events the iteration handles; with
+    /// semantic memory, memories are ranked by similarity to it.
+    fn prepend_auto_inject_skills(&mut self, prompt: String, events_context: &str) -> String {
         let mut prefix = String::new();
 
         // 1. Memory data + ralph-tools skill — special case with data loading
-        self.inject_memories_and_tools_skill(&mut prefix);
+        self.inject_memories_and_tools_skill(&mut prefix, events_context);
 
         // 2. RObot interaction skill — gated by robot.enabled
         self.inject_robot_skill(&mut prefix);
@@ -1010,12 +1312,13 @@ impl EventLoop {
 
     /// Injects memory data and the ralph-tools skill into the prefix.
     ///
-    /// Special case: loads memory entries from the store, applies budget
-    /// truncation, then appends the ralph-tools skill content (which covers
+    /// Special case: loads memory entries from the store, keeps those selected
+    /// by `memories.filter`, fits them to `memories.budget`, then appends the ralph-tools skill content (which covers
     /// both tasks and memories CLI usage).
     /// Memory data is gated by `memories.enabled && memories.inject == Auto`.
     /// The ralph-tools skill is injected when either memories or tasks are enabled.
-    fn inject_memories_and_tools_skill(&self, prefix: &mut String) {
+    fn inject_memories_and_tools_skill(&mut self, prefix: &mut String, events_context: &str) {
+        self.injected_memory_ids.clear();
         let memories_config = &self.config.memories;
 
         // Inject memory DATA if memories are enabled with auto-inject
@@ -1049,18 +1352,37 @@ impl EventLoop {
                 }
             };
 
-            if memories.is_empty() {
-                info!("Memory store is empty - no memories to inject");
+            let mut memories = filter_memories(memories, &memories_config.filter);
+            let memories = if rank_memories_semantically(
+                &store,
+                memories_config,
+                workspace_root,
+                &mut memories,
+                events_context,
+            ) {
+                // Ranked: keep the most similar memories, most similar first
+                if memories_config.max_injected > 0 {
+                    memories.truncate(memories_config.max_injected);
+                }
+                memories
             } else {
-                let mut memories_content = format_memories_as_markdown(&memories);
+                cap_memories(memories, memories_config.max_injected)
+            };
 
+            if memories.is_empty() {
+                info!("No memories to inject (store empty or nothing matched memories.filter)");
+            } else {
+                // Over budget, the memories matching the most filter tags
+                // (then the newest) are kept whole and the rest dropped.
+                let memories_content = format_memories_within_budget(
+                    memories.clone(),
+                    memories_config.budget,
+                    &memories_config.filter.tags,
+                );
                 if memories_config.budget > 0 {
-                    let original_len = memories_content.len();
-                    memories_content =
-                        truncate_to_budget(&memories_content, memories_config.budget);
                     debug!(
-                        "Applied budget: {} chars -> {} chars (budget: {})",
-                        original_len,
+                        "Applied budget: {} memories -> {} chars (budget: {})",
+                        memories.len(),
                         memories_content.len(),
                         memories_config.budget
                     );
@@ -1072,6 +1394,14 @@ impl EventLoop {
                     memories_content.len()
                 );
 
+                // Budget truncation may drop trailing memories; record only
+                // the ones whose block made it into the prompt.
+                self.injected_memory_ids = memories
+                    .iter()
+                    .filter(|m| memories_content.contains(&format!("### {}\n", m.id)))
+                    .map(|m| m.id.clone())
+                    .collect();
+
                 prefix.push_str(&memories_content);
             }
         }
@@ -1307,11 +1637,94 @@ impl EventLoop {
         final_prompt
     }
 
+    /// Replaces the batch's unverified `build.done` events with the outcome of
+    /// the verification command and records failing output for the next prompt.
+    fn apply_verification(
+        &mut self,
+        batch: &mut JsonlBatch,
+        verifier: &Verifier,
+        outcome: std::io::Result<VerificationResult>,
+    ) {
+        let (event, failure) = self.verification_event(verifier, outcome);
+        self.state.verification_failure = failure;
+        for &index in &batch.unverified {
+            batch.events[index] = event.clone();
+        }
+    }
+
+    /// Maps a verification outcome to the event that replaces the agent's
+    /// `build.done`, plus the truncated output on failure.
+    fn verification_event(
+        &self,
+        verifier: &Verifier,
+        outcome: std::io::Result<VerificationResult>,
+    ) -> (Event, Option<String>) {
+        let command = verifier.command();
+        match outcome {
+            Ok(result) => {
+                let mut failure = None;
+                if !result.passed {
+                    warn!(
+                        command = %command,
+                        exit_code = result.exit_code,
+                        "build.done rejected: verification command failed"
+                    );
+                    self.diagnostics.log_orchestration(
+                        self.state.iteration,
+                        "jsonl",
+                        crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
+                            reason: format!("verification `{}` {}", command, result.summary()),
+                        },
+                    );
+                    let max_chars = self.config.event_loop.verification_output_max_chars;
+                    if max_chars > 0 {
+                        failure = Some(result.output_tail(max_chars));
+                    }
+                }
+                (verifier.to_event(&result), failure)
+            }
+            Err(e) => {
+                warn!(command = %command, error = %e, "build.done rejected: verification could not run");
+                let event = Event::new(
+                    "build.blocked",
+                    format!("verification: error (`{command}` could not run: {e})"),
+                );
+                (event, None)
+            }
+        }
+    }
+
+    /// Appends the last failed verification output to the prompt, once.
+    fn inject_verification_failure(&mut self, prompt: &mut String) {
+        let Some(output) = self.state.verification_failure.take() else {
+            return;
+        };
+        let command = self
+            .config
+            .event_loop
+            .verification_command
+            .as_deref()
+            .unwrap_or_default();
+        prompt.push_str(&format!(
+            "\n\n## VERIFICATION FAILURE\n\n\
+            The last `build.done` was rejected because `{command}` failed. \
+            You MUST fix these errors before emitting `build.done` again:\n\n\
+            ```\n{output}\n```\n"
+        ));
+    }
+
     /// Builds the Ralph prompt (coordination mode).
     pub fn build_ralph_prompt(&self, prompt_content: &str) -> String {
         self.ralph.build_prompt(prompt_content, &[])
     }
 
+    /// Renders the prompt Ralph would receive with `hat_id` active, without
+    /// touching pending events. Used by dry runs to size hat prompts.
+    pub fn preview_hat_prompt(&self, prompt_content: &str, hat_id: &HatId) -> Option<String> {
+        let hat = self.registry.get(hat_id)?;
+        Some(self.ralph.build_prompt(prompt_content, &[hat]))
+    }
+
     /// Determines which hats should be active based on pending events.
     /// Returns list of Hat references that are triggered by any pending event.
     fn determine_active_hats(&self, events: &[Event]) -> Vec<&Hat> {
@@ -1359,7 +1772,10 @@ impl EventLoop {
         let Some(config) = self.registry.get_config(hat_id) else {
             return (false, None);
         };
-        let Some(max) = config.max_activations else {
+        let Some(max) = config
+            .max_activations
+            .or(self.config.event_loop.max_iterations_per_hat)
+        else {
             return (false, None);
         };
 
@@ -1479,6 +1895,11 @@ impl EventLoop {
         &mut self.bus
     }
 
+    /// Returns a read-only view of the event bus, e.g. to inspect pending events.
+    pub fn event_bus(&self) -> &EventBus {
+        &self.bus
+    }
+
     /// Processes output from a hat execution.
     ///
     /// Returns the termination reason if the loop should stop.
@@ -1551,8 +1972,11 @@ impl EventLoop {
         // This enforces tool use and prevents confabulation (agent claiming to emit without actually doing so).
         // See process_events_from_jsonl() for event processing.
 
-        // Check termination conditions
+        // Check termination conditions. --once is left to the next
+        // check_termination, after this iteration's events and completion
+        // have been handled.
         self.check_termination()
+            .filter(|reason| *reason != TerminationReason::SingleIteration)
     }
 
     /// Audits file modifications after a hat iteration.
@@ -1583,8 +2007,7 @@ impl EventLoop {
 
         match diff_output {
             Ok(output) if !output.stdout.is_empty() => {
-                let diff_stat =
-                    String::from_utf8_lossy(&output.stdout).trim().to_string();
+                let diff_stat = String::from_utf8_lossy(&output.stdout).trim().to_string();
                 warn!(
                     hat = %hat_id.as_str(),
                     diff = %diff_stat,
@@ -1611,7 +2034,9 @@ impl EventLoop {
 
     /// Extracts task identifier from build.blocked payload.
     /// Uses first line of payload as task ID.
-    fn extract_task_id(payload: &str) -> String {
+    ///
+    /// This is the key of [`LoopState::task_block_counts`].
+    pub fn extract_task_id(payload: &str) -> String {
         payload
             .lines()
             .next()
@@ -1793,8 +2218,85 @@ impl EventLoop {
     /// 3. Resetting counter when valid events are parsed
     ///
     /// Returns true if Ralph should be invoked to handle orphaned events.
+    ///
+    /// Runs `verification_command` synchronously; the loop runner uses
+    /// [`Self::process_events_from_jsonl_async`] instead.
     pub fn process_events_from_jsonl(&mut self) -> std::io::Result<bool> {
-        let result = self.event_reader.read_new_events()?;
+        let Some(mut batch) = self.read_jsonl_batch()? else {
+            return Ok(false);
+        };
+        if let Some(verifier) = self.pending_verifier(&batch) {
+            let outcome = verifier.run(&self.config.core.workspace_root);
+            self.apply_verification(&mut batch, &verifier, outcome);
+        }
+        Ok(self.publish_jsonl_batch(batch))
+    }
+
+    /// Async variant of [`Self::process_events_from_jsonl`] for the loop runner.
+    ///
+    /// The verification command for an agent's `build.done` runs on the async
+    /// runtime with `verification_timeout_seconds`, so the TUI and interrupt
+    /// handling stay responsive. Dropping the future kills the command.
+    pub async fn process_events_from_jsonl_async(&mut self) -> std::io::Result<bool> {
+        let Some(mut batch) = self.read_jsonl_batch()? else {
+            return Ok(false);
+        };
+        if let Some(verifier) = self.pending_verifier(&batch) {
+            let timeout = match self.config.event_loop.verification_timeout_seconds {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            };
+            let outcome = verifier
+                .run_async(&self.config.core.workspace_root, timeout)
+                .await;
+            self.apply_verification(&mut batch, &verifier, outcome);
+        }
+        Ok(self.publish_jsonl_batch(batch))
+    }
+
+    /// Returns the verifier to run when the batch holds an agent `build.done`.
+    fn pending_verifier(&self, batch: &JsonlBatch) -> Option<Verifier> {
+        if batch.unverified.is_empty() {
+            return None;
+        }
+        self.config
+            .event_loop
+            .verification_command
+            .as_deref()
+            .map(Verifier::new)
+    }
+
+    /// Reads new events from JSONL and validates them. Returns `None` when
+    /// nothing was written.
+    fn read_jsonl_batch(&mut self) -> std::io::Result<Option<JsonlBatch>> {
+        let mut result = self.event_reader.read_new_events()?;
+
+        for event in &mut result.events {
+            if let Some(canonical) = EventParser::resolve_topic_alias(
+                &event.topic,
+                &self.config.event_loop.topic_aliases,
+            ) {
+                event.topic = canonical.to_string();
+            }
+        }
+
+        // memory.injected and loop.iteration are observer-only records
+        // written by the runner.
+        result.events.retain(|event| {
+            event.topic != MEMORY_INJECTED_TOPIC && event.topic != LOOP_ITERATION_TOPIC
+        });
+
+        let max_events = self.config.event_loop.max_events_per_iteration;
+        if max_events > 0 && result.events.len() > max_events {
+            warn!(
+                received = result.events.len(),
+                dropped = result.events.len() - max_events,
+                max_events,
+                "Too many events in one iteration; dropped {} past the cap",
+                result.events.len() - max_events
+            );
+            result.events.truncate(max_events);
+        }
 
         // Handle malformed lines with backpressure
         for malformed in &result.malformed {
@@ -1818,7 +2320,7 @@ impl EventLoop {
         }
 
         if result.events.is_empty() && result.malformed.is_empty() {
-            return Ok(false);
+            return Ok(None);
         }
 
         // --- Scope enforcement: filter events against active hat's publishes ---
@@ -1858,10 +2360,9 @@ impl EventLoop {
         };
         // --- End scope enforcement ---
 
-        let mut has_orphans = false;
-
         // Validate and transform events (apply backpressure for build.done)
         let mut validated_events = Vec::new();
+        let mut unverified = Vec::new();
         let completion_topic = self.config.event_loop.completion_promise.as_str();
         let cancellation_topic = self.config.event_loop.cancellation_promise.clone();
         let total_events = events.len();
@@ -1904,7 +2405,12 @@ impl EventLoop {
                 continue;
             }
 
-            if event.topic == "build.done" {
+            if event.topic == "build.done" && self.config.event_loop.verification_command.is_some()
+            {
+                // Replaced by the verification outcome before publishing
+                unverified.push(validated_events.len());
+                validated_events.push(Event::new(event.topic.as_str(), &payload));
+            } else if event.topic == "build.done" {
                 // Validate build.done events have backpressure evidence
                 if let Some(evidence) = EventParser::parse_backpressure_evidence(&payload) {
                     if evidence.all_passed() {
@@ -2085,6 +2591,36 @@ impl EventLoop {
             }
         }
 
+        Ok(Some(JsonlBatch {
+            events: validated_events,
+            unverified,
+        }))
+    }
+
+    /// Publishes a validated batch and returns whether any event was orphaned.
+    fn publish_jsonl_batch(&mut self, batch: JsonlBatch) -> bool {
+        let mut validated_events = batch.events;
+        let mut has_orphans = false;
+
+        // Drop re-dispatches of abandoned tasks; check_termination stops the
+        // loop as thrashing once the planner keeps trying.
+        validated_events.retain(|event| {
+            if event.topic != "build.task".into() {
+                return true;
+            }
+            let task_id = Self::extract_task_id(&event.payload);
+            if !self.state.abandoned_tasks.contains(&task_id) {
+                return true;
+            }
+            self.state.abandoned_task_redispatches += 1;
+            warn!(
+                task_id = %task_id,
+                redispatches = self.state.abandoned_task_redispatches,
+                "Dropping build.task for abandoned task"
+            );
+            false
+        });
+
         // Track build.blocked events for thrashing detection
         let blocked_events: Vec<_> = validated_events
             .iter()
@@ -2285,7 +2821,7 @@ impl EventLoop {
                 topic = %event.topic,
                 "Publishing event from JSONL"
             );
-            self.bus.publish(event);
+            self.bus.publish_rate_limited(event);
         }
 
         // Publish human.response event if one was received during blocking
@@ -2298,14 +2834,16 @@ impl EventLoop {
             self.bus.publish(response);
         }
 
-        Ok(has_orphans)
+        has_orphans
     }
 
     /// Checks if output contains a completion event from Ralph.
     ///
     /// Completion must be emitted as an `<event>` tag, not plain text.
     pub fn check_ralph_completion(&self, output: &str) -> bool {
-        let events = EventParser::new().parse(output);
+        let events = EventParser::new()
+            .with_topic_aliases(self.config.event_loop.topic_aliases.clone())
+            .parse(output);
         events
             .iter()
             .any(|event| event.topic.as_str() == self.config.event_loop.completion_promise)
@@ -2318,7 +2856,8 @@ impl EventLoop {
     ///
     /// Returns the event for logging purposes.
     pub fn publish_terminate_event(&mut self, reason: &TerminationReason) -> Event {
-        // Stop the robot service if it was running
+        // Send the termination summary, then stop the robot service
+        self.send_robot_termination(reason);
         self.stop_robot_service();
 
         let elapsed = self.state.elapsed();
@@ -2339,7 +2878,7 @@ impl EventLoop {
         self.bus.publish(event.clone());
 
         info!(
-            reason = %reason.as_str(),
+            reason = %reason,
             iterations = self.state.iteration,
             duration = %duration_str,
             "Wrapping up: {}. {} iterations in {}.",
@@ -2359,6 +2898,27 @@ impl EventLoop {
         self.robot_service.as_ref().map(|s| s.shutdown_flag())
     }
 
+    /// Sends the termination summary through the robot service, if running.
+    fn send_robot_termination(&self, reason: &TerminationReason) {
+        let Some(service) = &self.robot_service else {
+            return;
+        };
+        let mut scratchpad = self.scratchpad_path();
+        if scratchpad.is_relative() {
+            scratchpad = self.config.core.workspace_root.join(scratchpad);
+        }
+        let context = TerminationContext {
+            reason: reason.as_str().to_string(),
+            iterations: self.state.iteration,
+            elapsed: self.state.elapsed(),
+            cumulative_cost: self.state.cumulative_cost,
+            scratchpad,
+        };
+        if let Err(e) = service.send_termination(&context) {
+            warn!(error = %e, "Failed to send termination notice via robot service");
+        }
+    }
+
     /// Stops the robot service if it's running.
     ///
     /// Called during loop termination to cleanly shut down the communication backend.
@@ -2424,6 +2984,23 @@ pub struct UserPrompt {
     pub text: String,
 }
 
+/// Installs `event_loop.topic_rate_limits` on the bus, skipping disabled
+/// (zero-window) entries.
+fn apply_topic_rate_limits(bus: &mut EventBus, config: &crate::config::EventLoopConfig) {
+    for (topic, limit) in &config.topic_rate_limits {
+        if limit.window_seconds == 0 {
+            continue;
+        }
+        bus.set_rate_limit(
+            topic.clone(),
+            TopicRateLimit {
+                max_events: limit.max,
+                window: Duration::from_secs(limit.window_seconds),
+            },
+        );
+    }
+}
+
 /// Formats a duration as human-readable string.
 fn format_duration(d: Duration) -> String {
     let total_secs = d.as_secs();
@@ -2452,12 +3029,14 @@ fn termination_status_text(reason: &TerminationReason) -> &'static str {
             "Loop thrashing detected - same hat repeatedly blocked."
         }
         TerminationReason::LoopStale => {
-            "Stale loop detected - same topic emitted 3+ times consecutively."
+            "Stale loop detected - same topic emitted too many times consecutively (event_loop.max_consecutive_same_topic)."
         }
         TerminationReason::ValidationFailure => "Too many consecutive malformed JSONL events.",
+        TerminationReason::HookFailed => "An iteration hook failed (hooks_strict).",
         TerminationReason::Stopped => "Manually stopped.",
         TerminationReason::Interrupted => "Interrupted by signal.",
         TerminationReason::RestartRequested => "Restarting by human request.",
         TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout).",
+        TerminationReason::SingleIteration => "Stopped after a single iteration.",
     }
 }
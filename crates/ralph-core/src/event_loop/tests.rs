@@ -33,6 +33,262 @@ hats:
     );
 }
 
+/// Event loop whose workspace holds two memories, tagged `auth` and `ui`.
+fn event_loop_with_memories(
+    memories: crate::config::MemoriesConfig,
+) -> (tempfile::TempDir, EventLoop) {
+    use crate::memory::{Memory, MemoryType};
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    for (content, tag) in [
+        ("Tokens live in the keyring", "auth"),
+        ("Buttons use the primary palette", "ui"),
+    ] {
+        store
+            .append(&Memory::new(
+                MemoryType::Pattern,
+                content.to_string(),
+                vec![tag.to_string()],
+            ))
+            .unwrap();
+    }
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.memories = memories;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    (temp_dir, event_loop)
+}
+
+#[test]
+fn test_auto_inject_adds_filtered_memories_to_prompt() {
+    let (_temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Auto,
+        filter: crate::config::MemoriesFilter {
+            tags: vec!["auth".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    assert!(prompt.contains("# Memories"), "{prompt}");
+    assert!(prompt.contains("Tokens live in the keyring"));
+    assert!(!prompt.contains("Buttons use the primary palette"));
+}
+
+#[test]
+fn test_max_injected_caps_memories_despite_budget_headroom() {
+    use crate::memory::{Memory, MemoryType};
+
+    let (temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Auto,
+        budget: 100_000,
+        max_injected: 2,
+        ..Default::default()
+    });
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    for id in ["mem-4000000000-0001", "mem-4000000000-0002"] {
+        store
+            .append(&Memory {
+                id: id.to_string(),
+                memory_type: MemoryType::Decision,
+                content: format!("Newest {id}"),
+                tags: vec![],
+                created: "2099-01-01".to_string(),
+            })
+            .unwrap();
+    }
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    assert_eq!(prompt.matches("### mem-").count(), 2, "{prompt}");
+    assert!(prompt.contains("Newest mem-4000000000-0001"));
+    assert!(prompt.contains("Newest mem-4000000000-0002"));
+    assert!(!prompt.contains("Tokens live in the keyring"));
+}
+
+#[cfg(feature = "semantic-memory")]
+#[test]
+fn test_semantic_ranking_injects_memories_closest_to_events() {
+    let (temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Auto,
+        max_injected: 1,
+        embedding_command: Some(
+            "if grep -qi token; then echo '[1, 0]'; else echo '[0, 1]'; fi".to_string(),
+        ),
+        ..Default::default()
+    });
+    event_loop.initialize("Fix the login token refresh");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    // By recency the newer `ui` memory would win the single slot.
+    assert!(prompt.contains("Tokens live in the keyring"), "{prompt}");
+    assert!(!prompt.contains("Buttons use the primary palette"));
+    assert!(temp_dir.path().join(".ralph/agent/memories.index").exists());
+}
+
+#[test]
+fn test_budget_trims_large_memories_file_to_highest_priority_entries() {
+    use crate::memory::{Memory, MemoryType};
+
+    let (temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Auto,
+        budget: 200,
+        filter: crate::config::MemoriesFilter {
+            tags: vec!["auth".to_string(), "legacy".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    for i in 1..=30 {
+        let tags = if i == 1 {
+            vec!["legacy".to_string(), "auth".to_string()]
+        } else {
+            vec!["legacy".to_string()]
+        };
+        store
+            .append(&Memory {
+                id: format!("mem-1000000000-{i:04}"),
+                memory_type: MemoryType::Context,
+                content: format!("Legacy note {i}: {}", "context ".repeat(50)),
+                tags,
+                created: "2020-01-01".to_string(),
+            })
+            .unwrap();
+    }
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    // Matches both filter tags, so it outranks newer single-tag memories
+    assert!(prompt.contains("Legacy note 1:"), "{prompt}");
+    // Newest of the remaining matches
+    assert!(prompt.contains("Tokens live in the keyring"), "{prompt}");
+    assert!(!prompt.contains("Legacy note 2:"));
+    assert!(!prompt.contains("Buttons use the primary palette"));
+    assert!(prompt.contains("29 memories omitted"), "{prompt}");
+    assert_eq!(event_loop.injected_memory_ids.len(), 2);
+}
+
+#[test]
+fn test_build_prompt_records_injected_memory_ids() {
+    let (temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Auto,
+        ..Default::default()
+    });
+    let mut expected: Vec<String> = MarkdownMemoryStore::with_default_path(temp_dir.path())
+        .load()
+        .unwrap()
+        .into_iter()
+        .map(|m| m.id)
+        .collect();
+
+    event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    let mut ids = event_loop.take_injected_memory_ids();
+    ids.sort();
+    expected.sort();
+    assert_eq!(ids, expected);
+    assert!(event_loop.take_injected_memory_ids().is_empty());
+}
+
+#[test]
+fn test_memory_injected_events_are_not_routed() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    let pending = event_loop.bus.pending_count();
+
+    write_event_to_jsonl(&events_path, MEMORY_INJECTED_TOPIC, "mem-1, mem-2");
+    let has_orphans = event_loop.process_events_from_jsonl().unwrap();
+
+    assert!(!has_orphans);
+    assert_eq!(event_loop.bus.pending_count(), pending);
+}
+
+#[test]
+fn test_loop_iteration_events_are_not_routed() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    let pending = event_loop.bus.pending_count();
+
+    write_event_to_jsonl(
+        &events_path,
+        LOOP_ITERATION_TOPIC,
+        r#"{"cost":0.1,"duration_ms":1200}"#,
+    );
+    let has_orphans = event_loop.process_events_from_jsonl().unwrap();
+
+    assert!(!has_orphans);
+    assert_eq!(event_loop.bus.pending_count(), pending);
+}
+
+#[test]
+fn test_topic_alias_routes_as_canonical_topic() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let yaml = r#"
+event_loop:
+  topic_aliases:
+    work_finished: work.done
+hats:
+  reviewer:
+    name: "Reviewer"
+    triggers: ["work.done"]
+    publishes: ["review.done"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "work_finished", "Implemented the parser");
+    // The canonical topic has a subscriber, so nothing is orphaned
+    assert!(!event_loop.process_events_from_jsonl().unwrap());
+
+    let pending = event_loop
+        .bus
+        .peek_pending(&HatId::new("reviewer"))
+        .expect("reviewer should have pending events");
+    let topics: Vec<&str> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, ["work.done"]);
+
+    // Tracked under the canonical topic too, e.g. for required_events
+    assert!(event_loop.state.seen_topics.contains("work.done"));
+    assert!(!event_loop.state.seen_topics.contains("work_finished"));
+}
+
+#[test]
+fn test_manual_inject_adds_no_memories_to_prompt() {
+    let (_temp_dir, mut event_loop) = event_loop_with_memories(crate::config::MemoriesConfig {
+        enabled: true,
+        inject: InjectMode::Manual,
+        ..Default::default()
+    });
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    assert!(!prompt.contains("Tokens live in the keyring"));
+    assert!(!prompt.contains("Buttons use the primary palette"));
+}
+
 #[test]
 fn test_guidance_persists_across_iterations_solo_mode() {
     let config = RalphConfig::default();
@@ -281,6 +537,119 @@ hats:
     );
 }
 
+#[test]
+fn test_global_max_iterations_per_hat_exhausts_hat_without_override() {
+    let yaml = r#"
+event_loop:
+  max_iterations_per_hat: 2
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds things"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    description: "Reviews changes"
+    triggers: ["review.start"]
+    publishes: ["review.done"]
+    max_activations: 5
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let ralph = HatId::new("ralph");
+
+    // The builder has no override, so the global cap of 2 applies.
+    for _ in 0..2 {
+        event_loop.bus.publish(Event::new("build.start", "go"));
+        let prompt = event_loop.build_prompt(&ralph).unwrap();
+        assert!(!prompt.contains("Event: builder.exhausted"));
+    }
+    event_loop.bus.publish(Event::new("build.start", "again"));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("Event: builder.exhausted"), "{prompt}");
+    assert!(prompt.contains("max_activations: 2"), "{prompt}");
+    assert!(
+        event_loop
+            .state
+            .exhausted_hats
+            .contains(&HatId::new("builder"))
+    );
+
+    // The reviewer's own max_activations overrides the global cap.
+    for _ in 0..3 {
+        event_loop.bus.publish(Event::new("review.start", "go"));
+        let _ = event_loop.build_prompt(&ralph).unwrap();
+    }
+    assert!(
+        !event_loop
+            .state
+            .exhausted_hats
+            .contains(&HatId::new("reviewer"))
+    );
+    assert_eq!(
+        event_loop
+            .state
+            .hat_activation_counts
+            .get(&HatId::new("reviewer")),
+        Some(&3)
+    );
+}
+
+#[test]
+fn test_exhausted_hat_is_skipped_by_next_hat() {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds things"
+    triggers: ["build.start"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    description: "Reviews changes"
+    triggers: ["review.start"]
+    publishes: ["review.done"]
+    max_activations: 1
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let ralph = HatId::new("ralph");
+    let reviewer_id = HatId::new("reviewer");
+
+    // First activation is within the cap.
+    event_loop.bus.publish(Event::new("review.start", "one"));
+    let _ = event_loop.build_prompt(&ralph).unwrap();
+
+    // Second attempt exceeds the cap and marks the reviewer exhausted.
+    event_loop.bus.publish(Event::new("review.start", "two"));
+    assert_eq!(event_loop.next_hat().map(HatId::as_str), Some("ralph"));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("Event: reviewer.exhausted"));
+    assert!(event_loop.state.exhausted_hats.contains(&reviewer_id));
+
+    // Drain the exhausted notification, which nothing subscribes to.
+    let _ = event_loop.build_prompt(&ralph).unwrap();
+
+    // Events for the exhausted reviewer alone no longer select an iteration.
+    event_loop.bus.publish(Event::new("review.start", "three"));
+    assert!(
+        event_loop.next_hat().is_none(),
+        "Exhausted hat should not be selected again"
+    );
+
+    // Other hats keep running.
+    event_loop.bus.publish(Event::new("build.start", "go"));
+    assert_eq!(event_loop.next_hat().map(HatId::as_str), Some("ralph"));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("Event: build.start - go"));
+    assert!(!prompt.contains("Event: review.start"));
+    assert_eq!(
+        event_loop.state.hat_activation_counts.get(&reviewer_id),
+        Some(&1)
+    );
+}
+
 #[test]
 fn test_termination_max_iterations() {
     let yaml = r"
@@ -297,6 +666,65 @@ event_loop:
     );
 }
 
+#[test]
+fn test_single_iteration_overrides_max_iterations() {
+    let single_hat = "event_loop:\n  max_iterations: 100\n";
+    let multi_hat = r#"
+event_loop:
+  max_iterations: 100
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    triggers: ["build.done"]
+    publishes: ["review.done"]
+"#;
+
+    for yaml in [single_hat, multi_hat] {
+        let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        config.event_loop.single_iteration = true;
+        let mut event_loop = EventLoop::new(config);
+        event_loop.initialize("Test");
+        assert_eq!(event_loop.check_termination(), None);
+
+        let hat_id = event_loop.next_hat().cloned().expect("a hat to run");
+        // The iteration's events and completion are handled first
+        assert_eq!(event_loop.process_output(&hat_id, "working", true), None);
+
+        let reason = event_loop.check_termination();
+        assert_eq!(reason, Some(TerminationReason::SingleIteration), "{yaml}");
+        assert_eq!(reason.unwrap().exit_code(), 0);
+    }
+}
+
+#[test]
+fn test_single_iteration_yields_to_failure_and_limit_reasons() {
+    let mut config = RalphConfig::default();
+    config.event_loop.single_iteration = true;
+    config.event_loop.max_consecutive_failures = 1;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let hat_id = event_loop.next_hat().cloned().expect("a hat to run");
+    assert_eq!(
+        event_loop.process_output(&hat_id, "boom", false),
+        Some(TerminationReason::ConsecutiveFailures)
+    );
+
+    let mut config = RalphConfig::default();
+    config.event_loop.single_iteration = true;
+    config.event_loop.max_iterations = 1;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let hat_id = event_loop.next_hat().cloned().expect("a hat to run");
+    assert_eq!(
+        event_loop.process_output(&hat_id, "working", true),
+        Some(TerminationReason::MaxIterations)
+    );
+}
+
 #[test]
 fn test_completion_promise_detection() {
     use std::fs;
@@ -561,19 +989,26 @@ hats:
 
 #[test]
 fn test_exit_codes_per_spec() {
-    // Per spec "Loop Termination" section:
-    // - 0: Completion promise detected (success)
-    // - 1: Consecutive failures or unrecoverable error (failure)
+    // - 0: Completion promise detected, cancelled, or --once (success)
+    // - 1: Thrashing, stale loop, validation or strict hook failure
     // - 2: Max iterations, max runtime, or max cost exceeded (limit)
-    // - 130: User interrupt (SIGINT = 128 + 2)
+    // - 3: Consecutive failures
+    // - 4: Restart requested
+    // - 130: User interrupt or stop (SIGINT = 128 + 2)
     assert_eq!(TerminationReason::CompletionPromise.exit_code(), 0);
-    assert_eq!(TerminationReason::ConsecutiveFailures.exit_code(), 1);
+    assert_eq!(TerminationReason::ConsecutiveFailures.exit_code(), 3);
     assert_eq!(TerminationReason::LoopThrashing.exit_code(), 1);
-    assert_eq!(TerminationReason::Stopped.exit_code(), 1);
+    assert_eq!(TerminationReason::Stopped.exit_code(), 130);
     assert_eq!(TerminationReason::MaxIterations.exit_code(), 2);
     assert_eq!(TerminationReason::MaxRuntime.exit_code(), 2);
     assert_eq!(TerminationReason::MaxCost.exit_code(), 2);
     assert_eq!(TerminationReason::Interrupted.exit_code(), 130);
+    assert_eq!(TerminationReason::LoopStale.exit_code(), 1);
+    assert_eq!(TerminationReason::ValidationFailure.exit_code(), 1);
+    assert_eq!(TerminationReason::HookFailed.exit_code(), 1);
+    assert_eq!(TerminationReason::RestartRequested.exit_code(), 4);
+    assert_eq!(TerminationReason::Cancelled.exit_code(), 0);
+    assert_eq!(TerminationReason::SingleIteration.exit_code(), 0);
 }
 
 /// Helper to write an event to a JSONL file for testing.
@@ -593,6 +1028,70 @@ fn write_event_to_jsonl(path: &std::path::Path, topic: &str, payload: &str) {
     writeln!(file, "{}", event_json).unwrap();
 }
 
+#[test]
+fn test_process_events_from_jsonl_reads_each_line_once() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Nothing written yet
+    assert!(!event_loop.process_events_from_jsonl().unwrap());
+
+    write_event_to_jsonl(&events_path, "build.done", "first");
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+    let after_first = event_loop.event_reader.position();
+    assert!(after_first > 0);
+
+    // Same file, no new lines: nothing to process
+    assert!(!event_loop.process_events_from_jsonl().unwrap());
+    assert_eq!(event_loop.event_reader.position(), after_first);
+
+    write_event_to_jsonl(&events_path, "review.done", "second");
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+    assert!(event_loop.event_reader.position() > after_first);
+    assert!(!event_loop.process_events_from_jsonl().unwrap());
+}
+
+#[test]
+fn test_agent_event_survives_rotation_between_reads() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.task", "first");
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+
+    // The agent emits, then the runner's logger rotates before the next read
+    write_event_to_jsonl(&events_path, "work.item", "emitted mid-iteration");
+    let mut logger = crate::EventLogger::new(&events_path).with_rotation(1, 2);
+    logger
+        .log_event(
+            1,
+            "ralph",
+            &Event::new("loop.note", "forces rotation"),
+            None,
+        )
+        .unwrap();
+    assert!(temp_dir.path().join("events.jsonl.1").exists());
+
+    let _ = event_loop.process_events_from_jsonl();
+    let topics: Vec<String> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .map(|e| e.topic.to_string())
+        .collect();
+    assert!(topics.contains(&"work.item".to_string()), "Got: {topics:?}");
+}
+
 #[test]
 fn test_loop_thrashing_detection() {
     use tempfile::tempdir;
@@ -1022,6 +1521,42 @@ fn test_planner_auto_cancellation_after_three_blocks() {
     );
 }
 
+#[test]
+fn test_abandoned_task_redispatch_is_dropped_and_counted() {
+    let (_temp_dir, events_path, mut event_loop) = event_loop_reading_jsonl(RalphConfig::default());
+    for attempt in ["can't compile", "still can't compile", "really stuck"] {
+        write_event_to_jsonl(
+            &events_path,
+            "build.blocked",
+            &format!("Fix bug\n{attempt}"),
+        );
+        event_loop.process_events_from_jsonl().unwrap();
+    }
+    assert_eq!(event_loop.state.abandoned_task_redispatches, 0);
+
+    // The abandoned task is not dispatched again; other tasks still are.
+    write_event_to_jsonl(&events_path, "build.task", "Fix bug\ntry once more");
+    write_event_to_jsonl(&events_path, "build.task", "Write docs");
+    event_loop.process_events_from_jsonl().unwrap();
+    assert_eq!(event_loop.state.abandoned_task_redispatches, 1);
+    assert_eq!(
+        event_loop.state.last_emitted_topic.as_deref(),
+        Some("build.task")
+    );
+    assert_eq!(event_loop.state.consecutive_same_topic, 1);
+    assert_eq!(event_loop.check_termination(), None);
+
+    for _ in 0..2 {
+        write_event_to_jsonl(&events_path, "build.task", "Fix bug");
+        event_loop.process_events_from_jsonl().unwrap();
+    }
+    assert_eq!(event_loop.state.abandoned_task_redispatches, 3);
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::LoopThrashing)
+    );
+}
+
 #[test]
 fn test_default_publishes_injects_when_no_events() {
     use std::collections::HashMap;
@@ -1813,21 +2348,58 @@ fn test_consecutive_failures_resets_on_success() {
 }
 
 #[test]
-fn test_cost_based_termination() {
-    // Kills: line 383 `>=` → `<`, lines 987 `add_cost` noop / `-=` / `*=`
-    let yaml = r"
-event_loop:
-  max_cost_usd: 10.0
-";
-    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+fn test_failure_backoff_grows_with_failures_and_resets_on_success() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_consecutive_failures = 10;
+    config.event_loop.failure_backoff_seconds = 10;
+    config.event_loop.max_failure_backoff_seconds = 30;
     let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
 
-    event_loop.add_cost(9.99);
-    assert_eq!(
-        event_loop.check_termination(),
-        None,
-        "Should NOT terminate below max cost"
-    );
+    let ralph = HatId::new("ralph");
+    assert_eq!(event_loop.failure_backoff(), Duration::ZERO);
+
+    let mut delays = Vec::new();
+    for _ in 0..4 {
+        event_loop.process_output(&ralph, "output", false);
+        delays.push(event_loop.failure_backoff().as_secs());
+    }
+    assert_eq!(delays, vec![10, 20, 30, 30], "doubles, then capped");
+
+    event_loop.process_output(&ralph, "output", true);
+    assert_eq!(event_loop.failure_backoff(), Duration::ZERO);
+
+    event_loop.process_output(&ralph, "output", false);
+    assert_eq!(event_loop.failure_backoff(), Duration::from_secs(10));
+}
+
+#[test]
+fn test_failure_backoff_disabled_by_default() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+
+    event_loop.process_output(&HatId::new("ralph"), "output", false);
+
+    assert_eq!(event_loop.state.consecutive_failures, 1);
+    assert_eq!(event_loop.failure_backoff(), Duration::ZERO);
+}
+
+#[test]
+fn test_cost_based_termination() {
+    // Kills: line 383 `>=` → `<`, lines 987 `add_cost` noop / `-=` / `*=`
+    let yaml = r"
+event_loop:
+  max_cost_usd: 10.0
+";
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.add_cost(9.99);
+    assert_eq!(
+        event_loop.check_termination(),
+        None,
+        "Should NOT terminate below max cost"
+    );
 
     event_loop.add_cost(0.01);
     assert_eq!(
@@ -1872,6 +2444,50 @@ fn test_malformed_events_increment_counter() {
     );
 }
 
+#[test]
+fn test_malformed_output_events_count_toward_validation_failure() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+
+    let output = r#"<event topic="build.done">tests: pass
+<event kind="no-topic">x</event>"#;
+    let parsed = EventParser::new().parse_with_malformed(output);
+    assert_eq!(parsed.malformed, 2);
+    event_loop.record_malformed_output_events(parsed.malformed);
+    assert_eq!(event_loop.state.consecutive_malformed_output_iterations, 1);
+
+    event_loop.record_malformed_output_events(1);
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.record_malformed_output_events(1);
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::ValidationFailure)
+    );
+}
+
+#[test]
+fn test_quoted_event_fragments_in_one_reply_do_not_fail_validation() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+
+    // Prose and a code sample quoting stray `<event` fragments.
+    let output = r#"To emit, write `<event topic="x">` on its own line.
+For example, a tag like <event kind="done"> without a topic is rejected,
+and so is an unterminated <event topic="build.done">tests: pass"#;
+    let parsed = EventParser::new().parse_with_malformed(output);
+    assert!(parsed.malformed >= 3, "malformed: {}", parsed.malformed);
+
+    event_loop.record_malformed_output_events(parsed.malformed);
+    assert_eq!(event_loop.check_termination(), None);
+
+    // A clean iteration resets the streak.
+    event_loop.record_malformed_output_events(1);
+    event_loop.record_malformed_output_events(0);
+    event_loop.record_malformed_output_events(1);
+    assert_eq!(event_loop.check_termination(), None);
+}
+
 #[test]
 fn test_malformed_counter_resets_on_valid_event() {
     // Kills: line 1072 `!` deletion
@@ -1921,6 +2537,60 @@ fn test_validation_failure_termination_at_threshold() {
     );
 }
 
+/// Builds a loop reading events from a temp JSONL file.
+fn event_loop_reading_jsonl(
+    config: RalphConfig,
+) -> (tempfile::TempDir, std::path::PathBuf, EventLoop) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    (temp_dir, events_path, event_loop)
+}
+
+#[test]
+fn test_repeated_topic_terminates_as_stale() {
+    let (_temp_dir, events_path, mut event_loop) = event_loop_reading_jsonl(RalphConfig::default());
+
+    for _ in 0..2 {
+        write_event_to_jsonl(&events_path, "build.task", "same task");
+        event_loop.process_events_from_jsonl().unwrap();
+    }
+    assert_eq!(event_loop.check_termination(), None);
+
+    write_event_to_jsonl(&events_path, "build.task", "same task");
+    event_loop.process_events_from_jsonl().unwrap();
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::LoopStale)
+    );
+}
+
+#[test]
+fn test_stale_topic_threshold_is_configurable() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_consecutive_same_topic = 5;
+    let (_temp_dir, events_path, mut event_loop) = event_loop_reading_jsonl(config);
+
+    for _ in 0..4 {
+        write_event_to_jsonl(&events_path, "build.blocked", "stuck");
+        event_loop.process_events_from_jsonl().unwrap();
+    }
+    assert_eq!(event_loop.check_termination(), None);
+
+    // A different topic resets the run.
+    write_event_to_jsonl(&events_path, "build.task", "retry");
+    event_loop.process_events_from_jsonl().unwrap();
+    write_event_to_jsonl(&events_path, "build.blocked", "stuck");
+    event_loop.process_events_from_jsonl().unwrap();
+    assert_eq!(event_loop.state.consecutive_same_topic, 1);
+
+    event_loop.config.event_loop.max_consecutive_same_topic = 0;
+    event_loop.state.consecutive_same_topic = 100;
+    assert_eq!(event_loop.check_termination(), None, "0 disables the check");
+}
+
 #[test]
 fn test_stop_requested_termination_clears_signal() {
     use tempfile::tempdir;
@@ -1945,6 +2615,85 @@ fn test_stop_requested_termination_clears_signal() {
     );
 }
 
+#[test]
+fn test_pause_requested_follows_signal_file() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let event_loop = EventLoop::new(config);
+
+    assert!(!event_loop.pause_requested());
+
+    let pause_path = temp_dir.path().join(".ralph/pause-requested");
+    std::fs::create_dir_all(pause_path.parent().unwrap()).unwrap();
+    std::fs::write(&pause_path, "").unwrap();
+    assert!(event_loop.pause_requested());
+    assert_eq!(
+        event_loop.check_termination(),
+        None,
+        "Pausing holds the loop rather than terminating it"
+    );
+
+    std::fs::remove_file(&pause_path).unwrap();
+    assert!(!event_loop.pause_requested());
+}
+
+#[test]
+fn test_stale_pause_is_cleared_and_stop_signals_are_visible() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let event_loop = EventLoop::new(config);
+    let ralph_dir = temp_dir.path().join(".ralph");
+    std::fs::create_dir_all(&ralph_dir).unwrap();
+
+    // Left behind by a crashed run
+    std::fs::write(ralph_dir.join("pause-requested"), "").unwrap();
+    event_loop.clear_stale_pause();
+    assert!(!event_loop.pause_requested());
+    event_loop.clear_stale_pause();
+
+    assert!(!event_loop.stop_requested());
+    std::fs::write(ralph_dir.join("cancel-requested"), "").unwrap();
+    assert!(event_loop.stop_requested());
+    // Peeking leaves the signal for check_termination
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::Stopped)
+    );
+    assert!(!event_loop.stop_requested());
+}
+
+#[test]
+fn test_cancel_requested_termination_clears_signal() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let event_loop = EventLoop::new(config);
+
+    assert_eq!(event_loop.check_termination(), None);
+
+    let cancel_path = temp_dir.path().join(".ralph/cancel-requested");
+    std::fs::create_dir_all(cancel_path.parent().unwrap()).unwrap();
+    std::fs::write(&cancel_path, "").unwrap();
+
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::Stopped),
+        "Should terminate when cancel requested signal exists"
+    );
+    assert!(
+        !cancel_path.exists(),
+        "Cancel signal should be removed after detection"
+    );
+}
+
 #[test]
 fn test_format_event_wraps_top_level_prompts() {
     // Kills: line 761 `==` → `!=` and `||` → `&&`
@@ -2251,6 +3000,131 @@ fn test_build_done_backpressure_rejects_high_complexity() {
     );
 }
 
+fn pending_topics_after_build_done(verification_command: &str) -> Vec<String> {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.verification_command = Some(verification_command.to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // No evidence in the payload: the verifier decides, not the agent's claim.
+    write_event_to_jsonl(&events_path, "build.done", "all good, trust me");
+    let _ = event_loop.process_events_from_jsonl();
+
+    event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .map(|e| e.topic.to_string())
+        .collect()
+}
+
+#[test]
+fn test_verification_command_pass_publishes_build_done() {
+    let topics = pending_topics_after_build_done("exit 0");
+
+    assert!(
+        topics.contains(&"build.done".to_string()),
+        "Got: {topics:?}"
+    );
+    assert!(!topics.contains(&"build.blocked".to_string()));
+}
+
+#[test]
+fn test_verification_command_fail_publishes_build_blocked() {
+    let topics = pending_topics_after_build_done("exit 1");
+
+    assert!(
+        topics.contains(&"build.blocked".to_string()),
+        "Got: {topics:?}"
+    );
+    assert!(!topics.contains(&"build.done".to_string()));
+}
+
+#[tokio::test]
+async fn test_async_verification_runs_after_batch_and_times_out() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.verification_command = Some("sleep 30".to_string());
+    config.event_loop.verification_timeout_seconds = 1;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "done");
+    let started = std::time::Instant::now();
+    let _ = event_loop.process_events_from_jsonl_async().await;
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+
+    let blocked: Vec<_> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .filter(|e| e.topic.as_str() == "build.blocked")
+        .map(|e| e.payload.clone())
+        .collect();
+    assert_eq!(blocked.len(), 1, "Got: {blocked:?}");
+    assert!(blocked[0].contains("timed out"), "{}", blocked[0]);
+}
+
+#[test]
+fn test_verification_failure_output_injected_into_next_prompt() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.verification_command =
+        Some("echo 'error[E0308]: mismatched types in src/lib.rs'; exit 1".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "done");
+    let _ = event_loop.process_events_from_jsonl();
+
+    let ralph_id = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("## VERIFICATION FAILURE"), "{prompt}");
+    assert!(prompt.contains("error[E0308]: mismatched types in src/lib.rs"));
+
+    // Injected once: the following prompt no longer carries it.
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(!prompt.contains("## VERIFICATION FAILURE"));
+}
+
+#[test]
+fn test_verification_failure_output_respects_max_chars() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.verification_command =
+        Some("echo 'noisy preamble'; echo 'real error'; exit 1".to_string());
+    config.event_loop.verification_output_max_chars = 10;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "done");
+    let _ = event_loop.process_events_from_jsonl();
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("chars truncated)\nreal error"), "{prompt}");
+}
+
 #[test]
 fn test_build_done_backpressure_rejects_duplication() {
     use tempfile::tempdir;
@@ -2720,7 +3594,7 @@ fn test_termination_reason_mappings() {
         (
             TerminationReason::ConsecutiveFailures,
             "consecutive_failures",
-            1,
+            3,
             false,
         ),
         (TerminationReason::LoopThrashing, "loop_thrashing", 1, false),
@@ -2730,12 +3604,12 @@ fn test_termination_reason_mappings() {
             1,
             false,
         ),
-        (TerminationReason::Stopped, "stopped", 1, false),
+        (TerminationReason::Stopped, "stopped", 130, false),
         (TerminationReason::Interrupted, "interrupted", 130, false),
         (
             TerminationReason::RestartRequested,
             "restart_requested",
-            3,
+            4,
             false,
         ),
     ];
@@ -2768,6 +3642,10 @@ fn test_termination_status_texts() {
             TerminationReason::LoopThrashing,
             "Loop thrashing detected - same hat repeatedly blocked.",
         ),
+        (
+            TerminationReason::LoopStale,
+            "Stale loop detected - same topic emitted too many times consecutively (event_loop.max_consecutive_same_topic).",
+        ),
         (
             TerminationReason::ValidationFailure,
             "Too many consecutive malformed JSONL events.",
@@ -2791,7 +3669,7 @@ fn test_format_duration_variants() {
 
     assert_eq!(format_duration(Duration::from_secs(45)), "45s");
     assert_eq!(format_duration(Duration::from_secs(61)), "1m 1s");
-    assert_eq!(format_duration(Duration::from_secs(3600)), "1h 0m 0s");
+    assert_eq!(format_duration(Duration::from_hours(1)), "1h 0m 0s");
     assert_eq!(format_duration(Duration::from_secs(3661)), "1h 1m 1s");
 }
 
@@ -2972,15 +3850,15 @@ fn test_verify_scratchpad_complete_variants() {
 fn test_termination_reason_exit_codes() {
     let cases = [
         (TerminationReason::CompletionPromise, 0),
-        (TerminationReason::ConsecutiveFailures, 1),
+        (TerminationReason::ConsecutiveFailures, 3),
         (TerminationReason::LoopThrashing, 1),
         (TerminationReason::ValidationFailure, 1),
-        (TerminationReason::Stopped, 1),
+        (TerminationReason::Stopped, 130),
         (TerminationReason::MaxIterations, 2),
         (TerminationReason::MaxRuntime, 2),
         (TerminationReason::MaxCost, 2),
         (TerminationReason::Interrupted, 130),
-        (TerminationReason::RestartRequested, 3),
+        (TerminationReason::RestartRequested, 4),
     ];
 
     for (reason, code) in cases {
@@ -3025,6 +3903,37 @@ fn test_termination_reason_strings_and_flags() {
     }
 }
 
+#[test]
+fn test_termination_reason_serde_round_trip() {
+    let cases = [
+        (TerminationReason::CompletionPromise, "completion_promise"),
+        (TerminationReason::MaxIterations, "max_iterations"),
+        (TerminationReason::MaxRuntime, "max_runtime"),
+        (TerminationReason::MaxCost, "max_cost"),
+        (
+            TerminationReason::ConsecutiveFailures,
+            "consecutive_failures",
+        ),
+        (TerminationReason::LoopThrashing, "loop_thrashing"),
+        (TerminationReason::LoopStale, "loop_stale"),
+        (TerminationReason::ValidationFailure, "validation_failure"),
+        (TerminationReason::HookFailed, "hook_failed"),
+        (TerminationReason::Stopped, "stopped"),
+        (TerminationReason::Interrupted, "interrupted"),
+        (TerminationReason::RestartRequested, "restart_requested"),
+        (TerminationReason::Cancelled, "cancelled"),
+        (TerminationReason::SingleIteration, "single_iteration"),
+    ];
+
+    for (reason, name) in cases {
+        assert_eq!(reason.to_string(), name);
+        let json = serde_json::to_string(&reason).unwrap();
+        assert_eq!(json, format!("\"{name}\""));
+        let parsed: TerminationReason = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, reason);
+    }
+}
+
 #[test]
 fn test_has_pending_human_events_detects_guidance() {
     let mut event_loop = EventLoop::new(RalphConfig::default());
@@ -3414,6 +4323,93 @@ fn test_chain_validation_accepts_completion_with_all_required_events() {
     );
 }
 
+#[test]
+fn test_early_completion_is_blocked_until_required_event_arrives() {
+    let mut config = RalphConfig::default();
+    config.event_loop.required_events = vec!["build.done".to_string()];
+    let (_temp_dir, events_path, mut event_loop) = event_loop_reading_jsonl(config);
+    event_loop.initialize("Test");
+    let ralph = HatId::new("ralph");
+    let _ = event_loop.bus.take_pending(&ralph);
+
+    // Completion before build.done is deferred.
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done early");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_completion_event(), None);
+
+    let pending = event_loop.bus.take_pending(&ralph);
+    let blocked = pending
+        .iter()
+        .find(|e| e.topic.as_str() == COMPLETION_BLOCKED_TOPIC)
+        .expect("completion.blocked should be published for Ralph");
+    assert!(blocked.payload.contains("- build.done"));
+    assert!(
+        !pending.iter().any(|e| e.topic.as_str() == "task.resume"),
+        "Blocked completion should not masquerade as task.resume"
+    );
+
+    // Once build.done is seen, completion goes through.
+    let evidence = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass\nperformance: pass";
+    write_event_to_jsonl(&events_path, "build.done", evidence);
+    let _ = event_loop.process_events_from_jsonl();
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
+#[test]
+fn test_completion_held_until_build_done_with_evidence() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.completion_evidence_topic = Some("build.done".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Completing straight away is rejected with a corrective resume
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_completion_event(), None);
+    let resume = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .find(|e| e.topic.as_str() == "task.resume")
+        .expect("corrective task.resume published");
+    assert!(
+        resume.payload.contains("Run and report tests"),
+        "{}",
+        resume.payload
+    );
+
+    // build.done without evidence is turned into build.blocked, so still held
+    write_event_to_jsonl(&events_path, "build.done", "done, trust me");
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_completion_event(), None);
+
+    // Passing evidence unlocks completion
+    write_event_to_jsonl(
+        &events_path,
+        "build.done",
+        "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass",
+    );
+    let _ = event_loop.process_events_from_jsonl();
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
 #[test]
 fn test_chain_validation_tracks_topics_across_iterations() {
     use tempfile::TempDir;
@@ -3704,7 +4700,7 @@ fn test_loop_cancel_disabled_when_empty_string() {
 
 // ── Phase 3: Human Timeout Event Injection Tests ──────────────────────
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
@@ -3794,3 +4790,290 @@ fn test_human_response_still_works() {
         "human.response event should be published when response received"
     );
 }
+
+/// Records termination notices; everything else is a no-op.
+struct RecordingRobotService {
+    terminations: Arc<std::sync::Mutex<Vec<ralph_proto::TerminationContext>>>,
+}
+
+impl ralph_proto::RobotService for RecordingRobotService {
+    fn send_question(&self, _payload: &str) -> anyhow::Result<i32> {
+        Ok(0)
+    }
+    fn wait_for_response(&self, _events_path: &Path) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+    fn send_checkin(
+        &self,
+        _: u32,
+        _: Duration,
+        _: Option<&ralph_proto::CheckinContext>,
+    ) -> anyhow::Result<i32> {
+        Ok(0)
+    }
+    fn send_termination(&self, context: &ralph_proto::TerminationContext) -> anyhow::Result<()> {
+        self.terminations
+            .lock()
+            .unwrap()
+            .push(ralph_proto::TerminationContext {
+                reason: context.reason.clone(),
+                iterations: context.iterations,
+                elapsed: context.elapsed,
+                cumulative_cost: context.cumulative_cost,
+                scratchpad: context.scratchpad.clone(),
+            });
+        Ok(())
+    }
+    fn timeout_secs(&self) -> u64 {
+        0
+    }
+    fn shutdown_flag(&self) -> Arc<AtomicBool> {
+        Arc::new(AtomicBool::new(false))
+    }
+    fn stop(self: Box<Self>) {}
+}
+
+#[test]
+fn test_terminate_event_sends_termination_through_robot_service() {
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = PathBuf::from("/work");
+    config.core.scratchpad = ".ralph/agent/scratchpad.md".to_string();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    event_loop.state.iteration = 3;
+    event_loop.state.cumulative_cost = 0.5;
+
+    let terminations = Arc::new(std::sync::Mutex::new(Vec::new()));
+    event_loop.set_robot_service(Box::new(RecordingRobotService {
+        terminations: Arc::clone(&terminations),
+    }));
+
+    event_loop.publish_terminate_event(&TerminationReason::CompletionPromise);
+    // The service is stopped after the notice, so a second termination sends nothing.
+    event_loop.publish_terminate_event(&TerminationReason::CompletionPromise);
+
+    let sent = terminations.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].reason, "completed");
+    assert_eq!(sent[0].iterations, 3);
+    assert!((sent[0].cumulative_cost - 0.5).abs() < f64::EPSILON);
+    assert_eq!(
+        sent[0].scratchpad,
+        PathBuf::from("/work/.ralph/agent/scratchpad.md")
+    );
+}
+
+#[test]
+fn test_explain_routing_names_selected_hat_and_matched_topic() {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.*"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    triggers: ["review.request"]
+    publishes: ["review.done"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    assert!(event_loop.explain_routing().is_none());
+
+    event_loop
+        .bus
+        .publish(Event::new("build.task", "Implement auth"));
+
+    let explanation = event_loop.explain_routing().unwrap().join("\n");
+    assert!(
+        explanation.contains("pending: build.task -> queued for builder"),
+        "Expected pending event line, got:\n{explanation}"
+    );
+    assert!(
+        explanation.contains("selected hat: builder (Builder)"),
+        "Expected selected hat, got:\n{explanation}"
+    );
+    assert!(
+        explanation.contains("'build.task' matched subscription 'build.*'"),
+        "Expected matched topic and pattern, got:\n{explanation}"
+    );
+    assert!(explanation.contains("executor: ralph"));
+}
+
+#[test]
+fn test_explain_routing_reports_fallback_when_no_hat_subscribes() {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.initialize("Test prompt");
+
+    let explanation = event_loop.explain_routing().unwrap().join("\n");
+    assert!(explanation.contains("pending: task.start -> queued for ralph"));
+    assert!(explanation.contains("selected hat: ralph"));
+}
+
+#[test]
+fn test_events_per_iteration_are_capped() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.max_events_per_iteration = 5;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    for i in 0..200 {
+        write_event_to_jsonl(&events_path, "work.item", &format!("item {i}"));
+    }
+    let _ = event_loop.process_events_from_jsonl();
+
+    let published: usize = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .filter(|e| e.topic.as_str() == "work.item")
+        .count();
+    assert_eq!(published, 5);
+}
+
+#[test]
+fn test_events_per_iteration_default_cap_is_generous() {
+    use tempfile::tempdir;
+
+    let count_published = |config: RalphConfig| {
+        let temp_dir = tempdir().unwrap();
+        let events_path = temp_dir.path().join("events.jsonl");
+        let mut event_loop = EventLoop::new(config);
+        event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+        for i in 0..250 {
+            write_event_to_jsonl(&events_path, "work.item", &format!("item {i}"));
+        }
+        let _ = event_loop.process_events_from_jsonl();
+
+        event_loop
+            .bus
+            .hat_ids()
+            .flat_map(|id| event_loop.bus.pending_for(id))
+            .filter(|e| e.topic.as_str() == "work.item")
+            .count()
+    };
+
+    let config = RalphConfig::default();
+    assert_eq!(config.event_loop.max_events_per_iteration, 200);
+    assert_eq!(count_published(config), 200);
+
+    // 0 opts out of the cap
+    let mut config = RalphConfig::default();
+    config.event_loop.max_events_per_iteration = 0;
+    assert_eq!(count_published(config), 250);
+}
+
+#[test]
+fn test_topic_rate_limit_from_config_drops_only_limited_topic() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.topic_rate_limits.insert(
+        "build.task".to_string(),
+        crate::config::TopicRateLimitConfig {
+            max: 2,
+            window_seconds: 60,
+        },
+    );
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    for i in 0..6 {
+        write_event_to_jsonl(&events_path, "build.task", &format!("task {i}"));
+        write_event_to_jsonl(&events_path, "work.item", &format!("item {i}"));
+    }
+    let _ = event_loop.process_events_from_jsonl();
+
+    let pending = |topic: &str| -> usize {
+        event_loop
+            .bus
+            .hat_ids()
+            .flat_map(|id| event_loop.bus.pending_for(id))
+            .filter(|e| e.topic.as_str() == topic)
+            .count()
+    };
+    assert_eq!(pending("build.task"), 2);
+    assert_eq!(pending("work.item"), 6);
+}
+
+#[test]
+fn test_topic_rate_limit_overlapping_patterns_use_most_specific() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    for (pattern, max) in [("work.*", 1), ("work.done", 3)] {
+        config.event_loop.topic_rate_limits.insert(
+            pattern.to_string(),
+            crate::config::TopicRateLimitConfig {
+                max,
+                window_seconds: 60,
+            },
+        );
+    }
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    for i in 0..5 {
+        write_event_to_jsonl(&events_path, "work.done", &format!("done {i}"));
+        write_event_to_jsonl(&events_path, "work.item", &format!("item {i}"));
+    }
+    let _ = event_loop.process_events_from_jsonl();
+
+    let pending = |topic: &str| -> usize {
+        event_loop
+            .bus
+            .hat_ids()
+            .flat_map(|id| event_loop.bus.pending_for(id))
+            .filter(|e| e.topic.as_str() == topic)
+            .count()
+    };
+    assert_eq!(pending("work.done"), 3);
+    assert_eq!(pending("work.item"), 1);
+}
+
+#[test]
+fn test_topic_rate_limit_does_not_throttle_orchestrator_events() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.topic_rate_limits.insert(
+        "*".to_string(),
+        crate::config::TopicRateLimitConfig {
+            max: 0,
+            window_seconds: 60,
+        },
+    );
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    event_loop.initialize_resume("Resume");
+    write_event_to_jsonl(&events_path, "task.resume", "from agent");
+    let _ = event_loop.process_events_from_jsonl();
+
+    let resumes: Vec<_> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.pending_for(id))
+        .filter(|e| e.topic.as_str() == "task.resume")
+        .map(|e| e.payload.clone())
+        .collect();
+    assert_eq!(resumes, vec!["Resume".to_string()]);
+}
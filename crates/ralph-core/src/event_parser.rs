@@ -6,7 +6,12 @@
 //! <event topic="handoff" target="reviewer">payload</event>
 //! ```
 
+use crate::event_reader::deserialize_flexible_payload;
 use ralph_proto::{Event, HatId};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Range;
+use tracing::{debug, warn};
 
 /// Strips ANSI escape sequences from a string.
 ///
@@ -216,11 +221,37 @@ impl QualityReport {
     }
 }
 
+/// Events parsed from CLI output, plus how many tags were malformed.
+#[derive(Debug, Default)]
+pub struct ParsedEvents {
+    pub events: Vec<Event>,
+    pub malformed: usize,
+}
+
 /// Parser for extracting events from CLI output.
 #[derive(Debug, Default)]
 pub struct EventParser {
     /// The source hat ID to attach to parsed events.
     source: Option<HatId>,
+
+    /// Stop parsing after this many events. `None` means unlimited.
+    max_events: Option<usize>,
+
+    /// Also parse JSON event lines alongside `<event>` tags.
+    jsonl: bool,
+
+    /// Alias topics rewritten to their canonical topic.
+    topic_aliases: HashMap<String, String>,
+}
+
+/// A JSON event line in agent output.
+#[derive(Deserialize)]
+struct JsonlEvent {
+    topic: String,
+    #[serde(default, deserialize_with = "deserialize_flexible_payload")]
+    payload: Option<String>,
+    #[serde(default)]
+    target: Option<String>,
 }
 
 impl EventParser {
@@ -235,19 +266,118 @@ impl EventParser {
         self
     }
 
+    /// Also parses JSON event lines, as written by `ralph emit`, from output.
+    ///
+    /// Each line must be a single object with a `topic`; `payload` may be a
+    /// string or structured JSON, and `target` is optional.
+    pub fn with_jsonl(mut self, enabled: bool) -> Self {
+        self.jsonl = enabled;
+        self
+    }
+
+    /// Caps the number of events returned by [`parse`](Self::parse).
+    ///
+    /// Output beyond the cap is ignored with a warning. 0 means unlimited.
+    pub fn with_max_events(mut self, max: usize) -> Self {
+        self.max_events = (max > 0).then_some(max);
+        self
+    }
+
+    /// Rewrites parsed topics found in `aliases` to their canonical topic.
+    pub fn with_topic_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        self.topic_aliases = aliases;
+        self
+    }
+
+    /// Returns the canonical topic for `topic`, or `None` if it isn't an alias.
+    ///
+    /// Aliases resolve in a single step: a canonical topic is never itself
+    /// looked up again.
+    pub fn resolve_topic_alias<'a>(
+        topic: &str,
+        aliases: &'a HashMap<String, String>,
+    ) -> Option<&'a str> {
+        let canonical = aliases.get(topic)?;
+        debug!(alias = %topic, canonical = %canonical, "Resolved topic alias");
+        Some(canonical)
+    }
+
     /// Parses events from CLI output text.
     ///
-    /// Returns a list of parsed events.
+    /// Returns a list of parsed events, at most `max_events` long. See
+    /// [`parse_with_malformed`](Self::parse_with_malformed) for how malformed
+    /// tags are handled.
     pub fn parse(&self, output: &str) -> Vec<Event> {
-        let mut events = Vec::new();
-        let mut remaining = output;
+        self.parse_with_malformed(output).events
+    }
 
-        while let Some(start_idx) = remaining.find("<event ") {
-            let after_start = &remaining[start_idx..];
+    /// Parses events from CLI output text, counting malformed tags.
+    ///
+    /// An `<event>` with no `</event>` before the next `<event ` (or the end of
+    /// output) is recovered with everything up to that point as its payload.
+    /// Unclosed, unterminated, and topic-less tags all count as malformed.
+    ///
+    /// With [`with_jsonl`](Self::with_jsonl), JSON event lines outside of
+    /// `<event>` tags are parsed too, merged in output order.
+    pub fn parse_with_malformed(&self, output: &str) -> ParsedEvents {
+        let mut malformed = 0;
+        let mut found = self.parse_xml(output, &mut malformed);
+
+        if self.jsonl {
+            let spans: Vec<_> = found.iter().map(|(span, _)| span.clone()).collect();
+            found.extend(Self::parse_jsonl(output, &spans, &mut malformed));
+            found.sort_by_key(|(span, _)| span.start);
+        }
+
+        if let Some(max) = self.max_events
+            && found.len() > max
+        {
+            warn!(
+                max_events = max,
+                "Event limit reached for this output; ignoring remaining events"
+            );
+            found.truncate(max);
+        }
+
+        let events = found
+            .into_iter()
+            .map(|(_, mut event)| {
+                if let Some(canonical) =
+                    Self::resolve_topic_alias(event.topic.as_str(), &self.topic_aliases)
+                {
+                    event.topic = canonical.into();
+                }
+                match &self.source {
+                    Some(source) => event.with_source(source.clone()),
+                    None => event,
+                }
+            })
+            .collect();
+
+        ParsedEvents { events, malformed }
+    }
+
+    /// Parses `<event>` tags, returning each event with its byte span.
+    fn parse_xml(&self, output: &str, malformed: &mut usize) -> Vec<(Range<usize>, Event)> {
+        let mut found = Vec::new();
+        let mut offset = 0;
+
+        while let Some(found_at) = output[offset..].find("<event ") {
+            // JSONL events may still precede the cap, so only stop early without them
+            if let Some(max) = self.max_events
+                && !self.jsonl
+                && found.len() > max
+            {
+                break;
+            }
+
+            let start = offset + found_at;
+            let after_start = &output[start..];
 
             // Find the end of the opening tag
             let Some(tag_end) = after_start.find('>') else {
-                remaining = &remaining[start_idx + 7..];
+                *malformed += 1;
+                offset = start + 7;
                 continue;
             };
 
@@ -258,37 +388,85 @@ impl EventParser {
             let target = Self::extract_attr(opening_tag, "target");
 
             let Some(topic) = topic else {
-                remaining = &remaining[start_idx + tag_end + 1..];
+                *malformed += 1;
+                offset = start + tag_end + 1;
                 continue;
             };
 
-            // Find the closing tag
+            // Find the closing tag, unless another event opens first
             let content_start = &after_start[tag_end + 1..];
-            let Some(close_idx) = content_start.find("</event>") else {
-                remaining = &remaining[start_idx + tag_end + 1..];
-                continue;
+            let next_open = content_start.find("<event ");
+            let (payload_end, consumed) = match content_start.find("</event>") {
+                Some(close_idx) if next_open.is_none_or(|open| close_idx < open) => {
+                    (close_idx, close_idx + 8) // 8 = "</event>".len()
+                }
+                _ => {
+                    let end = next_open.unwrap_or(content_start.len());
+                    warn!(topic = %topic, "Unclosed <event> tag; recovering payload");
+                    *malformed += 1;
+                    (end, end)
+                }
             };
 
-            let payload = content_start[..close_idx].trim().to_string();
+            let payload = content_start[..payload_end].trim().to_string();
 
             let mut event = Event::new(topic, payload);
 
-            if let Some(source) = &self.source {
-                event = event.with_source(source.clone());
-            }
-
             if let Some(target) = target {
                 event = event.with_target(target);
             }
 
-            events.push(event);
-
             // Move past this event
-            let total_consumed = start_idx + tag_end + 1 + close_idx + 8; // 8 = "</event>".len()
-            remaining = &remaining[total_consumed..];
+            offset = start + tag_end + 1 + consumed;
+            found.push((start..offset, event));
         }
 
-        events
+        found
+    }
+
+    /// Parses JSON event lines (`{"topic": ..., "payload": ...}`) that fall
+    /// outside every span in `skip`.
+    ///
+    /// Lines that look like JSON objects mentioning `"topic"` but fail to parse
+    /// count as malformed; other lines are ordinary output and are ignored.
+    fn parse_jsonl(
+        output: &str,
+        skip: &[Range<usize>],
+        malformed: &mut usize,
+    ) -> Vec<(Range<usize>, Event)> {
+        let mut found = Vec::new();
+        let mut line_start = 0;
+
+        for line in output.split_inclusive('\n') {
+            let span = line_start..line_start + line.len();
+            line_start = span.end;
+
+            let trimmed = line.trim();
+            if !(trimmed.starts_with('{') && trimmed.ends_with('}'))
+                || skip
+                    .iter()
+                    .any(|s| s.start < span.end && span.start < s.end)
+            {
+                continue;
+            }
+
+            match serde_json::from_str::<JsonlEvent>(trimmed) {
+                Ok(json) => {
+                    let mut event = Event::new(json.topic, json.payload.unwrap_or_default());
+                    if let Some(target) = json.target {
+                        event = event.with_target(target);
+                    }
+                    found.push((span, event));
+                }
+                Err(e) if trimmed.contains("\"topic\"") => {
+                    warn!(error = %e, "Malformed JSONL event line; skipping");
+                    *malformed += 1;
+                }
+                Err(_) => {}
+            }
+        }
+
+        found
     }
 
     /// Extracts an attribute value from an XML-like tag.
@@ -743,6 +921,21 @@ Working on implementation...
         assert_eq!(events[1].topic.as_str(), "impl.done");
     }
 
+    #[test]
+    fn test_parse_stops_at_max_events() {
+        let output = (0..1000)
+            .map(|i| format!("<event topic=\"spam.{i}\">x</event>"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let events = EventParser::new().with_max_events(10).parse(&output);
+        assert_eq!(events.len(), 10);
+        assert_eq!(events[9].topic.as_str(), "spam.9");
+
+        let unlimited = EventParser::new().with_max_events(0).parse(&output);
+        assert_eq!(unlimited.len(), 1000);
+    }
+
     #[test]
     fn test_parse_with_source() {
         let output = r#"<event topic="impl.done">Done</event>"#;
@@ -752,6 +945,152 @@ Working on implementation...
         assert_eq!(events[0].source.as_ref().unwrap().as_str(), "implementer");
     }
 
+    #[test]
+    fn test_parse_recovers_unclosed_event() {
+        let output = "<event topic=\"build.done\">tests: pass\nlint: pass\n";
+
+        let parsed = EventParser::new().parse_with_malformed(output);
+
+        assert_eq!(parsed.malformed, 1);
+        assert_eq!(parsed.events.len(), 1);
+        assert_eq!(parsed.events[0].topic.as_str(), "build.done");
+        assert_eq!(parsed.events[0].payload, "tests: pass\nlint: pass");
+    }
+
+    #[test]
+    fn test_parse_unclosed_event_stops_at_next_event() {
+        let output = r#"<event topic="build.task">Fix login
+<event topic="build.done">tests: pass</event>"#;
+
+        let parsed = EventParser::new().parse_with_malformed(output);
+
+        assert_eq!(parsed.malformed, 1);
+        let events: Vec<_> = parsed
+            .events
+            .iter()
+            .map(|e| (e.topic.as_str(), e.payload.as_str()))
+            .collect();
+        assert_eq!(
+            events,
+            vec![("build.task", "Fix login"), ("build.done", "tests: pass")]
+        );
+    }
+
+    #[test]
+    fn test_parse_event_split_across_chunks() {
+        let chunks = [
+            "Working...\n<ev",
+            "ent topic=\"impl",
+            ".done\">Implemented the ",
+            "parser</eve",
+            "nt>\n",
+        ];
+        let output: String = chunks.concat();
+
+        let parsed = EventParser::new().parse_with_malformed(&output);
+
+        assert_eq!(parsed.malformed, 0);
+        assert_eq!(parsed.events.len(), 1);
+        assert_eq!(parsed.events[0].topic.as_str(), "impl.done");
+        assert_eq!(parsed.events[0].payload, "Implemented the parser");
+    }
+
+    #[test]
+    fn test_parse_payload_with_angle_brackets() {
+        let output =
+            r#"<event topic="impl.done">Changed Vec<String> so a < b && <br> works</event>"#;
+
+        let parsed = EventParser::new().parse_with_malformed(output);
+
+        assert_eq!(parsed.malformed, 0);
+        assert_eq!(
+            parsed.events[0].payload,
+            "Changed Vec<String> so a < b && <br> works"
+        );
+    }
+
+    #[test]
+    fn test_parse_rewrites_topic_aliases() {
+        let output = r#"<event topic="tests_passed">tests: pass</event>
+{"topic": "review_please", "payload": "ready"}
+<event topic="build.done">canonical</event>"#;
+        let aliases = HashMap::from([
+            ("tests_passed".to_string(), "build.done".to_string()),
+            ("review_please".to_string(), "review.request".to_string()),
+            // Chains aren't followed: tests_passed stops at build.done
+            ("build.done".to_string(), "build.verified".to_string()),
+        ]);
+
+        let events = EventParser::new()
+            .with_jsonl(true)
+            .with_topic_aliases(aliases)
+            .parse(output);
+
+        let topics: Vec<&str> = events.iter().map(|e| e.topic.as_str()).collect();
+        assert_eq!(topics, ["build.done", "review.request", "build.verified"]);
+        assert_eq!(events[0].payload, "tests: pass");
+    }
+
+    #[test]
+    fn test_parse_counts_tags_without_topic_or_end() {
+        let output = r#"<event kind="x">ignored</event> then <event topic="a""#;
+
+        let parsed = EventParser::new().parse_with_malformed(output);
+
+        assert!(parsed.events.is_empty());
+        assert_eq!(parsed.malformed, 2);
+    }
+
+    #[test]
+    fn test_parse_mixed_xml_and_jsonl_preserves_order() {
+        let output = r#"Starting.
+{"topic": "build.task", "payload": "Fix login"}
+<event topic="build.done">tests: pass
+{"topic": "inside.xml", "payload": "part of the payload"}
+</event>
+{"topic": "review.request", "payload": {"files": ["a.rs"]}, "target": "reviewer"}
+{"not": "an event"}
+<event topic="review.done">LGTM</event>
+"#;
+
+        let parsed = EventParser::new()
+            .with_jsonl(true)
+            .parse_with_malformed(output);
+
+        assert_eq!(parsed.malformed, 0);
+        let topics: Vec<_> = parsed.events.iter().map(|e| e.topic.as_str()).collect();
+        assert_eq!(
+            topics,
+            vec!["build.task", "build.done", "review.request", "review.done"]
+        );
+        assert!(parsed.events[1].payload.contains("inside.xml"));
+        assert_eq!(parsed.events[2].payload, r#"{"files":["a.rs"]}"#);
+        assert_eq!(
+            parsed.events[2].target.as_ref().map(HatId::as_str),
+            Some("reviewer")
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_jsonl_unless_enabled() {
+        let output = "{\"topic\": \"build.done\", \"payload\": \"tests: pass\"}\n";
+
+        assert!(EventParser::new().parse(output).is_empty());
+        assert_eq!(EventParser::new().with_jsonl(true).parse(output).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_jsonl_counts_broken_event_lines() {
+        let output = "{\"topic\": \"build.done\", \"payload\": }\n{\"status\": \"ok\"}\n";
+
+        let parsed = EventParser::new()
+            .with_jsonl(true)
+            .parse_with_malformed(output);
+
+        assert!(parsed.events.is_empty());
+        assert_eq!(parsed.malformed, 1);
+    }
+
     #[test]
     fn test_no_events() {
         let output = "Just regular output with no events.";
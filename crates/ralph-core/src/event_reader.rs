@@ -1,9 +1,10 @@
 //! Event reader for consuming events from `.ralph/events.jsonl`.
 
+use crate::event_logger::{file_id, rotated_path};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
 /// Result of parsing events from a JSONL file.
@@ -60,7 +61,9 @@ impl MalformedLine {
 /// - `"payload": {...}` → `Some("{...}")` (serialized to JSON string)
 /// - `"payload": null` → `None`
 /// - missing field → `None`
-fn deserialize_flexible_payload<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+pub(crate) fn deserialize_flexible_payload<'de, D>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -98,6 +101,8 @@ pub struct Event {
 pub struct EventReader {
     path: PathBuf,
     position: u64,
+    /// Identity of the file `position` refers to, to detect rotation.
+    file_id: Option<(u64, u64)>,
 }
 
 impl EventReader {
@@ -106,6 +111,7 @@ impl EventReader {
         Self {
             path: path.into(),
             position: 0,
+            file_id: None,
         }
     }
 
@@ -120,17 +126,61 @@ impl EventReader {
     ///
     /// Returns an error if the file cannot be opened or read.
     pub fn read_new_events(&mut self) -> std::io::Result<ParseResult> {
+        let mut result = ParseResult::default();
         if !self.path.exists() {
-            return Ok(ParseResult::default());
+            return Ok(result);
+        }
+
+        let file = File::open(&self.path)?;
+        let metadata = file.metadata()?;
+        let current_id = file_id(&metadata);
+        let replaced = match (self.file_id, current_id) {
+            (Some(previous), Some(current)) => previous != current,
+            _ => metadata.len() < self.position,
+        };
+        if replaced {
+            // Rotation moved the file we were reading to `.1`; lines appended
+            // to it after the last read are still unread there.
+            self.drain_rotated(&mut result)?;
+            self.position = 0;
+        }
+        self.file_id = current_id;
+
+        self.position = Self::read_lines(file, &self.path, self.position, &mut result)?;
+        Ok(result)
+    }
+
+    /// Reads the rest of the rotated copy of the file last read, if it is
+    /// still there.
+    fn drain_rotated(&self, result: &mut ParseResult) -> std::io::Result<()> {
+        let rotated = rotated_path(&self.path, 1);
+        let Ok(file) = File::open(&rotated) else {
+            return Ok(());
+        };
+        let metadata = file.metadata()?;
+        let same_file = match (self.file_id, file_id(&metadata)) {
+            (Some(previous), Some(rotated_id)) => previous == rotated_id,
+            _ => metadata.len() >= self.position,
+        };
+        if same_file {
+            Self::read_lines(file, &rotated, self.position, result)?;
         }
+        Ok(())
+    }
 
-        let mut file = File::open(&self.path)?;
-        file.seek(SeekFrom::Start(self.position))?;
+    /// Parses lines of `file` from byte `position` into `result`, returning
+    /// the position after the last line read.
+    fn read_lines(
+        mut file: File,
+        path: &Path,
+        position: u64,
+        result: &mut ParseResult,
+    ) -> std::io::Result<u64> {
+        file.seek(SeekFrom::Start(position))?;
 
         let reader = BufReader::new(file);
-        let mut result = ParseResult::default();
-        let mut current_pos = self.position;
-        let mut line_number = self.count_lines_before_position();
+        let mut current_pos = position;
+        let mut line_number = Self::count_lines_before(path, position);
 
         for line in reader.lines() {
             let line = line?;
@@ -155,24 +205,23 @@ impl EventReader {
             current_pos += line_bytes;
         }
 
-        self.position = current_pos;
-        Ok(result)
+        Ok(current_pos)
     }
 
-    /// Counts lines before the current position (for line numbering).
-    fn count_lines_before_position(&self) -> u64 {
-        if self.position == 0 || !self.path.exists() {
+    /// Counts lines of `path` before `position` (for line numbering).
+    fn count_lines_before(path: &Path, position: u64) -> u64 {
+        if position == 0 || !path.exists() {
             return 0;
         }
         // Read file up to position and count newlines
-        if let Ok(file) = File::open(&self.path) {
+        if let Ok(file) = File::open(path) {
             let reader = BufReader::new(file);
             let mut count = 0u64;
             let mut bytes_read = 0u64;
             for line in reader.lines() {
                 if let Ok(line) = line {
                     bytes_read += line.len() as u64 + 1;
-                    if bytes_read > self.position {
+                    if bytes_read > position {
                         break;
                     }
                     count += 1;
@@ -306,6 +355,75 @@ mod tests {
         assert_eq!(result.events.len(), 1);
     }
 
+    #[test]
+    fn test_restarts_after_file_is_replaced() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(
+            &path,
+            "{\"topic\":\"old.one\",\"ts\":\"2024-01-01T00:00:00Z\"}\n\
+             {\"topic\":\"old.two\",\"ts\":\"2024-01-01T00:00:01Z\"}\n",
+        )
+        .unwrap();
+
+        let mut reader = EventReader::new(&path);
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 2);
+
+        // Rotation moves the file aside and starts a fresh, shorter one
+        std::fs::rename(&path, dir.path().join("events.jsonl.1")).unwrap();
+        std::fs::write(
+            &path,
+            "{\"topic\":\"new\",\"ts\":\"2024-01-01T00:00:02Z\"}\n",
+        )
+        .unwrap();
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "new");
+    }
+
+    #[test]
+    fn test_drains_rotated_file_before_reading_new_one() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(
+            &path,
+            "{\"topic\":\"seen\",\"ts\":\"2024-01-01T00:00:00Z\"}\n",
+        )
+        .unwrap();
+
+        let mut reader = EventReader::new(&path);
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 1);
+
+        // Appended after the last read, then rotated away with a new file
+        // that has already grown past the old read position
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"build.done","ts":"2024-01-01T00:00:01Z"}}"#
+        )
+        .unwrap();
+        drop(file);
+        std::fs::rename(&path, dir.path().join("events.jsonl.1")).unwrap();
+        std::fs::write(
+            &path,
+            "{\"topic\":\"after.rotation\",\"payload\":\"long enough to pass the old offset\",\"ts\":\"2024-01-01T00:00:02Z\"}\n",
+        )
+        .unwrap();
+
+        let topics: Vec<String> = reader
+            .read_new_events()
+            .unwrap()
+            .events
+            .into_iter()
+            .map(|e| e.topic)
+            .collect();
+        assert_eq!(topics, ["build.done", "after.rotation"]);
+    }
+
     #[test]
     fn test_structured_payload_as_object() {
         // Test that JSON objects in payload field are converted to strings
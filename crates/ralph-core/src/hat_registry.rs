@@ -1,11 +1,20 @@
 //! Hat registry for managing agent personas.
 
-use crate::config::{HatConfig, RalphConfig};
+use crate::config::{
+    ConfigError, ConfigWarning, HatConfig, RalphConfig, check_duplicate_hat_names,
+};
 use ralph_proto::{Hat, HatId, Topic};
 use std::collections::{BTreeMap, HashSet};
+use tracing::warn;
 
 /// Registry for managing and creating hats from configuration.
-#[derive(Debug, Default)]
+///
+/// Hats are keyed by [`HatId`] (the config key under `hats:`), which is
+/// canonical for routing, event targets, and activation tracking. A hat's
+/// `name` is for display (prompt tables, TUI, logs) and may differ from its
+/// key; use [`Self::get_by_name`] or [`Self::resolve`] when starting from
+/// user-facing text.
+#[derive(Debug, Clone, Default)]
 pub struct HatRegistry {
     hats: BTreeMap<HatId, Hat>,
     configs: BTreeMap<HatId, HatConfig>,
@@ -24,7 +33,27 @@ impl HatRegistry {
     /// Creates a registry from configuration.
     ///
     /// Empty config → empty registry (HatlessRalph is the fallback, not default hats).
+    ///
+    /// Duplicate hat names are logged as a warning; use [`Self::try_from_config`]
+    /// to reject them instead.
     pub fn from_config(config: &RalphConfig) -> Self {
+        if let Err(e) = check_duplicate_hat_names(&config.hats) {
+            warn!("{e}");
+        }
+
+        Self::build(config)
+    }
+
+    /// Creates a registry from configuration, rejecting ambiguous hat names.
+    ///
+    /// Returns `ConfigError::DuplicateHatName` when two distinct hat keys share
+    /// a name, or a hat's name collides with another hat's key.
+    pub fn try_from_config(config: &RalphConfig) -> Result<Self, ConfigError> {
+        check_duplicate_hat_names(&config.hats)?;
+        Ok(Self::build(config))
+    }
+
+    fn build(config: &RalphConfig) -> Self {
         let mut registry = Self::new();
 
         for (id, hat_config) in &config.hats {
@@ -80,6 +109,27 @@ impl HatRegistry {
         self.hats.get(id)
     }
 
+    /// Gets a hat by its display name (case-insensitive).
+    ///
+    /// Names are not guaranteed unique unless the config was validated; with
+    /// duplicates, the hat with the lowest ID wins.
+    pub fn get_by_name(&self, name: &str) -> Option<&Hat> {
+        let name = name.trim();
+        self.hats
+            .values()
+            .find(|hat| hat.name.trim().eq_ignore_ascii_case(name))
+    }
+
+    /// Resolves a hat from either its ID or its display name.
+    ///
+    /// IDs take precedence, so a hat whose key matches `key_or_name` is
+    /// returned even if another hat uses that string as its name.
+    pub fn resolve(&self, key_or_name: &str) -> Option<&Hat> {
+        self.hats
+            .get(&HatId::new(key_or_name))
+            .or_else(|| self.get_by_name(key_or_name))
+    }
+
     /// Gets a hat's configuration by ID.
     pub fn get_config(&self, id: &HatId) -> Option<&HatConfig> {
         self.configs.get(id)
@@ -125,6 +175,19 @@ impl HatRegistry {
             .map(|hat| &hat.id)
     }
 
+    /// Finds the first hat triggered by a topic along with the subscription that matched.
+    ///
+    /// Uses the same ID-ordered first match as [`Self::find_by_trigger`], so the
+    /// returned hat is the one routing would pick. Used to explain routing decisions.
+    pub fn find_subscription(&self, topic: &str) -> Option<(&Hat, &Topic)> {
+        self.hats.values().find_map(|hat| {
+            hat.subscriptions
+                .iter()
+                .find(|sub| sub.matches_str(topic))
+                .map(|sub| (hat, sub))
+        })
+    }
+
     /// Returns true if any hat is subscribed to the given topic.
     pub fn has_subscriber(&self, topic: &str) -> bool {
         let topic = Topic::new(topic);
@@ -144,6 +207,70 @@ impl HatRegistry {
             .any(|pub_topic| pub_topic.matches_str(topic))
     }
 
+    /// Checks the hat graph for hats that can never run and topics that go nowhere.
+    ///
+    /// A hat is unreachable when none of its triggers is published by another
+    /// hat, matches `starting_event`, `task.start`/`task.resume`, or a
+    /// `<hat>.exhausted` event for a hat with `max_activations`. A published
+    /// topic is a dead end when no hat subscribes to it and it isn't the
+    /// `completion_promise`. Topics match in either direction, so wildcard
+    /// triggers and wildcard publishes both count.
+    pub fn validate_topology(
+        &self,
+        starting_event: &str,
+        completion_promise: &str,
+    ) -> Vec<ConfigWarning> {
+        let mut entry_topics = vec![
+            starting_event.to_string(),
+            "task.start".to_string(),
+            "task.resume".to_string(),
+        ];
+        entry_topics.extend(
+            self.configs
+                .iter()
+                .filter(|(_, config)| config.max_activations.is_some())
+                .map(|(id, _)| format!("{id}.exhausted")),
+        );
+        let overlaps =
+            |a: &Topic, b: &str| a.matches_str(b) || Topic::new(b).matches_str(a.as_str());
+
+        let mut warnings = Vec::new();
+        for hat in self.hats.values() {
+            let reachable = hat.subscriptions.iter().any(|trigger| {
+                entry_topics.iter().any(|topic| overlaps(trigger, topic))
+                    || self
+                        .hats
+                        .values()
+                        .filter(|other| other.id != hat.id)
+                        .flat_map(|other| &other.publishes)
+                        .any(|published| overlaps(trigger, published.as_str()))
+            });
+            if !reachable {
+                warnings.push(ConfigWarning::UnreachableHat {
+                    hat: hat.id.to_string(),
+                    triggers: hat.subscriptions.iter().map(ToString::to_string).collect(),
+                });
+            }
+
+            for published in &hat.publishes {
+                let consumed = published.as_str() == completion_promise
+                    || self.hats.values().any(|other| {
+                        other
+                            .subscriptions
+                            .iter()
+                            .any(|trigger| overlaps(trigger, published.as_str()))
+                    });
+                if !consumed {
+                    warnings.push(ConfigWarning::DeadEndTopic {
+                        hat: hat.id.to_string(),
+                        topic: published.to_string(),
+                    });
+                }
+            }
+        }
+        warnings
+    }
+
     /// Returns the first hat subscribed to the given topic.
     ///
     /// Uses prefix index for O(1) early-exit when the topic prefix doesn't match
@@ -204,6 +331,131 @@ hats:
         assert!(review_hat.is_subscribed(&Topic::new("impl.done")));
     }
 
+    #[test]
+    fn test_duplicate_hat_names_rejected() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+  fixer:
+    name: "builder"
+    triggers: ["fix.task"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = HatRegistry::try_from_config(&config).unwrap_err();
+        match err {
+            ConfigError::DuplicateHatName { name, hat1, hat2 } => {
+                assert_eq!(name, "builder");
+                assert_eq!(hat1, "builder");
+                assert_eq!(hat2, "fixer");
+            }
+            other => panic!("expected DuplicateHatName, got {other:?}"),
+        }
+
+        // The infallible constructor still builds the registry (and only warns)
+        assert_eq!(HatRegistry::from_config(&config).len(), 2);
+    }
+
+    #[test]
+    fn test_hat_name_colliding_with_other_key_rejected() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Reviewer"
+    triggers: ["plan.task"]
+  reviewer:
+    name: "Code Reviewer"
+    triggers: ["review.task"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = HatRegistry::try_from_config(&config).unwrap_err();
+        assert!(err.to_string().contains("'Reviewer'"));
+        assert!(err.to_string().contains("'planner'"));
+        assert!(err.to_string().contains("'reviewer'"));
+    }
+
+    #[test]
+    fn test_unique_hat_names_accepted() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+  reviewer:
+    name: "Reviewer"
+    triggers: ["review.task"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let registry = HatRegistry::try_from_config(&config).unwrap();
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn test_get_by_name_when_name_differs_from_key() {
+        let yaml = r#"
+hats:
+  impl:
+    name: "🔨 Implementer"
+    triggers: ["build.task"]
+  qa:
+    name: "Reviewer"
+    triggers: ["review.task"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        // Name-based and ID-based lookups agree on the same hat
+        let by_name = registry.get_by_name("reviewer").unwrap();
+        let by_id = registry.get(&HatId::new("qa")).unwrap();
+        assert_eq!(by_name.id, by_id.id);
+
+        // Names are not IDs and IDs are not names
+        assert!(registry.get(&HatId::new("Reviewer")).is_none());
+        assert!(registry.get_by_name("qa").is_none());
+
+        // Trigger lookup returns the canonical ID, which resolves back to the same name
+        let triggered = registry.find_by_trigger("build.task").unwrap();
+        assert_eq!(triggered.as_str(), "impl");
+        assert_eq!(registry.get(triggered).unwrap().name, "🔨 Implementer");
+        assert_eq!(
+            registry.get_by_name("🔨 Implementer").map(|h| &h.id),
+            Some(triggered)
+        );
+    }
+
+    #[test]
+    fn test_find_subscription_returns_matched_pattern() {
+        let mut registry = HatRegistry::new();
+        registry.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        registry.register(Hat::new("reviewer", "Reviewer").subscribe("review.request"));
+
+        let (hat, pattern) = registry.find_subscription("build.task").unwrap();
+        assert_eq!(hat.id.as_str(), "builder");
+        assert_eq!(pattern.as_str(), "build.*");
+
+        // Agrees with find_by_trigger
+        assert_eq!(registry.find_by_trigger("build.task"), Some(&hat.id));
+        assert!(registry.find_subscription("deploy.done").is_none());
+    }
+
+    #[test]
+    fn test_resolve_prefers_id_over_name() {
+        let mut registry = HatRegistry::new();
+        registry.register(Hat::new("builder", "Builder").subscribe("build.task"));
+        registry.register(Hat::new("reviewer", "Code Reviewer").subscribe("review.task"));
+
+        assert_eq!(registry.resolve("builder").unwrap().id.as_str(), "builder");
+        assert_eq!(
+            registry.resolve("code reviewer").unwrap().id.as_str(),
+            "reviewer"
+        );
+        assert!(registry.resolve("missing").is_none());
+    }
+
     #[test]
     fn test_has_subscriber() {
         let yaml = r#"
@@ -465,4 +717,70 @@ hats:
         assert!(registry.can_publish(&HatId::new("ralph"), "anything"));
         assert!(registry.can_publish(&HatId::new("ralph"), "LOOP_COMPLETE"));
     }
+
+    fn broken_topology() -> HatRegistry {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    description: "Plans work"
+    triggers: ["plan.start"]
+    publishes: ["build.task", "plan.abandoned"]
+  builder:
+    name: "Builder"
+    description: "Builds code"
+    triggers: ["build.*"]
+    publishes: ["LOOP_COMPLETE"]
+  auditor:
+    name: "Auditor"
+    description: "Never triggered"
+    triggers: ["audit.request"]
+    publishes: ["plan.start"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        HatRegistry::from_config(&config)
+    }
+
+    #[test]
+    fn test_validate_topology_reports_orphan_and_dead_end() {
+        let warnings: Vec<String> = broken_topology()
+            .validate_topology("plan.start", "LOOP_COMPLETE")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Warning [hats.auditor]: Unreachable - nothing publishes its triggers (audit.request)",
+                "Warning [hats.planner.publishes]: No hat subscribes to 'plan.abandoned' (falls back to Ralph)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_topology_counts_exhausted_events_as_entry() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds code"
+    triggers: ["build.task"]
+    publishes: ["LOOP_COMPLETE"]
+    max_activations: 3
+  rescuer:
+    name: "Rescuer"
+    description: "Handles an exhausted builder"
+    triggers: ["builder.exhausted"]
+    publishes: ["LOOP_COMPLETE"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        assert!(
+            registry
+                .validate_topology("build.task", "LOOP_COMPLETE")
+                .is_empty()
+        );
+    }
 }
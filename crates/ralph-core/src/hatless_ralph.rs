@@ -4,7 +4,7 @@
 
 use crate::config::CoreConfig;
 use crate::hat_registry::HatRegistry;
-use ralph_proto::Topic;
+use ralph_proto::{HatId, Topic};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -42,7 +42,11 @@ pub struct EventReceiver {
 }
 
 /// Information about a hat for prompt generation.
+///
+/// `id` is the config key and is canonical for routing and lookups;
+/// `name` is for display only and may differ from the key.
 pub struct HatInfo {
+    pub id: HatId,
     pub name: String,
     pub description: String,
     pub subscribes_to: Vec<String>,
@@ -126,6 +130,7 @@ impl HatTopology {
                     .unwrap_or_default();
 
                 HatInfo {
+                    id: hat.id.clone(),
                     name: hat.name.clone(),
                     description: hat.description.clone(),
                     subscribes_to: hat
@@ -673,7 +678,7 @@ You MUST continue until all tasks are `[x]` or `[~]`.
 
             for active_hat in active_hats {
                 // Find matching HatInfo from topology to access event_receivers
-                let hat_info = topology.hats.iter().find(|h| h.name == active_hat.name);
+                let hat_info = topology.hats.iter().find(|h| h.id == active_hat.id);
 
                 if !active_hat.instructions.trim().is_empty() {
                     section.push_str(&format!("### {} Instructions\n\n", active_hat.name));
@@ -0,0 +1,270 @@
+//! User-configured shell hooks run by the orchestration loop.
+//!
+//! Hooks are executed via `bash -c` in the workspace root with `RALPH_*`
+//! environment variables describing the loop. Their output is captured and
+//! logged rather than written to the terminal, so they never interleave with
+//! agent output or the TUI. Hooks run on `tokio::process`, so a slow hook
+//! doesn't block the runtime the TUI and signal handlers share.
+
+use crate::config::EventLoopConfig;
+use crate::{LoopState, TerminationReason};
+use ralph_proto::HatId;
+use std::io;
+use std::path::Path;
+use std::process::ExitStatus;
+use tokio::process::Command;
+use tracing::{debug, error, info, warn};
+
+/// Runs a hook command in `dir` with the given extra environment variables.
+///
+/// # Errors
+///
+/// Returns an error if the command could not be spawned. A non-zero exit is
+/// reported through the returned status, not as an error.
+pub async fn run_hook(command: &str, dir: &Path, env: &[(&str, String)]) -> io::Result<ExitStatus> {
+    let output = Command::new("bash")
+        .args(["-c", command])
+        .current_dir(dir)
+        .envs(env.iter().map(|(k, v)| (*k, v.as_str())))
+        .output()
+        .await?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stdout.trim().is_empty() {
+        debug!(command = %command, "hook stdout: {}", stdout.trim());
+    }
+    if !stderr.trim().is_empty() {
+        debug!(command = %command, "hook stderr: {}", stderr.trim());
+    }
+
+    Ok(output.status)
+}
+
+/// Environment passed to termination hooks.
+pub fn termination_env(
+    reason: &TerminationReason,
+    state: &LoopState,
+) -> Vec<(&'static str, String)> {
+    vec![
+        ("RALPH_TERMINATION_REASON", reason.as_str().to_string()),
+        ("RALPH_SUCCESS", reason.is_success().to_string()),
+        ("RALPH_ITERATIONS", state.iteration.to_string()),
+        ("RALPH_COST_USD", format!("{:.4}", state.cumulative_cost)),
+        ("RALPH_ELAPSED_SECS", state.elapsed().as_secs().to_string()),
+    ]
+}
+
+/// Runs `on_complete_command` for a successful termination, or
+/// `on_failure_command` for any other reason. Does nothing if the matching
+/// hook is not configured. Failures are logged, never propagated: the loop has
+/// already ended.
+pub async fn run_termination_hook(
+    config: &EventLoopConfig,
+    reason: &TerminationReason,
+    state: &LoopState,
+    dir: &Path,
+) {
+    let (name, command) = if reason.is_success() {
+        ("on_complete_command", config.on_complete_command.as_deref())
+    } else {
+        ("on_failure_command", config.on_failure_command.as_deref())
+    };
+    let Some(command) = command.filter(|c| !c.trim().is_empty()) else {
+        return;
+    };
+
+    match run_hook(command, dir, &termination_env(reason, state)).await {
+        Ok(status) if status.success() => {
+            info!(hook = name, command = %command, "Termination hook succeeded");
+        }
+        Ok(status) => {
+            warn!(hook = name, command = %command, status = %status, "Termination hook failed");
+        }
+        Err(e) => {
+            warn!(hook = name, command = %command, error = %e, "Termination hook could not run");
+        }
+    }
+}
+
+/// Environment passed to iteration hooks.
+pub fn iteration_env(iteration: u32, hat: &HatId) -> Vec<(&'static str, String)> {
+    vec![
+        ("RALPH_ITERATION", iteration.to_string()),
+        ("RALPH_HAT", hat.to_string()),
+    ]
+}
+
+/// Runs an iteration hook if one is configured.
+///
+/// # Errors
+///
+/// Returns a description of the failure if the command exits non-zero or
+/// cannot be spawned. The caller decides whether that is fatal
+/// (`hooks_strict`) or only worth a warning.
+pub async fn run_iteration_hook(
+    name: &str,
+    command: Option<&str>,
+    dir: &Path,
+    env: &[(&str, String)],
+) -> Result<(), String> {
+    let Some(command) = command.filter(|c| !c.trim().is_empty()) else {
+        return Ok(());
+    };
+
+    match run_hook(command, dir, env).await {
+        Ok(status) if status.success() => {
+            debug!(hook = name, command = %command, "Iteration hook succeeded");
+            Ok(())
+        }
+        Ok(status) => Err(format!("{name} `{command}` failed: {status}")),
+        Err(e) => Err(format!("{name} `{command}` could not run: {e}")),
+    }
+}
+
+/// Runs an iteration hook and decides what a failure means for the loop.
+///
+/// Returns [`TerminationReason::HookFailed`] if the hook failed and
+/// `hooks_strict` is set. Otherwise a failure is only logged.
+pub async fn check_iteration_hook(
+    config: &EventLoopConfig,
+    name: &str,
+    command: Option<&str>,
+    dir: &Path,
+    env: &[(&str, String)],
+) -> Option<TerminationReason> {
+    let message = run_iteration_hook(name, command, dir, env).await.err()?;
+    if config.hooks_strict {
+        error!("{message}; stopping (hooks_strict)");
+        Some(TerminationReason::HookFailed)
+    } else {
+        warn!("{message}");
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_on_complete_hook_receives_environment() {
+        let dir = TempDir::new().unwrap();
+        let config = EventLoopConfig {
+            on_complete_command: Some("env | grep '^RALPH_' | sort > hook.env".to_string()),
+            on_failure_command: Some("touch failure-ran".to_string()),
+            ..EventLoopConfig::default()
+        };
+        let mut state = LoopState::new();
+        state.iteration = 7;
+        state.cumulative_cost = 1.25;
+
+        run_termination_hook(
+            &config,
+            &TerminationReason::CompletionPromise,
+            &state,
+            dir.path(),
+        )
+        .await;
+
+        let env = std::fs::read_to_string(dir.path().join("hook.env")).unwrap();
+        assert!(
+            env.contains("RALPH_TERMINATION_REASON=completed\n"),
+            "{env}"
+        );
+        assert!(env.contains("RALPH_SUCCESS=true\n"));
+        assert!(env.contains("RALPH_ITERATIONS=7\n"));
+        assert!(env.contains("RALPH_COST_USD=1.2500\n"));
+        assert!(env.contains("RALPH_ELAPSED_SECS="));
+        assert!(!dir.path().join("failure-ran").exists());
+    }
+
+    #[tokio::test]
+    async fn test_on_failure_hook_runs_for_non_success_reason() {
+        let dir = TempDir::new().unwrap();
+        let config = EventLoopConfig {
+            on_complete_command: Some("touch complete-ran".to_string()),
+            on_failure_command: Some(
+                "echo \"$RALPH_TERMINATION_REASON $RALPH_SUCCESS\" > failure.txt".to_string(),
+            ),
+            ..EventLoopConfig::default()
+        };
+
+        run_termination_hook(
+            &config,
+            &TerminationReason::MaxIterations,
+            &LoopState::new(),
+            dir.path(),
+        )
+        .await;
+
+        let written = std::fs::read_to_string(dir.path().join("failure.txt")).unwrap();
+        assert_eq!(written.trim(), "max_iterations false");
+        assert!(!dir.path().join("complete-ran").exists());
+    }
+
+    #[tokio::test]
+    async fn test_iteration_hook_reports_failure() {
+        let dir = TempDir::new().unwrap();
+        let env = iteration_env(3, &HatId::new("builder"));
+
+        assert!(
+            run_iteration_hook("pre_iteration_command", None, dir.path(), &env)
+                .await
+                .is_ok()
+        );
+        assert!(
+            run_iteration_hook(
+                "pre_iteration_command",
+                Some("test \"$RALPH_ITERATION:$RALPH_HAT\" = 3:builder"),
+                dir.path(),
+                &env,
+            )
+            .await
+            .is_ok()
+        );
+
+        let err = run_iteration_hook("post_iteration_command", Some("exit 2"), dir.path(), &env)
+            .await
+            .unwrap_err();
+        assert!(
+            err.contains("post_iteration_command `exit 2` failed"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_iteration_hook_stops_only_when_strict() {
+        let dir = TempDir::new().unwrap();
+        let env = iteration_env(1, &HatId::new("builder"));
+        let lenient = EventLoopConfig::default();
+        let strict = EventLoopConfig {
+            hooks_strict: true,
+            ..EventLoopConfig::default()
+        };
+
+        let check = |config| {
+            check_iteration_hook(
+                config,
+                "pre_iteration_command",
+                Some("exit 1"),
+                dir.path(),
+                &env,
+            )
+        };
+        assert_eq!(check(&lenient).await, None);
+        assert_eq!(check(&strict).await, Some(TerminationReason::HookFailed));
+        assert_eq!(
+            check_iteration_hook(
+                &strict,
+                "pre_iteration_command",
+                Some("true"),
+                dir.path(),
+                &env
+            )
+            .await,
+            None
+        );
+    }
+}
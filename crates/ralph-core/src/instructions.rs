@@ -259,6 +259,7 @@ mod tests {
             specs_dir: "./specifications/".to_string(),
             guardrails: vec!["Custom rule one".to_string(), "Custom rule two".to_string()],
             workspace_root: std::path::PathBuf::from("."),
+            redact_patterns: Vec::new(),
         };
         let builder = InstructionBuilder::new(custom_core);
 
@@ -13,7 +13,9 @@
 #[cfg(feature = "recording")]
 mod cli_capture;
 mod config;
+pub mod config_migration;
 pub mod diagnostics;
+pub mod dry_run;
 mod event_logger;
 mod event_loop;
 mod event_parser;
@@ -23,6 +25,7 @@ mod git_ops;
 mod handoff;
 mod hat_registry;
 mod hatless_ralph;
+pub mod hooks;
 mod instructions;
 mod landing;
 pub mod loop_completion;
@@ -32,11 +35,14 @@ pub mod loop_lock;
 mod loop_name;
 pub mod loop_registry;
 mod memory;
+#[cfg(feature = "semantic-memory")]
+mod memory_embeddings;
 pub mod memory_parser;
 mod memory_store;
 pub mod merge_queue;
 pub mod planning_session;
 pub mod preflight;
+pub mod redaction;
 #[cfg(feature = "recording")]
 mod session_player;
 #[cfg(feature = "recording")]
@@ -50,6 +56,7 @@ pub mod task_store;
 pub mod testing;
 mod text;
 pub mod utils;
+mod verifier;
 pub mod workspace;
 pub mod worktree;
 
@@ -58,13 +65,19 @@ pub use cli_capture::{CliCapture, CliCapturePair};
 pub use config::{
     CliConfig, ConfigError, CoreConfig, EventLoopConfig, EventMetadata, FeaturesConfig, HatBackend,
     HatConfig, InjectMode, MemoriesConfig, MemoriesFilter, RalphConfig, SkillOverride,
-    SkillsConfig,
+    SkillsConfig, TelegramParseMode, TopicRateLimitConfig,
 };
 // Re-export loop_name types (also available via FeaturesConfig.loop_naming)
 pub use diagnostics::DiagnosticsCollector;
-pub use event_logger::{EventHistory, EventLogger, EventRecord};
-pub use event_loop::{EventLoop, LoopState, TerminationReason, UserPrompt};
-pub use event_parser::EventParser;
+pub use event_logger::{
+    AsyncEventLogger, EventHistory, EventLogger, EventRecord, EventSeverity, EventStats, EventTail,
+    NameCount,
+};
+pub use event_loop::{
+    COMPLETION_BLOCKED_TOPIC, EventLoop, LOOP_ITERATION_TOPIC, LoopState, MEMORY_INJECTED_TOPIC,
+    TerminationReason, UserPrompt,
+};
+pub use event_parser::{EventParser, ParsedEvents};
 pub use event_reader::{Event, EventReader, MalformedLine, ParseResult};
 pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockedFile};
 pub use git_ops::{
@@ -84,8 +97,12 @@ pub use loop_lock::{LockError, LockGuard, LockMetadata, LoopLock};
 pub use loop_name::{LoopNameGenerator, LoopNamingConfig};
 pub use loop_registry::{LoopEntry, LoopRegistry, RegistryError};
 pub use memory::{Memory, MemoryType};
+#[cfg(feature = "semantic-memory")]
+pub use memory_embeddings::{CommandEmbedder, Embedder, EmbeddingIndex};
 pub use memory_store::{
-    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget,
+    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, MemoryMatch, MemoryPruneFilter, MemorySearchFilter,
+    cap_memories, filter_memories, format_memories_as_markdown, format_memories_within_budget,
+    select_within_budget, truncate_to_budget,
 };
 pub use merge_queue::{
     MergeButtonState, MergeEntry, MergeEvent, MergeEventType, MergeOption, MergeQueue,
@@ -101,21 +118,24 @@ pub use preflight::{
     PreflightRunner, extract_acceptance_criteria, extract_all_criteria, extract_criteria_from_file,
 };
 #[cfg(feature = "recording")]
-pub use session_player::{PlayerConfig, ReplayMode, SessionPlayer, TimestampedRecord};
+pub use session_player::{
+    InvalidLine, PlaybackCommand, PlayerConfig, ReplayMode, SessionPlayer, SessionValidation,
+    TimestampedRecord,
+};
 #[cfg(feature = "recording")]
 pub use session_recorder::{Record, SessionRecorder};
 pub use skill::{SkillEntry, SkillFrontmatter, SkillSource, parse_frontmatter};
 pub use skill_registry::SkillRegistry;
-pub use summary_writer::SummaryWriter;
+pub use summary_writer::{RunSummary, SummaryWriter};
 pub use task::{Task, TaskStatus};
 pub use task_definition::{
     TaskDefinition, TaskDefinitionError, TaskSetup, TaskSuite, Verification,
 };
 pub use task_store::TaskStore;
 pub use text::{floor_char_boundary, truncate_with_ellipsis};
+pub use verifier::{VerificationResult, Verifier};
 pub use workspace::{
-    CleanupPolicy, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
-    WorkspaceManager,
+    CleanupPolicy, TaskWorkspace, WorkspaceError, WorkspaceInfo, WorkspaceManager,
 };
 pub use worktree::{
     SyncStats, Worktree, WorktreeConfig, WorktreeError, create_worktree, ensure_gitignore,
@@ -95,6 +95,31 @@ pub enum LockError {
     UnsupportedPlatform,
 }
 
+/// Outcome of a non-blocking exclusive `flock()`.
+#[cfg(unix)]
+pub(crate) enum TryFlock {
+    /// The lock was taken; it is held until the `Flock` is dropped.
+    Acquired(nix::fcntl::Flock<File>),
+    /// Another open file description holds the lock; the file is handed back.
+    Held(File),
+}
+
+/// Tries to take an exclusive `flock()` on `file` without blocking.
+#[cfg(unix)]
+pub(crate) fn try_flock_exclusive(file: File) -> io::Result<TryFlock> {
+    use nix::errno::Errno;
+    use nix::fcntl::{Flock, FlockArg};
+
+    match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
+        Ok(flock) => Ok(TryFlock::Acquired(flock)),
+        // EWOULDBLOCK and EAGAIN are the same on some platforms (macOS)
+        Err((file, errno)) if errno == Errno::EWOULDBLOCK || errno == Errno::EAGAIN => {
+            Ok(TryFlock::Held(file))
+        }
+        Err((_, errno)) => Err(io::Error::other(format!("flock failed: {}", errno))),
+    }
+}
+
 /// The loop lock mechanism.
 ///
 /// Uses `flock()` to provide advisory locking on `.ralph/loop.lock`.
@@ -139,10 +164,8 @@ impl LoopLock {
         // Try to acquire exclusive lock (non-blocking)
         #[cfg(unix)]
         {
-            use nix::fcntl::{Flock, FlockArg};
-
-            match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
-                Ok(flock) => {
+            match try_flock_exclusive(file)? {
+                TryFlock::Acquired(flock) => {
                     // We got the lock - write our metadata
                     Self::write_metadata(&flock, prompt)?;
 
@@ -153,19 +176,10 @@ impl LoopLock {
                         lock_path,
                     })
                 }
-                Err((file, errno)) => {
-                    use nix::errno::Errno;
-                    // EWOULDBLOCK and EAGAIN are the same on some platforms (macOS)
-                    if errno == Errno::EWOULDBLOCK || errno == Errno::EAGAIN {
-                        // Lock is held by another process - read their metadata
-                        let metadata = Self::read_metadata(&file)?;
-                        Err(LockError::AlreadyLocked(metadata))
-                    } else {
-                        Err(LockError::Io(io::Error::new(
-                            io::ErrorKind::Other,
-                            format!("flock failed: {}", errno),
-                        )))
-                    }
+                TryFlock::Held(file) => {
+                    // Lock is held by another process - read their metadata
+                    let metadata = Self::read_metadata(&file)?;
+                    Err(LockError::AlreadyLocked(metadata))
                 }
             }
         }
@@ -278,25 +292,8 @@ impl LoopLock {
 
         #[cfg(unix)]
         {
-            use nix::errno::Errno;
-            use nix::fcntl::{Flock, FlockArg};
-
-            match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
-                Ok(_flock) => {
-                    // We got the lock - it will be released when _flock is dropped
-                    Ok(false)
-                }
-                Err((_, errno)) => {
-                    if errno == Errno::EWOULDBLOCK || errno == Errno::EAGAIN {
-                        Ok(true)
-                    } else {
-                        Err(LockError::Io(io::Error::new(
-                            io::ErrorKind::Other,
-                            format!("flock failed: {}", errno),
-                        )))
-                    }
-                }
-            }
+            // If we got the lock, it is released when the Flock is dropped
+            Ok(matches!(try_flock_exclusive(file)?, TryFlock::Held(_)))
         }
 
         #[cfg(not(unix))]
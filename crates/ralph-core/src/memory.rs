@@ -112,7 +112,7 @@ impl std::str::FromStr for MemoryType {
 /// > Can span multiple lines
 /// <!-- tags: tag1, tag2 | created: 2025-01-20 -->
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Memory {
     /// Unique identifier (format: `mem-{unix_timestamp}-{4_hex_chars}`)
     pub id: String,
@@ -181,6 +181,34 @@ impl Memory {
                 .any(|tag| tag.to_lowercase().contains(&query_lower))
     }
 
+    /// Scores how relevant this memory is to a search query.
+    ///
+    /// Each whitespace-separated query term scores one point per occurrence in
+    /// the content and two per tag containing it (case-insensitive). A score
+    /// of 0 means no term matched.
+    #[must_use]
+    pub fn relevance(&self, query: &str) -> usize {
+        let content = self.content.to_lowercase();
+        let tags: Vec<String> = self.tags.iter().map(|t| t.to_lowercase()).collect();
+
+        query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .map(|term| {
+                let in_content = content.matches(term.as_str()).count();
+                let in_tags = tags.iter().filter(|t| t.contains(term.as_str())).count();
+                in_content + 2 * in_tags
+            })
+            .sum()
+    }
+
+    /// Returns true if this memory's content matches `content` ignoring case
+    /// and whitespace differences (runs of spaces, newlines, indentation).
+    #[must_use]
+    pub fn has_same_content(&self, content: &str) -> bool {
+        normalize_content(&self.content) == normalize_content(content)
+    }
+
     /// Returns true if this memory has any of the specified tags.
     #[must_use]
     pub fn has_any_tag(&self, tags: &[String]) -> bool {
@@ -189,6 +217,22 @@ impl Memory {
             .iter()
             .any(|t| tags_lower.contains(&t.to_lowercase()))
     }
+
+    /// Returns true if this memory has every one of the specified tags.
+    #[must_use]
+    pub fn has_all_tags(&self, tags: &[String]) -> bool {
+        let own: Vec<String> = self.tags.iter().map(|t| t.to_lowercase()).collect();
+        tags.iter().all(|t| own.contains(&t.to_lowercase()))
+    }
+}
+
+/// Lowercases `content` and collapses all whitespace to single spaces.
+fn normalize_content(content: &str) -> String {
+    content
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[cfg(test)]
@@ -307,6 +351,33 @@ mod tests {
         assert!(!memory.matches_query("authentication"));
     }
 
+    #[test]
+    fn test_memory_relevance_counts_terms_in_content_and_tags() {
+        let memory = Memory::new(
+            MemoryType::Fix,
+            "Database pool: raise the database timeout".to_string(),
+            vec!["database".to_string(), "perf".to_string()],
+        );
+
+        assert_eq!(memory.relevance("database"), 4);
+        assert_eq!(memory.relevance("DATABASE timeout"), 5);
+        assert_eq!(memory.relevance("docker"), 0);
+        assert_eq!(memory.relevance(""), 0);
+    }
+
+    #[test]
+    fn test_memory_has_same_content_ignores_case_and_whitespace() {
+        let memory = Memory::new(
+            MemoryType::Fix,
+            "Run  `cargo test`\nbefore pushing".to_string(),
+            vec![],
+        );
+
+        assert!(memory.has_same_content("Run  `cargo test`\nbefore pushing"));
+        assert!(memory.has_same_content("  run `cargo test` BEFORE\tpushing\n"));
+        assert!(!memory.has_same_content("Run `cargo test` before merging"));
+    }
+
     #[test]
     fn test_memory_has_any_tag() {
         let memory = Memory {
@@ -323,6 +394,19 @@ mod tests {
         assert!(!memory.has_any_tag(&["unrelated".to_string()]));
     }
 
+    #[test]
+    fn test_memory_has_all_tags() {
+        let memory = Memory::new(
+            MemoryType::Fix,
+            "Pool fix".to_string(),
+            vec!["database".to_string(), "Docker".to_string()],
+        );
+
+        assert!(memory.has_all_tags(&["docker".to_string(), "database".to_string()]));
+        assert!(!memory.has_all_tags(&["docker".to_string(), "auth".to_string()]));
+        assert!(memory.has_all_tags(&[]));
+    }
+
     #[test]
     fn test_memory_type_all() {
         let all = MemoryType::all();
@@ -0,0 +1,496 @@
+//! Embedding-based memory search (`semantic-memory` feature).
+//!
+//! Memories are embedded by an [`Embedder`] and cached in
+//! `.ralph/agent/memories.index` next to the memories file, keyed by memory
+//! ID. Memory content never changes once written, so only memories missing
+//! from the index are embedded; entries for deleted memories are pruned.
+//!
+//! [`MarkdownMemoryStore::search_semantic`] ranks by cosine similarity and
+//! falls back to lexical [`MarkdownMemoryStore::search`] whenever an
+//! embedding is unavailable. [`CommandEmbedder`] runs the configured
+//! `memories.embedding_command`; memory injection and `ralph tools memory
+//! search` use it through [`MarkdownMemoryStore::rank_semantic`].
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::config::MemoriesConfig;
+use crate::memory::Memory;
+use crate::memory_store::MarkdownMemoryStore;
+
+/// Computes vector embeddings for text.
+///
+/// Returning `None` means embeddings are unavailable (no model configured,
+/// service unreachable, ...) and callers fall back to lexical search.
+pub trait Embedder {
+    fn embed(&self, text: &str) -> Option<Vec<f32>>;
+}
+
+/// Embeds text by running a shell command.
+///
+/// The command runs via `bash -c` in `dir`, receives the text on stdin, and
+/// must print a JSON array of numbers. Any failure makes the embedding
+/// unavailable, including running past the timeout (the command is killed).
+#[derive(Debug, Clone)]
+pub struct CommandEmbedder {
+    command: String,
+    dir: PathBuf,
+    timeout: Duration,
+}
+
+/// How long an embedding command may run before it is killed.
+const DEFAULT_EMBED_TIMEOUT: Duration = Duration::from_secs(30);
+
+impl CommandEmbedder {
+    /// Creates an embedder running `command` in `dir`.
+    pub fn new(command: impl Into<String>, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            command: command.into(),
+            dir: dir.into(),
+            timeout: DEFAULT_EMBED_TIMEOUT,
+        }
+    }
+
+    /// Sets how long the command may run before it is killed.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Creates the embedder for `memories.embedding_command`, if one is set.
+    pub fn from_config(config: &MemoriesConfig, dir: impl Into<PathBuf>) -> Option<Self> {
+        let command = config
+            .embedding_command
+            .as_deref()
+            .filter(|c| !c.trim().is_empty())?;
+        Some(Self::new(command, dir))
+    }
+}
+
+impl Embedder for CommandEmbedder {
+    fn embed(&self, text: &str) -> Option<Vec<f32>> {
+        let mut child = Command::new("bash")
+            .args(["-c", &self.command])
+            .current_dir(&self.dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .inspect_err(
+                |e| debug!(command = %self.command, error = %e, "Embedding command could not run"),
+            )
+            .ok()?;
+        // Feed stdin from its own thread so a command that writes output
+        // before draining its input cannot deadlock against us. Dropping
+        // stdin after the write closes it, so the command sees EOF.
+        let stdin = child.stdin.take();
+        let input = text.to_owned();
+        thread::spawn(move || {
+            if let Some(mut stdin) = stdin {
+                let _ = stdin.write_all(input.as_bytes());
+            }
+        });
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+                Ok(None) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    debug!(command = %self.command, timeout = ?self.timeout, "Embedding command timed out");
+                    return None;
+                }
+                Err(e) => {
+                    let _ = child.kill();
+                    debug!(command = %self.command, error = %e, "Embedding command could not be waited on");
+                    return None;
+                }
+            }
+        };
+        let stdout = stdout.join().unwrap_or_default();
+        if !status.success() {
+            let stderr = stderr.join().unwrap_or_default();
+            debug!(
+                command = %self.command,
+                status = %status,
+                "Embedding command failed: {}",
+                String::from_utf8_lossy(&stderr).trim()
+            );
+            return None;
+        }
+        serde_json::from_slice(&stdout)
+            .inspect_err(|e| debug!(command = %self.command, error = %e, "Embedding command printed no JSON array"))
+            .ok()
+    }
+}
+
+/// Reads a child pipe to the end on a background thread.
+fn read_pipe(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+/// Cached memory embeddings, stored as JSON.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EmbeddingIndex {
+    #[serde(skip)]
+    path: PathBuf,
+    vectors: BTreeMap<String, Vec<f32>>,
+}
+
+impl EmbeddingIndex {
+    /// Loads the index at `path`, or an empty one if it is missing or unreadable.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let mut index: Self = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        index.path = path;
+        index
+    }
+
+    /// Writes the index back to its path.
+    pub fn save(&self) -> io::Result<()> {
+        let json = serde_json::to_string(self).map_err(io::Error::other)?;
+        fs::write(&self.path, json)
+    }
+
+    /// Returns the cached embedding for a memory ID.
+    pub fn get(&self, id: &str) -> Option<&[f32]> {
+        self.vectors.get(id).map(Vec::as_slice)
+    }
+
+    /// Embeds memories missing from the index and drops entries for memories
+    /// that no longer exist.
+    ///
+    /// Returns `false` if any embedding was unavailable; the index is then
+    /// left as it was.
+    pub fn refresh(&mut self, memories: &[Memory], embedder: &dyn Embedder) -> bool {
+        let mut added = Vec::new();
+        for memory in memories
+            .iter()
+            .filter(|m| !self.vectors.contains_key(&m.id))
+        {
+            let Some(vector) = embedder.embed(&embedding_text(memory)) else {
+                return false;
+            };
+            added.push((memory.id.clone(), vector));
+        }
+
+        self.vectors
+            .retain(|id, _| memories.iter().any(|m| &m.id == id));
+        self.vectors.extend(added);
+        true
+    }
+}
+
+impl MarkdownMemoryStore {
+    /// Returns the embedding index path (`memories.index` beside the memories file).
+    #[must_use]
+    pub fn index_path(&self) -> PathBuf {
+        self.path().with_extension("index")
+    }
+
+    /// Searches memories by cosine similarity to the query, most similar first.
+    ///
+    /// Memories with a similarity of 0 or less are left out. If the query or
+    /// any memory cannot be embedded, this is lexical [`search`](Self::search)
+    /// with its scores as `f32`.
+    pub fn search_semantic(
+        &self,
+        query: &str,
+        embedder: &dyn Embedder,
+    ) -> io::Result<Vec<(Memory, f32)>> {
+        let memories = self.load()?;
+        let mut index = EmbeddingIndex::load(self.index_path());
+
+        let query_vector = embedder.embed(query);
+        let Some(query_vector) = query_vector.filter(|_| index.refresh(&memories, embedder)) else {
+            debug!("Embeddings unavailable; falling back to lexical memory search");
+            return self.search(query).map(|results| {
+                results
+                    .into_iter()
+                    .map(|(memory, score)| (memory, score as f32))
+                    .collect()
+            });
+        };
+
+        if let Err(e) = index.save() {
+            debug!(error = %e, "Failed to save memory embedding index");
+        }
+
+        let mut results: Vec<_> = memories
+            .into_iter()
+            .filter_map(|memory| {
+                let similarity = cosine_similarity(&query_vector, index.get(&memory.id)?);
+                (similarity > 0.0).then_some((memory, similarity))
+            })
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(results)
+    }
+
+    /// Sorts `memories` by similarity to the query, most similar first.
+    ///
+    /// Memories [`search_semantic`](Self::search_semantic) leaves out follow
+    /// the ranked ones in their original order, so nothing is dropped. On
+    /// error, `memories` is left untouched.
+    pub fn rank_semantic(
+        &self,
+        memories: &mut [Memory],
+        query: &str,
+        embedder: &dyn Embedder,
+    ) -> io::Result<()> {
+        let ranked: Vec<String> = self
+            .search_semantic(query, embedder)?
+            .into_iter()
+            .map(|(memory, _)| memory.id)
+            .collect();
+        memories.sort_by_key(|m| {
+            ranked
+                .iter()
+                .position(|id| id == &m.id)
+                .unwrap_or(usize::MAX)
+        });
+        Ok(())
+    }
+}
+
+/// The text embedded for a memory: its content followed by its tags.
+fn embedding_text(memory: &Memory) -> String {
+    if memory.tags.is_empty() {
+        memory.content.clone()
+    } else {
+        format!("{}\n{}", memory.content, memory.tags.join(", "))
+    }
+}
+
+/// Cosine similarity of two vectors; 0 for mismatched lengths or zero vectors.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::MemoryType;
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    /// Embeds text as counts of a few concept keywords, so synonyms land
+    /// close together without sharing words with the query.
+    struct StubEmbedder {
+        calls: Cell<usize>,
+        available: bool,
+    }
+
+    impl StubEmbedder {
+        const CONCEPTS: [&'static [&'static str]; 3] = [
+            &["database", "postgres", "sql", "storage"],
+            &["docker", "container", "compose"],
+            &["auth", "login", "token"],
+        ];
+
+        fn new(available: bool) -> Self {
+            Self {
+                calls: Cell::new(0),
+                available,
+            }
+        }
+    }
+
+    impl Embedder for StubEmbedder {
+        fn embed(&self, text: &str) -> Option<Vec<f32>> {
+            self.calls.set(self.calls.get() + 1);
+            if !self.available {
+                return None;
+            }
+            let text = text.to_lowercase();
+            Some(
+                Self::CONCEPTS
+                    .iter()
+                    .map(|words| words.iter().filter(|w| text.contains(*w)).count() as f32)
+                    .collect(),
+            )
+        }
+    }
+
+    fn store_with(memories: &[&str]) -> (TempDir, MarkdownMemoryStore) {
+        let temp_dir = TempDir::new().unwrap();
+        let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+        for content in memories {
+            store
+                .append(&Memory::new(
+                    MemoryType::Pattern,
+                    (*content).to_string(),
+                    vec![],
+                ))
+                .unwrap();
+        }
+        (temp_dir, store)
+    }
+
+    #[test]
+    fn test_search_semantic_ranks_by_similarity() {
+        let (_temp_dir, store) = store_with(&[
+            "Login tokens expire after a day",
+            "Postgres runs inside a compose container",
+            "SQL migrations live in db/",
+        ]);
+        let embedder = StubEmbedder::new(true);
+
+        let ranked: Vec<String> = store
+            .search_semantic("storage", &embedder)
+            .unwrap()
+            .into_iter()
+            .map(|(m, _)| m.content)
+            .collect();
+
+        assert_eq!(
+            ranked,
+            vec![
+                "SQL migrations live in db/",
+                "Postgres runs inside a compose container",
+            ]
+        );
+        assert!(store.index_path().exists());
+    }
+
+    #[test]
+    fn test_search_semantic_reuses_index() {
+        let (_temp_dir, store) = store_with(&["Postgres pool", "Docker compose"]);
+
+        let first = StubEmbedder::new(true);
+        store.search_semantic("database", &first).unwrap();
+        assert_eq!(first.calls.get(), 3);
+
+        let second = StubEmbedder::new(true);
+        store.search_semantic("database", &second).unwrap();
+        assert_eq!(second.calls.get(), 1, "only the query is embedded");
+    }
+
+    #[test]
+    fn test_search_semantic_falls_back_to_lexical() {
+        let (_temp_dir, store) = store_with(&["Postgres pool", "Docker compose"]);
+
+        let results = store
+            .search_semantic("docker", &StubEmbedder::new(false))
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.content, "Docker compose");
+        assert!(!store.index_path().exists());
+    }
+
+    #[test]
+    fn test_rank_semantic_keeps_unranked_memories_last() {
+        let (_temp_dir, store) = store_with(&[
+            "Login tokens expire after a day",
+            "Docker compose file",
+            "Postgres pool size",
+        ]);
+        let mut memories = store.load().unwrap();
+        let mut expected: Vec<String> = memories
+            .iter()
+            .map(|m| m.content.clone())
+            .filter(|c| c != "Postgres pool size")
+            .collect();
+        expected.insert(0, "Postgres pool size".to_string());
+
+        store
+            .rank_semantic(&mut memories, "storage", &StubEmbedder::new(true))
+            .unwrap();
+        let ranked: Vec<String> = memories.into_iter().map(|m| m.content).collect();
+
+        assert_eq!(ranked, expected);
+    }
+
+    #[test]
+    fn test_command_embedder_reads_vector_from_stdout() {
+        let dir = TempDir::new().unwrap();
+        let embedder = CommandEmbedder::new(
+            "if grep -qi postgres; then echo '[1, 0]'; else echo '[0, 1]'; fi",
+            dir.path(),
+        );
+
+        assert_eq!(embedder.embed("Postgres pool"), Some(vec![1.0, 0.0]));
+        assert_eq!(embedder.embed("Docker"), Some(vec![0.0, 1.0]));
+        assert_eq!(CommandEmbedder::new("exit 1", dir.path()).embed("x"), None);
+        assert_eq!(
+            CommandEmbedder::new("echo nope", dir.path()).embed("x"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_command_embedder_times_out() {
+        let dir = TempDir::new().unwrap();
+        let embedder = CommandEmbedder::new("sleep 5; echo '[1]'", dir.path())
+            .with_timeout(Duration::from_millis(200));
+
+        let started = Instant::now();
+        assert_eq!(embedder.embed("x"), None);
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_command_embedder_handles_output_before_input_is_read() {
+        // Both payloads exceed a pipe buffer: writing stdin inline would
+        // block while the command blocks writing stdout.
+        let dir = TempDir::new().unwrap();
+        let embedder = CommandEmbedder::new(
+            "printf '%200000s' ''; cat > /dev/null; echo '[1]'",
+            dir.path(),
+        )
+        .with_timeout(Duration::from_secs(10));
+
+        assert_eq!(embedder.embed(&"x".repeat(200_000)), Some(vec![1.0]));
+    }
+
+    #[test]
+    fn test_command_embedder_from_config_requires_command() {
+        let mut config = MemoriesConfig::default();
+        assert!(CommandEmbedder::from_config(&config, ".").is_none());
+        config.embedding_command = Some("  ".to_string());
+        assert!(CommandEmbedder::from_config(&config, ".").is_none());
+        config.embedding_command = Some("embed".to_string());
+        assert!(CommandEmbedder::from_config(&config, ".").is_some());
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < f32::EPSILON);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < f32::EPSILON);
+        assert!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]).abs() < f32::EPSILON);
+        assert!(cosine_similarity(&[1.0], &[1.0, 1.0]).abs() < f32::EPSILON);
+    }
+}
@@ -1,7 +1,7 @@
 //! Markdown parser for the memories file format.
 //!
-//! Parses `.ralph/agent/memories.md` into a vector of `Memory` structs.
-//! The format uses:
+//! Parses `.ralph/agent/memories.md` into a vector of `Memory` structs and
+//! serializes them back. The format uses:
 //! - `## Section` headers to denote memory types
 //! - `### mem-{id}` headers for individual memories
 //! - `> content` blockquotes for memory content
@@ -102,6 +102,43 @@ pub fn parse_memories(markdown: &str) -> Vec<Memory> {
     memories
 }
 
+/// Serialize memories into the memories file format.
+///
+/// Every type section is written, even when empty, in [`MemoryType::all`]
+/// order. Parsing the result with [`parse_memories`] yields the same memories.
+#[must_use]
+pub fn serialize_memories(memories: &[Memory]) -> String {
+    let mut content = String::from("# Memories\n");
+
+    for memory_type in MemoryType::all() {
+        content.push_str(&format!("\n## {}\n", memory_type.section_name()));
+
+        for memory in memories.iter().filter(|m| m.memory_type == *memory_type) {
+            content.push_str(&format_memory_block(memory));
+        }
+    }
+
+    content
+}
+
+/// Format a single memory as a `### id` block, without its section header.
+pub(crate) fn format_memory_block(memory: &Memory) -> String {
+    // Escape newlines in content by prefixing each line with `> `
+    let content_lines: Vec<_> = memory
+        .content
+        .lines()
+        .map(|line| format!("> {}", line))
+        .collect();
+
+    format!(
+        "\n### {}\n{}\n<!-- tags: {} | created: {} -->\n",
+        memory.id,
+        content_lines.join("\n"),
+        memory.tags.join(", "),
+        memory.created,
+    )
+}
+
 /// Helper to finalize and push a memory if we have enough data.
 fn flush_memory(
     memories: &mut Vec<Memory>,
@@ -131,6 +168,59 @@ fn flush_memory(
 mod tests {
     use super::*;
 
+    /// The fixture the memory e2e scenarios seed `.ralph/agent/memories.md` with.
+    const E2E_FIXTURE: &str = r"# Memories
+
+## Patterns
+
+### mem-1737300000-a1b1
+> Authentication uses JWT tokens with 24h expiry
+<!-- tags: auth, security | created: 2025-01-19 -->
+
+### mem-1737300100-a2b2
+> Database connections pool with max 10 connections
+<!-- tags: database, performance | created: 2025-01-19 -->
+
+## Fixes
+
+### mem-1737300200-a3b3
+> ECONNREFUSED on port 5432 means start docker compose
+<!-- tags: docker, database | created: 2025-01-19 -->
+";
+
+    #[test]
+    fn test_e2e_fixture_round_trips() {
+        let memories = parse_memories(E2E_FIXTURE);
+        assert_eq!(memories.len(), 3);
+        assert_eq!(memories[2].memory_type, MemoryType::Fix);
+        assert_eq!(memories[2].tags, vec!["docker", "database"]);
+
+        let serialized = serialize_memories(&memories);
+        assert_eq!(parse_memories(&serialized), memories);
+
+        // Only the empty sections are added; everything else is byte-for-byte.
+        let expected =
+            E2E_FIXTURE.replace("\n## Fixes", "\n## Decisions\n\n## Fixes") + "\n## Context\n";
+        assert_eq!(serialized, expected);
+    }
+
+    #[test]
+    fn test_serialize_is_stable_for_multiline_content() {
+        let memories = vec![Memory {
+            id: "mem-1737372000-a1b2".to_string(),
+            memory_type: MemoryType::Decision,
+            content: "Use Postgres\nfor ACID guarantees".to_string(),
+            tags: vec![],
+            created: "2025-01-20".to_string(),
+        }];
+
+        let serialized = serialize_memories(&memories);
+        let reparsed = parse_memories(&serialized);
+
+        assert_eq!(reparsed, memories);
+        assert_eq!(serialize_memories(&reparsed), serialized);
+    }
+
     #[test]
     fn test_parse_single_memory() {
         let markdown = r"# Memories
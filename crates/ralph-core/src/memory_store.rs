@@ -20,9 +20,108 @@ use std::path::{Path, PathBuf};
 
 use crate::text::floor_char_boundary;
 
+use crate::config::MemoriesFilter;
 use crate::file_lock::FileLock;
 use crate::memory::{Memory, MemoryType};
-use crate::memory_parser::parse_memories;
+use crate::memory_parser::{format_memory_block, parse_memories, serialize_memories};
+
+/// Type and tag restrictions for [`MarkdownMemoryStore::search_filtered`].
+///
+/// Types come from the section a memory is stored under (`## Patterns`,
+/// `## Fixes`, ...) and tags from its `<!-- tags: ... -->` metadata. Tags
+/// compare case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemorySearchFilter {
+    /// Only memories of this type.
+    pub memory_type: Option<MemoryType>,
+    /// Every one of these tags is required.
+    pub all_tags: Vec<String>,
+    /// At least one of these tags is required (no restriction when empty).
+    pub any_tags: Vec<String>,
+}
+
+impl MemorySearchFilter {
+    /// Returns true if `memory` passes the type and tag restrictions.
+    pub fn matches(&self, memory: &Memory) -> bool {
+        self.memory_type.is_none_or(|t| memory.memory_type == t)
+            && memory.has_all_tags(&self.all_tags)
+            && (self.any_tags.is_empty() || memory.has_any_tag(&self.any_tags))
+    }
+
+    /// Tags on `memory` that were requested by this filter or contain a query
+    /// term, in the memory's own tag order.
+    pub fn matched_tags(&self, memory: &Memory, query: Option<&str>) -> Vec<String> {
+        let requested: Vec<String> = self
+            .all_tags
+            .iter()
+            .chain(&self.any_tags)
+            .map(|t| t.to_lowercase())
+            .collect();
+        let terms: Vec<String> = query
+            .map(|q| q.split_whitespace().map(str::to_lowercase).collect())
+            .unwrap_or_default();
+
+        memory
+            .tags
+            .iter()
+            .filter(|tag| {
+                let tag = tag.to_lowercase();
+                requested.contains(&tag) || terms.iter().any(|term| tag.contains(term.as_str()))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// A memory returned by [`MarkdownMemoryStore::search_filtered`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryMatch {
+    pub memory: Memory,
+    /// Relevance to the query, or `None` when searching by filter only.
+    pub score: Option<usize>,
+    /// Tags that matched the filter or the query, for highlighting.
+    pub matched_tags: Vec<String>,
+}
+
+/// Criteria for [`MarkdownMemoryStore::prune`].
+///
+/// A memory matches when it was created more than `older_than_days` days ago
+/// and/or carries any of `tags`; when both are set it must meet both. A filter
+/// with neither set matches nothing. Memories whose `created:` date doesn't
+/// parse never match on age.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryPruneFilter {
+    /// Only memories created more than this many days ago.
+    pub older_than_days: Option<u32>,
+    /// Only memories with at least one of these tags.
+    pub tags: Vec<String>,
+}
+
+impl MemoryPruneFilter {
+    /// Returns true if neither criterion is set.
+    pub fn is_empty(&self) -> bool {
+        self.older_than_days.is_none() && self.tags.is_empty()
+    }
+
+    /// Returns true if `memory` should be pruned as of today.
+    pub fn matches(&self, memory: &Memory) -> bool {
+        self.matches_on(memory, chrono::Utc::now().date_naive())
+    }
+
+    /// Returns true if `memory` should be pruned as of `today`.
+    pub fn matches_on(&self, memory: &Memory, today: chrono::NaiveDate) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let old_enough = self.older_than_days.is_none_or(|days| {
+            chrono::NaiveDate::parse_from_str(&memory.created, "%Y-%m-%d")
+                .is_ok_and(|created| (today - created).num_days() > i64::from(days))
+        });
+
+        old_enough && (self.tags.is_empty() || memory.has_any_tag(&self.tags))
+    }
+}
 
 /// Default path for the memories file relative to the workspace root.
 pub const DEFAULT_MEMORIES_PATH: &str = ".ralph/agent/memories.md";
@@ -120,20 +219,53 @@ impl MarkdownMemoryStore {
         let lock = FileLock::new(&self.path)?;
         let _guard = lock.exclusive()?;
 
-        let content = if self.exists() {
-            fs::read_to_string(&self.path)?
+        let content = self.read_or_template()?;
+        self.append_internal(memory, &content)
+    }
+
+    /// Appends a memory unless one with the same content already exists.
+    ///
+    /// Content is compared with [`Memory::has_same_content`] (case- and
+    /// whitespace-insensitive). Returns the existing memory when it is a
+    /// duplicate, in which case nothing is written.
+    /// Uses an exclusive lock so the check and the write are atomic.
+    pub fn append_unique(&self, memory: &Memory) -> io::Result<Option<Memory>> {
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = self.read_or_template()?;
+        if let Some(existing) = parse_memories(&content)
+            .into_iter()
+            .find(|m| m.has_same_content(&memory.content))
+        {
+            return Ok(Some(existing));
+        }
+
+        self.append_internal(memory, &content)?;
+        Ok(None)
+    }
+
+    /// Reads the memories file, or returns the empty template (creating the
+    /// parent directory) if it doesn't exist. The caller must hold the lock.
+    fn read_or_template(&self) -> io::Result<String> {
+        if self.exists() {
+            fs::read_to_string(&self.path)
         } else {
             // Ensure parent directory exists
             if let Some(parent) = self.path.parent() {
                 fs::create_dir_all(parent)?;
             }
-            self.template()
-        };
+            Ok(self.template())
+        }
+    }
 
+    /// Inserts `memory` into its section of `content` and writes the file.
+    /// The caller must hold the exclusive lock.
+    fn append_internal(&self, memory: &Memory, content: &str) -> io::Result<()> {
         let section = format!("## {}", memory.memory_type.section_name());
-        let memory_block = self.format_memory(memory);
+        let memory_block = format_memory_block(memory);
 
-        let new_content = if let Some(pos) = self.find_section_insert_point(&content, &section) {
+        let new_content = if let Some(pos) = self.find_section_insert_point(content, &section) {
             format!("{}{}{}", &content[..pos], memory_block, &content[pos..])
         } else {
             // Section doesn't exist, append section + memory at end
@@ -170,21 +302,109 @@ impl MarkdownMemoryStore {
         Ok(true)
     }
 
+    /// Removes every memory, keeping the empty section headers.
+    ///
+    /// Returns the number of memories removed. A missing file is left missing.
+    /// Uses an exclusive lock to prevent concurrent writes.
+    pub fn clear(&self) -> io::Result<usize> {
+        if !self.exists() {
+            return Ok(0);
+        }
+
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = fs::read_to_string(&self.path)?;
+        let removed = parse_memories(&content).len();
+        self.write_all_internal(&[])?;
+
+        Ok(removed)
+    }
+
+    /// Removes every memory matching `filter`, keeping the section headers.
+    ///
+    /// Returns the removed memories; the file is only rewritten if there were
+    /// any. A missing file is left missing.
+    /// Uses an exclusive lock to prevent concurrent writes.
+    pub fn prune(&self, filter: &MemoryPruneFilter) -> io::Result<Vec<Memory>> {
+        if !self.exists() {
+            return Ok(Vec::new());
+        }
+
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = fs::read_to_string(&self.path)?;
+        let (removed, remaining): (Vec<_>, Vec<_>) = parse_memories(&content)
+            .into_iter()
+            .partition(|m| filter.matches(m));
+
+        if !removed.is_empty() {
+            self.write_all_internal(&remaining)?;
+        }
+
+        Ok(removed)
+    }
+
     /// Returns the memory with the given ID, if it exists.
     pub fn get(&self, id: &str) -> io::Result<Option<Memory>> {
         let memories = self.load()?;
         Ok(memories.into_iter().find(|m| m.id == id))
     }
 
-    /// Searches memories by query string.
+    /// Searches memories by query string, most relevant first.
     ///
-    /// Matches against content and tags (case-insensitive).
-    pub fn search(&self, query: &str) -> io::Result<Vec<Memory>> {
-        let memories = self.load()?;
-        Ok(memories
+    /// Each result carries its [`Memory::relevance`] score; memories scoring 0
+    /// are left out. Equal scores keep their file order.
+    pub fn search(&self, query: &str) -> io::Result<Vec<(Memory, usize)>> {
+        let mut results: Vec<_> = self
+            .load()?
             .into_iter()
-            .filter(|m| m.matches_query(query))
-            .collect())
+            .map(|m| {
+                let score = m.relevance(query);
+                (m, score)
+            })
+            .filter(|(_, score)| *score > 0)
+            .collect();
+        results.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        Ok(results)
+    }
+
+    /// Searches memories restricted by type and tags, most relevant first.
+    ///
+    /// With a query, results are ranked by [`Memory::relevance`] and memories
+    /// scoring 0 are left out. Without one, every memory passing `filter` is
+    /// returned unscored, ranked by how many of the requested tags it carries.
+    /// Equal ranks keep their file order.
+    pub fn search_filtered(
+        &self,
+        query: Option<&str>,
+        filter: &MemorySearchFilter,
+    ) -> io::Result<Vec<MemoryMatch>> {
+        let mut results: Vec<MemoryMatch> = self
+            .load()?
+            .into_iter()
+            .filter(|m| filter.matches(m))
+            .filter_map(|m| {
+                let score = query.map(|q| m.relevance(q));
+                if score == Some(0) {
+                    return None;
+                }
+                let matched_tags = filter.matched_tags(&m, query);
+                Some(MemoryMatch {
+                    memory: m,
+                    score,
+                    matched_tags,
+                })
+            })
+            .collect();
+
+        if query.is_some() {
+            results.sort_by_key(|r| std::cmp::Reverse(r.score));
+        } else {
+            results.sort_by_key(|r| std::cmp::Reverse(r.matched_tags.len()));
+        }
+        Ok(results)
     }
 
     /// Filters memories by type.
@@ -215,41 +435,7 @@ impl MarkdownMemoryStore {
             fs::create_dir_all(parent)?;
         }
 
-        let mut content = String::from("# Memories\n");
-
-        // Group memories by type
-        for memory_type in MemoryType::all() {
-            let type_memories: Vec<_> = memories
-                .iter()
-                .filter(|m| m.memory_type == *memory_type)
-                .collect();
-
-            content.push_str(&format!("\n## {}\n", memory_type.section_name()));
-
-            for memory in type_memories {
-                content.push_str(&self.format_memory(memory));
-            }
-        }
-
-        fs::write(&self.path, content)
-    }
-
-    /// Formats a memory as a markdown block.
-    fn format_memory(&self, memory: &Memory) -> String {
-        // Escape newlines in content by prefixing each line with `> `
-        let content_lines: Vec<_> = memory
-            .content
-            .lines()
-            .map(|line| format!("> {}", line))
-            .collect();
-
-        format!(
-            "\n### {}\n{}\n<!-- tags: {} | created: {} -->\n",
-            memory.id,
-            content_lines.join("\n"),
-            memory.tags.join(", "),
-            memory.created,
-        )
+        fs::write(&self.path, serialize_memories(memories))
     }
 
     /// Finds the insertion point for a new memory in the given section.
@@ -303,22 +489,160 @@ pub fn format_memories_as_markdown(memories: &[Memory]) -> String {
             continue;
         }
 
-        output.push_str(&format!("\n## {}\n", memory_type.section_name()));
+        output.push_str(&format_section_heading(*memory_type));
 
         for memory in type_memories {
-            output.push_str(&format!(
-                "\n### {}\n> {}\n<!-- tags: {} | created: {} -->\n",
-                memory.id,
-                memory.content.replace('\n', "\n> "),
-                memory.tags.join(", "),
-                memory.created
-            ));
+            output.push_str(&format_memory_block(memory));
         }
     }
 
     output
 }
 
+/// Formats a type's section heading.
+fn format_section_heading(memory_type: MemoryType) -> String {
+    format!("\n## {}\n", memory_type.section_name())
+}
+
+/// Keeps the memories selected by `filter`: any of its types (unknown type
+/// names are ignored), any of its tags, and created within its `recent` days.
+/// Empty criteria match everything.
+///
+/// Used by `ralph memory prime` and the event loop's auto-injection feature.
+#[must_use]
+pub fn filter_memories(mut memories: Vec<Memory>, filter: &MemoriesFilter) -> Vec<Memory> {
+    let types: Vec<MemoryType> = filter
+        .types
+        .iter()
+        .filter_map(|t| t.trim().parse().ok())
+        .collect();
+    if !types.is_empty() {
+        memories.retain(|m| types.contains(&m.memory_type));
+    }
+
+    if !filter.tags.is_empty() {
+        memories.retain(|m| m.has_any_tag(&filter.tags));
+    }
+
+    if filter.recent > 0 {
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(filter.recent));
+        let cutoff_str = cutoff.format("%Y-%m-%d").to_string();
+        memories.retain(|m| m.created >= cutoff_str);
+    }
+
+    memories
+}
+
+/// Keeps the `max` most recent memories (by creation date, then ID), in
+/// their original order. 0 means unlimited.
+///
+/// Applied after [`filter_memories`] and before the token budget, so
+/// whichever limit is smaller wins.
+#[must_use]
+pub fn cap_memories(memories: Vec<Memory>, max: usize) -> Vec<Memory> {
+    if max == 0 || memories.len() <= max {
+        return memories;
+    }
+
+    let mut newest: Vec<&Memory> = memories.iter().collect();
+    newest.sort_by(|a, b| (&b.created, &b.id).cmp(&(&a.created, &a.id)));
+    let keep: Vec<String> = newest[..max].iter().map(|m| m.id.clone()).collect();
+
+    memories
+        .into_iter()
+        .filter(|m| keep.contains(&m.id))
+        .collect()
+}
+
+/// Selects the memories that fit, whole, within a token budget (~4 chars
+/// per token of their markdown), keeping their original order.
+///
+/// Memories are taken in priority order until the next one would overflow:
+/// most `priority_tags` matched first, then most recent (creation date, then
+/// ID). The top-priority memory is always kept, even if it alone exceeds the
+/// budget, so callers can truncate it rather than inject nothing.
+/// A budget of 0 keeps everything.
+#[must_use]
+pub fn select_within_budget(
+    memories: Vec<Memory>,
+    budget: usize,
+    priority_tags: &[String],
+) -> Vec<Memory> {
+    if budget == 0 || memories.is_empty() {
+        return memories;
+    }
+
+    let char_budget = budget * 4;
+    let tags_matched = |m: &Memory| {
+        priority_tags
+            .iter()
+            .filter(|tag| m.has_any_tag(std::slice::from_ref(*tag)))
+            .count()
+    };
+
+    let mut ranked: Vec<&Memory> = memories.iter().collect();
+    ranked.sort_by(|a, b| {
+        (tags_matched(b), &b.created, &b.id).cmp(&(tags_matched(a), &a.created, &a.id))
+    });
+
+    // Matches the layout of format_memories_as_markdown: a title, one
+    // heading per type present, then each memory's block.
+    let mut used = "# Memories\n".len();
+    let mut sections: Vec<MemoryType> = Vec::new();
+    let mut keep: Vec<&str> = Vec::new();
+    for memory in ranked {
+        let new_section = !sections.contains(&memory.memory_type);
+        let mut len = format_memory_block(memory).len();
+        if new_section {
+            len += format_section_heading(memory.memory_type).len();
+        }
+        if !keep.is_empty() && used + len > char_budget {
+            break;
+        }
+        used += len;
+        if new_section {
+            sections.push(memory.memory_type);
+        }
+        keep.push(&memory.id);
+    }
+
+    let keep: Vec<String> = keep.into_iter().map(str::to_string).collect();
+    memories
+        .into_iter()
+        .filter(|m| keep.contains(&m.id))
+        .collect()
+}
+
+/// Formats memories as markdown within a token budget (0 = unlimited).
+///
+/// Keeps whole memories chosen by [`select_within_budget`] and notes how many
+/// were omitted; if the top-priority memory alone is over budget, it is cut
+/// down with [`truncate_to_budget`].
+#[must_use]
+pub fn format_memories_within_budget(
+    memories: Vec<Memory>,
+    budget: usize,
+    priority_tags: &[String],
+) -> String {
+    let total = memories.len();
+    let selected = select_within_budget(memories, budget, priority_tags);
+    let content = format_memories_as_markdown(&selected);
+
+    if budget > 0 && content.len() > budget * 4 {
+        return truncate_to_budget(&content, budget);
+    }
+
+    let omitted = total - selected.len();
+    if omitted > 0 {
+        format!(
+            "{}\n<!-- truncated: budget {} tokens exceeded, {} memories omitted -->",
+            content, budget, omitted
+        )
+    } else {
+        content
+    }
+}
+
 /// Truncates memory content to approximately fit within a token budget.
 ///
 /// Uses a simple heuristic of ~4 characters per token. Tries to end
@@ -504,6 +828,32 @@ mod tests {
         assert!(memories.iter().any(|m| m.memory_type == MemoryType::Fix));
     }
 
+    #[test]
+    fn test_append_unique_skips_duplicate_content() {
+        let (_temp_dir, store) = create_temp_store();
+
+        let original = Memory::new(
+            MemoryType::Pattern,
+            "Uses barrel exports".to_string(),
+            vec![],
+        );
+        assert!(store.append_unique(&original).unwrap().is_none());
+
+        // Same content in a different section and casing is still a duplicate
+        let duplicate = Memory::new(MemoryType::Fix, "uses  barrel\nexports".to_string(), vec![]);
+        let existing = store.append_unique(&duplicate).unwrap().unwrap();
+        assert_eq!(existing.id, original.id);
+
+        let distinct = Memory::new(
+            MemoryType::Pattern,
+            "Uses default exports".to_string(),
+            vec![],
+        );
+        assert!(store.append_unique(&distinct).unwrap().is_none());
+
+        assert_eq!(store.load().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_delete_removes_memory() {
         let (_temp_dir, store) = create_temp_store();
@@ -529,6 +879,51 @@ mod tests {
         assert!(!deleted);
     }
 
+    #[test]
+    fn test_delete_last_memory_in_section_keeps_clean_header() {
+        let (_temp_dir, store) = create_temp_store();
+        let pattern = Memory::new(MemoryType::Pattern, "Keep me".to_string(), vec![]);
+        let fix = Memory::new(MemoryType::Fix, "Remove me".to_string(), vec![]);
+        store.append(&pattern).unwrap();
+        store.append(&fix).unwrap();
+
+        assert!(store.delete(&fix.id).unwrap());
+
+        let content = fs::read_to_string(store.path()).unwrap();
+        assert!(content.contains("## Fixes\n\n## Context\n"), "{content}");
+        assert!(!content.contains("Remove me"));
+        let remaining = store.load().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, pattern.id);
+    }
+
+    #[test]
+    fn test_clear_removes_all_memories_and_keeps_sections() {
+        let (_temp_dir, store) = create_temp_store();
+        for (memory_type, content) in [
+            (MemoryType::Pattern, "One"),
+            (MemoryType::Decision, "Two"),
+            (MemoryType::Context, "Three"),
+        ] {
+            store
+                .append(&Memory::new(memory_type, content.to_string(), vec![]))
+                .unwrap();
+        }
+
+        assert_eq!(store.clear().unwrap(), 3);
+
+        assert!(store.load().unwrap().is_empty());
+        assert_eq!(fs::read_to_string(store.path()).unwrap(), store.template());
+    }
+
+    #[test]
+    fn test_clear_missing_file_is_noop() {
+        let (_temp_dir, store) = create_temp_store();
+
+        assert_eq!(store.clear().unwrap(), 0);
+        assert!(!store.exists());
+    }
+
     #[test]
     fn test_get_finds_memory() {
         let (_temp_dir, store) = create_temp_store();
@@ -556,6 +951,101 @@ mod tests {
         assert!(found.is_none());
     }
 
+    /// Same data as the e2e `MemorySearchScenario`.
+    const SAMPLE_MEMORIES: &str = r"# Memories
+
+## Patterns
+
+### mem-1737300000-a1b1
+> Authentication uses JWT tokens with 24h expiry
+<!-- tags: auth, security | created: 2025-01-19 -->
+
+### mem-1737300100-a2b2
+> Database connections pool with max 10 connections
+<!-- tags: database, performance | created: 2025-01-19 -->
+
+## Fixes
+
+### mem-1737300200-a3b3
+> ECONNREFUSED on port 5432 means start docker compose
+<!-- tags: docker, database | created: 2025-01-19 -->
+";
+
+    fn sample_store() -> (TempDir, MarkdownMemoryStore) {
+        let (temp_dir, store) = create_temp_store();
+        fs::create_dir_all(store.path().parent().unwrap()).unwrap();
+        fs::write(store.path(), SAMPLE_MEMORIES).unwrap();
+        (temp_dir, store)
+    }
+
+    fn ids(results: &[MemoryMatch]) -> Vec<&str> {
+        results.iter().map(|r| r.memory.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_filtered_by_type_uses_section() {
+        let (_temp_dir, store) = sample_store();
+        let filter = MemorySearchFilter {
+            memory_type: Some(MemoryType::Fix),
+            ..Default::default()
+        };
+
+        let results = store.search_filtered(Some("database"), &filter).unwrap();
+
+        assert_eq!(ids(&results), vec!["mem-1737300200-a3b3"]);
+        assert_eq!(results[0].matched_tags, vec!["database"]);
+    }
+
+    #[test]
+    fn test_search_filtered_by_tags() {
+        let (_temp_dir, store) = sample_store();
+
+        let all = MemorySearchFilter {
+            all_tags: vec!["database".to_string(), "Docker".to_string()],
+            ..Default::default()
+        };
+        let results = store.search_filtered(None, &all).unwrap();
+        assert_eq!(ids(&results), vec!["mem-1737300200-a3b3"]);
+        assert_eq!(results[0].matched_tags, vec!["docker", "database"]);
+        assert_eq!(results[0].score, None);
+
+        // Memories carrying more of the requested tags rank first
+        let any = MemorySearchFilter {
+            any_tags: vec![
+                "security".to_string(),
+                "docker".to_string(),
+                "database".to_string(),
+            ],
+            ..Default::default()
+        };
+        let results = store.search_filtered(None, &any).unwrap();
+        assert_eq!(
+            ids(&results),
+            vec![
+                "mem-1737300200-a3b3",
+                "mem-1737300000-a1b1",
+                "mem-1737300100-a2b2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_filtered_ranks_query_within_filter() {
+        let (_temp_dir, store) = sample_store();
+        let filter = MemorySearchFilter {
+            memory_type: Some(MemoryType::Pattern),
+            ..Default::default()
+        };
+
+        let results = store.search_filtered(Some("auth"), &filter).unwrap();
+        assert_eq!(ids(&results), vec!["mem-1737300000-a1b1"]);
+        assert_eq!(results[0].matched_tags, vec!["auth"]);
+        assert!(results[0].score.unwrap() > 0);
+
+        let none = store.search_filtered(Some("docker"), &filter).unwrap();
+        assert!(none.is_empty(), "docker memory is a fix, not a pattern");
+    }
+
     #[test]
     fn test_search_matches_content() {
         let (_temp_dir, store) = create_temp_store();
@@ -576,7 +1066,7 @@ mod tests {
 
         let results = store.search("barrel").unwrap();
         assert_eq!(results.len(), 1);
-        assert!(results[0].content.contains("barrel"));
+        assert!(results[0].0.content.contains("barrel"));
     }
 
     #[test]
@@ -595,6 +1085,45 @@ mod tests {
         assert_eq!(results.len(), 1);
     }
 
+    #[test]
+    fn test_search_ranks_by_relevance() {
+        let (_temp_dir, store) = create_temp_store();
+        let entries = [
+            ("Docker compose starts the database", vec![]),
+            (
+                "Database pool: the database caps connections",
+                vec!["database"],
+            ),
+            ("Uses barrel exports", vec!["structure"]),
+            ("Migrations run before database seeding", vec![]),
+        ];
+        for (content, tags) in entries {
+            let tags = tags.into_iter().map(String::from).collect();
+            store
+                .append(&Memory::new(MemoryType::Pattern, content.to_string(), tags))
+                .unwrap();
+        }
+
+        let ranked: Vec<(String, usize)> = store
+            .search("database docker")
+            .unwrap()
+            .into_iter()
+            .map(|(m, score)| (m.content, score))
+            .collect();
+
+        assert_eq!(
+            ranked,
+            vec![
+                (
+                    "Database pool: the database caps connections".to_string(),
+                    4
+                ),
+                ("Docker compose starts the database".to_string(), 2),
+                ("Migrations run before database seeding".to_string(), 1),
+            ]
+        );
+    }
+
     #[test]
     fn test_filter_by_type() {
         let (_temp_dir, store) = create_temp_store();
@@ -753,4 +1282,227 @@ mod tests {
         assert!(result.len() < content.len());
         assert!(result.contains("<!-- truncated:"));
     }
+
+    #[test]
+    fn test_prune_filter_matches_on_age_and_tags() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let memory = |created: &str, tags: &[&str]| Memory {
+            id: "mem-1700000000-0001".to_string(),
+            memory_type: MemoryType::Fix,
+            content: "x".to_string(),
+            tags: tags.iter().map(|t| (*t).to_string()).collect(),
+            created: created.to_string(),
+        };
+        let old = |days| MemoryPruneFilter {
+            older_than_days: Some(days),
+            ..Default::default()
+        };
+
+        assert!(old(30).matches_on(&memory("2026-01-15", &[]), today));
+        // Exactly 30 days old is not older than 30 days
+        assert!(!old(30).matches_on(&memory("2026-01-30", &[]), today));
+        assert!(!old(30).matches_on(&memory("someday", &[]), today));
+
+        let tagged = MemoryPruneFilter {
+            tags: vec!["Legacy".to_string()],
+            ..Default::default()
+        };
+        assert!(tagged.matches_on(&memory("2026-02-28", &["legacy"]), today));
+        assert!(!tagged.matches_on(&memory("2026-02-28", &["auth"]), today));
+
+        let both = MemoryPruneFilter {
+            older_than_days: Some(30),
+            tags: vec!["legacy".to_string()],
+        };
+        assert!(both.matches_on(&memory("2026-01-15", &["legacy"]), today));
+        assert!(!both.matches_on(&memory("2026-02-28", &["legacy"]), today));
+        assert!(!both.matches_on(&memory("2026-01-15", &["auth"]), today));
+
+        assert!(!MemoryPruneFilter::default().matches_on(&memory("2000-01-01", &[]), today));
+    }
+
+    #[test]
+    fn test_prune_removes_matching_and_keeps_sections() {
+        let (_temp_dir, store) = create_temp_store();
+        let today = chrono::Utc::now().date_naive();
+        let day = |ago: i64| {
+            (today - chrono::Duration::days(ago))
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        for (i, (memory_type, ago)) in [
+            (MemoryType::Pattern, 400),
+            (MemoryType::Pattern, 1),
+            (MemoryType::Fix, 90),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            store
+                .append(&Memory {
+                    id: format!("mem-1700000000-{i:04}"),
+                    memory_type,
+                    content: format!("Entry {i}"),
+                    tags: vec![],
+                    created: day(ago),
+                })
+                .unwrap();
+        }
+
+        let removed = store
+            .prune(&MemoryPruneFilter {
+                older_than_days: Some(60),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let ids = |memories: Vec<Memory>| memories.into_iter().map(|m| m.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(removed),
+            vec!["mem-1700000000-0000", "mem-1700000000-0002"]
+        );
+        assert_eq!(ids(store.load().unwrap()), vec!["mem-1700000000-0001"]);
+        let content = fs::read_to_string(store.path()).unwrap();
+        assert!(content.contains("## Fixes"));
+        assert!(
+            store
+                .prune(&MemoryPruneFilter::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_select_within_budget_keeps_highest_priority_memories() {
+        let memory = |id: &str, created: &str, tags: &[&str]| Memory {
+            id: id.to_string(),
+            memory_type: MemoryType::Pattern,
+            content: "x".repeat(400),
+            tags: tags.iter().map(|t| (*t).to_string()).collect(),
+            created: created.to_string(),
+        };
+        let memories = vec![
+            memory("mem-1700000000-0001", "2026-01-01", &["auth"]),
+            memory("mem-1700000000-0002", "2026-01-05", &[]),
+            memory("mem-1700000000-0003", "2026-01-04", &[]),
+            memory("mem-1700000000-0004", "2026-01-02", &[]),
+        ];
+        let priority = vec!["auth".to_string()];
+        let ids = |memories: Vec<Memory>| memories.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        // Each block is ~470 chars; 300 tokens (~1200 chars) fits two.
+        assert_eq!(
+            ids(select_within_budget(memories.clone(), 300, &priority)),
+            vec!["mem-1700000000-0001", "mem-1700000000-0002"]
+        );
+        // Without priority tags, recency decides
+        assert_eq!(
+            ids(select_within_budget(memories.clone(), 300, &[])),
+            vec!["mem-1700000000-0002", "mem-1700000000-0003"]
+        );
+        // The top memory is kept even when it alone is over budget
+        assert_eq!(
+            ids(select_within_budget(memories.clone(), 10, &priority)),
+            vec!["mem-1700000000-0001"]
+        );
+        assert_eq!(select_within_budget(memories, 0, &priority).len(), 4);
+    }
+
+    #[test]
+    fn test_format_memories_within_budget_trims_large_store() {
+        let memories: Vec<Memory> = (1..=50)
+            .map(|i| Memory {
+                id: format!("mem-1700000000-{i:04}"),
+                memory_type: MemoryType::Fix,
+                content: format!("Fix number {i}: {}", "detail ".repeat(20)),
+                tags: if i == 7 {
+                    vec!["build".to_string()]
+                } else {
+                    vec![]
+                },
+                created: format!("2026-01-{:02}", (i % 28) + 1),
+            })
+            .collect();
+        let full = format_memories_as_markdown(&memories);
+
+        let trimmed = format_memories_within_budget(memories, 200, &["build".to_string()]);
+
+        assert!(full.len() > 200 * 4);
+        assert!(trimmed.len() < full.len());
+        assert!(trimmed.starts_with("# Memories\n"));
+        assert!(trimmed.contains("### mem-1700000000-0007\n"), "{trimmed}");
+        assert!(trimmed.contains("memories omitted -->"), "{trimmed}");
+        // Whole blocks only: every kept memory ends with its metadata line
+        assert_eq!(
+            trimmed.matches("### mem-").count(),
+            trimmed.matches("| created:").count()
+        );
+    }
+
+    #[test]
+    fn test_cap_memories_keeps_newest_in_order() {
+        let memory = |id: &str, created: &str| Memory {
+            id: id.to_string(),
+            memory_type: MemoryType::Pattern,
+            content: id.to_string(),
+            tags: vec![],
+            created: created.to_string(),
+        };
+        let memories = vec![
+            memory("mem-1700000000-0001", "2026-01-03"),
+            memory("mem-1700000000-0002", "2026-01-01"),
+            memory("mem-1700000000-0003", "2026-01-03"),
+            memory("mem-1700000000-0004", "2026-01-02"),
+        ];
+
+        let ids = |memories: Vec<Memory>| memories.into_iter().map(|m| m.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(cap_memories(memories.clone(), 3)),
+            vec![
+                "mem-1700000000-0001",
+                "mem-1700000000-0003",
+                "mem-1700000000-0004"
+            ]
+        );
+        assert_eq!(cap_memories(memories.clone(), 0).len(), 4);
+        assert_eq!(cap_memories(memories, 10).len(), 4);
+    }
+
+    #[test]
+    fn test_filter_memories_by_type_tags_and_recency() {
+        let mut old = Memory::new(MemoryType::Fix, "Old fix".to_string(), vec!["ci".into()]);
+        old.created = "2000-01-01".to_string();
+        let memories = vec![
+            Memory::new(
+                MemoryType::Pattern,
+                "Uses tokio".to_string(),
+                vec!["async".into()],
+            ),
+            Memory::new(MemoryType::Fix, "Flaky test".to_string(), vec!["CI".into()]),
+            old,
+        ];
+        let contents = |filter: &MemoriesFilter| -> Vec<String> {
+            filter_memories(memories.clone(), filter)
+                .into_iter()
+                .map(|m| m.content)
+                .collect()
+        };
+
+        assert_eq!(contents(&MemoriesFilter::default()).len(), 3);
+        assert_eq!(
+            contents(&MemoriesFilter {
+                tags: vec!["ci".to_string()],
+                recent: 30,
+                ..MemoriesFilter::default()
+            }),
+            vec!["Flaky test"]
+        );
+        assert_eq!(
+            contents(&MemoriesFilter {
+                types: vec!["pattern".to_string(), "bogus".to_string()],
+                ..MemoriesFilter::default()
+            }),
+            vec!["Uses tokio"]
+        );
+    }
 }
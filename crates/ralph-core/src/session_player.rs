@@ -2,11 +2,14 @@
 //!
 //! `SessionPlayer` reads events from JSONL files and replays them with
 //! configurable timing. Supports terminal output replay (with ANSI colors),
-//! plain text mode (ANSI stripped), and step-through debugging.
+//! plain text mode (ANSI stripped), step-through debugging, and interactive
+//! pause/seek controls.
 
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ralph_proto::{TerminalWrite, UxEvent};
+use std::fmt;
 use std::io::{self, BufRead, Write};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::session_recorder::Record;
 
@@ -28,6 +31,9 @@ pub struct PlayerConfig {
     /// If true, pause after each event and wait for Enter.
     pub step_mode: bool,
 
+    /// If true, read playback controls from the keyboard (ignored in step mode).
+    pub interactive: bool,
+
     /// Output mode for UX events.
     pub replay_mode: ReplayMode,
 
@@ -40,6 +46,7 @@ impl Default for PlayerConfig {
         Self {
             speed: 1.0,
             step_mode: false,
+            interactive: false,
             replay_mode: ReplayMode::Terminal,
             event_filter: Vec::new(),
         }
@@ -72,6 +79,13 @@ impl PlayerConfig {
         self
     }
 
+    /// Enables keyboard controls: space pauses/resumes, `→`/`←` skip one
+    /// record, and a number followed by Enter seeks to that record index.
+    pub fn with_interactive_mode(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
     /// Filters to specific event types.
     pub fn with_filter(mut self, events: Vec<String>) -> Self {
         self.event_filter = events;
@@ -79,6 +93,203 @@ impl PlayerConfig {
     }
 }
 
+/// A playback control issued during interactive replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackCommand {
+    /// Pause, or resume if paused.
+    TogglePause,
+    /// Show the next record immediately.
+    Forward,
+    /// Go back one record.
+    Back,
+    /// Continue playback from this record index.
+    Seek(usize),
+    /// Stop playback.
+    Quit,
+}
+
+impl PlaybackCommand {
+    /// Returns the playback position after this command.
+    ///
+    /// The position is the index of the next record to play, so records
+    /// `0..position` have been shown and `record_count` means playback is done.
+    pub fn target(self, position: usize, record_count: usize) -> usize {
+        match self {
+            Self::Forward => (position + 1).min(record_count),
+            Self::Back => position.saturating_sub(1),
+            Self::Seek(index) => index.min(record_count),
+            Self::TogglePause | Self::Quit => position,
+        }
+    }
+}
+
+/// Maps keystrokes to playback commands, buffering typed digits until Enter.
+#[derive(Debug, Default)]
+struct KeyControls {
+    digits: String,
+}
+
+impl KeyControls {
+    fn command(&mut self, key: KeyEvent) -> Option<PlaybackCommand> {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(PlaybackCommand::Quit)
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                self.digits.push(c);
+                None
+            }
+            KeyCode::Backspace => {
+                self.digits.pop();
+                None
+            }
+            KeyCode::Enter => std::mem::take(&mut self.digits)
+                .parse()
+                .ok()
+                .map(PlaybackCommand::Seek),
+            KeyCode::Char(' ') => Some(PlaybackCommand::TogglePause),
+            KeyCode::Right => Some(PlaybackCommand::Forward),
+            KeyCode::Left => Some(PlaybackCommand::Back),
+            KeyCode::Char('q') | KeyCode::Esc => Some(PlaybackCommand::Quit),
+            _ => None,
+        }
+    }
+
+    /// Waits up to `timeout` (forever if `None`) for a command.
+    fn next(&mut self, timeout: Option<Duration>) -> io::Result<Option<PlaybackCommand>> {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        loop {
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if !event::poll(remaining)? {
+                    return Ok(None);
+                }
+            }
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+                && let Some(command) = self.command(key)
+            {
+                return Ok(Some(command));
+            }
+        }
+    }
+}
+
+/// Keeps the terminal in raw mode until dropped.
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> io::Result<Self> {
+        crossterm::terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+/// Writes a bare `\n` as `\r\n`.
+///
+/// Raw mode turns off the terminal's own newline translation, so without
+/// this each replayed line would start where the previous one ended.
+struct CrlfWriter<W> {
+    inner: W,
+    last_was_cr: bool,
+}
+
+impl<W: Write> CrlfWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            last_was_cr: false,
+        }
+    }
+}
+
+impl<W: Write> Write for CrlfWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut start = 0;
+        for (i, &byte) in buf.iter().enumerate() {
+            let after_cr = if i == 0 {
+                self.last_was_cr
+            } else {
+                buf[i - 1] == b'\r'
+            };
+            if byte == b'\n' && !after_cr {
+                self.inner.write_all(&buf[start..i])?;
+                self.inner.write_all(b"\r")?;
+                start = i;
+            }
+        }
+        self.inner.write_all(&buf[start..])?;
+        if let Some(&last) = buf.last() {
+            self.last_was_cr = last == b'\r';
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A session file line that is not a valid record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLine {
+    /// 1-based line number.
+    pub line: usize,
+
+    /// Parse error message.
+    pub error: String,
+
+    /// Start of the offending line.
+    pub snippet: String,
+}
+
+impl InvalidLine {
+    /// Maximum characters of the line kept in `snippet`.
+    const SNIPPET_LEN: usize = 60;
+
+    fn new(line: usize, content: &str, error: &serde_json::Error) -> Self {
+        let content = content.trim();
+        let mut snippet: String = content.chars().take(Self::SNIPPET_LEN).collect();
+        if content.chars().count() > Self::SNIPPET_LEN {
+            snippet.push('…');
+        }
+        Self {
+            line,
+            error: error.to_string(),
+            snippet,
+        }
+    }
+}
+
+impl fmt::Display for InvalidLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {} ({})", self.line, self.error, self.snippet)
+    }
+}
+
+/// Result of checking every line of a session file.
+#[derive(Debug, Clone, Default)]
+pub struct SessionValidation {
+    /// Number of lines that parsed as records.
+    pub valid_records: usize,
+
+    /// Lines that failed to parse, in file order.
+    pub invalid_lines: Vec<InvalidLine>,
+}
+
+impl SessionValidation {
+    /// Returns true if every non-blank line is a valid record.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_lines.is_empty()
+    }
+}
+
 /// A parsed record with timing information for replay.
 #[derive(Debug, Clone)]
 pub struct TimestampedRecord {
@@ -122,20 +333,23 @@ pub struct SessionPlayer {
 
 impl SessionPlayer {
     /// Creates a player from a JSONL reader.
+    ///
+    /// Fails on the first line that is not a valid record, naming its line
+    /// number and content.
     pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
         let mut records = Vec::new();
         let mut first_ts: Option<u64> = None;
 
-        for line in reader.lines() {
+        for (index, line) in reader.lines().enumerate() {
             let line = line?;
             if line.trim().is_empty() {
                 continue;
             }
 
-            let record: Record = serde_json::from_str(&line).map_err(|e| {
+            let record = Self::parse_line(index + 1, &line).map_err(|invalid| {
                 io::Error::new(
                     io::ErrorKind::InvalidData,
-                    format!("Invalid JSON record: {}", e),
+                    format!("Invalid JSON record on {}", invalid),
                 )
             })?;
 
@@ -154,6 +368,33 @@ impl SessionPlayer {
         })
     }
 
+    /// Checks every line of a JSONL session without stopping at the first error.
+    ///
+    /// Only I/O errors are returned as `Err`; invalid lines are collected in
+    /// the result.
+    pub fn validate<R: BufRead>(reader: R) -> io::Result<SessionValidation> {
+        let mut validation = SessionValidation::default();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match Self::parse_line(index + 1, &line) {
+                Ok(_) => validation.valid_records += 1,
+                Err(invalid) => validation.invalid_lines.push(invalid),
+            }
+        }
+
+        Ok(validation)
+    }
+
+    /// Parses one JSONL line (1-based `line_number`) as a record.
+    fn parse_line(line_number: usize, line: &str) -> Result<Record, InvalidLine> {
+        serde_json::from_str(line).map_err(|e| InvalidLine::new(line_number, line, &e))
+    }
+
     /// Creates a player from raw JSONL bytes.
     pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
         Self::from_reader(io::BufReader::new(bytes))
@@ -203,12 +444,25 @@ impl SessionPlayer {
         self.position = 0;
     }
 
+    /// Returns the index of the next record to play.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
     /// Replays all UX terminal events to the given writer.
     ///
     /// This is a synchronous replay that respects timing delays adjusted
     /// by the speed multiplier. In step mode, it waits for Enter after
-    /// each event.
+    /// each event; in interactive mode, it reads playback controls from the
+    /// keyboard.
     pub fn replay_terminal<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.config.interactive && !self.config.step_mode {
+            let _raw_mode = RawModeGuard::enable()?;
+            let mut controls = KeyControls::default();
+            let mut writer = CrlfWriter::new(writer);
+            return self.replay_with_controls(&mut writer, |timeout| controls.next(timeout));
+        }
+
         self.reset();
         let mut last_offset_ms: u64 = 0;
 
@@ -242,6 +496,81 @@ impl SessionPlayer {
         writer.flush()
     }
 
+    /// Replays terminal events, taking playback commands from `next_command`.
+    ///
+    /// `next_command` is given how long to wait before the next record is due
+    /// (`None` while paused) and returns `None` if no command arrived in time.
+    fn replay_with_controls<W, F>(&mut self, writer: &mut W, mut next_command: F) -> io::Result<()>
+    where
+        W: Write,
+        F: FnMut(Option<Duration>) -> io::Result<Option<PlaybackCommand>>,
+    {
+        self.reset();
+        let mut paused = false;
+
+        while self.position < self.records.len() {
+            writer.flush()?;
+            let wait = (!paused).then(|| self.delay_before(self.position));
+
+            let Some(command) = next_command(wait)? else {
+                if !paused {
+                    self.output_record(writer, self.position)?;
+                    self.position += 1;
+                }
+                continue;
+            };
+
+            match command {
+                PlaybackCommand::TogglePause => paused = !paused,
+                PlaybackCommand::Quit => break,
+                _ => {
+                    let target = command.target(self.position, self.records.len());
+                    self.move_to(writer, target)?;
+                }
+            }
+        }
+
+        writer.flush()
+    }
+
+    /// Delay before the record at `index`, adjusted by the speed multiplier.
+    fn delay_before(&self, index: usize) -> Duration {
+        let offset = |i: usize| self.records.get(i).map_or(0, |r| r.offset_ms);
+        let delay_ms = offset(index).saturating_sub(offset(index.saturating_sub(1)));
+        if self.config.speed > 0.0 {
+            Duration::from_millis((delay_ms as f32 / self.config.speed) as u64)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Moves playback to `target`, showing records `0..target` without delay.
+    ///
+    /// Moving backwards clears the screen (terminal mode) and re-renders from
+    /// the start, since output already written can't be taken back.
+    fn move_to<W: Write>(&mut self, writer: &mut W, target: usize) -> io::Result<()> {
+        if target < self.position {
+            if self.config.replay_mode == ReplayMode::Terminal {
+                writer.write_all(b"\x1b[2J\x1b[H")?;
+            }
+            self.position = 0;
+        }
+        while self.position < target {
+            self.output_record(writer, self.position)?;
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    /// Outputs the record at `index` if it is a terminal write.
+    fn output_record<W: Write>(&self, writer: &mut W, index: usize) -> io::Result<()> {
+        if let Ok(UxEvent::TerminalWrite(write)) = Self::parse_ux_event(&self.records[index].record)
+        {
+            self.output_terminal_write(writer, &write)?;
+        }
+        Ok(())
+    }
+
     /// Outputs a terminal write event based on replay mode.
     fn output_terminal_write<W: Write>(
         &self,
@@ -490,6 +819,123 @@ mod tests {
         assert_eq!(String::from_utf8(output).unwrap(), "Hello World");
     }
 
+    fn three_writes() -> SessionPlayer {
+        let jsonl = [
+            make_write_record(b"A", true, 0, 1000),
+            make_write_record(b"B", true, 10, 1000),
+            make_write_record(b"C", true, 20, 1000),
+        ]
+        .join("\n");
+        SessionPlayer::from_bytes(jsonl.as_bytes()).unwrap()
+    }
+
+    /// Replays with scripted commands; once they run out, every wait times out.
+    fn replay_scripted(player: &mut SessionPlayer, commands: &[PlaybackCommand]) -> String {
+        let mut commands = commands.iter().copied();
+        let mut output = Vec::new();
+        player
+            .replay_with_controls(&mut output, |_| Ok(commands.next()))
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_playback_command_target() {
+        use PlaybackCommand::*;
+
+        let count = 5;
+        assert_eq!(Forward.target(0, count), 1);
+        assert_eq!(Forward.target(count, count), count);
+        assert_eq!(Back.target(3, count), 2);
+        assert_eq!(Back.target(0, count), 0);
+        assert_eq!(Seek(2).target(4, count), 2);
+        assert_eq!(Seek(99).target(0, count), count);
+        assert_eq!(TogglePause.target(3, count), 3);
+        assert_eq!(Seek(0).target(0, 0), 0);
+    }
+
+    #[test]
+    fn test_key_controls_buffer_digits_until_enter() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut controls = KeyControls::default();
+
+        assert_eq!(controls.command(key(KeyCode::Char('1'))), None);
+        assert_eq!(controls.command(key(KeyCode::Char('2'))), None);
+        assert_eq!(controls.command(key(KeyCode::Backspace)), None);
+        assert_eq!(controls.command(key(KeyCode::Char('4'))), None);
+        assert_eq!(
+            controls.command(key(KeyCode::Enter)),
+            Some(PlaybackCommand::Seek(14))
+        );
+        // The buffer is cleared, so a bare Enter does nothing
+        assert_eq!(controls.command(key(KeyCode::Enter)), None);
+        assert_eq!(
+            controls.command(key(KeyCode::Char(' '))),
+            Some(PlaybackCommand::TogglePause)
+        );
+        assert_eq!(
+            controls.command(key(KeyCode::Left)),
+            Some(PlaybackCommand::Back)
+        );
+        assert_eq!(
+            controls.command(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(PlaybackCommand::Quit)
+        );
+    }
+
+    #[test]
+    fn test_replay_with_controls_seeks_and_rewinds() {
+        let mut player = three_writes().with_config(PlayerConfig::text().with_speed(100.0));
+
+        let output = replay_scripted(
+            &mut player,
+            &[PlaybackCommand::Seek(2), PlaybackCommand::Back],
+        );
+
+        // Seek shows A and B, Back re-renders A, then playback continues
+        assert_eq!(output, "ABABC");
+        assert_eq!(player.position(), player.record_count());
+    }
+
+    #[test]
+    fn test_replay_with_controls_clears_screen_on_rewind() {
+        let mut player = three_writes().with_config(PlayerConfig::terminal().with_speed(100.0));
+
+        let output = replay_scripted(
+            &mut player,
+            &[PlaybackCommand::Forward, PlaybackCommand::Back],
+        );
+
+        assert_eq!(output, "A\x1b[2J\x1b[HABC");
+    }
+
+    #[test]
+    fn test_replay_with_controls_pause_and_quit() {
+        let mut player = three_writes();
+
+        let output = replay_scripted(
+            &mut player,
+            &[
+                PlaybackCommand::Forward,
+                PlaybackCommand::TogglePause,
+                PlaybackCommand::Quit,
+            ],
+        );
+
+        assert_eq!(output, "A");
+        assert_eq!(player.position(), 1);
+    }
+
+    #[test]
+    fn test_crlf_writer_translates_bare_newlines() {
+        let mut output = Vec::new();
+        let mut writer = CrlfWriter::new(&mut output);
+        writer.write_all(b"one\ntwo\r\nthree\r").unwrap();
+        writer.write_all(b"\nfour\n").unwrap();
+
+        assert_eq!(output, b"one\r\ntwo\r\nthree\r\nfour\r\n");
+    }
+
     #[test]
     fn test_replay_text_mode() {
         let line = make_write_record(b"\x1b[32mGreen\x1b[0m", true, 0, 1000);
@@ -521,6 +967,45 @@ mod tests {
         assert_eq!(player.record_count(), 0);
     }
 
+    const MALFORMED_SESSION: &str =
+        include_str!("../tests/fixtures/invalid/malformed_session.jsonl");
+
+    #[test]
+    fn test_from_reader_reports_bad_line() {
+        let err = SessionPlayer::from_bytes(MALFORMED_SESSION.as_bytes()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let message = err.to_string();
+        assert!(message.contains("on line 3:"), "{message}");
+        assert!(
+            message.contains(r#"{"ts": 3000, "event": "ux.terminal.write""#),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_validate_counts_records_and_invalid_lines() {
+        let validation = SessionPlayer::validate(MALFORMED_SESSION.as_bytes()).unwrap();
+
+        assert!(!validation.is_valid());
+        assert_eq!(validation.valid_records, 3);
+        assert_eq!(validation.invalid_lines.len(), 1);
+
+        let invalid = &validation.invalid_lines[0];
+        assert_eq!(invalid.line, 3);
+        assert!(invalid.snippet.ends_with('…'), "{}", invalid.snippet);
+        assert!(invalid.snippet.chars().count() <= InvalidLine::SNIPPET_LEN + 1);
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_session() {
+        let line = make_write_record(b"Hello", true, 0, 1000);
+        let validation = SessionPlayer::validate(format!("{line}\n\n{line}\n").as_bytes()).unwrap();
+
+        assert!(validation.is_valid());
+        assert_eq!(validation.valid_records, 2);
+    }
+
     #[test]
     fn test_whitespace_lines_skipped() {
         let line = make_write_record(b"test", true, 0, 1000);
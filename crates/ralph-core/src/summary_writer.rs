@@ -7,6 +7,7 @@ use crate::event_logger::EventHistory;
 use crate::event_loop::{LoopState, TerminationReason};
 use crate::landing::LandingResult;
 use crate::loop_context::LoopContext;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::io;
@@ -218,10 +219,12 @@ impl SummaryWriter {
             TerminationReason::LoopThrashing => "Failed: loop thrashing detected",
             TerminationReason::LoopStale => "Failed: stale loop detected",
             TerminationReason::ValidationFailure => "Failed: too many malformed JSONL events",
+            TerminationReason::HookFailed => "Failed: iteration hook failed (hooks_strict)",
             TerminationReason::Stopped => "Stopped manually",
             TerminationReason::Interrupted => "Interrupted by signal",
             TerminationReason::RestartRequested => "Restarting by human request",
             TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout)",
+            TerminationReason::SingleIteration => "Stopped after a single iteration",
         }
     }
 
@@ -298,6 +301,38 @@ fn format_duration(d: Duration) -> String {
     }
 }
 
+/// Machine-readable run summary written by `ralph run --summary-json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub reason: TerminationReason,
+    pub iteration: u32,
+    pub elapsed_secs: f64,
+    pub cumulative_cost: f64,
+}
+
+impl RunSummary {
+    /// Captures the summary of a loop terminating for `reason`.
+    pub fn new(reason: &TerminationReason, state: &LoopState) -> Self {
+        Self {
+            reason: reason.clone(),
+            iteration: state.iteration,
+            elapsed_secs: state.elapsed().as_secs_f64(),
+            cumulative_cost: state.cumulative_cost,
+        }
+    }
+
+    /// Writes the summary as pretty-printed JSON, creating parent directories.
+    pub fn write_json(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,6 +352,7 @@ mod tests {
             abandoned_tasks: Vec::new(),
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
+            consecutive_malformed_output_iterations: 0,
             completion_requested: false,
             hat_activation_counts: std::collections::HashMap::new(),
             exhausted_hats: std::collections::HashSet::new(),
@@ -326,6 +362,7 @@ mod tests {
             last_emitted_topic: None,
             consecutive_same_topic: 0,
             cancellation_requested: false,
+            verification_failure: None,
         }
     }
 
@@ -460,4 +497,23 @@ More text here.
         assert!(content.contains("**Stashes cleared:** 2"));
         assert!(content.contains("**Working tree clean:** Yes"));
     }
+
+    #[test]
+    fn test_run_summary_json_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("ci/summary.json");
+        let summary = RunSummary::new(&TerminationReason::MaxIterations, &test_state());
+
+        summary.write_json(&path).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["reason"], "max_iterations");
+        assert_eq!(json["iteration"], 12);
+        assert_eq!(json["cumulative_cost"], 1.5);
+        assert!(json["elapsed_secs"].is_f64());
+
+        let parsed: RunSummary = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, summary);
+    }
 }
@@ -20,13 +20,15 @@
 //! assert!(task.verification.command.contains("Hello, World!"));
 //! ```
 
+use crate::event_loop::TerminationReason;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-/// A suite of benchmark tasks loaded from a JSON file.
+/// A suite of benchmark tasks.
 ///
 /// The suite contains multiple tasks that can be run sequentially during
-/// batch benchmarking.
+/// batch benchmarking. It is loaded from a `tasks.json` or `tasks.yaml` file,
+/// or from a directory holding one task definition per file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskSuite {
     /// List of task definitions.
@@ -38,15 +40,109 @@ pub struct TaskSuite {
 }
 
 impl TaskSuite {
-    /// Loads a task suite from a JSON file.
+    /// Loads a task suite from a file or directory.
+    ///
+    /// - `*.yaml` / `*.yml` files are parsed as YAML, anything else as JSON.
+    /// - A directory is read as one task definition per `*.json`, `*.yaml` or
+    ///   `*.yml` file, merged in file name order.
+    ///
+    /// Besides schema validation, checks that every prompt and setup file
+    /// exists relative to [`tasks_dir`](Self::tasks_dir).
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TaskDefinitionError> {
         let path_ref = path.as_ref();
-        let content = std::fs::read_to_string(path_ref)?;
-        let suite: Self = serde_json::from_str(&content)?;
+        let suite = if path_ref.is_dir() {
+            Self::from_dir(path_ref)?
+        } else {
+            let content = std::fs::read_to_string(path_ref)?;
+            if is_yaml(path_ref) {
+                Self::from_yaml(&content)?
+            } else {
+                Self::from_json(&content)?
+            }
+        };
         suite.validate()?;
+        suite.validate_files(Self::tasks_dir(path_ref))?;
         Ok(suite)
     }
 
+    /// Returns the directory that task file paths are resolved against.
+    ///
+    /// This is the suite directory itself for directory-based suites, and the
+    /// suite file's parent directory otherwise.
+    pub fn tasks_dir(path: &Path) -> &Path {
+        if path.is_dir() {
+            path
+        } else {
+            path.parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or_else(|| Path::new("."))
+        }
+    }
+
+    /// Parses a task suite from JSON.
+    ///
+    /// Tasks are deserialized one at a time so that a malformed entry is
+    /// reported against its index and name rather than as a bare serde error.
+    pub fn from_json(content: &str) -> Result<Self, TaskDefinitionError> {
+        Self::from_value(serde_json::from_str(content)?)
+    }
+
+    /// Parses a task suite from YAML, with the same layout as the JSON form.
+    pub fn from_yaml(content: &str) -> Result<Self, TaskDefinitionError> {
+        Self::from_value(serde_yaml::from_str(content)?)
+    }
+
+    /// Loads a suite from a directory of per-task definition files.
+    ///
+    /// Each `*.json`, `*.yaml` or `*.yml` file holds a single task. Other
+    /// entries are ignored.
+    pub fn from_dir(dir: &Path) -> Result<Self, TaskDefinitionError> {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| matches!(ext, "json" | "yaml" | "yml"))
+            })
+            .collect();
+        files.sort();
+
+        let mut tasks = Vec::with_capacity(files.len());
+        for file in files {
+            let in_file = |source: TaskDefinitionError| TaskDefinitionError::InFile {
+                path: file.clone(),
+                source: Box::new(source),
+            };
+            let content = std::fs::read_to_string(&file).map_err(|e| in_file(e.into()))?;
+            let task = if is_yaml(&file) {
+                serde_yaml::from_str(&content).map_err(|e| in_file(e.into()))?
+            } else {
+                serde_json::from_str(&content).map_err(|e| in_file(e.into()))?
+            };
+            tasks.push(task);
+        }
+
+        Ok(Self {
+            tasks,
+            metadata: SuiteMetadata::default(),
+        })
+    }
+
+    fn from_value(value: serde_json::Value) -> Result<Self, TaskDefinitionError> {
+        if let Some(tasks) = value.get("tasks").and_then(|t| t.as_array()) {
+            for (index, task) in tasks.iter().enumerate() {
+                if let Err(e) = TaskDefinition::deserialize(task) {
+                    let name = task.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                    return Err(TaskDefinitionError::in_task(index, name, e.into()));
+                }
+            }
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+
     /// Validates all tasks in the suite.
     pub fn validate(&self) -> Result<(), TaskDefinitionError> {
         if self.tasks.is_empty() {
@@ -55,17 +151,18 @@ impl TaskSuite {
             ));
         }
 
-        for task in &self.tasks {
-            task.validate()?;
+        for (index, task) in self.tasks.iter().enumerate() {
+            task.validate()
+                .map_err(|e| TaskDefinitionError::in_task(index, &task.name, e))?;
         }
 
         // Check for duplicate names
-        let mut names = std::collections::HashSet::new();
-        for task in &self.tasks {
-            if !names.insert(&task.name) {
+        let mut names = std::collections::HashMap::new();
+        for (index, task) in self.tasks.iter().enumerate() {
+            if let Some(first) = names.insert(&task.name, index) {
                 return Err(TaskDefinitionError::Validation(format!(
-                    "Duplicate task name: '{}'",
-                    task.name
+                    "Duplicate task name: '{}' (tasks[{}] and tasks[{}])",
+                    task.name, first, index
                 )));
             }
         }
@@ -73,6 +170,35 @@ impl TaskSuite {
         Ok(())
     }
 
+    /// Checks that prompt and setup files referenced by each task exist.
+    ///
+    /// Paths are resolved against `tasks_dir`, the same way workspace setup
+    /// resolves them.
+    pub fn validate_files(&self, tasks_dir: &Path) -> Result<(), TaskDefinitionError> {
+        for (index, task) in self.tasks.iter().enumerate() {
+            let referenced = std::iter::once(("prompt_file", &task.prompt_file))
+                .chain(task.setup.files.iter().map(|f| ("setup.files", f)))
+                .chain(task.setup.script.iter().map(|s| ("setup.script", s)));
+
+            for (field, file) in referenced {
+                if !tasks_dir.join(file).exists() {
+                    return Err(TaskDefinitionError::in_task(
+                        index,
+                        &task.name,
+                        TaskDefinitionError::Validation(format!(
+                            "{} '{}' not found in {}",
+                            field,
+                            file,
+                            tasks_dir.display()
+                        )),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns tasks filtered by complexity level.
     pub fn filter_by_complexity(&self, complexity: &str) -> Vec<&TaskDefinition> {
         self.tasks
@@ -155,6 +281,14 @@ pub struct TaskDefinition {
     #[serde(default)]
     pub expected_iterations: Option<u32>,
 
+    /// Termination reason the loop is expected to end with (e.g.
+    /// `completion_promise`).
+    ///
+    /// A run that ends any other way is flagged as a mismatch in results,
+    /// even if verification passed.
+    #[serde(default)]
+    pub expected_termination: Option<TerminationReason>,
+
     /// Timeout in seconds for the entire task.
     #[serde(default = "default_timeout_seconds")]
     pub timeout_seconds: u64,
@@ -245,6 +379,15 @@ impl TaskDefinition {
         self.expected_iterations
             .map(|expected| actual as i32 - expected as i32)
     }
+
+    /// Returns true if expected_termination is set and `actual` differs.
+    ///
+    /// A loop that never ran (`None`) never matches an expectation.
+    pub fn termination_mismatch(&self, actual: Option<&TerminationReason>) -> bool {
+        self.expected_termination
+            .as_ref()
+            .is_some_and(|expected| actual != Some(expected))
+    }
 }
 
 /// Builder for constructing task definitions.
@@ -257,6 +400,7 @@ pub struct TaskDefinitionBuilder {
     complexity: String,
     max_iterations: u32,
     expected_iterations: Option<u32>,
+    expected_termination: Option<TerminationReason>,
     timeout_seconds: u64,
     setup: TaskSetup,
     tags: Vec<String>,
@@ -278,6 +422,7 @@ impl TaskDefinitionBuilder {
             complexity: default_complexity(),
             max_iterations: default_max_iterations(),
             expected_iterations: None,
+            expected_termination: None,
             timeout_seconds: default_timeout_seconds(),
             setup: TaskSetup::default(),
             tags: Vec::new(),
@@ -326,6 +471,12 @@ impl TaskDefinitionBuilder {
         self
     }
 
+    /// Sets the termination reason the loop is expected to end with.
+    pub fn expected_termination(mut self, reason: TerminationReason) -> Self {
+        self.expected_termination = Some(reason);
+        self
+    }
+
     /// Sets the timeout in seconds.
     pub fn timeout_seconds(mut self, seconds: u64) -> Self {
         self.timeout_seconds = seconds;
@@ -373,6 +524,7 @@ impl TaskDefinitionBuilder {
             complexity: self.complexity,
             max_iterations: self.max_iterations,
             expected_iterations: self.expected_iterations,
+            expected_termination: self.expected_termination,
             timeout_seconds: self.timeout_seconds,
             setup: self.setup,
             tags: self.tags,
@@ -446,6 +598,10 @@ pub enum TaskDefinitionError {
     #[error("JSON parse error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// YAML parse error.
+    #[error("YAML parse error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     /// Missing required field.
     #[error("Missing required field: {0}")]
     MissingField(String),
@@ -453,6 +609,46 @@ pub enum TaskDefinitionError {
     /// Validation error.
     #[error("Validation error: {0}")]
     Validation(String),
+
+    /// Error in a specific task of a suite.
+    #[error("Task {task}: {source}")]
+    InTask {
+        /// The offending task, e.g. `tasks[2] 'fizzbuzz'`.
+        task: String,
+        /// The underlying error.
+        source: Box<TaskDefinitionError>,
+    },
+
+    /// Error in a task file of a directory-based suite.
+    #[error("{}: {source}", path.display())]
+    InFile {
+        /// The offending task file.
+        path: PathBuf,
+        /// The underlying error.
+        source: Box<TaskDefinitionError>,
+    },
+}
+
+impl TaskDefinitionError {
+    /// Wraps an error with the index and name of the task that caused it.
+    fn in_task(index: usize, name: &str, source: TaskDefinitionError) -> Self {
+        let task = if name.is_empty() {
+            format!("tasks[{}]", index)
+        } else {
+            format!("tasks[{}] '{}'", index, name)
+        };
+        Self::InTask {
+            task,
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Returns true if the path has a YAML extension.
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "yaml" | "yml"))
 }
 
 #[cfg(test)]
@@ -567,6 +763,24 @@ mod tests {
         assert!(task.iteration_delta(5).is_none());
     }
 
+    #[test]
+    fn test_termination_mismatch() {
+        let task = TaskDefinition::builder("test", "prompt.md", "DONE")
+            .verification_command("echo ok")
+            .expected_termination(TerminationReason::CompletionPromise)
+            .build();
+
+        assert!(!task.termination_mismatch(Some(&TerminationReason::CompletionPromise)));
+        assert!(task.termination_mismatch(Some(&TerminationReason::MaxIterations)));
+        assert!(task.termination_mismatch(None));
+
+        // Without an expectation nothing is a mismatch
+        let task = TaskDefinition::builder("test", "prompt.md", "DONE")
+            .verification_command("echo ok")
+            .build();
+        assert!(!task.termination_mismatch(Some(&TerminationReason::MaxIterations)));
+    }
+
     #[test]
     fn test_task_suite_parse() {
         let json = r#"{
@@ -580,7 +794,8 @@ mod tests {
                     },
                     "complexity": "simple",
                     "max_iterations": 5,
-                    "expected_iterations": 1
+                    "expected_iterations": 1,
+                    "expected_termination": "completion_promise"
                 },
                 {
                     "name": "fizzbuzz-tdd",
@@ -613,6 +828,10 @@ mod tests {
         assert_eq!(hello.complexity, "simple");
         assert_eq!(hello.max_iterations, 5);
         assert_eq!(hello.expected_iterations, Some(1));
+        assert_eq!(
+            hello.expected_termination,
+            Some(TerminationReason::CompletionPromise)
+        );
 
         let fizzbuzz = &suite.tasks[1];
         assert_eq!(fizzbuzz.name, "fizzbuzz-tdd");
@@ -650,6 +869,147 @@ mod tests {
 
         let err = suite.validate().unwrap_err();
         assert!(err.to_string().contains("Duplicate task name"));
+        assert!(err.to_string().contains("tasks[0] and tasks[1]"));
+    }
+
+    #[test]
+    fn test_task_suite_missing_verification_names_task() {
+        let json = r#"{
+            "tasks": [
+                {"name": "ok", "prompt_file": "p.md", "completion_promise": "DONE", "verification": {"command": "echo ok"}},
+                {"name": "no-verify", "prompt_file": "p.md", "completion_promise": "DONE"}
+            ]
+        }"#;
+
+        let err = TaskSuite::from_json(json).unwrap_err();
+        assert!(matches!(
+            &err,
+            TaskDefinitionError::InTask { task, source }
+                if task == "tasks[1] 'no-verify'"
+                    && matches!(**source, TaskDefinitionError::Json(_))
+        ));
+        assert!(err.to_string().contains("missing field `verification`"));
+    }
+
+    #[test]
+    fn test_task_suite_empty_verification_command_names_task() {
+        let ok = TaskDefinition::builder("ok", "prompt.md", "DONE")
+            .verification_command("echo ok")
+            .build();
+        let empty = TaskDefinition::builder("empty-verify", "prompt.md", "DONE").build();
+        let suite = TaskSuite {
+            tasks: vec![ok, empty],
+            metadata: SuiteMetadata::default(),
+        };
+
+        let err = suite.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Task tasks[1] 'empty-verify': Missing required field: verification.command"
+        );
+    }
+
+    #[test]
+    fn test_task_suite_validate_files_missing_setup_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("prompt.md"), "Do it").unwrap();
+
+        let task = TaskDefinition::builder("needs-setup", "prompt.md", "DONE")
+            .verification_command("echo ok")
+            .setup_files(vec!["fixtures/input.txt".to_string()])
+            .build();
+        let suite = TaskSuite {
+            tasks: vec![task],
+            metadata: SuiteMetadata::default(),
+        };
+
+        let err = suite.validate_files(temp_dir.path()).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("tasks[0] 'needs-setup'"));
+        assert!(message.contains("setup.files 'fixtures/input.txt' not found"));
+
+        std::fs::create_dir_all(temp_dir.path().join("fixtures")).unwrap();
+        std::fs::write(temp_dir.path().join("fixtures/input.txt"), "data").unwrap();
+        assert!(suite.validate_files(temp_dir.path()).is_ok());
+    }
+
+    #[test]
+    fn test_task_suite_from_yaml_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("prompt.md"), "Do it").unwrap();
+        let suite_path = temp_dir.path().join("tasks.yaml");
+        std::fs::write(
+            &suite_path,
+            r"
+metadata:
+  name: YAML Suite
+tasks:
+  - name: yaml-task
+    prompt_file: prompt.md
+    completion_promise: DONE
+    verification:
+      command: echo ok
+    complexity: simple
+",
+        )
+        .unwrap();
+
+        let suite = TaskSuite::from_file(&suite_path).unwrap();
+        assert_eq!(suite.metadata.name.as_deref(), Some("YAML Suite"));
+        assert_eq!(suite.tasks.len(), 1);
+        assert_eq!(suite.tasks[0].name, "yaml-task");
+        assert_eq!(suite.tasks[0].complexity, "simple");
+    }
+
+    #[test]
+    fn test_task_suite_from_directory_merges_task_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::write(dir.join("prompt.md"), "Do it").unwrap();
+        std::fs::write(dir.join("README.md"), "Not a task").unwrap();
+        std::fs::write(
+            dir.join("a-hello.json"),
+            r#"{"name": "hello", "prompt_file": "prompt.md", "completion_promise": "DONE", "verification": {"command": "echo ok"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b-fizzbuzz.yaml"),
+            "name: fizzbuzz\nprompt_file: prompt.md\ncompletion_promise: DONE\nverification:\n  command: pytest\ntags: [python]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("c-sort.yml"),
+            "name: sort\nprompt_file: prompt.md\ncompletion_promise: DONE\nverification:\n  command: cargo test\n",
+        )
+        .unwrap();
+
+        let suite = TaskSuite::from_file(dir).unwrap();
+        let names: Vec<&str> = suite.tasks.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["hello", "fizzbuzz", "sort"]);
+        assert_eq!(suite.tasks[1].tags, ["python"]);
+        assert_eq!(TaskSuite::tasks_dir(dir), dir);
+    }
+
+    #[test]
+    fn test_task_suite_from_directory_names_bad_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("broken.yaml"),
+            "name: broken\nprompt_file: prompt.md\n",
+        )
+        .unwrap();
+
+        let err = TaskSuite::from_file(temp_dir.path()).unwrap_err();
+        assert!(
+            matches!(&err, TaskDefinitionError::InFile { path, .. } if path.ends_with("broken.yaml"))
+        );
+        assert!(err.to_string().contains("broken.yaml"));
+    }
+
+    #[test]
+    fn test_bundled_bench_suite_is_valid() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../bench/tasks.json");
+        TaskSuite::from_file(path).unwrap();
     }
 
     #[test]
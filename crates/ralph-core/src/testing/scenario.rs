@@ -2,7 +2,10 @@
 
 use super::mock_backend::MockBackend;
 use crate::config::RalphConfig;
+use crate::event_loop::EventLoop;
+use crate::event_parser::EventParser;
 use crate::event_reader::Event;
+use ralph_proto::HatId;
 
 /// A test scenario definition.
 #[derive(Debug)]
@@ -37,27 +40,65 @@ impl Scenario {
     }
 }
 
+/// Callback invoked with the event loop after each scenario iteration.
+pub type IterationHook = Box<dyn Fn(&EventLoop)>;
+
 /// Executes test scenarios with mock backend.
 pub struct ScenarioRunner {
     backend: MockBackend,
+    between_iterations: Option<IterationHook>,
 }
 
 impl ScenarioRunner {
     /// Creates a new scenario runner with mock backend.
     pub fn new(backend: MockBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            between_iterations: None,
+        }
+    }
+
+    /// Calls `hook` with the event loop after every iteration, so tests can
+    /// assert on bus and loop state (pending topics, iteration count) step by step.
+    pub fn with_between_iterations(mut self, hook: impl Fn(&EventLoop) + 'static) -> Self {
+        self.between_iterations = Some(Box::new(hook));
+        self
     }
 
     /// Executes a scenario and returns the trace.
+    ///
+    /// Drives an [`EventLoop`] built from the scenario config: each iteration
+    /// builds the prompt for the next hat, runs the mock backend, and
+    /// publishes any `<event>` tags in the response onto the bus.
     pub fn run(&self, scenario: &Scenario) -> ExecutionTrace {
+        let mut event_loop = EventLoop::new(scenario.config.clone());
+        event_loop.initialize(&format!("Scenario: {}", scenario.name));
+
         let mut iterations = 0;
         let events = Vec::new();
 
-        // Simulate iterations by calling the mock backend
         while iterations < scenario.expected_iterations {
-            let prompt = format!("Iteration {}", iterations + 1);
-            let _response = self.backend.execute(&prompt);
+            let hat_id = event_loop
+                .next_hat()
+                .cloned()
+                .unwrap_or_else(|| HatId::new("ralph"));
+            let prompt = event_loop
+                .build_prompt(&hat_id)
+                .unwrap_or_else(|| format!("Iteration {}", iterations + 1));
+            let response = self.backend.execute(&prompt);
+
+            event_loop.process_output(&hat_id, &response, true);
+            for event in EventParser::new()
+                .with_source(hat_id.clone())
+                .parse(&response)
+            {
+                event_loop.bus().publish_rate_limited(event);
+            }
             iterations += 1;
+
+            if let Some(hook) = &self.between_iterations {
+                hook(&event_loop);
+            }
         }
 
         ExecutionTrace {
@@ -102,6 +143,39 @@ mod tests {
         assert_eq!(trace.iterations, 1);
     }
 
+    #[test]
+    fn test_between_iterations_sees_bus_drain() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let backend = MockBackend::new(vec![
+            r#"<event topic="build.task">Implement feature X</event>"#.into(),
+            "Feature implemented, nothing left to do.".into(),
+        ]);
+        let steps = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&steps);
+        let runner = ScenarioRunner::new(backend).with_between_iterations(move |event_loop| {
+            let pending: Vec<String> = event_loop
+                .event_bus()
+                .pending_for(&HatId::new("ralph"))
+                .iter()
+                .map(|e| e.topic.to_string())
+                .collect();
+            recorded
+                .borrow_mut()
+                .push((event_loop.state().iteration, pending));
+        });
+
+        let scenario = Scenario::new("drain", RalphConfig::default()).with_iterations(2);
+        let trace = runner.run(&scenario);
+
+        assert_eq!(trace.iterations, 2);
+        assert_eq!(
+            *steps.borrow(),
+            vec![(1, vec!["build.task".to_string()]), (2, Vec::new())]
+        );
+    }
+
     #[test]
     fn test_mock_backend_simulates_hat_execution() {
         // Demo: Simulate a hat execution with scripted response
@@ -0,0 +1,324 @@
+//! Verification command runner shared by the benchmark harness and the main loop.
+//!
+//! A [`Verifier`] runs a shell command (tests, lint, ...) and compares its exit
+//! code against the expected one. The event loop uses it to turn an agent's
+//! self-reported `build.done` into `build.done` or `build.blocked` based on the
+//! real result.
+
+use crate::task_definition::Verification;
+use ralph_proto::Event;
+use std::io;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::time::Duration;
+
+/// Result of running a verification command.
+#[derive(Debug, Clone)]
+pub struct VerificationResult {
+    /// Whether verification passed (exit code matched expected).
+    pub passed: bool,
+
+    /// Actual exit code from the command.
+    pub exit_code: i32,
+
+    /// Expected exit code for success.
+    pub expected_exit_code: i32,
+
+    /// Stdout output from the command.
+    pub stdout: String,
+
+    /// Stderr output from the command.
+    pub stderr: String,
+}
+
+impl VerificationResult {
+    /// Returns a human-readable summary of the result.
+    pub fn summary(&self) -> String {
+        if self.passed {
+            format!("PASSED (exit code {})", self.exit_code)
+        } else {
+            format!(
+                "FAILED (exit code {}, expected {})",
+                self.exit_code, self.expected_exit_code
+            )
+        }
+    }
+
+    /// Returns combined stdout and stderr, keeping only the last `max_chars`
+    /// characters so the actual errors survive truncation.
+    pub fn output_tail(&self, max_chars: usize) -> String {
+        let combined = match (self.stdout.trim(), self.stderr.trim()) {
+            (out, "") => out.to_string(),
+            ("", err) => err.to_string(),
+            (out, err) => format!("{out}\n{err}"),
+        };
+
+        let total = combined.chars().count();
+        if total <= max_chars {
+            return combined;
+        }
+
+        let tail: String = combined.chars().skip(total - max_chars).collect();
+        format!("... ({} chars truncated)\n{tail}", total - max_chars)
+    }
+}
+
+/// Runs a configured verification command via `bash -c`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verifier {
+    command: String,
+    success_exit_code: i32,
+}
+
+impl Verifier {
+    /// Creates a verifier that expects exit code 0.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            success_exit_code: 0,
+        }
+    }
+
+    /// Sets the exit code that indicates success.
+    #[must_use]
+    pub fn with_success_exit_code(mut self, code: i32) -> Self {
+        self.success_exit_code = code;
+        self
+    }
+
+    /// Returns the command this verifier runs.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Runs the command in `dir`.
+    ///
+    /// An empty command is treated as passing without spawning anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command could not be spawned (not the same as
+    /// the command returning an unexpected exit code).
+    pub fn run(&self, dir: &Path) -> io::Result<VerificationResult> {
+        if self.command.is_empty() {
+            return Ok(Self::empty_result());
+        }
+
+        tracing::debug!(
+            "Running verification in {}: {}",
+            dir.display(),
+            self.command
+        );
+
+        let output = Command::new("bash")
+            .args(["-c", &self.command])
+            .current_dir(dir)
+            .output()?;
+
+        Ok(self.result_from_output(&output))
+    }
+
+    /// Runs the command in `dir` without blocking the async runtime.
+    ///
+    /// The command is killed if it outlives `timeout` or if the returned
+    /// future is dropped, so callers can race it against an interrupt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command could not be spawned, or an
+    /// [`io::ErrorKind::TimedOut`] error if it ran past `timeout`.
+    pub async fn run_async(
+        &self,
+        dir: &Path,
+        timeout: Option<Duration>,
+    ) -> io::Result<VerificationResult> {
+        if self.command.is_empty() {
+            return Ok(Self::empty_result());
+        }
+
+        tracing::debug!(
+            "Running verification in {}: {}",
+            dir.display(),
+            self.command
+        );
+
+        let output = tokio::process::Command::new("bash")
+            .args(["-c", &self.command])
+            .current_dir(dir)
+            .kill_on_drop(true)
+            .output();
+
+        let output = match timeout {
+            Some(limit) => tokio::time::timeout(limit, output).await.map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("timed out after {}s", limit.as_secs()),
+                )
+            })??,
+            None => output.await?,
+        };
+
+        Ok(self.result_from_output(&output))
+    }
+
+    fn empty_result() -> VerificationResult {
+        VerificationResult {
+            passed: true,
+            exit_code: 0,
+            expected_exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+        }
+    }
+
+    fn result_from_output(&self, output: &Output) -> VerificationResult {
+        let exit_code = output.status.code().unwrap_or(-1);
+        let passed = exit_code == self.success_exit_code;
+
+        tracing::debug!(
+            "Verification result: {} (exit code {}, expected {})",
+            if passed { "PASSED" } else { "FAILED" },
+            exit_code,
+            self.success_exit_code
+        );
+
+        VerificationResult {
+            passed,
+            exit_code,
+            expected_exit_code: self.success_exit_code,
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        }
+    }
+
+    /// Maps a result to the event the loop publishes in place of the agent's
+    /// `build.done`: `build.done` on pass, `build.blocked` on failure.
+    pub fn to_event(&self, result: &VerificationResult) -> Event {
+        if result.passed {
+            Event::new(
+                "build.done",
+                format!("verification: pass (`{}`)", self.command),
+            )
+        } else {
+            Event::new(
+                "build.blocked",
+                format!(
+                    "verification: fail (`{}` {}). Fix the failures before emitting build.done.",
+                    self.command,
+                    result.summary()
+                ),
+            )
+        }
+    }
+}
+
+impl From<&Verification> for Verifier {
+    fn from(verification: &Verification) -> Self {
+        Self::new(verification.command.clone())
+            .with_success_exit_code(verification.success_exit_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_run_passing_command() {
+        let dir = TempDir::new().unwrap();
+        let result = Verifier::new("echo ok").run(dir.path()).unwrap();
+
+        assert!(result.passed);
+        assert_eq!(result.exit_code, 0);
+        assert!(result.stdout.contains("ok"));
+    }
+
+    #[test]
+    fn test_run_failing_command() {
+        let dir = TempDir::new().unwrap();
+        let result = Verifier::new("exit 3").run(dir.path()).unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(result.exit_code, 3);
+    }
+
+    #[test]
+    fn test_run_empty_command_passes() {
+        let dir = TempDir::new().unwrap();
+        assert!(Verifier::new("").run(dir.path()).unwrap().passed);
+    }
+
+    #[tokio::test]
+    async fn test_run_async_matches_run() {
+        let dir = TempDir::new().unwrap();
+        let result = Verifier::new("echo ok; exit 3")
+            .run_async(dir.path(), None)
+            .await
+            .unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(result.exit_code, 3);
+        assert!(result.stdout.contains("ok"));
+    }
+
+    #[tokio::test]
+    async fn test_run_async_times_out() {
+        let dir = TempDir::new().unwrap();
+        let err = Verifier::new("sleep 30")
+            .run_async(dir.path(), Some(Duration::from_millis(100)))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn test_pass_maps_to_build_done() {
+        let dir = TempDir::new().unwrap();
+        let verifier = Verifier::new("true");
+        let event = verifier.to_event(&verifier.run(dir.path()).unwrap());
+
+        assert_eq!(event.topic.as_str(), "build.done");
+        assert!(event.payload.contains("verification: pass"));
+    }
+
+    #[test]
+    fn test_fail_maps_to_build_blocked() {
+        let dir = TempDir::new().unwrap();
+        let verifier = Verifier::new("false");
+        let event = verifier.to_event(&verifier.run(dir.path()).unwrap());
+
+        assert_eq!(event.topic.as_str(), "build.blocked");
+        assert!(event.payload.contains("FAILED (exit code 1, expected 0)"));
+    }
+
+    #[test]
+    fn test_output_tail_keeps_end_of_output() {
+        let result = VerificationResult {
+            passed: false,
+            exit_code: 1,
+            expected_exit_code: 0,
+            stdout: "running 3 tests".to_string(),
+            stderr: "error: test_foo failed".to_string(),
+        };
+
+        assert_eq!(
+            result.output_tail(100),
+            "running 3 tests\nerror: test_foo failed"
+        );
+        assert_eq!(
+            result.output_tail(15),
+            "... (23 chars truncated)\ntest_foo failed"
+        );
+    }
+
+    #[test]
+    fn test_from_verification_keeps_expected_exit_code() {
+        let verifier = Verifier::from(&Verification::expect_failure("exit 2", 2));
+        let dir = TempDir::new().unwrap();
+
+        assert_eq!(verifier.command(), "exit 2");
+        assert!(verifier.run(dir.path()).unwrap().passed);
+    }
+}
@@ -28,12 +28,16 @@
 //! # Ok::<(), ralph_core::workspace::WorkspaceError>(())
 //! ```
 
+#[cfg(unix)]
+use crate::loop_lock::{TryFlock, try_flock_exclusive};
 use crate::task_definition::{TaskDefinition, Verification};
-use std::fs;
-use std::io;
+pub use crate::verifier::VerificationResult;
+use crate::verifier::Verifier;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Cleanup policy for workspace directories.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -85,6 +89,9 @@ pub struct TaskWorkspace {
 
     /// Whether this workspace has been cleaned up.
     cleaned_up: bool,
+
+    /// Exclusive lock held for the lifetime of the workspace.
+    _lock: WorkspaceLock,
 }
 
 impl TaskWorkspace {
@@ -100,7 +107,9 @@ impl TaskWorkspace {
     ///
     /// # Errors
     ///
-    /// Returns `WorkspaceError` if directory creation or git init fails.
+    /// Returns `WorkspaceError::Locked` if another process already holds the
+    /// workspace, or another `WorkspaceError` if directory creation or git
+    /// init fails.
     pub fn create(task: &TaskDefinition, base_dir: &Path) -> Result<Self, WorkspaceError> {
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -110,8 +119,9 @@ impl TaskWorkspace {
         let dir_name = format!("ralph-bench-{}-{}", task.name, timestamp);
         let path = base_dir.join(&dir_name);
 
-        // Create workspace directory
+        // Create workspace directory and claim it before touching its contents
         fs::create_dir_all(&path)?;
+        let lock = WorkspaceLock::acquire(&path)?;
 
         // Create .ralph/agent directory with empty scratchpad
         let agent_dir = path.join(".ralph").join("agent");
@@ -140,11 +150,23 @@ impl TaskWorkspace {
             .current_dir(&path)
             .output()?;
 
+        // Keep the lock file out of the agent's commits
+        let exclude_path = path.join(".git").join("info").join("exclude");
+        if let Some(parent) = exclude_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut exclude = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&exclude_path)?;
+        writeln!(exclude, "{}", WorkspaceLock::LOCK_FILE)?;
+
         Ok(Self {
             path,
             task_name: task.name.clone(),
             created_at: timestamp,
             cleaned_up: false,
+            _lock: lock,
         })
     }
 
@@ -296,39 +318,6 @@ impl Drop for TaskWorkspace {
     }
 }
 
-/// Result of running a verification command.
-#[derive(Debug, Clone)]
-pub struct VerificationResult {
-    /// Whether verification passed (exit code matched expected).
-    pub passed: bool,
-
-    /// Actual exit code from the command.
-    pub exit_code: i32,
-
-    /// Expected exit code for success.
-    pub expected_exit_code: i32,
-
-    /// Stdout output from the command.
-    pub stdout: String,
-
-    /// Stderr output from the command.
-    pub stderr: String,
-}
-
-impl VerificationResult {
-    /// Returns a human-readable summary of the result.
-    pub fn summary(&self) -> String {
-        if self.passed {
-            format!("PASSED (exit code {})", self.exit_code)
-        } else {
-            format!(
-                "FAILED (exit code {}, expected {})",
-                self.exit_code, self.expected_exit_code
-            )
-        }
-    }
-}
-
 impl TaskWorkspace {
     /// Runs a verification command in the workspace directory.
     ///
@@ -350,49 +339,100 @@ impl TaskWorkspace {
         &self,
         verification: &Verification,
     ) -> Result<VerificationResult, WorkspaceError> {
-        if verification.command.is_empty() {
-            // No verification command - consider it passed
-            return Ok(VerificationResult {
-                passed: true,
-                exit_code: 0,
-                expected_exit_code: 0,
-                stdout: String::new(),
-                stderr: String::new(),
-            });
-        }
+        Verifier::from(verification)
+            .run(&self.path)
+            .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))
+    }
+}
 
-        tracing::debug!(
-            "Running verification in {}: {}",
-            self.path.display(),
-            verification.command
-        );
+/// An exclusive lock on a workspace directory.
+///
+/// Uses `flock()` on `.ralph-ws.lock` so that two benchmark processes can't
+/// reuse the same workspace. The lock is released when this is dropped (or
+/// when the process exits). On non-Unix platforms no lock is taken.
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    /// The open file handle (keeps the flock).
+    #[cfg(unix)]
+    _flock: nix::fcntl::Flock<File>,
 
-        let output = Command::new("bash")
-            .args(["-c", &verification.command])
-            .current_dir(&self.path)
-            .output()
-            .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
+    /// Placeholder for non-unix (no locking is performed).
+    #[cfg(not(unix))]
+    _file: File,
 
-        let exit_code = output.status.code().unwrap_or(-1);
-        let passed = exit_code == verification.success_exit_code;
+    /// Path to the lock file.
+    lock_path: PathBuf,
+}
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+impl WorkspaceLock {
+    /// The lock file name within the workspace directory.
+    pub const LOCK_FILE: &'static str = ".ralph-ws.lock";
 
-        tracing::debug!(
-            "Verification result: {} (exit code {}, expected {})",
-            if passed { "PASSED" } else { "FAILED" },
-            exit_code,
-            verification.success_exit_code
-        );
+    /// Tries to lock the workspace directory (non-blocking).
+    ///
+    /// # Errors
+    ///
+    /// Returns `WorkspaceError::Locked` if the workspace is already locked,
+    /// or `WorkspaceError::Io` if the lock file can't be opened.
+    pub fn acquire(workspace_dir: &Path) -> Result<Self, WorkspaceError> {
+        let lock_path = workspace_dir.join(Self::LOCK_FILE);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)?;
+
+        #[cfg(unix)]
+        {
+            match try_flock_exclusive(file)? {
+                TryFlock::Acquired(flock) => {
+                    tracing::debug!("Acquired workspace lock at {}", lock_path.display());
+                    Ok(Self {
+                        _flock: flock,
+                        lock_path,
+                    })
+                }
+                TryFlock::Held(_) => Err(WorkspaceError::Locked(workspace_dir.to_path_buf())),
+            }
+        }
 
-        Ok(VerificationResult {
-            passed,
-            exit_code,
-            expected_exit_code: verification.success_exit_code,
-            stdout,
-            stderr,
-        })
+        #[cfg(not(unix))]
+        {
+            Ok(Self {
+                _file: file,
+                lock_path,
+            })
+        }
+    }
+
+    /// Returns the path to the lock file.
+    pub fn lock_path(&self) -> &Path {
+        &self.lock_path
+    }
+
+    /// Returns true if another holder currently locks the workspace.
+    ///
+    /// Workspaces without a lock file, and any workspace on non-Unix
+    /// platforms, are reported as unlocked.
+    pub fn is_held(workspace_dir: &Path) -> bool {
+        #[cfg(unix)]
+        {
+            let Ok(file) = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(workspace_dir.join(Self::LOCK_FILE))
+            else {
+                return false;
+            };
+            matches!(try_flock_exclusive(file), Ok(TryFlock::Held(_)))
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = workspace_dir;
+            false
+        }
     }
 }
 
@@ -495,10 +535,14 @@ impl WorkspaceManager {
         }
 
         // Sort by timestamp (newest first)
-        workspaces.sort_by(|a, b| b.1.cmp(&a.1));
+        workspaces.sort_by_key(|w| std::cmp::Reverse(w.1));
 
-        // Delete workspaces beyond keep_last_n
+        // Delete workspaces beyond keep_last_n, sparing any still in use
         for (path, _) in workspaces.into_iter().skip(keep_last_n) {
+            if WorkspaceLock::is_held(&path) {
+                tracing::debug!("Skipping locked workspace: {}", path.display());
+                continue;
+            }
             tracing::debug!("Rotating old workspace: {}", path.display());
             fs::remove_dir_all(&path)?;
         }
@@ -506,6 +550,38 @@ impl WorkspaceManager {
         Ok(())
     }
 
+    /// Removes workspaces whose timestamp is older than `max_age`.
+    ///
+    /// Workspaces without a parseable timestamp, and workspaces whose lock is
+    /// held by a running benchmark, are left alone.
+    ///
+    /// # Returns
+    ///
+    /// The number of workspaces removed.
+    pub fn prune_older_than(&self, max_age: Duration) -> Result<usize, WorkspaceError> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let max_age_ms = u64::try_from(max_age.as_millis()).unwrap_or(u64::MAX);
+        let cutoff = now_ms.saturating_sub(max_age_ms);
+
+        let mut pruned = 0;
+        for ws in self.list_workspaces()? {
+            if ws.timestamp.is_some_and(|ts| ts < cutoff) {
+                if WorkspaceLock::is_held(&ws.path) {
+                    tracing::debug!("Skipping locked workspace: {}", ws.path.display());
+                    continue;
+                }
+                tracing::debug!("Pruning stale workspace: {}", ws.path.display());
+                fs::remove_dir_all(&ws.path)?;
+                pruned += 1;
+            }
+        }
+
+        Ok(pruned)
+    }
+
     /// Lists all workspace directories in the base directory.
     pub fn list_workspaces(&self) -> Result<Vec<WorkspaceInfo>, WorkspaceError> {
         if !self.base_dir.exists() {
@@ -537,7 +613,7 @@ impl WorkspaceManager {
         }
 
         // Sort by timestamp (newest first)
-        workspaces.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        workspaces.sort_by_key(|w| std::cmp::Reverse(w.timestamp));
 
         Ok(workspaces)
     }
@@ -578,6 +654,10 @@ pub enum WorkspaceError {
     /// Verification command failed to execute.
     #[error("Verification failed: {0}")]
     Verification(String),
+
+    /// The workspace is locked by another run.
+    #[error("Workspace {} is already in use by another run", .0.display())]
+    Locked(PathBuf),
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -672,6 +752,27 @@ mod tests {
         workspace.cleanup().unwrap();
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_workspace_lock_rejects_second_holder() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Never);
+        let task = make_test_task("lock-test");
+
+        let workspace = manager.create_workspace(&task).unwrap();
+        let path = workspace.path().to_path_buf();
+        assert!(path.join(WorkspaceLock::LOCK_FILE).exists());
+
+        // A second attempt fails while the first lock is held
+        let err = WorkspaceLock::acquire(&path).unwrap_err();
+        assert!(matches!(err, WorkspaceError::Locked(ref p) if p == &path));
+        assert!(err.to_string().contains("already in use"));
+
+        // Dropping the workspace releases the lock
+        drop(workspace);
+        assert!(WorkspaceLock::acquire(&path).is_ok());
+    }
+
     #[test]
     fn test_workspace_setup_with_prompt() {
         let temp_dir = TempDir::new().unwrap();
@@ -805,18 +906,97 @@ mod tests {
         let ws3 = manager.create_workspace(&task).unwrap();
 
         // All three exist
-        assert!(ws1.path().exists());
-        assert!(ws2.path().exists());
-        assert!(ws3.path().exists());
+        let paths: Vec<PathBuf> = [&ws1, &ws2, &ws3]
+            .iter()
+            .map(|ws| ws.path().to_path_buf())
+            .collect();
+        assert!(paths.iter().all(|p| p.exists()));
+
+        // Release the locks so the runs count as finished
+        drop((ws1, ws2, ws3));
 
         // Rotate should keep only 2
         manager.rotate_workspaces(2).unwrap();
 
         // ws1 should be deleted (oldest)
-        assert!(!ws1.path().exists());
+        assert!(!paths[0].exists());
         // ws2 and ws3 should remain
-        assert!(ws2.path().exists());
-        assert!(ws3.path().exists());
+        assert!(paths[1].exists());
+        assert!(paths[2].exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_workspace_manager_cleanup_skips_locked_workspaces() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Never);
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let day_ms = 24 * 60 * 60 * 1000;
+        let in_use = temp_dir
+            .path()
+            .join(format!("ralph-bench-in-use-{}", now_ms - 10 * day_ms));
+        let idle = temp_dir
+            .path()
+            .join(format!("ralph-bench-idle-{}", now_ms - 9 * day_ms));
+        for dir in [&in_use, &idle] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        let lock = WorkspaceLock::acquire(&in_use).unwrap();
+        assert!(WorkspaceLock::is_held(&in_use));
+        assert!(!WorkspaceLock::is_held(&idle));
+
+        manager.rotate_workspaces(0).unwrap();
+        assert!(in_use.exists());
+        assert!(!idle.exists());
+
+        let pruned = manager
+            .prune_older_than(Duration::from_hours(7 * 24))
+            .unwrap();
+        assert_eq!(pruned, 0);
+        assert!(in_use.exists());
+
+        drop(lock);
+        let pruned = manager
+            .prune_older_than(Duration::from_hours(7 * 24))
+            .unwrap();
+        assert_eq!(pruned, 1);
+        assert!(!in_use.exists());
+    }
+
+    #[test]
+    fn test_workspace_manager_prune_older_than() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Never);
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let day_ms = 24 * 60 * 60 * 1000;
+
+        let old = temp_dir
+            .path()
+            .join(format!("ralph-bench-old-{}", now_ms - 10 * day_ms));
+        let recent = temp_dir
+            .path()
+            .join(format!("ralph-bench-recent-{}", now_ms - day_ms));
+        let undated = temp_dir.path().join("ralph-bench-undated");
+        for dir in [&old, &recent, &undated] {
+            fs::create_dir_all(dir).unwrap();
+        }
+
+        let pruned = manager
+            .prune_older_than(Duration::from_hours(7 * 24))
+            .unwrap();
+
+        assert_eq!(pruned, 1);
+        assert!(!old.exists());
+        assert!(recent.exists());
+        assert!(undated.exists());
     }
 
     #[test]
@@ -80,7 +80,7 @@ pub struct AnalyzerConfig {
 impl Default for AnalyzerConfig {
     fn default() -> Self {
         Self {
-            timeout: Duration::from_secs(120),
+            timeout: Duration::from_mins(2),
             max_iterations: 1,
             backend: "claude".to_string(),
         }
@@ -700,6 +700,7 @@ mod tests {
                 },
             ],
             duration: Duration::from_secs_f64(45.2),
+            attempts: 1,
         }
     }
 
@@ -725,6 +726,7 @@ mod tests {
                 },
             ],
             duration: Duration::from_secs_f64(12.3),
+            attempts: 1,
         }
     }
 
@@ -733,7 +735,7 @@ mod tests {
         let workspace = PathBuf::from(".e2e-tests");
         let analyzer = MetaRalphAnalyzer::new(workspace.clone());
         assert_eq!(analyzer.workspace(), &workspace);
-        assert_eq!(analyzer.config().timeout, Duration::from_secs(120));
+        assert_eq!(analyzer.config().timeout, Duration::from_mins(2));
         assert_eq!(analyzer.config().max_iterations, 1);
         assert_eq!(analyzer.config().backend, "claude");
     }
@@ -742,12 +744,12 @@ mod tests {
     fn test_analyzer_with_config() {
         let workspace = PathBuf::from(".e2e-tests");
         let config = AnalyzerConfig {
-            timeout: Duration::from_secs(60),
+            timeout: Duration::from_mins(1),
             max_iterations: 2,
             backend: "kiro".to_string(),
         };
         let analyzer = MetaRalphAnalyzer::with_config(workspace.clone(), config);
-        assert_eq!(analyzer.config().timeout, Duration::from_secs(60));
+        assert_eq!(analyzer.config().timeout, Duration::from_mins(1));
         assert_eq!(analyzer.config().max_iterations, 2);
         assert_eq!(analyzer.config().backend, "kiro");
     }
@@ -804,7 +806,7 @@ mod tests {
     #[test]
     fn test_generate_analyzer_config_custom() {
         let config = AnalyzerConfig {
-            timeout: Duration::from_secs(60),
+            timeout: Duration::from_mins(1),
             max_iterations: 3,
             backend: "kiro".to_string(),
         };
@@ -1046,10 +1048,7 @@ ANALYSIS_COMPLETE
     #[tokio::test]
     #[ignore = "requires ralph binary"]
     async fn test_analyzer_integration() {
-        use std::env;
-
-        let workspace =
-            env::temp_dir().join(format!("ralph-e2e-analyzer-test-{}", std::process::id()));
+        let workspace = crate::workspace::unique_test_dir("ralph-e2e-analyzer-test");
         std::fs::create_dir_all(&workspace).unwrap();
 
         let analyzer = MetaRalphAnalyzer::new(workspace.clone());
@@ -175,6 +175,7 @@ impl AuthChecker {
     /// - Claude: `claude --version` with API key set returns successfully
     /// - Kiro: `kiro-cli --version` similarly
     /// - OpenCode: `opencode --version`
+    /// - Gemini, Pi: `gemini --version`, `pi --version`
     ///
     /// For now, we use a simple heuristic: if the CLI is available and
     /// can report its version, we assume it's configured. A more robust
@@ -192,6 +193,7 @@ impl AuthChecker {
             Backend::Claude => Self::check_claude_auth().await,
             Backend::Kiro => Self::check_kiro_auth().await,
             Backend::OpenCode => Self::check_opencode_auth().await,
+            Backend::Gemini | Backend::Pi => Self::check_version_succeeds(backend).await,
         }
     }
 
@@ -235,6 +237,18 @@ impl AuthChecker {
 
         matches!(output, Ok(o) if o.status.success())
     }
+
+    /// Generic check for backends without a dedicated one: `<cmd> --version` succeeds.
+    async fn check_version_succeeds(backend: Backend) -> bool {
+        let output = Command::new(backend.command())
+            .arg("--version")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await;
+
+        matches!(output, Ok(o) if o.status.success())
+    }
 }
 
 #[cfg(test)]
@@ -310,13 +324,15 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_check_all_returns_three_backends() {
+    async fn test_check_all_returns_every_backend() {
         let checker = AuthChecker::new();
         let results = checker.check_all().await;
-        assert_eq!(results.len(), 3);
+        assert_eq!(results.len(), 5);
         assert!(results.iter().any(|r| r.backend == Backend::Claude));
         assert!(results.iter().any(|r| r.backend == Backend::Kiro));
         assert!(results.iter().any(|r| r.backend == Backend::OpenCode));
+        assert!(results.iter().any(|r| r.backend == Backend::Gemini));
+        assert!(results.iter().any(|r| r.backend == Backend::Pi));
     }
 
     #[tokio::test]
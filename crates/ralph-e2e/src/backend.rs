@@ -15,6 +15,10 @@ pub enum Backend {
     Kiro,
     /// OpenCode CLI backend
     OpenCode,
+    /// Gemini CLI backend
+    Gemini,
+    /// Pi CLI backend
+    Pi,
 }
 
 impl Backend {
@@ -24,19 +28,29 @@ impl Backend {
             Backend::Claude => "claude",
             Backend::Kiro => "kiro-cli",
             Backend::OpenCode => "opencode",
+            Backend::Gemini => "gemini",
+            Backend::Pi => "pi",
         }
     }
 
     /// Returns all available backends.
     pub fn all() -> &'static [Backend] {
-        &[Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        &[
+            Backend::Claude,
+            Backend::Kiro,
+            Backend::OpenCode,
+            Backend::Gemini,
+            Backend::Pi,
+        ]
     }
 
     /// Returns the default timeout for this backend.
     pub fn default_timeout(&self) -> Duration {
         match self {
-            Backend::Claude => Duration::from_secs(600), // 10 minutes - Claude iterations can take 60-120s each
-            Backend::Kiro | Backend::OpenCode => Duration::from_secs(300), // 5 minutes
+            Backend::Claude => Duration::from_mins(10), // 10 minutes - Claude iterations can take 60-120s each
+            Backend::Kiro | Backend::OpenCode | Backend::Gemini | Backend::Pi => {
+                Duration::from_mins(5) // 5 minutes
+            }
         }
     }
 
@@ -44,7 +58,7 @@ impl Backend {
     pub fn default_max_iterations(&self) -> u32 {
         match self {
             Backend::Claude => 5, // Extra buffer for LLM non-determinism
-            Backend::Kiro | Backend::OpenCode => 3,
+            Backend::Kiro | Backend::OpenCode | Backend::Gemini | Backend::Pi => 3,
         }
     }
 
@@ -54,6 +68,8 @@ impl Backend {
             Backend::Claude => "claude",
             Backend::Kiro => "kiro",
             Backend::OpenCode => "opencode",
+            Backend::Gemini => "gemini",
+            Backend::Pi => "pi",
         }
     }
 }
@@ -64,6 +80,8 @@ impl fmt::Display for Backend {
             Backend::Claude => write!(f, "Claude"),
             Backend::Kiro => write!(f, "Kiro"),
             Backend::OpenCode => write!(f, "OpenCode"),
+            Backend::Gemini => write!(f, "Gemini"),
+            Backend::Pi => write!(f, "Pi"),
         }
     }
 }
@@ -29,6 +29,7 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::time::Duration;
 use thiserror::Error;
@@ -59,7 +60,7 @@ impl ScenarioConfig {
             config_file: PathBuf::from("ralph.yml"),
             prompt: PromptSource::Inline(prompt.into()),
             max_iterations: 1,
-            timeout: Duration::from_secs(300), // 5 minutes - Claude iterations can take 60-120s
+            timeout: Duration::from_mins(5), // 5 minutes - Claude iterations can take 60-120s
             extra_args: vec![],
         }
     }
@@ -102,7 +103,7 @@ pub struct ExecutionResult {
     /// Reason for termination, if detected.
     pub termination_reason: Option<String>,
 
-    /// Whether the execution timed out.
+    /// Whether the execution timed out (the process was killed).
     pub timed_out: bool,
 }
 
@@ -224,6 +225,30 @@ impl RalphExecutor {
             .unwrap_or_else(|| PathBuf::from("ralph"))
     }
 
+    /// Builds the `ralph run` arguments for a scenario.
+    ///
+    /// The scenario's `extra_args` come last so they can override the defaults
+    /// (e.g. `--color never`, `--backend`).
+    fn run_args(config: &ScenarioConfig) -> Vec<OsString> {
+        let mut args: Vec<OsString> = vec![
+            "run".into(),
+            "-c".into(),
+            config.config_file.clone().into(),
+            "--max-iterations".into(),
+            config.max_iterations.to_string().into(),
+        ];
+
+        // Handle prompt
+        args.push("-p".into());
+        match &config.prompt {
+            PromptSource::File(path) => args.push(format!("@{}", path.display()).into()),
+            PromptSource::Inline(prompt) => args.push(prompt.into()),
+        }
+
+        args.extend(config.extra_args.iter().map(OsString::from));
+        args
+    }
+
     /// Executes ralph with the given configuration.
     pub async fn run(&self, config: &ScenarioConfig) -> Result<ExecutionResult, ExecutorError> {
         self.run_with_timeout(config, config.timeout).await
@@ -255,15 +280,13 @@ impl RalphExecutor {
         // Build the command
         // Note: Pass config_file (not full config_path) because current_dir is set to workspace
         let mut cmd = Command::new(self.ralph_binary());
-        cmd.arg("run")
-            .arg("-c")
-            .arg(&config.config_file)
-            .arg("--max-iterations")
-            .arg(config.max_iterations.to_string())
+        cmd.args(Self::run_args(config))
             .current_dir(&self.workspace)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
+            // Don't leave ralph running if this future is dropped mid-run
+            .kill_on_drop(true)
             // Always enable diagnostics for E2E tests to aid debugging
             .env("RALPH_DIAGNOSTICS", "1")
             // Pass workspace root so Ralph resolves paths correctly in E2E tests
@@ -271,20 +294,9 @@ impl RalphExecutor {
             // Use Haiku for faster, cheaper E2E tests
             .env("CLAUDE_MODEL", "haiku");
 
-        // Handle prompt
-        match &config.prompt {
-            PromptSource::File(path) => {
-                cmd.arg("-p").arg(format!("@{}", path.display()));
-            }
-            PromptSource::Inline(prompt) => {
-                cmd.arg("-p").arg(prompt);
-            }
-        }
-
-        // Add extra args
-        for arg in &config.extra_args {
-            cmd.arg(arg);
-        }
+        // Own process group, so a timeout can stop everything ralph started
+        #[cfg(unix)]
+        cmd.process_group(0);
 
         // Spawn the process
         let mut child = cmd.spawn()?;
@@ -294,68 +306,86 @@ impl RalphExecutor {
             stdin.shutdown().await.ok();
         }
 
-        // Wait with timeout - we need to handle the timeout separately
-        // because wait_with_output consumes the child
-        let wait_result =
-            tokio::time::timeout(timeout, async { child.wait_with_output().await }).await;
+        // Drain output in the background so the child can be killed on timeout
+        // without losing what it printed so far
+        let stdout_reader = tokio::spawn(read_to_end(child.stdout.take()));
+        let stderr_reader = tokio::spawn(read_to_end(child.stderr.take()));
 
-        let duration = start.elapsed();
-
-        match wait_result {
-            Ok(Ok(output)) => {
-                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-
-                // Read scratchpad if it exists
-                let scratchpad = self.read_scratchpad().await;
-
-                // Read events from JSONL file (primary source)
-                let events = self.read_events_from_jsonl().await;
-
-                // Count iterations from output
-                let iterations = self.count_iterations(&stdout);
-
-                // Detect termination reason
-                let termination_reason = self.detect_termination_reason(&stdout);
-
-                Ok(ExecutionResult {
-                    exit_code: output.status.code(),
-                    stdout,
-                    stderr,
-                    duration,
-                    scratchpad,
-                    events,
-                    iterations,
-                    termination_reason,
-                    timed_out: false,
-                })
-            }
-            Ok(Err(e)) => Err(ExecutorError::SpawnError(e)),
+        let (status, timed_out) = match tokio::time::timeout(timeout, child.wait()).await {
+            Ok(status) => (Some(status.map_err(ExecutorError::SpawnError)?), false),
             Err(_) => {
-                // Timeout occurred - the child is already consumed by wait_with_output
-                // The process may still be running, but we can't kill it directly
-                // Return a timeout result indicating what happened
-                Ok(ExecutionResult {
-                    exit_code: None,
-                    stdout: String::new(),
-                    stderr: String::new(),
-                    duration,
-                    scratchpad: self.read_scratchpad().await,
-                    events: vec![],
-                    iterations: 0,
-                    termination_reason: Some("TIMEOUT".to_string()),
-                    timed_out: true,
-                })
+                // Stop ralph and everything it spawned so nothing outlives the scenario
+                stop_process_tree(&mut child).await;
+                (None, true)
             }
-        }
+        };
+
+        let duration = start.elapsed();
+
+        // Processes spawned by ralph may still hold the pipes open after a kill
+        let stdout = collect_output(stdout_reader, timed_out).await;
+        let stderr = collect_output(stderr_reader, timed_out).await;
+
+        // Read scratchpad if it exists
+        let scratchpad = self.read_scratchpad(config).await;
+
+        // Read events from JSONL file (primary source)
+        let events = self.read_events_from_jsonl().await;
+
+        // Count iterations from output
+        let iterations = self.count_iterations(&stdout);
+
+        // Detect termination reason
+        let termination_reason = if timed_out {
+            Some("TIMEOUT".to_string())
+        } else {
+            self.detect_termination_reason(&stdout)
+        };
+
+        Ok(ExecutionResult {
+            exit_code: status.and_then(|s| s.code()),
+            stdout,
+            stderr,
+            duration,
+            scratchpad,
+            events,
+            iterations,
+            termination_reason,
+            timed_out,
+        })
     }
 
     /// Reads the scratchpad file from the workspace.
-    async fn read_scratchpad(&self) -> Option<String> {
-        let scratchpad_path = self.workspace.join(".agent").join("scratchpad.md");
+    async fn read_scratchpad(&self, config: &ScenarioConfig) -> Option<String> {
+        let scratchpad_path = self.scratchpad_path(config);
         tokio::fs::read_to_string(scratchpad_path).await.ok()
     }
 
+    /// Resolves the scratchpad path: `core.scratchpad` from the scenario's
+    /// config, else ralph's default, falling back to the legacy
+    /// `.agent/scratchpad.md` if the resolved file doesn't exist.
+    fn scratchpad_path(&self, config: &ScenarioConfig) -> PathBuf {
+        let configured = std::fs::read_to_string(self.workspace.join(&config.config_file))
+            .ok()
+            .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+            .and_then(|yaml| {
+                yaml.get("core")?
+                    .get("scratchpad")?
+                    .as_str()
+                    .map(str::to_string)
+            });
+
+        let path = self
+            .workspace
+            .join(configured.as_deref().unwrap_or(DEFAULT_SCRATCHPAD));
+        let legacy = self.workspace.join(".agent").join("scratchpad.md");
+        if !path.exists() && legacy.exists() {
+            legacy
+        } else {
+            path
+        }
+    }
+
     /// Reads events from .ralph/events.jsonl file.
     ///
     /// Ralph writes events to JSONL format since commit dfb8f8de.
@@ -452,20 +482,79 @@ mod duration_serde {
     }
 }
 
+/// Ralph's default scratchpad path, relative to the workspace.
+const DEFAULT_SCRATCHPAD: &str = ".ralph/agent/scratchpad.md";
+
+/// How long to wait for output pipes to close after stopping a timed out process.
+const OUTPUT_GRACE: Duration = Duration::from_secs(2);
+
+/// How long ralph gets to shut its agent down after SIGINT before its
+/// process group is killed.
+const STOP_GRACE: Duration = Duration::from_secs(5);
+
+/// Stops a timed out ralph process and everything it spawned, then reaps it.
+///
+/// SIGINT lets ralph terminate its agent (which runs in its own session) and
+/// write its summary. Whatever is left in ralph's process group after the
+/// grace period, or after ralph exits, is killed.
+#[cfg(unix)]
+async fn stop_process_tree(child: &mut tokio::process::Child) {
+    use nix::sys::signal::{Signal, killpg};
+    use nix::unistd::Pid;
+
+    let Some(group) = child.id().and_then(|pid| i32::try_from(pid).ok()) else {
+        return;
+    };
+    let group = Pid::from_raw(group);
+
+    if killpg(group, Signal::SIGINT).is_ok() {
+        tokio::time::timeout(STOP_GRACE, child.wait()).await.ok();
+    }
+    // The group outlives its leader while any member is still running
+    killpg(group, Signal::SIGKILL).ok();
+    child.kill().await.ok();
+}
+
+/// Kills and reaps a timed out process.
+#[cfg(not(unix))]
+async fn stop_process_tree(child: &mut tokio::process::Child) {
+    child.kill().await.ok();
+}
+
+/// Reads a child's output pipe to the end.
+async fn read_to_end(pipe: Option<impl tokio::io::AsyncRead + Unpin>) -> Vec<u8> {
+    use tokio::io::AsyncReadExt;
+
+    let mut buf = Vec::new();
+    if let Some(mut pipe) = pipe {
+        pipe.read_to_end(&mut buf).await.ok();
+    }
+    buf
+}
+
+/// Collects a pipe reader's output, giving up after [`OUTPUT_GRACE`] if the
+/// process was killed.
+async fn collect_output(reader: tokio::task::JoinHandle<Vec<u8>>, killed: bool) -> String {
+    let bytes = if killed {
+        match tokio::time::timeout(OUTPUT_GRACE, reader).await {
+            Ok(result) => result.unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    } else {
+        reader.await.unwrap_or_default()
+    };
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
     use std::path::Path;
 
     /// Creates a unique test workspace path.
     fn test_workspace(test_name: &str) -> PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-executor-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-executor-{test_name}"))
     }
 
     /// Sets up a test workspace with a minimal ralph.yml.
@@ -525,10 +614,58 @@ mod tests {
         assert_eq!(config.config_file, PathBuf::from("ralph.yml"));
         assert!(matches!(config.prompt, PromptSource::Inline(p) if p == "Say hello"));
         assert_eq!(config.max_iterations, 1);
-        assert_eq!(config.timeout, Duration::from_secs(300));
+        assert_eq!(config.timeout, Duration::from_mins(5));
         assert!(config.extra_args.is_empty());
     }
 
+    #[test]
+    fn test_run_args_forward_extra_args() {
+        let config = ScenarioConfig {
+            extra_args: vec![
+                "--color".to_string(),
+                "never".to_string(),
+                "--backend".to_string(),
+                "kiro".to_string(),
+            ],
+            ..ScenarioConfig::minimal("Say hello")
+        };
+
+        let args = RalphExecutor::run_args(&config);
+
+        assert_eq!(
+            args,
+            [
+                "run",
+                "-c",
+                "ralph.yml",
+                "--max-iterations",
+                "1",
+                "-p",
+                "Say hello",
+                "--color",
+                "never",
+                "--backend",
+                "kiro",
+            ]
+            .map(OsString::from)
+        );
+    }
+
+    #[test]
+    fn test_run_args_prompt_file_uses_at_syntax() {
+        let config = ScenarioConfig {
+            prompt: PromptSource::File(PathBuf::from("PROMPT.md")),
+            ..ScenarioConfig::minimal("")
+        };
+
+        let args = RalphExecutor::run_args(&config);
+
+        assert_eq!(
+            args[5..],
+            [OsString::from("-p"), OsString::from("@PROMPT.md")]
+        );
+    }
+
     #[test]
     fn test_count_iterations_none() {
         let executor = RalphExecutor::new(PathBuf::from("/tmp"));
@@ -634,6 +771,146 @@ mod tests {
         assert_eq!(parsed.iterations, 2);
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_kills_and_reaps_on_timeout() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let workspace = test_workspace("timeout");
+        setup_workspace(&workspace);
+
+        // Stand-in for ralph that never finishes
+        let binary = workspace.join("slow-ralph.sh");
+        fs::write(
+            &binary,
+            "#!/bin/sh\necho $$ > slow-ralph.pid\necho started\nexec sleep 30\n",
+        )
+        .unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = RalphExecutor::with_binary(workspace.clone(), binary);
+        let config = ScenarioConfig::minimal("test");
+
+        let result = executor
+            .run_with_timeout(&config, Duration::from_millis(500))
+            .await
+            .unwrap();
+
+        assert!(result.timed_out);
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.termination_reason.as_deref(), Some("TIMEOUT"));
+        assert!(result.duration < Duration::from_secs(10));
+        assert_eq!(result.stdout.trim(), "started");
+
+        // A reaped process no longer has a /proc entry (a zombie would)
+        let pid = fs::read_to_string(workspace.join("slow-ralph.pid")).unwrap();
+        if Path::new("/proc/self").exists() {
+            assert!(!Path::new("/proc").join(pid.trim()).exists());
+        }
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_timeout_leaves_no_child_processes() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let workspace = test_workspace("timeout-children");
+        setup_workspace(&workspace);
+
+        // Stand-in for ralph with an agent that ignores SIGINT, as background
+        // jobs of a non-interactive shell do
+        let binary = workspace.join("slow-ralph.sh");
+        fs::write(
+            &binary,
+            "#!/bin/sh\nsleep 30 &\necho $! > agent.pid\nexec sleep 30\n",
+        )
+        .unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = RalphExecutor::with_binary(workspace.clone(), binary);
+        let result = executor
+            .run_with_timeout(&ScenarioConfig::minimal("test"), Duration::from_millis(500))
+            .await
+            .unwrap();
+        assert!(result.timed_out);
+
+        // Gone, or at most a zombie waiting for init to reap it
+        let pid = fs::read_to_string(workspace.join("agent.pid")).unwrap();
+        if Path::new("/proc/self").exists() {
+            let stat = fs::read_to_string(Path::new("/proc").join(pid.trim()).join("stat"));
+            let running = stat.as_ref().is_ok_and(|s| !s.contains(") Z "));
+            assert!(!running, "agent still running: {stat:?}");
+        }
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[test]
+    fn test_scratchpad_path_resolution() {
+        let workspace = test_workspace("scratchpad-path");
+        setup_workspace(&workspace);
+        let executor = RalphExecutor::new(workspace.clone());
+        let config = ScenarioConfig::minimal("test");
+
+        // Ralph's default when nothing exists yet
+        assert_eq!(
+            executor.scratchpad_path(&config),
+            workspace.join(".ralph/agent/scratchpad.md")
+        );
+
+        // Legacy location when only it exists
+        fs::write(workspace.join(".agent/scratchpad.md"), "legacy").unwrap();
+        assert_eq!(
+            executor.scratchpad_path(&config),
+            workspace.join(".agent/scratchpad.md")
+        );
+
+        // Configured path wins once it exists
+        fs::write(
+            workspace.join("ralph.yml"),
+            "cli:\n  backend: claude\ncore:\n  scratchpad: notes.md\n",
+        )
+        .unwrap();
+        fs::write(workspace.join("notes.md"), "configured").unwrap();
+        assert_eq!(
+            executor.scratchpad_path(&config),
+            workspace.join("notes.md")
+        );
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_captures_scratchpad() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let workspace = test_workspace("scratchpad-capture");
+        setup_workspace(&workspace);
+
+        // Stand-in for ralph that leaves a scratchpad behind
+        let binary = workspace.join("fake-ralph.sh");
+        fs::write(
+            &binary,
+            "#!/bin/sh\nmkdir -p .ralph/agent\nprintf '## Tasks\\n- [x] done\\n' > .ralph/agent/scratchpad.md\n",
+        )
+        .unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = RalphExecutor::with_binary(workspace.clone(), binary);
+        let result = executor
+            .run(&ScenarioConfig::minimal("test"))
+            .await
+            .unwrap();
+
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(result.scratchpad.as_deref(), Some("## Tasks\n- [x] done\n"));
+
+        cleanup_workspace(&workspace);
+    }
+
     // Integration test that requires ralph binary - skip in CI
     #[tokio::test]
     #[ignore = "requires ralph binary"]
@@ -49,10 +49,11 @@ pub use crate::models::{Assertion, ReportFormat, TestResult};
 pub use crate::reporter::{
     AnalyzedResultData, BackendSummary, JsonReporter, MarkdownReporter, QualityBreakdown,
     ReportSummary, ReportWriter, ReporterError, TerminalReporter, TestReport, TierSummary,
-    Verbosity, create_incremental_progress_callback, create_progress_callback,
+    Verbosity, create_incremental_progress_callback, create_progress_callback, write_report,
 };
 pub use crate::runner::{
     ProgressCallback, ProgressEvent, RunConfig, RunResults, RunnerError, TestRunner,
+    scenario_matches,
 };
 pub use crate::scenarios::{
     // Core traits and helpers
@@ -99,6 +100,7 @@ pub use crate::scenarios::{
     TestScenario,
     TimeoutScenario,
     ToolUseScenario,
+    all_scenarios,
 };
 pub use crate::workspace::WorkspaceManager;
 
@@ -2,7 +2,7 @@
 //!
 //! End-to-end test harness for the Ralph Orchestrator.
 //!
-//! This binary validates Ralph's behavior against real AI backends (Claude, Kiro, OpenCode).
+//! This binary validates Ralph's behavior against real AI backends (Claude, Kiro, OpenCode, Gemini, Pi).
 //! It exercises the full orchestration loop including:
 //! - Backend connectivity and authentication
 //! - Event parsing and routing
@@ -25,52 +25,10 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use colored::Colorize;
 use ralph_e2e::{
-    AuthChecker,
-    // Tier 7: Error Handling
-    AuthFailureScenario,
-    Backend as LibBackend,
-    BackendUnavailableScenario,
-    // Tier 3: Events
-    BackpressureScenario,
-    // Tier 2: Orchestration Loop
-    CompletionScenario,
-    // Tier 1: Connectivity
-    ConnectivityScenario,
-    EventsScenario,
-    // Tier 5: Hat Collections
-    HatBackendOverrideScenario,
-    HatEventRoutingScenario,
-    HatInstructionsScenario,
-    HatMultiWorkflowScenario,
-    HatSingleScenario,
-    MaxIterationsScenario,
-    // Tier 6: Memory System
-    MemoryAddScenario,
-    MemoryCorruptedFileScenario,
-    MemoryInjectionScenario,
-    MemoryLargeContentScenario,
-    MemoryMissingFileScenario,
-    MemoryPersistenceScenario,
-    MemoryRapidWriteScenario,
-    MemorySearchScenario,
-    MockConfig,
-    MultiIterScenario,
-    ReportFormat as LibReportFormat,
-    ReportWriter,
-    RunConfig,
-    SingleIterScenario,
-    // Tier 4: Capabilities
-    StreamingScenario,
-    TerminalReporter,
-    TestRunner,
-    TestScenario,
-    TimeoutScenario,
-    ToolUseScenario,
-    Verbosity,
-    WorkspaceManager,
-    create_incremental_progress_callback,
-    resolve_ralph_binary,
-    run_mock_cli,
+    AuthChecker, Backend as LibBackend, MockConfig, ReportFormat as LibReportFormat, ReportWriter,
+    RunConfig, TerminalReporter, TestRunner, Verbosity, WorkspaceManager, all_scenarios,
+    create_incremental_progress_callback, resolve_ralph_binary, run_mock_cli, scenario_matches,
+    write_report,
 };
 
 /// Backend selection for E2E tests.
@@ -85,6 +43,10 @@ pub enum Backend {
     Kiro,
     /// Test OpenCode backend only
     Opencode,
+    /// Test Gemini backend only
+    Gemini,
+    /// Test Pi backend only
+    Pi,
 }
 
 impl std::fmt::Display for Backend {
@@ -94,6 +56,8 @@ impl std::fmt::Display for Backend {
             Backend::Claude => write!(f, "claude"),
             Backend::Kiro => write!(f, "kiro"),
             Backend::Opencode => write!(f, "opencode"),
+            Backend::Gemini => write!(f, "gemini"),
+            Backend::Pi => write!(f, "pi"),
         }
     }
 }
@@ -106,6 +70,8 @@ impl Backend {
             Backend::Claude => Some(LibBackend::Claude),
             Backend::Kiro => Some(LibBackend::Kiro),
             Backend::Opencode => Some(LibBackend::OpenCode),
+            Backend::Gemini => Some(LibBackend::Gemini),
+            Backend::Pi => Some(LibBackend::Pi),
         }
     }
 }
@@ -159,22 +125,40 @@ pub struct TestOpts {
     #[arg(short, long)]
     pub quiet: bool,
 
-    /// List available test scenarios without running them
+    /// Print one ✅ line per passing scenario and only the failing
+    /// assertions (expected/actual) of failing ones
     #[arg(long)]
+    pub verbose_assertions: bool,
+
+    /// List available test scenarios without running them
+    #[arg(long, alias = "list-scenarios")]
     pub list: bool,
 
     /// Run only tests matching this pattern
     #[arg(long)]
     pub filter: Option<String>,
 
+    /// Run only the scenario with this ID, or every scenario in this tier
+    /// (e.g. "tier 3")
+    #[arg(long)]
+    pub scenario: Option<String>,
+
     /// Generate report in specified format
     #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
     pub report: ReportFormat,
 
+    /// Also write a JSON report to this path (for CI artifacts)
+    #[arg(long)]
+    pub output: Option<std::path::PathBuf>,
+
     /// Keep test workspaces after tests complete (for debugging)
     #[arg(long)]
     pub keep_workspace: bool,
 
+    /// Run up to this many scenarios concurrently
+    #[arg(short, long, default_value_t = 1)]
+    pub jobs: usize,
+
     /// Skip meta-Ralph analysis (faster, raw results only)
     #[arg(long)]
     pub skip_analysis: bool,
@@ -211,45 +195,6 @@ impl ReportFormat {
     }
 }
 
-/// Returns all registered test scenarios.
-fn get_all_scenarios() -> Vec<Box<dyn TestScenario>> {
-    vec![
-        // Tier 1: Connectivity (backend-agnostic)
-        Box::new(ConnectivityScenario::new()),
-        // Tier 2: Orchestration Loop (backend-agnostic)
-        Box::new(SingleIterScenario::new()),
-        Box::new(MultiIterScenario::new()),
-        Box::new(CompletionScenario::new()),
-        // Tier 3: Events (backend-agnostic)
-        Box::new(EventsScenario::new()),
-        Box::new(BackpressureScenario::new()),
-        // Tier 4: Capabilities (backend-agnostic)
-        Box::new(ToolUseScenario::new()),
-        Box::new(StreamingScenario::new()),
-        // Tier 5: Hat Collections (backend-agnostic)
-        Box::new(HatSingleScenario::new()),
-        Box::new(HatMultiWorkflowScenario::new()),
-        Box::new(HatInstructionsScenario::new()),
-        Box::new(HatEventRoutingScenario::new()),
-        Box::new(HatBackendOverrideScenario::new()),
-        // Tier 6: Memory System (backend-agnostic)
-        Box::new(MemoryAddScenario::new()),
-        Box::new(MemorySearchScenario::new()),
-        Box::new(MemoryInjectionScenario::new()),
-        Box::new(MemoryPersistenceScenario::new()),
-        // Tier 6: Memory System (Chaos Tests)
-        Box::new(MemoryCorruptedFileScenario::new()),
-        Box::new(MemoryMissingFileScenario::new()),
-        Box::new(MemoryRapidWriteScenario::new()),
-        Box::new(MemoryLargeContentScenario::new()),
-        // Tier 7: Error Handling (backend-agnostic)
-        Box::new(TimeoutScenario::new()),
-        Box::new(MaxIterationsScenario::new()),
-        Box::new(AuthFailureScenario::new()),
-        Box::new(BackendUnavailableScenario::new()),
-    ]
-}
-
 fn main() {
     let cli = Cli::parse();
 
@@ -323,35 +268,48 @@ async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity) {
         println!();
     }
 
-    // List scenarios
-    let scenarios = get_all_scenarios();
+    // List scenarios matching the backend, --scenario, and --filter selections
+    let mut config = RunConfig::new();
+    if let Some(backend) = opts.backend.to_lib_backend() {
+        config = config.with_backend(backend);
+    }
+    if let Some(scenario) = &opts.scenario {
+        config = config.with_scenario(scenario);
+    }
+    if let Some(filter) = &opts.filter {
+        config = config.with_filter(filter);
+    }
+
+    let scenarios: Vec<_> = all_scenarios()
+        .into_iter()
+        .filter(|s| scenario_matches(s.as_ref(), &config))
+        .collect();
     println!("{}\n", "Available scenarios:".bold());
 
     // Group by tier
     let mut current_tier = String::new();
     for scenario in &scenarios {
-        // Filter by backend if specified
-        if let Some(backend) = opts.backend.to_lib_backend()
-            && !scenario.supported_backends().contains(&backend)
-        {
-            continue;
-        }
-
         // Print tier header if changed
         if scenario.tier() != current_tier {
             current_tier = scenario.tier().to_string();
             println!("  {}", current_tier.bold().underline());
         }
 
+        let backends: Vec<_> = scenario
+            .supported_backends()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
         println!(
-            "    {}  {}",
+            "    {}  {}  {}",
             scenario.id().cyan(),
-            scenario.description().dimmed()
+            scenario.description().dimmed(),
+            format!("[{}]", backends.join(", ")).dimmed()
         );
     }
 
     if scenarios.is_empty() {
-        println!("  {}", "No scenarios implemented yet".yellow());
+        println!("  {}", "No matching scenarios".yellow());
     }
 
     println!(
@@ -408,15 +366,21 @@ async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
     let workspace_mgr = WorkspaceManager::new(workspace_path.clone());
 
     // Get scenarios
-    let scenarios = get_all_scenarios();
+    let scenarios = all_scenarios();
 
     // Build run configuration
-    let mut config = RunConfig::new().keep_workspaces(opts.keep_workspace);
+    let mut config = RunConfig::new()
+        .keep_workspaces(opts.keep_workspace)
+        .with_parallelism(opts.jobs);
 
     if let Some(filter) = &opts.filter {
         config = config.with_filter(filter);
     }
 
+    if let Some(scenario) = &opts.scenario {
+        config = config.with_scenario(scenario);
+    }
+
     if let Some(backend) = opts.backend.to_lib_backend() {
         config = config.with_backend(backend);
     }
@@ -440,7 +404,8 @@ async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
     let runner = TestRunner::new(workspace_mgr, scenarios)
         .with_binary(ralph_binary)
         .on_progress(create_incremental_progress_callback(
-            verbosity,
+            TerminalReporter::with_verbosity(verbosity)
+                .with_failing_assertions_only(opts.verbose_assertions),
             workspace_path.clone(),
         ));
 
@@ -481,12 +446,28 @@ async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
         }
     }
 
+    if let Some(output) = &opts.output {
+        match write_report(&results, output) {
+            Ok(()) if verbosity != Verbosity::Quiet => {
+                println!(
+                    "{}",
+                    format!("Report written: {}", output.display()).dimmed()
+                );
+            }
+            Ok(()) => {}
+            Err(e) => {
+                eprintln!("{} Failed to write report: {}", "Warning:".yellow(), e);
+            }
+        }
+    }
+
     // Print summary
-    let reporter = TerminalReporter::with_verbosity(verbosity);
+    let reporter = TerminalReporter::with_verbosity(verbosity)
+        .with_failing_assertions_only(opts.verbose_assertions);
 
     if verbosity != Verbosity::Quiet {
-        // Print failures in detail
-        if !results.all_passed() {
+        // Print failures in detail, unless they were already printed inline
+        if !results.all_passed() && !reporter.failing_assertions_only() {
             reporter.print_failures(&results);
         }
     }
@@ -36,10 +36,24 @@ pub struct TestResult {
     /// How long the test took
     #[serde(with = "duration_serde")]
     pub duration: Duration,
+    /// How many times the scenario ran (more than 1 when it was retried)
+    #[serde(default = "default_attempts")]
+    pub attempts: u32,
+}
+
+impl TestResult {
+    /// Returns true if the scenario passed only after failing at least once.
+    pub fn is_flaky(&self) -> bool {
+        self.passed && self.attempts > 1
+    }
+}
+
+pub(crate) fn default_attempts() -> u32 {
+    1
 }
 
 /// A single assertion within a test.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Assertion {
     /// Name of the assertion
     pub name: String,
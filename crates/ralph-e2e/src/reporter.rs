@@ -60,6 +60,10 @@ pub struct TerminalReporter {
 
     /// Track current tier for grouping output.
     current_tier: Option<String>,
+
+    /// Print one line per passing scenario and only the failing assertions
+    /// of failing ones.
+    failing_assertions_only: bool,
 }
 
 impl Default for TerminalReporter {
@@ -74,6 +78,7 @@ impl TerminalReporter {
         Self {
             verbosity: Verbosity::Normal,
             current_tier: None,
+            failing_assertions_only: false,
         }
     }
 
@@ -82,9 +87,23 @@ impl TerminalReporter {
         Self {
             verbosity,
             current_tier: None,
+            failing_assertions_only: false,
         }
     }
 
+    /// Condenses scenario output to a single ✅ line for passes and only the
+    /// failing assertions (with expected/actual) for failures.
+    pub fn with_failing_assertions_only(mut self, enabled: bool) -> Self {
+        self.failing_assertions_only = enabled;
+        self
+    }
+
+    /// Returns whether failing assertions are printed inline as scenarios
+    /// complete, making [`Self::print_failures`] redundant.
+    pub fn failing_assertions_only(&self) -> bool {
+        self.failing_assertions_only
+    }
+
     /// Handles a progress event, printing appropriate output.
     pub fn handle_progress(&mut self, event: ProgressEvent) {
         match event {
@@ -102,10 +121,20 @@ impl TerminalReporter {
                 scenario_id,
                 passed,
                 duration,
-                ..
+                result,
             } => {
                 if self.verbosity != Verbosity::Quiet {
-                    self.print_scenario_completed(&scenario_id, passed, duration);
+                    if self.failing_assertions_only {
+                        print!("{}", self.render_condensed(&result));
+                    } else {
+                        self.print_scenario_completed(&scenario_id, passed, duration);
+                    }
+                    if result.is_flaky() {
+                        println!(
+                            "     {}",
+                            format!("⚠️  flaky: passed on attempt {}", result.attempts).yellow()
+                        );
+                    }
                 }
             }
             ProgressEvent::ScenarioSkipped {
@@ -216,6 +245,10 @@ impl TerminalReporter {
         if skipped > 0 {
             parts.push(format!("{} skipped", skipped).dimmed().to_string());
         }
+        let flaky = results.flaky().len();
+        if flaky > 0 {
+            parts.push(format!("{} flaky", flaky).yellow().to_string());
+        }
 
         let summary = parts.join(", ");
         let verdict_text = format!("{}: {} of {} tests", verdict, passed, total);
@@ -225,6 +258,20 @@ impl TerminalReporter {
             println!("   {}", summary);
         }
 
+        // Per-tier pass rates
+        let tiers = results.tier_summaries();
+        if tiers.len() > 1 {
+            for (tier, summary) in &tiers {
+                println!(
+                    "   {} {}/{} ({:.0}%)",
+                    tier,
+                    summary.passed,
+                    summary.total,
+                    summary.pass_rate() * 100.0
+                );
+            }
+        }
+
         // Duration
         println!(
             "\n   {}",
@@ -264,6 +311,25 @@ impl TerminalReporter {
         }
     }
 
+    /// Renders one scenario in condensed form: a single ✅ line when it
+    /// passed, otherwise the ❌ line followed by its failing assertions only.
+    pub fn render_condensed(&self, result: &TestResult) -> String {
+        if result.passed {
+            return format!("  ✅ {}\n", result.scenario_id);
+        }
+
+        let mut out = format!("  ❌ {}\n", result.scenario_id.red().bold());
+        for assertion in result.assertions.iter().filter(|a| !a.passed) {
+            out.push_str(&format!("     {} {}\n", "✗".red(), assertion.name));
+            out.push_str(&format!(
+                "       Expected: {}\n",
+                assertion.expected.green()
+            ));
+            out.push_str(&format!("       Actual:   {}\n", assertion.actual.red()));
+        }
+        out
+    }
+
     /// Prints results grouped by tier.
     pub fn print_by_tier(&self, results: &RunResults) {
         for (tier, tier_results) in results.by_tier() {
@@ -370,10 +436,16 @@ impl IncrementalState {
         if !passes.is_empty() {
             content.push_str("## ✅ Passed\n\n");
             for result in passes {
+                let flaky = if result.is_flaky() {
+                    format!(" ⚠️ flaky, attempt {}", result.attempts)
+                } else {
+                    String::new()
+                };
                 content.push_str(&format!(
-                    "- {} ({:.1}s)\n",
+                    "- {} ({:.1}s){}\n",
                     result.scenario_id,
-                    result.duration.as_secs_f64()
+                    result.duration.as_secs_f64(),
+                    flaky
                 ));
             }
         }
@@ -401,11 +473,11 @@ fn truncate_for_report(s: &str, max_len: usize) -> String {
 /// This is useful for long test runs where you want to see failures immediately
 /// rather than waiting for all tests to finish.
 pub fn create_incremental_progress_callback(
-    verbosity: Verbosity,
+    reporter: TerminalReporter,
     output_path: PathBuf,
 ) -> crate::runner::ProgressCallback {
     let state = std::sync::Arc::new(std::sync::Mutex::new(IncrementalState {
-        reporter: TerminalReporter::with_verbosity(verbosity),
+        reporter,
         results: Vec::new(),
         output_path,
         total_scenarios: 0,
@@ -454,6 +526,7 @@ mod tests {
                         actual: "Received 100 bytes".to_string(),
                     }],
                     duration: Duration::from_secs(5),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "test-fail".to_string(),
@@ -468,6 +541,7 @@ mod tests {
                         actual: "Exit code 1".to_string(),
                     }],
                     duration: Duration::from_secs(3),
+                    attempts: 1,
                 },
             ],
             duration: Duration::from_secs(8),
@@ -523,6 +597,7 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(5),
+                attempts: 1,
             },
         });
     }
@@ -558,6 +633,7 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                attempts: 1,
             }],
             duration: Duration::from_secs(1),
             skipped_count: 0,
@@ -586,6 +662,7 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                attempts: 1,
             }],
             duration: Duration::from_secs(1),
             skipped_count: 0,
@@ -617,6 +694,41 @@ mod tests {
         reporter.print_by_tier(&results);
     }
 
+    #[test]
+    fn test_render_condensed_shows_only_failing_assertions() {
+        colored::control::set_override(false);
+        let mut results = mock_results();
+        results.results[1].assertions.insert(
+            0,
+            Assertion {
+                name: "Output captured".to_string(),
+                passed: true,
+                expected: "Some output".to_string(),
+                actual: "42 bytes".to_string(),
+            },
+        );
+        let reporter = TerminalReporter::new().with_failing_assertions_only(true);
+
+        let output: String = results
+            .results
+            .iter()
+            .map(|r| reporter.render_condensed(r))
+            .collect();
+
+        assert_eq!(
+            output,
+            concat!(
+                "  ✅ test-pass\n",
+                "  ❌ test-fail\n",
+                "     ✗ Exit code\n",
+                "       Expected: Exit code 0\n",
+                "       Actual:   Exit code 1\n",
+            )
+        );
+        assert!(!output.contains("Response received"));
+        assert!(!output.contains("Output captured"));
+    }
+
     #[test]
     fn test_create_progress_callback() {
         let callback = create_progress_callback(Verbosity::Normal);
@@ -712,6 +824,17 @@ pub struct TierSummary {
     pub failed: usize,
 }
 
+impl TierSummary {
+    /// Fraction of tests that passed, from 0.0 to 1.0 (0.0 for an empty tier).
+    pub fn pass_rate(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.passed as f64 / self.total as f64
+        }
+    }
+}
+
 /// Summary for a single backend.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BackendSummary {
@@ -742,6 +865,10 @@ pub struct AnalyzedResultData {
     #[serde(with = "duration_serde")]
     pub duration: Duration,
 
+    /// How many times the scenario ran (more than 1 when it was retried).
+    #[serde(default = "crate::models::default_attempts")]
+    pub attempts: u32,
+
     /// Assertions.
     pub assertions: Vec<crate::models::Assertion>,
 
@@ -763,6 +890,7 @@ impl From<&TestResult> for AnalyzedResultData {
             tier: result.tier.clone(),
             passed: result.passed,
             duration: result.duration,
+            attempts: result.attempts,
             assertions: result.assertions.clone(),
             diagnosis: None,
             analysis: None,
@@ -779,6 +907,7 @@ impl From<&AnalyzedResult> for AnalyzedResultData {
             tier: result.result.tier.clone(),
             passed: result.result.passed,
             duration: result.result.duration,
+            attempts: result.result.attempts,
             assertions: result.result.assertions.clone(),
             diagnosis: result.diagnosis.clone(),
             analysis: result.analysis.clone(),
@@ -899,8 +1028,24 @@ impl MarkdownReporter {
         if results.skipped_count > 0 {
             report.push_str(&format!("| ⏭️ Skipped | {} |\n", results.skipped_count));
         }
+        let flaky = results.flaky();
+        if !flaky.is_empty() {
+            report.push_str(&format!("| ⚠️ Flaky | {} |\n", flaky.len()));
+        }
         report.push('\n');
 
+        // Passed only after retrying; worth a look even though they count as passes
+        if !flaky.is_empty() {
+            report.push_str("### Flaky Tests\n");
+            for result in flaky {
+                report.push_str(&format!(
+                    "- {}: passed on attempt {}\n",
+                    result.scenario_id, result.attempts
+                ));
+            }
+            report.push('\n');
+        }
+
         // Failures by tier
         let tiers = results.by_tier();
         let failed_tiers: Vec<_> = tiers
@@ -1503,15 +1648,11 @@ impl JsonReporter {
         }
 
         // By tier
-        let mut by_tier: HashMap<String, TierSummary> = HashMap::new();
-        for (tier, tests) in results.by_tier() {
-            let tier_summary = TierSummary {
-                total: tests.len(),
-                passed: tests.iter().filter(|t| t.passed).count(),
-                failed: tests.iter().filter(|t| !t.passed).count(),
-            };
-            by_tier.insert(tier.to_string(), tier_summary);
-        }
+        let by_tier: HashMap<String, TierSummary> = results
+            .tier_summaries()
+            .into_iter()
+            .map(|(tier, summary)| (tier.to_string(), summary))
+            .collect();
 
         // By backend
         let mut by_backend: HashMap<String, BackendSummary> = HashMap::new();
@@ -1588,6 +1729,21 @@ impl Default for JsonReporter {
 // Report Writer
 // ============================================================================
 
+/// Writes a JSON [`TestReport`] for `results` to `path`, creating parent
+/// directories as needed.
+///
+/// Unlike [`ReportWriter`], the path is chosen by the caller, so CI can drop
+/// the report wherever it collects artifacts.
+pub fn write_report(results: &RunResults, path: &Path) -> Result<(), ReporterError> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let report = JsonReporter::new().build_report(results, None);
+    let file = std::fs::File::create(path)?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), &report)?;
+    Ok(())
+}
+
 /// Orchestrates writing reports to files.
 pub struct ReportWriter {
     /// Output directory for reports.
@@ -1695,6 +1851,7 @@ mod reporter_tests {
                 actual: "Received 100 bytes".to_string(),
             }],
             duration: Duration::from_secs(12),
+            attempts: 1,
         }
     }
 
@@ -1720,6 +1877,7 @@ mod reporter_tests {
                 },
             ],
             duration: Duration::from_secs(45),
+            attempts: 1,
         }
     }
 
@@ -1787,6 +1945,7 @@ mod reporter_tests {
                     actual: "Found memories".to_string(),
                 }],
                 duration: Duration::from_secs(38),
+                attempts: 1,
             },
             diagnosis: None,
             analysis: Some(PassedAnalysis {
@@ -1830,6 +1989,17 @@ mod reporter_tests {
         assert!(report.contains("❌ Failed | 0"));
     }
 
+    #[test]
+    fn test_markdown_flags_flaky_passes() {
+        let reporter = MarkdownReporter::new();
+        let mut results = mock_run_results_all_pass();
+        results.results[0].attempts = 2;
+        let report = reporter.generate(&results, None);
+
+        assert!(report.contains("⚠️ Flaky | 1"));
+        assert!(report.contains("passed on attempt 2"));
+    }
+
     #[test]
     fn test_markdown_generate_mixed() {
         let reporter = MarkdownReporter::new();
@@ -2073,7 +2243,7 @@ mod reporter_tests {
 
     #[test]
     fn test_report_writer_write_markdown() {
-        let temp_dir = std::env::temp_dir().join(format!("ralph-e2e-test-{}", std::process::id()));
+        let temp_dir = crate::workspace::unique_test_dir("ralph-e2e-test");
         std::fs::create_dir_all(&temp_dir).unwrap();
 
         let writer = ReportWriter::new(temp_dir.clone());
@@ -2090,10 +2260,35 @@ mod reporter_tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_write_report_round_trips() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("artifacts/e2e.json");
+        let results = mock_run_results_mixed();
+
+        write_report(&results, &path).unwrap();
+
+        let parsed: TestReport =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(!parsed.passed);
+        assert_eq!(parsed.summary.total, 2);
+        assert_eq!(parsed.summary.passed, 1);
+        assert_eq!(parsed.summary.failed, 1);
+        assert_eq!(parsed.summary.skipped, 1);
+        assert_eq!(parsed.duration, Duration::from_secs(57));
+
+        let failed = &parsed.results[1];
+        assert_eq!(failed.scenario_id, "hat-instructions");
+        assert_eq!(failed.tier, "Tier 5: Hat Collections");
+        assert_eq!(failed.backend, "Claude");
+        assert!(!failed.passed);
+        assert_eq!(failed.duration, Duration::from_secs(45));
+        assert_eq!(failed.assertions, mock_failed_result().assertions);
+    }
+
     #[test]
     fn test_report_writer_write_json() {
-        let temp_dir =
-            std::env::temp_dir().join(format!("ralph-e2e-test-json-{}", std::process::id()));
+        let temp_dir = crate::workspace::unique_test_dir("ralph-e2e-test-json");
         std::fs::create_dir_all(&temp_dir).unwrap();
 
         let writer = ReportWriter::new(temp_dir.clone());
@@ -2113,8 +2308,7 @@ mod reporter_tests {
 
     #[test]
     fn test_report_writer_write_both() {
-        let temp_dir =
-            std::env::temp_dir().join(format!("ralph-e2e-test-both-{}", std::process::id()));
+        let temp_dir = crate::workspace::unique_test_dir("ralph-e2e-test-both");
         std::fs::create_dir_all(&temp_dir).unwrap();
 
         let writer = ReportWriter::new(temp_dir.clone());
@@ -2136,10 +2330,7 @@ mod reporter_tests {
 
     #[test]
     fn test_report_writer_creates_directory() {
-        let temp_dir = std::env::temp_dir().join(format!(
-            "ralph-e2e-test-newdir-{}/nested",
-            std::process::id()
-        ));
+        let temp_dir = crate::workspace::unique_test_dir("ralph-e2e-test-newdir").join("nested");
         // Don't create the directory - writer should create it
 
         let writer = ReportWriter::new(temp_dir.clone());
@@ -27,8 +27,10 @@ use crate::Backend;
 use crate::executor::RalphExecutor;
 use crate::mock::{CassetteResolver, MockConfig, build_mock_cli_args};
 use crate::models::TestResult;
+use crate::reporter::TierSummary;
 use crate::scenarios::{ScenarioError, TestScenario};
 use crate::workspace::WorkspaceManager;
+use futures::stream::{self, StreamExt};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use thiserror::Error;
@@ -55,6 +57,10 @@ pub struct RunConfig {
     /// Filter scenarios by pattern (matches scenario ID or description).
     pub filter: Option<String>,
 
+    /// Only run the scenario with this exact ID, or every scenario in this
+    /// tier ("Tier 3" or the full tier name). Case-insensitive.
+    pub scenario: Option<String>,
+
     /// Only run scenarios for this backend.
     pub backend: Option<Backend>,
 
@@ -66,6 +72,9 @@ pub struct RunConfig {
 
     /// Mock mode configuration (if enabled).
     pub mock_config: Option<MockConfig>,
+
+    /// Maximum scenarios run concurrently (0 or 1 = one at a time).
+    pub parallelism: usize,
 }
 
 impl RunConfig {
@@ -80,6 +89,12 @@ impl RunConfig {
         self
     }
 
+    /// Selects a single scenario ID or tier.
+    pub fn with_scenario(mut self, scenario: impl Into<String>) -> Self {
+        self.scenario = Some(scenario.into());
+        self
+    }
+
     /// Sets the backend filter.
     pub fn with_backend(mut self, backend: Backend) -> Self {
         self.backend = Some(backend);
@@ -92,6 +107,12 @@ impl RunConfig {
         self
     }
 
+    /// Sets how many scenarios may run concurrently.
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
     /// Enables mock mode with the given configuration.
     pub fn with_mock(mut self, config: MockConfig) -> Self {
         self.mock_config = Some(config);
@@ -151,6 +172,27 @@ impl RunResults {
     pub fn failures(&self) -> Vec<&TestResult> {
         self.results.iter().filter(|r| !r.passed).collect()
     }
+
+    /// Returns results that passed only after a retry.
+    pub fn flaky(&self) -> Vec<&TestResult> {
+        self.results.iter().filter(|r| r.is_flaky()).collect()
+    }
+
+    /// Returns pass counts per tier, sorted by tier name.
+    pub fn tier_summaries(&self) -> Vec<(&str, TierSummary)> {
+        self.by_tier()
+            .into_iter()
+            .map(|(tier, results)| {
+                let passed = results.iter().filter(|r| r.passed).count();
+                let summary = TierSummary {
+                    total: results.len(),
+                    passed,
+                    failed: results.len() - passed,
+                };
+                (tier, summary)
+            })
+            .collect()
+    }
 }
 
 /// Progress callback for test execution updates.
@@ -231,7 +273,7 @@ impl TestRunner {
     pub fn matching_scenarios(&self, config: &RunConfig) -> Vec<&dyn TestScenario> {
         self.scenarios
             .iter()
-            .filter(|s| self.matches_config(s.as_ref(), config))
+            .filter(|s| scenario_matches(s.as_ref(), config))
             .map(|s| s.as_ref())
             .collect()
     }
@@ -244,10 +286,10 @@ impl TestRunner {
         let start = Instant::now();
         let matching = self.matching_scenarios(config);
 
-        if matching.is_empty() && config.filter.is_some() {
-            return Err(RunnerError::NoMatchingScenarios(
-                config.filter.clone().unwrap(),
-            ));
+        if matching.is_empty()
+            && let Some(pattern) = config.scenario.as_ref().or(config.filter.as_ref())
+        {
+            return Err(RunnerError::NoMatchingScenarios(pattern.clone()));
         }
 
         // Calculate total scenarios: if no backend specified, multiply by supported backends
@@ -259,9 +301,8 @@ impl TestRunner {
 
         self.emit_progress(ProgressEvent::RunStarted { total_scenarios });
 
-        let mut results = Vec::new();
-        let mut skipped_count = 0;
-
+        // Expand into one job per (scenario, backend)
+        let mut jobs = Vec::new();
         for scenario in matching {
             // Determine which backends to run for this scenario
             let backends_to_run: Vec<Backend> = match &config.backend {
@@ -277,121 +318,26 @@ impl TestRunner {
                     // All backends mode: append backend name
                     format!("{}-{}", scenario.id(), backend.as_config_str())
                 };
-                let tier = scenario.tier().to_string();
-
-                self.emit_progress(ProgressEvent::ScenarioStarted {
-                    scenario_id: scenario_id.clone(),
-                    tier: tier.clone(),
-                });
-
-                // Create workspace for this scenario
-                let workspace_path = self
-                    .workspace_mgr
-                    .create_workspace(&scenario_id)
-                    .map_err(|e| RunnerError::WorkspaceError(e.to_string()))?;
-
-                // Setup the scenario with the target backend
-                let setup_result = scenario.setup(&workspace_path, backend);
-                let scenario_config = match setup_result {
-                    Ok(cfg) => cfg,
-                    Err(e) => {
-                        self.emit_progress(ProgressEvent::ScenarioSkipped {
-                            scenario_id: scenario_id.clone(),
-                            reason: format!("Setup failed: {}", e),
-                        });
-                        skipped_count += 1;
-
-                        if !config.keep_workspaces {
-                            self.workspace_mgr.cleanup(&scenario_id).ok();
-                        }
-                        continue;
-                    }
-                };
-
-                // Configure mock mode if enabled
-                if let Some(ref mock_config) = config.mock_config
-                    && let Err(e) = self.configure_mock_mode(
-                        &workspace_path,
-                        scenario.id(),
-                        backend,
-                        mock_config,
-                    )
-                {
-                    self.emit_progress(ProgressEvent::ScenarioSkipped {
-                        scenario_id: scenario_id.clone(),
-                        reason: format!("Mock setup failed: {}", e),
-                    });
-                    skipped_count += 1;
-
-                    if !config.keep_workspaces {
-                        self.workspace_mgr.cleanup(&scenario_id).ok();
-                    }
-                    continue;
-                }
+                jobs.push((scenario, backend, scenario_id));
+            }
+        }
 
-                // Execute the scenario
-                let executor = match &self.ralph_binary {
-                    Some(binary) => {
-                        RalphExecutor::with_binary(workspace_path.clone(), binary.clone())
-                    }
-                    None => RalphExecutor::new(workspace_path.clone()),
-                };
-                let scenario_start = Instant::now();
-
-                let result = scenario.run(&executor, &scenario_config).await;
-                let scenario_duration = scenario_start.elapsed();
-
-                match result {
-                    Ok(mut test_result) => {
-                        // Update scenario_id to include backend suffix when running all
-                        if config.backend.is_none() {
-                            test_result.scenario_id = scenario_id.clone();
-                        }
-                        test_result.backend = backend.to_string();
-                        let passed = test_result.passed;
-
-                        self.emit_progress(ProgressEvent::ScenarioCompleted {
-                            scenario_id: scenario_id.clone(),
-                            passed,
-                            duration: scenario_duration,
-                            result: test_result.clone(),
-                        });
-
-                        results.push(test_result);
-                    }
-                    Err(e) => {
-                        // Create a failed result for the scenario
-                        let failed_result = TestResult {
-                            scenario_id: scenario_id.clone(),
-                            scenario_description: scenario.description().to_string(),
-                            backend: backend.to_string(),
-                            tier: tier.clone(),
-                            passed: false,
-                            assertions: vec![crate::models::Assertion {
-                                name: "Execution".to_string(),
-                                passed: false,
-                                expected: "Scenario executes successfully".to_string(),
-                                actual: format!("Error: {}", e),
-                            }],
-                            duration: scenario_duration,
-                        };
-
-                        self.emit_progress(ProgressEvent::ScenarioCompleted {
-                            scenario_id: scenario_id.clone(),
-                            passed: false,
-                            duration: scenario_duration,
-                            result: failed_result.clone(),
-                        });
-
-                        results.push(failed_result);
-                    }
-                }
+        // Each job has its own workspace (named after its scenario ID), so up to
+        // `parallelism` of them can run at once. `buffered` keeps results in job order.
+        let outcomes: Vec<Result<Option<TestResult>, RunnerError>> = stream::iter(jobs)
+            .map(|(scenario, backend, scenario_id)| {
+                self.run_job(scenario, backend, scenario_id, config)
+            })
+            .buffered(config.parallelism.max(1))
+            .collect()
+            .await;
 
-                // Cleanup unless keeping workspaces
-                if !config.keep_workspaces {
-                    scenario.cleanup(&workspace_path).ok();
-                    self.workspace_mgr.cleanup(&scenario_id).ok();
-                }
+        let mut results = Vec::new();
+        let mut skipped_count = 0;
+        for outcome in outcomes {
+            match outcome? {
+                Some(result) => results.push(result),
+                None => skipped_count += 1,
             }
         }
 
@@ -408,36 +354,153 @@ impl TestRunner {
         Ok(run_results)
     }
 
-    /// Runs all registered scenarios with default configuration.
-    pub async fn run_all(&self) -> Result<RunResults, RunnerError> {
-        self.run(&RunConfig::default()).await
+    /// Runs one scenario against one backend in its own workspace.
+    ///
+    /// A failed scenario is re-run in a fresh workspace up to
+    /// [`TestScenario::max_retries`] times; the returned result records how
+    /// many attempts it took.
+    ///
+    /// Returns `None` if the scenario was skipped during setup.
+    async fn run_job(
+        &self,
+        scenario: &dyn TestScenario,
+        backend: Backend,
+        scenario_id: String,
+        config: &RunConfig,
+    ) -> Result<Option<TestResult>, RunnerError> {
+        self.emit_progress(ProgressEvent::ScenarioStarted {
+            scenario_id: scenario_id.clone(),
+            tier: scenario.tier().to_string(),
+        });
+
+        let start = Instant::now();
+        let max_attempts = scenario.max_retries().saturating_add(1);
+        let mut attempts = 0;
+        let test_result = loop {
+            attempts += 1;
+            let Some(mut test_result) = self
+                .run_attempt(scenario, backend, &scenario_id, config)
+                .await?
+            else {
+                return Ok(None);
+            };
+
+            let last = test_result.passed || attempts >= max_attempts;
+            // Earlier attempts never keep their workspace; the retry starts fresh.
+            if !last || !config.keep_workspaces {
+                scenario
+                    .cleanup(&self.workspace_mgr.workspace_path(&scenario_id))
+                    .ok();
+                self.workspace_mgr.cleanup(&scenario_id).ok();
+            }
+            if last {
+                test_result.attempts = attempts;
+                break test_result;
+            }
+        };
+
+        self.emit_progress(ProgressEvent::ScenarioCompleted {
+            scenario_id,
+            passed: test_result.passed,
+            duration: start.elapsed(),
+            result: test_result.clone(),
+        });
+
+        Ok(Some(test_result))
     }
 
-    /// Checks if a scenario matches the run configuration.
-    fn matches_config(&self, scenario: &dyn TestScenario, config: &RunConfig) -> bool {
-        // Check backend filter: scenario must support the requested backend
-        if let Some(backend) = &config.backend
-            && !scenario.supported_backends().contains(backend)
+    /// Sets up and runs a single attempt of a scenario.
+    ///
+    /// Returns `None` if the scenario was skipped during setup.
+    async fn run_attempt(
+        &self,
+        scenario: &dyn TestScenario,
+        backend: Backend,
+        scenario_id: &str,
+        config: &RunConfig,
+    ) -> Result<Option<TestResult>, RunnerError> {
+        // Create workspace for this scenario
+        let workspace_path = self
+            .workspace_mgr
+            .create_workspace(scenario_id)
+            .map_err(|e| RunnerError::WorkspaceError(e.to_string()))?;
+
+        // Setup the scenario with the target backend
+        let setup_result = scenario.setup(&workspace_path, backend);
+        let scenario_config = match setup_result {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                self.emit_progress(ProgressEvent::ScenarioSkipped {
+                    scenario_id: scenario_id.to_string(),
+                    reason: format!("Setup failed: {}", e),
+                });
+
+                if !config.keep_workspaces {
+                    self.workspace_mgr.cleanup(scenario_id).ok();
+                }
+                return Ok(None);
+            }
+        };
+
+        // Configure mock mode if enabled
+        if let Some(ref mock_config) = config.mock_config
+            && let Err(e) =
+                self.configure_mock_mode(&workspace_path, scenario.id(), backend, mock_config)
         {
-            return false;
-        }
+            self.emit_progress(ProgressEvent::ScenarioSkipped {
+                scenario_id: scenario_id.to_string(),
+                reason: format!("Mock setup failed: {}", e),
+            });
 
-        // Check pattern filter
-        if let Some(filter) = &config.filter {
-            let filter_lower = filter.to_lowercase();
-            let id_matches = scenario.id().to_lowercase().contains(&filter_lower);
-            let desc_matches = scenario
-                .description()
-                .to_lowercase()
-                .contains(&filter_lower);
-            let tier_matches = scenario.tier().to_lowercase().contains(&filter_lower);
-
-            if !id_matches && !desc_matches && !tier_matches {
-                return false;
+            if !config.keep_workspaces {
+                self.workspace_mgr.cleanup(scenario_id).ok();
             }
+            return Ok(None);
         }
 
-        true
+        // Execute the scenario
+        let executor = match &self.ralph_binary {
+            Some(binary) => RalphExecutor::with_binary(workspace_path.clone(), binary.clone()),
+            None => RalphExecutor::new(workspace_path.clone()),
+        };
+        let scenario_start = Instant::now();
+
+        let result = scenario.run(&executor, &scenario_config).await;
+        let scenario_duration = scenario_start.elapsed();
+
+        let test_result = match result {
+            Ok(mut test_result) => {
+                // Update scenario_id to include backend suffix when running all
+                if config.backend.is_none() {
+                    test_result.scenario_id = scenario_id.to_string();
+                }
+                test_result.backend = backend.to_string();
+                test_result
+            }
+            // Create a failed result for the scenario
+            Err(e) => TestResult {
+                scenario_id: scenario_id.to_string(),
+                scenario_description: scenario.description().to_string(),
+                backend: backend.to_string(),
+                tier: scenario.tier().to_string(),
+                passed: false,
+                assertions: vec![crate::models::Assertion {
+                    name: "Execution".to_string(),
+                    passed: false,
+                    expected: "Scenario executes successfully".to_string(),
+                    actual: format!("Error: {}", e),
+                }],
+                duration: scenario_duration,
+                attempts: 1,
+            },
+        };
+
+        Ok(Some(test_result))
+    }
+
+    /// Runs all registered scenarios with default configuration.
+    pub async fn run_all(&self) -> Result<RunResults, RunnerError> {
+        self.run(&RunConfig::default()).await
     }
 
     /// Emits a progress event if a callback is registered.
@@ -524,13 +587,50 @@ impl TestRunner {
     }
 }
 
+/// Checks if a scenario matches the run configuration's backend, scenario,
+/// and pattern filters.
+pub fn scenario_matches(scenario: &dyn TestScenario, config: &RunConfig) -> bool {
+    // Check backend filter: scenario must support the requested backend
+    if let Some(backend) = &config.backend
+        && !scenario.supported_backends().contains(backend)
+    {
+        return false;
+    }
+
+    // Check scenario selector: exact ID or tier
+    if let Some(selected) = &config.scenario {
+        let selected = selected.trim().to_lowercase();
+        let tier = scenario.tier().to_lowercase();
+        let tier_label = tier.split(':').next().unwrap_or_default().trim();
+        if scenario.id().to_lowercase() != selected && tier != selected && tier_label != selected {
+            return false;
+        }
+    }
+
+    // Check pattern filter
+    if let Some(filter) = &config.filter {
+        let filter_lower = filter.to_lowercase();
+        let id_matches = scenario.id().to_lowercase().contains(&filter_lower);
+        let desc_matches = scenario
+            .description()
+            .to_lowercase()
+            .contains(&filter_lower);
+        let tier_matches = scenario.tier().to_lowercase().contains(&filter_lower);
+
+        if !id_matches && !desc_matches && !tier_matches {
+            return false;
+        }
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::executor::ScenarioConfig;
     use crate::models::Assertion;
     use async_trait::async_trait;
-    use std::env;
     use std::path::{Path, PathBuf};
     use std::sync::Arc;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -625,17 +725,144 @@ mod tests {
                     actual: if self.should_pass { "pass" } else { "fail" }.to_string(),
                 }],
                 duration: Duration::from_millis(100),
+                attempts: 1,
             })
         }
     }
 
+    /// Scenario that records how many scenarios are in flight and checks that
+    /// its workspace is not shared.
+    struct ProbeScenario {
+        id: String,
+        tier: String,
+        should_pass: bool,
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl TestScenario for ProbeScenario {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn description(&self) -> &str {
+            &self.id
+        }
+
+        fn tier(&self) -> &str {
+            &self.tier
+        }
+
+        fn supported_backends(&self) -> Vec<Backend> {
+            vec![Backend::Claude]
+        }
+
+        fn setup(
+            &self,
+            workspace: &Path,
+            _backend: Backend,
+        ) -> Result<ScenarioConfig, ScenarioError> {
+            std::fs::write(workspace.join("owner"), &self.id)?;
+            Ok(ScenarioConfig::minimal("probe"))
+        }
+
+        async fn run(
+            &self,
+            executor: &RalphExecutor,
+            _config: &ScenarioConfig,
+        ) -> Result<TestResult, ScenarioError> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let owner = std::fs::read_to_string(executor.workspace().join("owner"))?;
+            let isolated = owner == self.id;
+
+            Ok(TestResult {
+                scenario_id: self.id.clone(),
+                scenario_description: self.description().to_string(),
+                backend: String::new(),
+                tier: self.tier.clone(),
+                passed: self.should_pass && isolated,
+                assertions: vec![Assertion {
+                    name: "Workspace isolated".to_string(),
+                    passed: isolated,
+                    expected: self.id.clone(),
+                    actual: owner,
+                }],
+                duration: Duration::from_millis(50),
+                attempts: 1,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_runner_parallel_aggregates_isolated_results() {
+        let workspace = test_workspace_base("run-parallel");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let probes = [
+            ("a", "Tier 1", true),
+            ("b", "Tier 1", false),
+            ("c", "Tier 2", true),
+            ("d", "Tier 2", true),
+            ("e", "Tier 2", true),
+        ];
+        let scenarios: Vec<Box<dyn TestScenario>> = probes
+            .iter()
+            .map(|(id, tier, should_pass)| {
+                Box::new(ProbeScenario {
+                    id: (*id).to_string(),
+                    tier: (*tier).to_string(),
+                    should_pass: *should_pass,
+                    in_flight: in_flight.clone(),
+                    max_in_flight: max_in_flight.clone(),
+                }) as Box<dyn TestScenario>
+            })
+            .collect();
+
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+        let config = RunConfig::new()
+            .with_backend(Backend::Claude)
+            .with_parallelism(2);
+        let results = runner.run(&config).await.unwrap();
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+        let ids: Vec<&str> = results
+            .results
+            .iter()
+            .map(|r| r.scenario_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "c", "d", "e"], "results keep job order");
+        assert!(
+            results
+                .results
+                .iter()
+                .all(|r| r.assertions[0].passed && r.backend == "Claude"),
+            "{:?}",
+            results.results
+        );
+
+        let tiers: Vec<(&str, usize, usize)> = results
+            .tier_summaries()
+            .into_iter()
+            .map(|(tier, s)| (tier, s.passed, s.total))
+            .collect();
+        assert_eq!(tiers, vec![("Tier 1", 1, 2), ("Tier 2", 3, 3)]);
+        assert!((results.tier_summaries()[0].1.pass_rate() - 0.5).abs() < f64::EPSILON);
+
+        // Every workspace was cleaned up
+        assert_eq!(std::fs::read_dir(&workspace).unwrap().count(), 0);
+
+        cleanup_workspace(&workspace);
+    }
+
     /// Creates a unique test workspace path.
     fn test_workspace_base(test_name: &str) -> PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-runner-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-runner-{test_name}"))
     }
 
     /// Cleans up a test workspace.
@@ -677,6 +904,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "test-2".to_string(),
@@ -686,6 +914,7 @@ mod tests {
                     passed: false,
                     assertions: vec![],
                     duration: Duration::from_secs(2),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "test-3".to_string(),
@@ -695,6 +924,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
             ],
             duration: Duration::from_secs(4),
@@ -718,6 +948,7 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                attempts: 1,
             }],
             duration: Duration::from_secs(1),
             skipped_count: 0,
@@ -738,6 +969,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "test-2".to_string(),
@@ -747,6 +979,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "test-3".to_string(),
@@ -756,6 +989,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
             ],
             duration: Duration::from_secs(3),
@@ -781,6 +1015,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
                 TestResult {
                     scenario_id: "fail".to_string(),
@@ -790,6 +1025,7 @@ mod tests {
                     passed: false,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    attempts: 1,
                 },
             ],
             duration: Duration::from_secs(2),
@@ -864,6 +1100,68 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    #[test]
+    fn test_scenario_selector_matches_id_or_tier() {
+        let scenarios = [
+            MockScenario::new("connect", true).with_tier("Tier 1: Connectivity"),
+            MockScenario::new("connect-retry", true).with_tier("Tier 1: Connectivity"),
+            MockScenario::new("events", true).with_tier("Tier 3: Events"),
+            MockScenario::new("backpressure", true).with_tier("Tier 3: Events"),
+        ];
+        let selected = |config: &RunConfig| -> Vec<&str> {
+            scenarios
+                .iter()
+                .filter(|s| scenario_matches(*s, config))
+                .map(|s| s.id())
+                .collect()
+        };
+
+        // Exact ID, unlike the substring --filter
+        assert_eq!(
+            selected(&RunConfig::new().with_scenario("connect")),
+            vec!["connect"]
+        );
+        assert_eq!(
+            selected(&RunConfig::new().with_filter("connect")),
+            vec!["connect", "connect-retry"]
+        );
+
+        // Tier label or full tier name, case-insensitive
+        assert_eq!(
+            selected(&RunConfig::new().with_scenario("tier 3")),
+            vec!["events", "backpressure"]
+        );
+        assert_eq!(
+            selected(&RunConfig::new().with_scenario("Tier 3: Events")),
+            vec!["events", "backpressure"]
+        );
+        assert!(selected(&RunConfig::new().with_scenario("tier")).is_empty());
+
+        // Composes with --filter
+        assert_eq!(
+            selected(&RunConfig::new().with_scenario("tier 3").with_filter("back")),
+            vec!["backpressure"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runner_unknown_scenario_is_an_error() {
+        let workspace = test_workspace_base("unknown-scenario");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> =
+            vec![Box::new(MockScenario::new("mock-1", true))];
+
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+        let result = runner.run(&RunConfig::new().with_scenario("missing")).await;
+
+        assert!(matches!(
+            result,
+            Err(RunnerError::NoMatchingScenarios(ref s)) if s == "missing"
+        ));
+
+        cleanup_workspace(&workspace);
+    }
+
     #[test]
     fn test_runner_matching_scenarios_with_backend() {
         let workspace = test_workspace_base("matching-backend");
@@ -955,6 +1253,113 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    /// Scenario that fails its first `failures` runs, then passes.
+    struct FlakyScenario {
+        failures: usize,
+        max_retries: u32,
+        runs: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl TestScenario for FlakyScenario {
+        fn id(&self) -> &'static str {
+            "flaky"
+        }
+
+        fn description(&self) -> &'static str {
+            "Fails before passing"
+        }
+
+        fn tier(&self) -> &'static str {
+            "Tier 0: Mock"
+        }
+
+        fn supported_backends(&self) -> Vec<Backend> {
+            vec![Backend::Claude]
+        }
+
+        fn max_retries(&self) -> u32 {
+            self.max_retries
+        }
+
+        fn setup(
+            &self,
+            workspace: &Path,
+            _backend: Backend,
+        ) -> Result<ScenarioConfig, ScenarioError> {
+            // A retry must start from a fresh workspace.
+            let marker = workspace.join("attempted");
+            if marker.exists() {
+                return Err(ScenarioError::SetupError("workspace reused".to_string()));
+            }
+            std::fs::write(marker, "")?;
+            Ok(ScenarioConfig::minimal("flaky"))
+        }
+
+        async fn run(
+            &self,
+            _executor: &RalphExecutor,
+            _config: &ScenarioConfig,
+        ) -> Result<TestResult, ScenarioError> {
+            let run = self.runs.fetch_add(1, Ordering::SeqCst) + 1;
+            let passed = run > self.failures;
+            Ok(TestResult {
+                scenario_id: "flaky".to_string(),
+                scenario_description: self.description().to_string(),
+                backend: String::new(),
+                tier: self.tier().to_string(),
+                passed,
+                assertions: vec![],
+                duration: Duration::from_millis(10),
+                attempts: 1,
+            })
+        }
+    }
+
+    async fn run_flaky(failures: usize, max_retries: u32, name: &str) -> (RunResults, usize) {
+        let workspace = test_workspace_base(name);
+        let runs = Arc::new(AtomicUsize::new(0));
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![Box::new(FlakyScenario {
+            failures,
+            max_retries,
+            runs: runs.clone(),
+        })];
+
+        let runner = TestRunner::new(WorkspaceManager::new(workspace.clone()), scenarios);
+        let results = runner.run_all().await.unwrap();
+        cleanup_workspace(&workspace);
+        (results, runs.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_runner_retries_until_pass_and_flags_flaky() {
+        let (results, runs) = run_flaky(1, 2, "run-retry-pass").await;
+
+        assert_eq!(runs, 2);
+        assert!(results.all_passed());
+        assert_eq!(results.results[0].attempts, 2);
+        assert_eq!(results.flaky().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_runner_fails_after_exhausting_retries() {
+        let (results, runs) = run_flaky(5, 2, "run-retry-fail").await;
+
+        assert_eq!(runs, 3);
+        assert_eq!(results.failed_count(), 1);
+        assert_eq!(results.results[0].attempts, 3);
+        assert!(results.flaky().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_runner_does_not_retry_by_default() {
+        let (results, runs) = run_flaky(1, 0, "run-no-retry").await;
+
+        assert_eq!(runs, 1);
+        assert_eq!(results.failed_count(), 1);
+        assert_eq!(results.results[0].attempts, 1);
+    }
+
     #[tokio::test]
     async fn test_runner_run_with_filter() {
         let workspace = test_workspace_base("run-filter");
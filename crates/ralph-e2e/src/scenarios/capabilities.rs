@@ -150,6 +150,7 @@ You MUST use a tool to read the file. Do not guess the contents.",
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -332,6 +333,7 @@ Keep your response short."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -410,7 +412,6 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
@@ -422,11 +423,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-caps-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-caps-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
@@ -143,6 +143,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -198,7 +199,6 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
@@ -210,11 +210,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-connectivity-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-connectivity-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
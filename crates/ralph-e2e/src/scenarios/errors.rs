@@ -151,6 +151,7 @@ Start the essay now.";
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -343,6 +344,7 @@ Complete exactly one step per iteration. Stop after each step."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -512,6 +514,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -705,6 +708,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -803,7 +807,6 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
 
     #[test]
@@ -814,11 +817,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-errors-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-errors-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
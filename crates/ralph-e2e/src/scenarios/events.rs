@@ -69,7 +69,7 @@ impl TestScenario for EventsScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -153,6 +153,7 @@ Nothing else."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -240,7 +241,7 @@ impl TestScenario for BackpressureScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -330,6 +331,7 @@ Now emit the event:"#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -392,7 +394,6 @@ fn truncate(s: &str, max_len: usize) -> String {
 mod tests {
     use super::*;
     use crate::executor::EventRecord;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
@@ -404,11 +405,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-events-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-events-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
@@ -475,6 +472,23 @@ mod tests {
         assert!(scenario.description().contains("event"));
     }
 
+    #[test]
+    fn test_events_setup_for_gemini_backend() {
+        let workspace = test_workspace("events-setup-gemini");
+        fs::create_dir_all(&workspace).unwrap();
+
+        let scenario = EventsScenario::new();
+        assert!(scenario.supported_backends().contains(&Backend::Gemini));
+        let config = scenario.setup(&workspace, Backend::Gemini).unwrap();
+
+        let content = fs::read_to_string(workspace.join("ralph.yml")).unwrap();
+        assert!(content.contains("backend: gemini"), "{content}");
+        assert!(!content.contains("backend: claude"));
+        assert_eq!(config.timeout, Backend::Gemini.default_timeout());
+
+        cleanup_workspace(&workspace);
+    }
+
     #[test]
     fn test_events_setup_creates_config() {
         let workspace = test_workspace("events-setup");
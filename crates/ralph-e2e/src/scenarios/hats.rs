@@ -193,6 +193,7 @@ After the builder completes, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -415,6 +416,7 @@ IMPORTANT: You MUST output the XML event tags exactly as shown above in your res
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -620,6 +622,7 @@ After the review is complete, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -850,6 +853,7 @@ After tests complete, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1049,6 +1053,7 @@ After completion, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1120,7 +1125,6 @@ fn truncate(s: &str, max_len: usize) -> String {
 mod tests {
     use super::*;
     use crate::executor::EventRecord;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
@@ -1132,11 +1136,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-hats-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-hats-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
@@ -88,7 +88,7 @@ impl TestScenario for MemoryAddScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -188,6 +188,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -309,7 +310,7 @@ impl TestScenario for MemorySearchScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -418,6 +419,7 @@ Output LOOP_COMPLETE when done."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -529,7 +531,7 @@ impl TestScenario for MemoryInjectionScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -638,6 +640,7 @@ Then output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -753,7 +756,7 @@ impl TestScenario for MemoryPersistenceScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -850,6 +853,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -977,7 +981,7 @@ impl TestScenario for MemoryCorruptedFileScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -1100,6 +1104,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1197,7 +1202,7 @@ impl TestScenario for MemoryMissingFileScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -1290,6 +1295,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1395,7 +1401,7 @@ impl TestScenario for MemoryRapidWriteScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -1482,6 +1488,7 @@ IMPORTANT: Use the Bash tool to execute each command."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1584,7 +1591,7 @@ impl TestScenario for MemoryLargeContentScenario {
     }
 
     fn supported_backends(&self) -> Vec<Backend> {
-        vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
+        Backend::all().to_vec()
     }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -1670,6 +1677,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -1716,7 +1724,6 @@ impl MemoryLargeContentScenario {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
@@ -1728,11 +1735,7 @@ mod tests {
     }
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-memory-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-memory-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
@@ -1778,6 +1781,23 @@ mod tests {
         assert!(supported.contains(&Backend::Claude));
         assert!(supported.contains(&Backend::Kiro));
         assert!(supported.contains(&Backend::OpenCode));
+        assert!(supported.contains(&Backend::Gemini));
+        assert!(supported.contains(&Backend::Pi));
+    }
+
+    #[test]
+    fn test_memory_add_setup_for_pi_backend() {
+        let workspace = test_workspace("memory-add-setup-pi");
+        fs::create_dir_all(&workspace).unwrap();
+
+        let scenario = MemoryAddScenario::new();
+        let config = scenario.setup(&workspace, Backend::Pi).unwrap();
+
+        let content = fs::read_to_string(workspace.join("ralph.yml")).unwrap();
+        assert!(content.contains("backend: pi"), "{content}");
+        assert_eq!(config.timeout, Backend::Pi.default_timeout());
+
+        cleanup_workspace(&workspace);
     }
 
     #[test]
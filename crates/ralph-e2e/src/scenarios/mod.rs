@@ -42,6 +42,7 @@
 //! ```
 
 mod capabilities;
+mod command;
 mod connectivity;
 mod errors;
 mod events;
@@ -52,6 +53,7 @@ mod orchestration;
 mod tasks;
 
 pub use capabilities::{StreamingScenario, ToolUseScenario};
+pub use command::{AssertionFn, CommandScenario};
 pub use connectivity::ConnectivityScenario;
 pub use errors::{
     AuthFailureScenario, BackendUnavailableScenario, MaxIterationsScenario, TimeoutScenario,
@@ -78,6 +80,45 @@ use std::path::Path;
 use std::time::Duration;
 use thiserror::Error;
 
+/// Returns every scenario the `ralph-e2e` binary runs, in tier order.
+pub fn all_scenarios() -> Vec<Box<dyn TestScenario>> {
+    vec![
+        // Tier 1: Connectivity (backend-agnostic)
+        Box::new(ConnectivityScenario::new()),
+        // Tier 2: Orchestration Loop (backend-agnostic)
+        Box::new(SingleIterScenario::new()),
+        Box::new(MultiIterScenario::new()),
+        Box::new(CompletionScenario::new()),
+        // Tier 3: Events (backend-agnostic)
+        Box::new(EventsScenario::new()),
+        Box::new(BackpressureScenario::new()),
+        // Tier 4: Capabilities (backend-agnostic)
+        Box::new(ToolUseScenario::new()),
+        Box::new(StreamingScenario::new()),
+        // Tier 5: Hat Collections (backend-agnostic)
+        Box::new(HatSingleScenario::new()),
+        Box::new(HatMultiWorkflowScenario::new()),
+        Box::new(HatInstructionsScenario::new()),
+        Box::new(HatEventRoutingScenario::new()),
+        Box::new(HatBackendOverrideScenario::new()),
+        // Tier 6: Memory System (backend-agnostic)
+        Box::new(MemoryAddScenario::new()),
+        Box::new(MemorySearchScenario::new()),
+        Box::new(MemoryInjectionScenario::new()),
+        Box::new(MemoryPersistenceScenario::new()),
+        // Tier 6: Memory System (Chaos Tests)
+        Box::new(MemoryCorruptedFileScenario::new()),
+        Box::new(MemoryMissingFileScenario::new()),
+        Box::new(MemoryRapidWriteScenario::new()),
+        Box::new(MemoryLargeContentScenario::new()),
+        // Tier 7: Error Handling (backend-agnostic)
+        Box::new(TimeoutScenario::new()),
+        Box::new(MaxIterationsScenario::new()),
+        Box::new(AuthFailureScenario::new()),
+        Box::new(BackendUnavailableScenario::new()),
+    ]
+}
+
 /// Errors that can occur during scenario execution.
 #[derive(Debug, Error)]
 pub enum ScenarioError {
@@ -122,6 +163,15 @@ pub trait TestScenario: Send + Sync {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
 
+    /// How many times the runner re-runs this scenario after a failure.
+    ///
+    /// Live backends are nondeterministic, so a scenario may opt into retries
+    /// to reduce false negatives. A result that passes only after retrying
+    /// is flagged as flaky. Defaults to no retries.
+    fn max_retries(&self) -> u32 {
+        0
+    }
+
     /// Sets up the scenario by creating necessary files in the workspace.
     ///
     /// The `backend` parameter specifies which backend to configure for.
@@ -271,6 +321,23 @@ impl Assertions {
             .with_passed(contains)
     }
 
+    /// Asserts that the scratchpad left after the run contains the substring.
+    pub fn scratchpad_contains(result: &ExecutionResult, expected: &str) -> Assertion {
+        let contains = result
+            .scratchpad
+            .as_ref()
+            .is_some_and(|s| s.contains(expected));
+        AssertionBuilder::new(format!("Scratchpad contains '{}'", truncate(expected, 30)))
+            .expected(format!("Contains: {}", expected))
+            .actual(match &result.scratchpad {
+                _ if contains => "Found in scratchpad".to_string(),
+                Some(scratchpad) => format!("Not found. Scratchpad: {}", truncate(scratchpad, 100)),
+                None => "No scratchpad".to_string(),
+            })
+            .build()
+            .with_passed(contains)
+    }
+
     /// Asserts that a specific event was emitted.
     pub fn event_emitted(result: &ExecutionResult, topic: &str) -> Assertion {
         let found = result.events.iter().any(|e| e.topic == topic);
@@ -288,6 +355,28 @@ impl Assertions {
             .with_passed(found)
     }
 
+    /// Asserts that the topics appear in this relative order among the emitted
+    /// events (other events may come between them).
+    pub fn events_in_order(result: &ExecutionResult, topics: &[&str]) -> Assertion {
+        let emitted: Vec<&str> = result.events.iter().map(|e| e.topic.as_str()).collect();
+        let mut remaining = emitted.iter();
+        let missing = topics
+            .iter()
+            .find(|topic| !remaining.any(|emitted| emitted == *topic));
+
+        AssertionBuilder::new(format!("Events in order: {}", topics.join(" → ")))
+            .expected(format!("Events in order: {}", topics.join(", ")))
+            .actual(match missing {
+                None => "Events found in order".to_string(),
+                Some(topic) => format!(
+                    "'{}' not found after the preceding topics. Events: {:?}",
+                    topic, emitted
+                ),
+            })
+            .build()
+            .with_passed(missing.is_none())
+    }
+
     /// Asserts that the execution completed within the expected iteration count.
     pub fn iterations_within(result: &ExecutionResult, max: u32) -> Assertion {
         let within = result.iterations <= max;
@@ -403,6 +492,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_scenarios_have_unique_ids() {
+        let scenarios = all_scenarios();
+        let mut ids: Vec<_> = scenarios.iter().map(|s| s.id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), scenarios.len());
+    }
+
     #[test]
     fn test_assertion_builder() {
         let assertion = AssertionBuilder::new("Test assertion")
@@ -496,6 +594,21 @@ mod tests {
         assert!(!assertion.passed);
     }
 
+    #[test]
+    fn test_scratchpad_contains() {
+        let mut result = mock_execution_result();
+        let missing = Assertions::scratchpad_contains(&result, "[x]");
+        assert!(!missing.passed);
+        assert_eq!(missing.actual, "No scratchpad");
+
+        result.scratchpad = Some("## Tasks\n- [x] Build complete".to_string());
+        assert!(Assertions::scratchpad_contains(&result, "[x] Build").passed);
+
+        let absent = Assertions::scratchpad_contains(&result, "[ ] Deploy");
+        assert!(!absent.passed);
+        assert!(absent.actual.starts_with("Not found."), "{}", absent.actual);
+    }
+
     #[test]
     fn test_event_emitted_passed() {
         let result = mock_execution_result();
@@ -503,6 +616,50 @@ mod tests {
         assert!(assertion.passed);
     }
 
+    fn result_with_events(topics: &[&str]) -> ExecutionResult {
+        let mut result = mock_execution_result();
+        result.events = topics
+            .iter()
+            .map(|topic| EventRecord {
+                topic: (*topic).to_string(),
+                payload: String::new(),
+            })
+            .collect();
+        result
+    }
+
+    #[test]
+    fn test_events_in_order_passed() {
+        let result = result_with_events(&["build.task", "review.request", "build.done"]);
+        let assertion = Assertions::events_in_order(&result, &["build.task", "build.done"]);
+        assert!(assertion.passed, "{}", assertion.actual);
+    }
+
+    #[test]
+    fn test_events_in_order_failed_when_reversed() {
+        let result = result_with_events(&["build.done", "build.task"]);
+        let assertion = Assertions::events_in_order(&result, &["build.task", "build.done"]);
+        assert!(!assertion.passed);
+        assert!(
+            assertion.actual.starts_with("'build.done' not found"),
+            "{}",
+            assertion.actual
+        );
+    }
+
+    #[test]
+    fn test_events_in_order_repeated_topics() {
+        let result = result_with_events(&["build.task", "build.done", "build.task"]);
+        assert!(
+            Assertions::events_in_order(&result, &["build.task", "build.done", "build.task"])
+                .passed
+        );
+        assert!(
+            !Assertions::events_in_order(&result, &["build.done", "build.done"]).passed,
+            "each listed topic needs its own event"
+        );
+    }
+
     #[test]
     fn test_event_emitted_failed() {
         let result = mock_execution_result();
@@ -155,6 +155,7 @@ Write to the scratchpad first, then output LOOP_COMPLETE.";
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -324,6 +325,7 @@ After all events are emitted, output LOOP_COMPLETE on its own line."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -501,6 +503,7 @@ Keep outputting LOOP_COMPLETE until the orchestration ends.";
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -553,16 +556,11 @@ impl AssertionExt for crate::models::Assertion {
 mod tests {
     use super::*;
     use crate::executor::EventRecord;
-    use std::env;
     use std::fs;
     use std::time::Duration;
 
     fn test_workspace(test_name: &str) -> std::path::PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-orch-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        crate::workspace::unique_test_dir(&format!("ralph-e2e-orch-{test_name}"))
     }
 
     fn cleanup_workspace(path: &std::path::PathBuf) {
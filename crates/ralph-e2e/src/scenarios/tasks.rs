@@ -184,6 +184,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -402,6 +403,7 @@ IMPORTANT: Execute each command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -584,6 +586,7 @@ memories:
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
@@ -779,6 +782,7 @@ Execute each command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            attempts: 1,
         })
     }
 }
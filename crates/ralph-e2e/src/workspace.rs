@@ -134,18 +134,51 @@ impl WorkspaceManager {
     }
 }
 
+/// Returns a fresh temp-dir path for a unit test, `<temp>/<prefix>-<unique>`.
+///
+/// The suffix combines a random value, the current time, and a per-process
+/// counter, so test binaries running concurrently (or a recycled PID) never
+/// share a directory. The directory is not created.
+#[cfg(test)]
+pub(crate) fn unique_test_dir(prefix: &str) -> PathBuf {
+    use std::collections::hash_map::RandomState;
+    use std::hash::BuildHasher;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let random = RandomState::new().hash_one((nanos, count, std::process::id()));
+    std::env::temp_dir().join(format!("{prefix}-{nanos:x}-{random:016x}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::env;
 
     /// Creates a unique test base path to avoid conflicts between parallel tests.
     fn test_base_path(test_name: &str) -> PathBuf {
-        env::temp_dir().join(format!(
-            "ralph-e2e-test-{}-{}",
-            test_name,
-            std::process::id()
-        ))
+        unique_test_dir(&format!("ralph-e2e-test-{test_name}"))
+    }
+
+    #[test]
+    fn test_unique_test_dir_is_distinct_per_call() {
+        let first = unique_test_dir("ralph-e2e-test-unique");
+        let second = unique_test_dir("ralph-e2e-test-unique");
+
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), second.parent());
+        assert!(
+            first
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("ralph-e2e-test-unique-")
+        );
     }
 
     #[test]
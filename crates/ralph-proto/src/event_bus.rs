@@ -3,13 +3,30 @@
 //! The event bus routes events to subscribed hats based on topic patterns.
 //! Multiple observers can be added to receive all published events for
 //! recording, TUI updates, and benchmarking purposes.
+//!
+//! Optional per-topic rate limits drop agent-emitted events once a topic has
+//! been published too often within a time window.
+//!
+//! Every publish logs the hats it triggers at debug level, so routing can be
+//! traced with `--verbose` (or `RUST_LOG=ralph_proto=debug`).
 
-use crate::{Event, Hat, HatId};
-use std::collections::BTreeMap;
+use crate::{Event, Hat, HatId, Topic};
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
 
 /// Type alias for the observer callback function.
 type Observer = Box<dyn Fn(&Event) + Send + 'static>;
 
+/// Caps how many times a topic may be published within a sliding window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopicRateLimit {
+    /// Publishes allowed per window; further ones are dropped.
+    pub max_events: u32,
+    /// Length of the sliding window.
+    pub window: Duration,
+}
+
 /// Central pub/sub hub for routing events between hats.
 #[derive(Default)]
 pub struct EventBus {
@@ -25,6 +42,12 @@ pub struct EventBus {
     /// Observers that receive all published events.
     /// Multiple observers can be registered (e.g., session recorder + TUI).
     observers: Vec<Observer>,
+
+    /// Rate limits by topic pattern; the most specific matching pattern applies.
+    rate_limits: Vec<(Topic, TopicRateLimit)>,
+
+    /// Recent publish times per concrete rate-limited topic.
+    recent_publishes: BTreeMap<String, VecDeque<Instant>>,
 }
 
 impl EventBus {
@@ -71,6 +94,77 @@ impl EventBus {
         self.pending.entry(id).or_default();
     }
 
+    /// Limits how often topics matching `pattern` (e.g. `build.*`) may be
+    /// published through [`publish_rate_limited`](Self::publish_rate_limited).
+    /// Each concrete topic is counted separately. When several patterns
+    /// match, the most specific one wins (`build.done` over `build.*` over
+    /// `*`); ties go to the pattern set first. Setting a pattern again
+    /// replaces its limit.
+    pub fn set_rate_limit(&mut self, pattern: impl Into<String>, limit: TopicRateLimit) {
+        let pattern = Topic::new(pattern);
+        if let Some(existing) = self.rate_limits.iter_mut().find(|(p, _)| *p == pattern) {
+            existing.1 = limit;
+        } else {
+            self.rate_limits.push((pattern, limit));
+        }
+    }
+
+    /// Returns the limit from the most specific pattern matching `topic`.
+    fn rate_limit_for(&self, topic: &str) -> Option<TopicRateLimit> {
+        let literal_segments =
+            |pattern: &Topic| pattern.as_str().split('.').filter(|s| *s != "*").count();
+
+        let mut best: Option<(usize, TopicRateLimit)> = None;
+        for (pattern, limit) in &self.rate_limits {
+            if !pattern.matches_str(topic) {
+                continue;
+            }
+            let specificity = literal_segments(pattern);
+            if best.is_none_or(|(best_specificity, _)| specificity > best_specificity) {
+                best = Some((specificity, *limit));
+            }
+        }
+        best.map(|(_, limit)| limit)
+    }
+
+    /// Records a publish of `topic` and returns false if it exceeds the
+    /// topic's rate limit.
+    fn admit(&mut self, topic: &str, now: Instant) -> bool {
+        let Some(limit) = self.rate_limit_for(topic) else {
+            return true;
+        };
+
+        let recent = self.recent_publishes.entry(topic.to_string()).or_default();
+        while recent
+            .front()
+            .is_some_and(|&at| now.duration_since(at) >= limit.window)
+        {
+            recent.pop_front();
+        }
+
+        if recent.len() >= limit.max_events as usize {
+            return false;
+        }
+        recent.push_back(now);
+        true
+    }
+
+    /// Publishes an agent-emitted event, subject to the topic rate limits.
+    ///
+    /// Events over their topic's rate limit are dropped with a warning
+    /// before observers or hats see them. Orchestrator events should use
+    /// [`publish`](Self::publish), which is never limited.
+    pub fn publish_rate_limited(&mut self, event: Event) -> Vec<HatId> {
+        if !self.admit(event.topic.as_str(), Instant::now()) {
+            warn!(
+                topic = %event.topic.as_str(),
+                "Dropping event: topic exceeded its rate limit"
+            );
+            return Vec::new();
+        }
+        self.publish(event)
+    }
+
     /// Publishes an event to all subscribed hats.
     ///
     /// Returns the list of hat IDs that received the event.
@@ -83,6 +177,7 @@ impl EventBus {
         }
 
         if event.topic.as_str().starts_with("human.") {
+            debug!(topic = %event.topic, "Published event -> human queue");
             self.human_pending.push(event);
             return Vec::new();
         }
@@ -91,6 +186,12 @@ impl EventBus {
 
         // If there's a direct target, route only to that hat
         if let Some(ref target) = event.target {
+            debug!(
+                topic = %event.topic,
+                target = %target,
+                known = self.hats.contains_key(target),
+                "Published event -> direct target"
+            );
             if self.hats.contains_key(target) {
                 self.pending
                     .entry(target.clone())
@@ -101,40 +202,51 @@ impl EventBus {
             return recipients;
         }
 
+        let chosen_recipients = self.find_all_by_trigger(&event.topic);
+        debug!(
+            topic = %event.topic,
+            triggers = ?chosen_recipients.iter().map(HatId::as_str).collect::<Vec<_>>(),
+            "Published event -> triggers"
+        );
+
+        for id in chosen_recipients {
+            self.pending
+                .entry(id.clone())
+                .or_default()
+                .push(event.clone());
+            recipients.push(id);
+        }
+
+        recipients
+    }
+
+    /// Returns the hats an untargeted event on `topic` is routed to.
+    ///
+    /// Hats with a specific subscription win; hats that match only through
+    /// the global `*` wildcard are used when there are none. IDs are in
+    /// sorted order.
+    pub fn find_all_by_trigger(&self, topic: &Topic) -> Vec<HatId> {
         // Route with priority: specific subscriptions > fallback wildcards
         // Per spec: "If event has subscriber → Select that hat's backend"
         //           "If no subscriber → Select Ralph's backend (cli.backend)"
-
-        // First, find hats with specific (non-global-wildcard) subscriptions
         let mut specific_recipients = Vec::new();
         let mut fallback_recipients = Vec::new();
 
         for (id, hat) in &self.hats {
-            if hat.has_specific_subscription(&event.topic) {
+            if hat.has_specific_subscription(topic) {
                 // Hat has a specific subscription for this topic
                 specific_recipients.push(id.clone());
-            } else if hat.is_subscribed(&event.topic) {
+            } else if hat.is_subscribed(topic) {
                 // Hat matches only via global wildcard (fallback)
                 fallback_recipients.push(id.clone());
             }
         }
 
-        // Use specific subscribers if any, otherwise fall back to wildcard handlers
-        let chosen_recipients = if specific_recipients.is_empty() {
+        if specific_recipients.is_empty() {
             fallback_recipients
         } else {
             specific_recipients
-        };
-
-        for id in chosen_recipients {
-            self.pending
-                .entry(id.clone())
-                .or_default()
-                .push(event.clone());
-            recipients.push(id);
         }
-
-        recipients
     }
 
     /// Takes all pending events for a hat.
@@ -157,16 +269,51 @@ impl EventBus {
         &self.human_pending
     }
 
+    /// Returns pending events for a hat without consuming them.
+    ///
+    /// Unlike [`peek_pending`](Self::peek_pending), an unknown hat yields an
+    /// empty slice, which keeps test assertions short.
+    pub fn pending_for(&self, hat_id: &HatId) -> &[Event] {
+        self.pending.get(hat_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Checks if a hat has pending events.
+    pub fn has_pending_for(&self, hat_id: &HatId) -> bool {
+        !self.pending_for(hat_id).is_empty()
+    }
+
     /// Checks if there are any pending events for any hat.
     pub fn has_pending(&self) -> bool {
         !self.human_pending.is_empty() || self.pending.values().any(|events| !events.is_empty())
     }
 
+    /// Drops all pending events, including human interaction events.
+    ///
+    /// Registered hats and observers are kept.
+    pub fn clear(&mut self) {
+        self.pending.values_mut().for_each(Vec::clear);
+        self.human_pending.clear();
+    }
+
     /// Checks if there are any pending human interaction events.
     pub fn has_human_pending(&self) -> bool {
         !self.human_pending.is_empty()
     }
 
+    /// Returns the total number of queued events, including human interaction events.
+    pub fn pending_count(&self) -> usize {
+        self.human_pending.len() + self.pending.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns every hat that has pending events, in the order
+    /// [`next_hat_with_pending`](Self::next_hat_with_pending) would pick them.
+    pub fn hats_with_pending(&self) -> impl Iterator<Item = &HatId> {
+        self.pending
+            .iter()
+            .filter(|(_, events)| !events.is_empty())
+            .map(|(id, _)| id)
+    }
+
     /// Returns the next hat with pending events.
     /// BTreeMap iteration is already sorted by key.
     pub fn next_hat_with_pending(&self) -> Option<&HatId> {
@@ -205,6 +352,162 @@ mod tests {
         assert_eq!(recipients[0].as_str(), "impl");
     }
 
+    #[test]
+    fn test_find_all_by_trigger_prefers_specific_over_wildcard() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("ralph", "Ralph").subscribe("*"));
+        bus.register(Hat::new("reviewer", "Reviewer").subscribe("build.done"));
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+
+        let ids = bus.find_all_by_trigger(&Topic::new("build.done"));
+        assert_eq!(ids, vec![HatId::new("builder"), HatId::new("reviewer")]);
+
+        let ids = bus.find_all_by_trigger(&Topic::new("deploy.start"));
+        assert_eq!(ids, vec![HatId::new("ralph")]);
+    }
+
+    #[test]
+    fn test_publish_logs_triggered_hats_at_debug() {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .without_time()
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        bus.register(Hat::new("reviewer", "Reviewer").subscribe("build.done"));
+        bus.register(Hat::new("deployer", "Deployer").subscribe("deploy.*"));
+
+        tracing::subscriber::with_default(subscriber, || {
+            bus.publish(Event::new("build.done", "Built"));
+        });
+
+        let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("DEBUG"), "logs: {logs}");
+        assert!(logs.contains("topic=build.done"), "logs: {logs}");
+        assert!(
+            logs.contains(r#"triggers=["builder", "reviewer"]"#),
+            "logs: {logs}"
+        );
+        assert!(!logs.contains("deployer"), "logs: {logs}");
+    }
+
+    #[test]
+    fn test_rate_limit_drops_excess_for_one_topic_only() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        bus.set_rate_limit(
+            "build.task",
+            TopicRateLimit {
+                max_events: 3,
+                window: Duration::from_mins(1),
+            },
+        );
+
+        let delivered = (0..5)
+            .filter(|_| {
+                !bus.publish_rate_limited(Event::new("build.task", "again"))
+                    .is_empty()
+            })
+            .count();
+        assert_eq!(delivered, 3);
+
+        let other = (0..5)
+            .filter(|_| {
+                !bus.publish_rate_limited(Event::new("build.done", "done"))
+                    .is_empty()
+            })
+            .count();
+        assert_eq!(other, 5, "unlimited topics are unaffected");
+
+        // Plain publish (orchestrator events) ignores the limit.
+        assert!(!bus.publish(Event::new("build.task", "resume")).is_empty());
+
+        assert_eq!(bus.pending_for(&HatId::new("builder")).len(), 9);
+    }
+
+    #[test]
+    fn test_rate_limit_most_specific_pattern_wins() {
+        let limit = |max_events| TopicRateLimit {
+            max_events,
+            window: Duration::from_mins(1),
+        };
+        // Same patterns in both insertion orders must behave the same.
+        for patterns in [
+            [("*", 5), ("build.*", 2), ("build.done", 1)],
+            [("build.done", 1), ("build.*", 2), ("*", 5)],
+        ] {
+            let mut bus = EventBus::new();
+            for (pattern, max) in patterns {
+                bus.set_rate_limit(pattern, limit(max));
+            }
+            let admitted = |bus: &mut EventBus, topic: &str| {
+                let now = Instant::now();
+                (0..10).filter(|_| bus.admit(topic, now)).count()
+            };
+
+            assert_eq!(admitted(&mut bus, "build.done"), 1);
+            assert_eq!(admitted(&mut bus, "build.task"), 2);
+            assert_eq!(admitted(&mut bus, "review.done"), 5);
+        }
+    }
+
+    #[test]
+    fn test_set_rate_limit_replaces_same_pattern() {
+        let mut bus = EventBus::new();
+        for max_events in [1, 3] {
+            bus.set_rate_limit(
+                "build.*",
+                TopicRateLimit {
+                    max_events,
+                    window: Duration::from_mins(1),
+                },
+            );
+        }
+        let now = Instant::now();
+        assert_eq!((0..5).filter(|_| bus.admit("build.task", now)).count(), 3);
+    }
+
+    #[test]
+    fn test_rate_limit_pattern_counts_topics_separately_and_window_slides() {
+        let mut bus = EventBus::new();
+        bus.set_rate_limit(
+            "build.*",
+            TopicRateLimit {
+                max_events: 1,
+                window: Duration::from_secs(10),
+            },
+        );
+        let start = Instant::now();
+
+        assert!(bus.admit("build.task", start));
+        assert!(!bus.admit("build.task", start + Duration::from_secs(5)));
+        assert!(bus.admit("build.blocked", start + Duration::from_secs(5)));
+        assert!(bus.admit("build.task", start + Duration::from_secs(10)));
+        assert!(bus.admit("review.done", start));
+    }
+
     #[test]
     fn test_no_match() {
         let mut bus = EventBus::new();
@@ -368,6 +671,73 @@ mod tests {
         assert_eq!(*count.lock().unwrap(), 1); // Still 1, observers cleared
     }
 
+    #[test]
+    fn test_pending_for_inspects_single_hat() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        bus.register(Hat::new("reviewer", "Reviewer").subscribe("review.*"));
+
+        bus.publish(Event::new("build.start", "Go"));
+
+        let builder = HatId::new("builder");
+        let reviewer = HatId::new("reviewer");
+        assert_eq!(bus.pending_for(&builder).len(), 1);
+        assert_eq!(bus.pending_for(&builder)[0].payload, "Go");
+        assert!(bus.has_pending_for(&builder));
+        assert!(!bus.has_pending_for(&reviewer));
+        assert!(bus.pending_for(&HatId::new("unknown")).is_empty());
+        assert!(bus.has_pending());
+    }
+
+    #[test]
+    fn test_pending_counts_across_hats() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        bus.register(Hat::new("reviewer", "Reviewer").subscribe("review.*"));
+        bus.register(Hat::new("planner", "Planner").subscribe("plan.*"));
+
+        bus.publish(Event::new("build.start", "One"));
+        bus.publish(Event::new("build.retry", "Two"));
+        bus.publish(Event::new("review.start", "Three"));
+        bus.publish(Event::new("human.interact", "question"));
+
+        assert_eq!(bus.pending_count(), 4);
+        assert_eq!(bus.pending_for(&HatId::new("builder")).len(), 2);
+        assert_eq!(bus.pending_for(&HatId::new("reviewer")).len(), 1);
+        assert_eq!(
+            bus.hats_with_pending()
+                .map(HatId::as_str)
+                .collect::<Vec<_>>(),
+            vec!["builder", "reviewer"]
+        );
+
+        bus.take_pending(&HatId::new("builder"));
+        assert_eq!(bus.pending_count(), 2);
+        assert_eq!(
+            bus.hats_with_pending()
+                .map(HatId::as_str)
+                .collect::<Vec<_>>(),
+            vec!["reviewer"]
+        );
+    }
+
+    #[test]
+    fn test_clear_drops_pending_but_keeps_hats() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+
+        bus.publish(Event::new("build.start", "Go"));
+        bus.publish(Event::new("human.interact", "question"));
+        bus.clear();
+
+        assert!(!bus.has_pending());
+        assert!(!bus.has_human_pending());
+        assert!(bus.get_hat(&HatId::new("builder")).is_some());
+
+        bus.publish(Event::new("build.start", "Again"));
+        assert!(bus.has_pending_for(&HatId::new("builder")));
+    }
+
     #[test]
     fn test_peek_pending_does_not_consume() {
         let mut bus = EventBus::new();
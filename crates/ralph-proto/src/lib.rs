@@ -21,9 +21,9 @@ mod ux_event;
 pub use daemon::{DaemonAdapter, StartLoopFn};
 pub use error::{Error, Result};
 pub use event::Event;
-pub use event_bus::EventBus;
+pub use event_bus::{EventBus, TopicRateLimit};
 pub use hat::{Hat, HatId};
-pub use robot::{CheckinContext, RobotService};
+pub use robot::{CheckinContext, RobotService, TerminationContext};
 pub use topic::Topic;
 pub use ux_event::{
     FrameCapture, TerminalColorMode, TerminalResize, TerminalWrite, TuiFrame, UxEvent,
@@ -6,7 +6,7 @@
 //! receive responses, and send periodic check-ins — without knowing which
 //! communication platform is being used.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::Duration;
@@ -27,6 +27,21 @@ pub struct CheckinContext {
     pub cumulative_cost: f64,
 }
 
+/// Summary of a finished loop, sent to the human when it terminates.
+#[derive(Debug, Default)]
+pub struct TerminationContext {
+    /// Termination reason as reported in `loop.terminate` (e.g., "completed").
+    pub reason: String,
+    /// Iterations run.
+    pub iterations: u32,
+    /// Wall-clock time since the loop started.
+    pub elapsed: Duration,
+    /// Cumulative cost in USD.
+    pub cumulative_cost: f64,
+    /// Scratchpad to attach to the summary.
+    pub scratchpad: PathBuf,
+}
+
 /// A communication service for human-in-the-loop interaction.
 ///
 /// Implementors handle platform-specific concerns: sending messages,
@@ -57,6 +72,15 @@ pub trait RobotService: Send + Sync {
         context: Option<&CheckinContext>,
     ) -> anyhow::Result<i32>;
 
+    /// Send a summary of the finished loop.
+    ///
+    /// Called once during loop termination, before [`stop`](Self::stop).
+    /// The default implementation sends nothing.
+    fn send_termination(&self, context: &TerminationContext) -> anyhow::Result<()> {
+        let _ = context;
+        Ok(())
+    }
+
     /// Get the configured response timeout in seconds.
     fn timeout_secs(&self) -> u64;
 
@@ -1,8 +1,13 @@
+use std::future::Future;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use tracing::warn;
 
 use crate::error::{TelegramError, TelegramResult};
+use crate::rate_limit::RateLimiter;
 
 /// Trait abstracting Telegram bot operations for testability.
 ///
@@ -33,55 +38,422 @@ pub trait BotApi: Send + Sync {
         file_path: &Path,
         caption: Option<&str>,
     ) -> TelegramResult<i32>;
+
+    /// Formatting syntax that sent text and captions are parsed with.
+    fn parse_mode(&self) -> ParseMode {
+        ParseMode::Html
+    }
+
+    /// Send the scratchpad file as a document with the given caption,
+    /// formatted for [`parse_mode`](Self::parse_mode).
+    ///
+    /// If the file doesn't exist, sends the caption as a text message with a
+    /// note that no scratchpad was found instead.
+    ///
+    /// Returns the Telegram message ID of the sent message.
+    async fn send_scratchpad(
+        &self,
+        chat_id: i64,
+        scratchpad: &Path,
+        caption: &str,
+    ) -> TelegramResult<i32> {
+        if scratchpad.is_file() {
+            return self.send_document(chat_id, scratchpad, Some(caption)).await;
+        }
+        let mode = self.parse_mode();
+        let text = format!(
+            "{caption}\n\nNo scratchpad found at {}{}",
+            mode.code(&scratchpad.display().to_string()),
+            mode.escape(".")
+        );
+        self.send_message(chat_id, &text).await
+    }
+}
+
+/// Telegram formatting syntax for outgoing messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Telegram HTML: `<b>`, `<code>`, `<pre>`; escapes `<`, `>`, `&`.
+    #[default]
+    Html,
+    /// Telegram MarkdownV2: `*bold*`, `` `code` ``; escapes every reserved character.
+    MarkdownV2,
+}
+
+impl ParseMode {
+    /// Escape plain text so it renders literally.
+    pub fn escape(self, text: &str) -> String {
+        match self {
+            Self::Html => escape_html(text),
+            Self::MarkdownV2 => escape_markdown_v2(text),
+        }
+    }
+
+    /// Render plain text in bold.
+    pub fn bold(self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<b>{}</b>", escape_html(text)),
+            Self::MarkdownV2 => format!("*{}*", escape_markdown_v2(text)),
+        }
+    }
+
+    /// Render plain text in italics.
+    pub fn italic(self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<i>{}</i>", escape_html(text)),
+            Self::MarkdownV2 => format!("_{}_", escape_markdown_v2(text)),
+        }
+    }
+
+    /// Render plain text as inline code.
+    pub fn code(self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<code>{}</code>", escape_html(text)),
+            Self::MarkdownV2 => format!("`{}`", escape_markdown_v2_code(text)),
+        }
+    }
+
+    /// Render plain text as a preformatted block.
+    pub fn pre(self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<pre>{}</pre>", escape_html(text)),
+            Self::MarkdownV2 => format!("```\n{}\n```", escape_markdown_v2_code(text)),
+        }
+    }
+
+    /// Convert Ralph-generated markdown; see [`markdown_to_telegram_html`].
+    pub fn from_markdown(self, md: &str) -> String {
+        render_markdown(md, self)
+    }
+}
+
+impl From<ParseMode> for teloxide::types::ParseMode {
+    fn from(mode: ParseMode) -> Self {
+        match mode {
+            ParseMode::Html => Self::Html,
+            ParseMode::MarkdownV2 => Self::MarkdownV2,
+        }
+    }
+}
+
+/// Default number of attempts for each send before giving up.
+pub const DEFAULT_SEND_ATTEMPTS: u32 = 3;
+
+/// Default base delay for send retries; doubles after each failed attempt.
+pub const DEFAULT_SEND_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Default cap on messages per second to a single chat.
+pub const DEFAULT_MESSAGES_PER_SECOND: u32 = 1;
+
+/// Longest wait between send retries, however many attempts failed or how
+/// long Telegram asks us to wait.
+pub const MAX_SEND_RETRY_DELAY: Duration = Duration::from_mins(1);
+
+/// Descriptions Telegram gives alongside 5xx responses. teloxide drops the
+/// status code, so these are how server errors are told apart.
+const SERVER_ERROR_DESCRIPTIONS: &[&str] = &[
+    "Internal Server Error",
+    "Bad Gateway",
+    "Service Unavailable",
+    "Gateway Timeout",
+];
+
+/// When to retry a failed send attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Retry {
+    /// The request can never succeed as sent (bad markup, blocked chat, ...).
+    Never,
+    /// Transient failure (network error, 5xx): retry with exponential backoff.
+    Backoff,
+    /// Flood control (429): retry once Telegram's `retry_after` has passed.
+    After(Duration),
+}
+
+/// A failed send attempt.
+#[derive(Debug)]
+struct SendFailure {
+    reason: String,
+    retry: Retry,
+}
+
+impl SendFailure {
+    /// Classifies a teloxide error: network errors, 429 and 5xx are worth
+    /// retrying, everything else is permanent.
+    fn from_request_error(error: &teloxide::RequestError) -> Self {
+        use teloxide::{ApiError, RequestError};
+
+        let retry = match error {
+            RequestError::Network(_) => Retry::Backoff,
+            RequestError::RetryAfter(seconds) => Retry::After(seconds.duration()),
+            // A gateway in front of Telegram answered with a non-JSON 5xx page
+            RequestError::InvalidJson { .. } => Retry::Backoff,
+            RequestError::Api(ApiError::Unknown(description))
+                if SERVER_ERROR_DESCRIPTIONS
+                    .iter()
+                    .any(|server_error| description.starts_with(server_error)) =>
+            {
+                Retry::Backoff
+            }
+            _ => Retry::Never,
+        };
+        Self {
+            reason: error.to_string(),
+            retry,
+        }
+    }
 }
 
 /// Wraps a `teloxide::Bot` and provides formatted messaging for Ralph.
+///
+/// Sends that fail with a network error or a 5xx are retried with
+/// exponential backoff: up to `max_attempts` tries, sleeping `base_delay`,
+/// `2 * base_delay`, ... between them, up to [`MAX_SEND_RETRY_DELAY`]. A 429
+/// is retried after the server's `retry_after` (same cap); other API errors
+/// fail at once.
+/// Each request is also rate-limited per chat; clones share the limiter.
+#[derive(Clone)]
 pub struct TelegramBot {
     bot: teloxide::Bot,
+    max_attempts: u32,
+    base_delay: Duration,
+    limiter: Arc<RateLimiter>,
+    parse_mode: ParseMode,
 }
 
 impl TelegramBot {
-    /// Create a new TelegramBot from a bot token.
+    /// Create a new TelegramBot from a bot token with the default retry
+    /// policy ([`DEFAULT_SEND_ATTEMPTS`] attempts, [`DEFAULT_SEND_BASE_DELAY`])
+    /// and rate limit ([`DEFAULT_MESSAGES_PER_SECOND`]).
     pub fn new(token: &str) -> Self {
-        if cfg!(test) {
+        Self::with_retry(token, DEFAULT_SEND_ATTEMPTS, DEFAULT_SEND_BASE_DELAY)
+    }
+
+    /// Create a new TelegramBot with a custom send retry policy and the
+    /// default rate limit.
+    ///
+    /// `max_attempts` is clamped to at least 1.
+    pub fn with_retry(token: &str, max_attempts: u32, base_delay: Duration) -> Self {
+        Self::with_limits(token, max_attempts, base_delay, DEFAULT_MESSAGES_PER_SECOND)
+    }
+
+    /// Create a new TelegramBot with a custom retry policy and rate limit.
+    ///
+    /// At most `messages_per_second` requests go to any one chat per second;
+    /// excess sends queue up and go out in order. Zero disables the limit.
+    /// `max_attempts` is clamped to at least 1.
+    pub fn with_limits(
+        token: &str,
+        max_attempts: u32,
+        base_delay: Duration,
+        messages_per_second: u32,
+    ) -> Self {
+        let bot = if cfg!(test) {
             let client = teloxide::net::default_reqwest_settings()
                 .no_proxy()
                 .build()
                 .expect("Client creation failed");
-            Self {
-                bot: teloxide::Bot::with_client(token, client),
-            }
+            teloxide::Bot::with_client(token, client)
         } else {
-            Self {
-                bot: teloxide::Bot::new(token),
+            teloxide::Bot::new(token)
+        };
+        Self {
+            bot,
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            limiter: Arc::new(RateLimiter::new(messages_per_second)),
+            parse_mode: ParseMode::Html,
+        }
+    }
+
+    /// Format and send messages with `parse_mode` instead of HTML.
+    pub fn with_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
+    /// Wait until every queued send has gone out, e.g. before shutting down.
+    pub async fn flush(&self) {
+        self.limiter.flush().await;
+    }
+
+    /// Run `send` until it succeeds, fails permanently, or the attempts are
+    /// exhausted.
+    ///
+    /// Holds this chat's place in the rate limiter queue throughout, taking
+    /// a token before every attempt. `send` builds and performs one request
+    /// per call, so tests can stand in for the Telegram transport. On
+    /// failure, returns `TelegramError::Send` with the number of attempts
+    /// made and the last error.
+    async fn send_with_retry<F, Fut>(&self, chat_id: i64, mut send: F) -> TelegramResult<i32>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<i32, SendFailure>>,
+    {
+        let mut slot = self.limiter.queue(chat_id).await;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            slot.take().await;
+            let failure = match send().await {
+                Ok(message_id) => return Ok(message_id),
+                Err(failure) => failure,
+            };
+
+            let delay = match failure.retry {
+                _ if attempt >= self.max_attempts => None,
+                Retry::Never => None,
+                Retry::Backoff => Some(self.retry_delay(attempt)),
+                Retry::After(delay) => Some(delay.min(MAX_SEND_RETRY_DELAY)),
+            };
+            warn!(
+                attempt,
+                max_attempts = self.max_attempts,
+                error = %failure.reason,
+                "Telegram send failed, {}",
+                match (delay, failure.retry) {
+                    (Some(_), Retry::After(_)) => "retrying after the server's delay",
+                    (Some(_), _) => "retrying with backoff",
+                    (None, Retry::Never) => "not retrying a permanent error",
+                    (None, _) => "all retries exhausted",
+                }
+            );
+            match delay {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => {
+                    return Err(TelegramError::Send {
+                        attempts: attempt,
+                        reason: failure.reason,
+                    });
+                }
             }
         }
     }
 
-    /// Format an outgoing question message using Telegram HTML.
+    /// Backoff after failed `attempt`: `base_delay` doubled for each earlier
+    /// failure, capped at [`MAX_SEND_RETRY_DELAY`].
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .map_or(MAX_SEND_RETRY_DELAY, |delay| {
+                delay.min(MAX_SEND_RETRY_DELAY)
+            })
+    }
+
+    /// Format an outgoing question message in the bot's parse mode.
     ///
     /// Includes emoji, hat name, iteration number, and the question text.
-    /// The question body is converted from markdown to Telegram HTML for
-    /// rich rendering. The hat and loop ID are HTML-escaped for safety.
-    pub fn format_question(hat: &str, iteration: u32, loop_id: &str, question: &str) -> String {
-        let escaped_hat = escape_html(hat);
-        let escaped_loop = escape_html(loop_id);
-        let formatted_question = markdown_to_telegram_html(question);
+    /// The question body is converted from markdown for rich rendering.
+    /// The hat and loop ID are escaped for safety.
+    pub fn format_question(
+        &self,
+        hat: &str,
+        iteration: u32,
+        loop_id: &str,
+        question: &str,
+    ) -> String {
+        let mode = self.parse_mode;
         format!(
-            "❓ <b>{escaped_hat}</b> (iteration {iteration}, loop <code>{escaped_loop}</code>)\n\n{formatted_question}",
+            "❓ {} {}{}{}\n\n{}",
+            mode.bold(hat),
+            mode.escape(&format!("(iteration {iteration}, loop ")),
+            mode.code(loop_id),
+            mode.escape(")"),
+            mode.from_markdown(question)
         )
     }
 
     /// Format a greeting message sent when the bot starts.
-    pub fn format_greeting(loop_id: &str) -> String {
-        let escaped = escape_html(loop_id);
-        format!("🤖 Ralph bot online — monitoring loop <code>{escaped}</code>")
+    pub fn format_greeting(&self, loop_id: &str) -> String {
+        format!(
+            "🤖 Ralph bot online — monitoring loop {}",
+            self.parse_mode.code(loop_id)
+        )
     }
 
     /// Format a farewell message sent when the bot shuts down.
-    pub fn format_farewell(loop_id: &str) -> String {
-        let escaped = escape_html(loop_id);
-        format!("👋 Ralph bot shutting down — loop <code>{escaped}</code> complete")
+    pub fn format_farewell(&self, loop_id: &str) -> String {
+        format!(
+            "👋 Ralph bot shutting down — loop {} complete",
+            self.parse_mode.code(loop_id)
+        )
+    }
+
+    /// Format the caption for the scratchpad sent when a loop terminates.
+    pub fn format_scratchpad_caption(
+        &self,
+        loop_id: &str,
+        reason: &str,
+        iterations: u32,
+    ) -> String {
+        let mode = self.parse_mode;
+        format!(
+            "📝 Scratchpad for loop {} — {} after {iterations} iterations",
+            mode.code(loop_id),
+            mode.bold(reason)
+        )
+    }
+
+    /// Format a summary message sent when a loop terminates.
+    ///
+    /// `reason` is the `loop.terminate` reason string (e.g., `completed`).
+    /// Leads with an icon for the reason, followed by a stats block with
+    /// iterations, elapsed time, and cost. All interpolated values are
+    /// escaped for the bot's parse mode.
+    pub fn format_termination(
+        &self,
+        loop_id: &str,
+        reason: &str,
+        iterations: u32,
+        elapsed: Duration,
+        cost: f64,
+    ) -> String {
+        let mode = self.parse_mode;
+        let stats = format!(
+            "Iterations: {iterations}\nElapsed:    {}\nCost:       ${cost:.4}",
+            format_elapsed(elapsed)
+        );
+        format!(
+            "{} Loop {} finished: {}\n\n{}",
+            termination_icon(reason),
+            mode.code(loop_id),
+            mode.bold(reason),
+            mode.pre(&stats)
+        )
+    }
+}
+
+/// Icon shown at the start of a termination message, one per reason.
+fn termination_icon(reason: &str) -> &'static str {
+    match reason {
+        "completed" => "✅",
+        "max_iterations" => "🔁",
+        "max_runtime" => "⏱️",
+        "max_cost" => "💸",
+        "consecutive_failures" => "❌",
+        "loop_thrashing" => "🌀",
+        "loop_stale" => "🧊",
+        "validation_failure" => "⚠️",
+        "hook_failed" => "🪝",
+        "stopped" => "⏹️",
+        "interrupted" => "🛑",
+        "restart_requested" => "🔄",
+        "cancelled" => "🚫",
+        "single_iteration" => "🔂",
+        _ => "🏁",
+    }
+}
+
+/// Formats a duration as `1h 2m 3s`, omitting leading zero units.
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes}m {seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
     }
 }
 
@@ -94,6 +466,32 @@ pub fn escape_html(text: &str) -> String {
         .replace('>', "&gt;")
 }
 
+/// Characters Telegram's MarkdownV2 reserves outside of code entities.
+const MARKDOWN_V2_RESERVED: &[char] = &[
+    '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!', '\\',
+];
+
+/// Escape special characters for Telegram's MarkdownV2 parse mode.
+///
+/// Every reserved character (`` _*[]()~`>#+-=|{}.! `` and `\`) is prefixed
+/// with a backslash.
+pub fn escape_markdown_v2(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_V2_RESERVED.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape text inside a MarkdownV2 code entity, where only `` ` `` and `\`
+/// are special.
+fn escape_markdown_v2_code(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('`', "\\`")
+}
+
 /// Convert Ralph-generated markdown to Telegram HTML.
 ///
 /// Handles the subset of markdown that Ralph produces:
@@ -105,8 +503,14 @@ pub fn escape_html(text: &str) -> String {
 ///
 /// Text that isn't markdown is HTML-escaped to prevent injection.
 /// This function is for Ralph-generated content; use [`escape_html`] for
-/// user-supplied text.
+/// user-supplied text. [`ParseMode::from_markdown`] renders the same subset
+/// as MarkdownV2.
 pub fn markdown_to_telegram_html(md: &str) -> String {
+    render_markdown(md, ParseMode::Html)
+}
+
+/// Render Ralph-generated markdown in the given parse mode.
+fn render_markdown(md: &str, mode: ParseMode) -> String {
     let mut result = String::with_capacity(md.len());
     let mut in_code_block = false;
     let mut code_block_content = String::new();
@@ -117,9 +521,7 @@ pub fn markdown_to_telegram_html(md: &str) -> String {
         if trimmed.starts_with("```") {
             if in_code_block {
                 // Closing code fence
-                result.push_str("<pre>");
-                result.push_str(&escape_html(&code_block_content));
-                result.push_str("</pre>");
+                result.push_str(&mode.pre(&code_block_content));
                 result.push('\n');
                 code_block_content.clear();
                 in_code_block = false;
@@ -143,9 +545,7 @@ pub fn markdown_to_telegram_html(md: &str) -> String {
             if !result.is_empty() {
                 result.push('\n');
             }
-            result.push_str("<b>");
-            result.push_str(&escape_html(header_text));
-            result.push_str("</b>");
+            result.push_str(&mode.bold(header_text));
             continue;
         }
 
@@ -155,7 +555,7 @@ pub fn markdown_to_telegram_html(md: &str) -> String {
                 result.push('\n');
             }
             result.push_str("• ");
-            result.push_str(&convert_inline(&escape_html(item_text)));
+            result.push_str(&convert_inline(item_text, mode));
             continue;
         }
 
@@ -163,14 +563,12 @@ pub fn markdown_to_telegram_html(md: &str) -> String {
         if !result.is_empty() {
             result.push('\n');
         }
-        result.push_str(&convert_inline(&escape_html(line)));
+        result.push_str(&convert_inline(line, mode));
     }
 
     // Handle unclosed code block
     if in_code_block && !code_block_content.is_empty() {
-        result.push_str("<pre>");
-        result.push_str(&escape_html(&code_block_content));
-        result.push_str("</pre>");
+        result.push_str(&mode.pre(&code_block_content));
     }
 
     result
@@ -204,15 +602,15 @@ fn strip_list_item(line: &str) -> Option<&str> {
     }
 }
 
-/// Convert inline markdown (bold and inline code) within already-escaped HTML text.
+/// Convert inline markdown (bold and inline code) in raw text.
 ///
-/// Processes `**bold**` → `<b>bold</b>` and `` `code` `` → `<code>code</code>`.
-/// Since input is already HTML-escaped, bold delimiters (`**`) and backticks
-/// appear literally and won't conflict with HTML entities.
-fn convert_inline(escaped: &str) -> String {
-    let mut out = String::with_capacity(escaped.len());
-    let chars: Vec<char> = escaped.chars().collect();
+/// Processes `**bold**` and `` `code` `` into the mode's entities and
+/// escapes everything else, including the contents of each entity.
+fn convert_inline(text: &str, mode: ParseMode) -> String {
+    let mut out = String::with_capacity(text.len());
+    let chars: Vec<char> = text.chars().collect();
     let len = chars.len();
+    let mut plain = String::new();
     let mut i = 0;
 
     while i < len {
@@ -220,11 +618,8 @@ fn convert_inline(escaped: &str) -> String {
         if chars[i] == '`'
             && let Some(end) = find_closing_backtick(&chars, i + 1)
         {
-            out.push_str("<code>");
-            for c in &chars[i + 1..end] {
-                out.push(*c);
-            }
-            out.push_str("</code>");
+            out.push_str(&mode.escape(&std::mem::take(&mut plain)));
+            out.push_str(&mode.code(&chars[i + 1..end].iter().collect::<String>()));
             i = end + 1;
             continue;
         }
@@ -235,19 +630,17 @@ fn convert_inline(escaped: &str) -> String {
             && chars[i + 1] == '*'
             && let Some(end) = find_closing_double_star(&chars, i + 2)
         {
-            out.push_str("<b>");
-            for c in &chars[i + 2..end] {
-                out.push(*c);
-            }
-            out.push_str("</b>");
+            out.push_str(&mode.escape(&std::mem::take(&mut plain)));
+            out.push_str(&mode.bold(&chars[i + 2..end].iter().collect::<String>()));
             i = end + 2;
             continue;
         }
 
-        out.push(chars[i]);
+        plain.push(chars[i]);
         i += 1;
     }
 
+    out.push_str(&mode.escape(&plain));
     out
 }
 
@@ -271,22 +664,27 @@ fn find_closing_double_star(chars: &[char], start: usize) -> Option<usize> {
 
 #[async_trait]
 impl BotApi for TelegramBot {
+    fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+
     async fn send_message(&self, chat_id: i64, text: &str) -> TelegramResult<i32> {
         use teloxide::payloads::SendMessageSetters;
         use teloxide::prelude::*;
-        use teloxide::types::ParseMode;
-
-        let result = self
-            .bot
-            .send_message(teloxide::types::ChatId(chat_id), text)
-            .parse_mode(ParseMode::Html)
-            .await
-            .map_err(|e| TelegramError::Send {
-                attempts: 1,
-                reason: e.to_string(),
-            })?;
 
-        Ok(result.id.0)
+        self.send_with_retry(chat_id, || {
+            let request = self
+                .bot
+                .send_message(teloxide::types::ChatId(chat_id), text)
+                .parse_mode(self.parse_mode.into());
+            async move {
+                request
+                    .await
+                    .map(|msg| msg.id.0)
+                    .map_err(|e| SendFailure::from_request_error(&e))
+            }
+        })
+        .await
     }
 
     async fn send_document(
@@ -297,23 +695,26 @@ impl BotApi for TelegramBot {
     ) -> TelegramResult<i32> {
         use teloxide::payloads::SendDocumentSetters;
         use teloxide::prelude::*;
-        use teloxide::types::{InputFile, ParseMode};
+        use teloxide::types::InputFile;
 
-        let input_file = InputFile::file(file_path);
-        let mut request = self
-            .bot
-            .send_document(teloxide::types::ChatId(chat_id), input_file);
+        self.send_with_retry(chat_id, || {
+            let input_file = InputFile::file(file_path);
+            let mut request = self
+                .bot
+                .send_document(teloxide::types::ChatId(chat_id), input_file);
 
-        if let Some(cap) = caption {
-            request = request.caption(cap).parse_mode(ParseMode::Html);
-        }
-
-        let result = request.await.map_err(|e| TelegramError::Send {
-            attempts: 1,
-            reason: e.to_string(),
-        })?;
+            if let Some(cap) = caption {
+                request = request.caption(cap).parse_mode(self.parse_mode.into());
+            }
 
-        Ok(result.id.0)
+            async move {
+                request
+                    .await
+                    .map(|msg| msg.id.0)
+                    .map_err(|e| SendFailure::from_request_error(&e))
+            }
+        })
+        .await
     }
 
     async fn send_photo(
@@ -324,40 +725,43 @@ impl BotApi for TelegramBot {
     ) -> TelegramResult<i32> {
         use teloxide::payloads::SendPhotoSetters;
         use teloxide::prelude::*;
-        use teloxide::types::{InputFile, ParseMode};
+        use teloxide::types::InputFile;
 
-        let input_file = InputFile::file(file_path);
-        let mut request = self
-            .bot
-            .send_photo(teloxide::types::ChatId(chat_id), input_file);
+        self.send_with_retry(chat_id, || {
+            let input_file = InputFile::file(file_path);
+            let mut request = self
+                .bot
+                .send_photo(teloxide::types::ChatId(chat_id), input_file);
 
-        if let Some(cap) = caption {
-            request = request.caption(cap).parse_mode(ParseMode::Html);
-        }
-
-        let result = request.await.map_err(|e| TelegramError::Send {
-            attempts: 1,
-            reason: e.to_string(),
-        })?;
+            if let Some(cap) = caption {
+                request = request.caption(cap).parse_mode(self.parse_mode.into());
+            }
 
-        Ok(result.id.0)
+            async move {
+                request
+                    .await
+                    .map(|msg| msg.id.0)
+                    .map_err(|e| SendFailure::from_request_error(&e))
+            }
+        })
+        .await
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod mock {
     use super::*;
     use std::sync::{Arc, Mutex};
 
     /// A mock BotApi for testing that records sent messages.
-    struct MockBot {
+    pub(crate) struct MockBot {
         sent: Arc<Mutex<Vec<(i64, String)>>>,
         next_id: Arc<Mutex<i32>>,
         should_fail: bool,
     }
 
     impl MockBot {
-        fn new() -> Self {
+        pub(crate) fn new() -> Self {
             Self {
                 sent: Arc::new(Mutex::new(Vec::new())),
                 next_id: Arc::new(Mutex::new(1)),
@@ -365,7 +769,7 @@ mod tests {
             }
         }
 
-        fn failing() -> Self {
+        pub(crate) fn failing() -> Self {
             Self {
                 sent: Arc::new(Mutex::new(Vec::new())),
                 next_id: Arc::new(Mutex::new(1)),
@@ -373,7 +777,7 @@ mod tests {
             }
         }
 
-        fn sent_messages(&self) -> Vec<(i64, String)> {
+        pub(crate) fn sent_messages(&self) -> Vec<(i64, String)> {
             self.sent.lock().unwrap().clone()
         }
     }
@@ -442,10 +846,24 @@ mod tests {
             Ok(current)
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockBot;
+    use super::*;
+
+    fn html_bot() -> TelegramBot {
+        TelegramBot::new("123:fake")
+    }
+
+    fn markdown_bot() -> TelegramBot {
+        TelegramBot::new("123:fake").with_parse_mode(ParseMode::MarkdownV2)
+    }
 
     #[test]
     fn format_question_includes_hat_and_loop() {
-        let msg = TelegramBot::format_question("Builder", 3, "main", "Which DB should I use?");
+        let msg = html_bot().format_question("Builder", 3, "main", "Which DB should I use?");
         assert!(msg.contains("<b>Builder</b>"));
         assert!(msg.contains("iteration 3"));
         assert!(msg.contains("<code>main</code>"));
@@ -454,14 +872,14 @@ mod tests {
 
     #[test]
     fn format_question_escapes_html_in_content() {
-        let msg = TelegramBot::format_question("Hat", 1, "loop-1", "Use <b>this</b> & that?");
+        let msg = html_bot().format_question("Hat", 1, "loop-1", "Use <b>this</b> & that?");
         assert!(msg.contains("&lt;b&gt;this&lt;/b&gt;"));
         assert!(msg.contains("&amp; that?"));
     }
 
     #[test]
     fn format_question_renders_markdown() {
-        let msg = TelegramBot::format_question(
+        let msg = html_bot().format_question(
             "Builder",
             5,
             "main",
@@ -473,18 +891,197 @@ mod tests {
 
     #[test]
     fn format_greeting_includes_loop_id() {
-        let msg = TelegramBot::format_greeting("feature-auth");
+        let msg = html_bot().format_greeting("feature-auth");
         assert!(msg.contains("<code>feature-auth</code>"));
         assert!(msg.contains("online"));
     }
 
     #[test]
     fn format_farewell_includes_loop_id() {
-        let msg = TelegramBot::format_farewell("main");
+        let msg = html_bot().format_farewell("main");
         assert!(msg.contains("<code>main</code>"));
         assert!(msg.contains("shutting down"));
     }
 
+    const ALL_REASONS: [&str; 14] = [
+        "completed",
+        "max_iterations",
+        "max_runtime",
+        "max_cost",
+        "consecutive_failures",
+        "loop_thrashing",
+        "loop_stale",
+        "validation_failure",
+        "hook_failed",
+        "stopped",
+        "interrupted",
+        "restart_requested",
+        "cancelled",
+        "single_iteration",
+    ];
+
+    #[test]
+    fn format_termination_uses_distinct_icon_per_reason() {
+        let icons: std::collections::HashSet<&str> = ALL_REASONS
+            .into_iter()
+            .map(|reason| {
+                let msg =
+                    html_bot().format_termination("main", reason, 1, Duration::from_secs(1), 0.0);
+                let icon = termination_icon(reason);
+                assert!(msg.starts_with(icon), "{reason}: {msg}");
+                icon
+            })
+            .collect();
+        assert_eq!(icons.len(), ALL_REASONS.len());
+        assert!(!icons.contains(termination_icon("something_new")));
+    }
+
+    #[test]
+    fn format_termination_includes_loop_id_reason_and_stats() {
+        for reason in ALL_REASONS {
+            let msg = html_bot().format_termination(
+                "loop-1234",
+                reason,
+                7,
+                Duration::from_secs(3725),
+                1.5,
+            );
+            assert!(msg.contains("<code>loop-1234</code>"), "{msg}");
+            assert!(msg.contains(&format!("<b>{reason}</b>")), "{msg}");
+            assert!(msg.contains("Iterations: 7"), "{msg}");
+            assert!(msg.contains("Elapsed:    1h 2m 5s"), "{msg}");
+            assert!(msg.contains("Cost:       $1.5000"), "{msg}");
+        }
+    }
+
+    #[test]
+    fn format_termination_escapes_loop_id() {
+        let msg = html_bot().format_termination("<b>&evil</b>", "stopped", 0, Duration::ZERO, 0.0);
+        assert!(msg.contains("<code>&lt;b&gt;&amp;evil&lt;/b&gt;</code>"));
+        assert!(!msg.contains("<b>&evil"));
+    }
+
+    #[test]
+    fn format_scratchpad_caption_summarizes_run() {
+        let caption = html_bot().format_scratchpad_caption("<loop>", "completed", 4);
+        assert!(caption.contains("<code>&lt;loop&gt;</code>"));
+        assert!(caption.contains("<b>completed</b> after 4 iterations"));
+    }
+
+    #[tokio::test]
+    async fn send_scratchpad_sends_document_with_caption() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let scratchpad = temp_dir.path().join("scratchpad.md");
+        std::fs::write(&scratchpad, "# Notes").unwrap();
+        let bot = MockBot::new();
+
+        bot.send_scratchpad(123, &scratchpad, "📝 caption")
+            .await
+            .unwrap();
+
+        let sent = bot.sent_messages();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(
+            sent[0],
+            (123, format!("[doc:{}] 📝 caption", scratchpad.display()))
+        );
+    }
+
+    #[tokio::test]
+    async fn send_scratchpad_falls_back_to_text_when_missing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let scratchpad = temp_dir.path().join("missing.md");
+        let bot = MockBot::new();
+
+        bot.send_scratchpad(123, &scratchpad, "📝 caption")
+            .await
+            .unwrap();
+
+        let sent = bot.sent_messages();
+        assert_eq!(sent.len(), 1);
+        assert!(!sent[0].1.starts_with("[doc:"), "{}", sent[0].1);
+        assert!(sent[0].1.starts_with("📝 caption"));
+        assert!(sent[0].1.contains("No scratchpad found at <code>"));
+    }
+
+    #[test]
+    fn escape_markdown_v2_escapes_every_reserved_char() {
+        for c in "_*[]()~`>#+-=|{}.!\\".chars() {
+            assert_eq!(escape_markdown_v2(&c.to_string()), format!("\\{c}"));
+        }
+        assert_eq!(
+            escape_markdown_v2(r"a_b*c[d]e(f)g~h`i>j#k+l-m=n|o{p}q.r!s\t"),
+            r"a\_b\*c\[d\]e\(f\)g\~h\`i\>j\#k\+l\-m\=n\|o\{p\}q\.r\!s\\t"
+        );
+        assert_eq!(escape_markdown_v2("plain text 123"), "plain text 123");
+        assert_eq!(escape_markdown_v2("a & b < c"), "a & b < c");
+        assert_eq!(escape_markdown_v2(""), "");
+    }
+
+    #[test]
+    fn format_question_markdown_v2() {
+        let msg = markdown_bot().format_question(
+            "Build_er",
+            3,
+            "loop-1",
+            "Use **async** or `sync_fn()`? Costs $1.50!",
+        );
+        assert_eq!(
+            msg,
+            "❓ *Build\\_er* \\(iteration 3, loop `loop-1`\\)\n\n\
+             Use *async* or `sync_fn()`? Costs $1\\.50\\!"
+        );
+    }
+
+    #[test]
+    fn format_question_markdown_v2_escapes_every_unformatted_reserved_char() {
+        let msg = markdown_bot().format_question("Hat", 1, "main", "Why (a.b) - [c]!");
+        let body = msg.split_once("\n\n").unwrap().1;
+        assert_eq!(body, r"Why \(a\.b\) \- \[c\]\!");
+    }
+
+    #[test]
+    fn markdown_v2_code_escapes_only_backtick_and_backslash() {
+        let mode = ParseMode::MarkdownV2;
+        assert_eq!(mode.code(r"a.b`c\d"), r"`a.b\`c\\d`");
+        assert_eq!(mode.pre("x = 1;"), "```\nx = 1;\n```");
+        assert_eq!(mode.italic("fix a.b"), r"_fix a\.b_");
+        assert_eq!(ParseMode::Html.italic("a < b"), "<i>a &lt; b</i>");
+        assert_eq!(
+            mode.from_markdown("# Plan\n- step **one**\n```\nlet x = 1.0;\n```"),
+            "*Plan*\n• step *one*```\nlet x = 1.0;\n```\n"
+        );
+    }
+
+    #[test]
+    fn markdown_v2_formats_termination_and_captions() {
+        let bot = markdown_bot();
+        let msg = bot.format_termination("feature-x", "max_cost", 7, Duration::from_secs(65), 1.5);
+        assert_eq!(
+            msg,
+            "💸 Loop `feature-x` finished: *max\\_cost*\n\n\
+             ```\nIterations: 7\nElapsed:    1m 5s\nCost:       $1.5000\n```"
+        );
+        assert_eq!(
+            bot.format_greeting("main"),
+            "🤖 Ralph bot online — monitoring loop `main`"
+        );
+        assert_eq!(
+            bot.format_scratchpad_caption("main", "completed", 2),
+            "📝 Scratchpad for loop `main` — *completed* after 2 iterations"
+        );
+    }
+
+    #[test]
+    fn parse_mode_maps_to_teloxide() {
+        assert_eq!(html_bot().parse_mode(), ParseMode::Html);
+        assert_eq!(markdown_bot().parse_mode(), ParseMode::MarkdownV2);
+        assert!(matches!(
+            teloxide::types::ParseMode::from(ParseMode::MarkdownV2),
+            teloxide::types::ParseMode::MarkdownV2
+        ));
+    }
+
     #[test]
     fn escape_html_handles_special_chars() {
         assert_eq!(
@@ -600,6 +1197,173 @@ mod tests {
         assert_eq!(result, "• <b>bold</b> item\n• <code>code</code> item");
     }
 
+    /// A transport that fails `failures` times with `retry`, then returns
+    /// message ID 42.
+    fn failing_transport(
+        failures: u32,
+        retry: super::Retry,
+        calls: &std::sync::atomic::AtomicU32,
+    ) -> impl FnMut() -> std::future::Ready<Result<i32, super::SendFailure>> + '_ {
+        move || {
+            let call = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            std::future::ready(if call <= failures {
+                Err(super::SendFailure {
+                    reason: format!("network down (call {call})"),
+                    retry,
+                })
+            } else {
+                Ok(42)
+            })
+        }
+    }
+
+    /// A transport that fails `failures` times with a network error.
+    fn flaky_transport(
+        failures: u32,
+        calls: &std::sync::atomic::AtomicU32,
+    ) -> impl FnMut() -> std::future::Ready<Result<i32, super::SendFailure>> + '_ {
+        failing_transport(failures, super::Retry::Backoff, calls)
+    }
+
+    #[test]
+    fn with_retry_clamps_attempts_to_at_least_one() {
+        let bot = TelegramBot::with_retry("123:fake", 0, Duration::ZERO);
+        assert_eq!(bot.max_attempts, 1);
+
+        let bot = TelegramBot::new("123:fake");
+        assert_eq!(bot.max_attempts, DEFAULT_SEND_ATTEMPTS);
+
+        assert_eq!(bot.base_delay, DEFAULT_SEND_BASE_DELAY);
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        let bot = TelegramBot::with_retry("123:fake", u32::MAX, Duration::from_secs(1));
+
+        assert_eq!(bot.retry_delay(1), Duration::from_secs(1));
+        assert_eq!(bot.retry_delay(3), Duration::from_secs(4));
+        assert_eq!(bot.retry_delay(7), MAX_SEND_RETRY_DELAY);
+        assert_eq!(bot.retry_delay(40), MAX_SEND_RETRY_DELAY);
+        assert_eq!(bot.retry_delay(u32::MAX), MAX_SEND_RETRY_DELAY);
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_succeeds_after_two_failures() {
+        let bot = TelegramBot::with_limits("123:fake", 3, Duration::ZERO, 0);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+
+        let id = bot
+            .send_with_retry(1, flaky_transport(2, &calls))
+            .await
+            .unwrap();
+
+        assert_eq!(id, 42);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_reports_attempts_when_exhausted() {
+        let bot = TelegramBot::with_limits("123:fake", 3, Duration::ZERO, 0);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+
+        let err = bot
+            .send_with_retry(1, flaky_transport(5, &calls))
+            .await
+            .unwrap_err();
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+        match err {
+            TelegramError::Send { attempts, reason } => {
+                assert_eq!(attempts, 3);
+                assert_eq!(reason, "network down (call 3)");
+            }
+            other => panic!("expected Send error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_backs_off_exponentially() {
+        let bot = TelegramBot::with_limits("123:fake", 3, Duration::from_millis(10), 0);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let start = tokio::time::Instant::now();
+
+        let id = bot
+            .send_with_retry(1, flaky_transport(2, &calls))
+            .await
+            .unwrap();
+
+        assert_eq!(id, 42);
+        // 10ms after the first failure, 20ms after the second.
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_gives_up_on_permanent_errors() {
+        let bot = TelegramBot::with_limits("123:fake", 3, Duration::ZERO, 0);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+
+        let err = bot
+            .send_with_retry(1, failing_transport(5, super::Retry::Never, &calls))
+            .await
+            .unwrap_err();
+
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(matches!(err, TelegramError::Send { attempts: 1, .. }));
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_waits_for_retry_after() {
+        let bot = TelegramBot::with_limits("123:fake", 3, Duration::ZERO, 0);
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let retry = super::Retry::After(Duration::from_millis(50));
+        let start = tokio::time::Instant::now();
+
+        let id = bot
+            .send_with_retry(1, failing_transport(1, retry, &calls))
+            .await
+            .unwrap();
+
+        assert_eq!(id, 42);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn send_failure_retries_only_transient_errors() {
+        use super::{Retry, SendFailure};
+        use teloxide::types::Seconds;
+        use teloxide::{ApiError, RequestError};
+
+        let classify = |error: RequestError| SendFailure::from_request_error(&error).retry;
+
+        assert_eq!(
+            classify(RequestError::RetryAfter(Seconds::from_seconds(7))),
+            Retry::After(Duration::from_secs(7))
+        );
+        assert_eq!(
+            classify(RequestError::Api(ApiError::Unknown(
+                "Internal Server Error".to_string()
+            ))),
+            Retry::Backoff
+        );
+        assert_eq!(
+            classify(RequestError::InvalidJson {
+                source: serde_json::from_str::<i32>("<html>").unwrap_err(),
+                raw: "<html>502 Bad Gateway</html>".into(),
+            }),
+            Retry::Backoff
+        );
+        assert_eq!(
+            classify(RequestError::Api(ApiError::CantParseEntities(
+                "Bad Request: can't parse entities".to_string()
+            ))),
+            Retry::Never
+        );
+        assert_eq!(
+            classify(RequestError::Api(ApiError::BotBlocked)),
+            Retry::Never
+        );
+    }
+
     #[tokio::test]
     async fn mock_bot_send_message_succeeds() {
         let bot = MockBot::new();
@@ -1,28 +1,82 @@
 use std::path::Path;
 
-use crate::bot::escape_html;
+use crate::bot::{BotApi, ParseMode};
+use crate::error::TelegramResult;
 use crate::loop_lock::{LockState, lock_path, lock_state};
 
+/// Signal file that holds the loop at its next iteration boundary while present.
+const PAUSE_REQUESTED_FILE: &str = ".ralph/pause-requested";
+
+/// A chat command that controls the running loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlCommand {
+    /// `/status` — report on the loop without changing it.
+    Status,
+    /// `/pause` — hold the loop at the next iteration boundary.
+    Pause,
+    /// `/resume` — let a paused loop continue.
+    Resume,
+    /// `/stop` — stop the loop at the next iteration boundary.
+    Stop,
+}
+
+/// Parse a chat message into a [`ControlCommand`].
+///
+/// Accepts the `@bot` suffix Telegram adds in group chats. Returns `None`
+/// for plain text and for other commands.
+pub fn parse_control_command(text: &str) -> Option<ControlCommand> {
+    let (command, _args) = parse_command(text.trim());
+    match command {
+        "/status" => Some(ControlCommand::Status),
+        "/pause" => Some(ControlCommand::Pause),
+        "/resume" => Some(ControlCommand::Resume),
+        "/stop" => Some(ControlCommand::Stop),
+        _ => None,
+    }
+}
+
 /// Check if a message is a bot command (starts with `/`).
 pub fn is_command(text: &str) -> bool {
     text.starts_with('/')
 }
 
-/// Parse and execute a bot command, returning the response message.
+/// Handle a bot command and send the response to `chat_id`.
+///
+/// Returns `None` if `text` isn't a recognized command, so the caller can
+/// route it as a regular message.
+pub async fn respond_to_command(
+    bot: &dyn BotApi,
+    chat_id: i64,
+    text: &str,
+    workspace_root: &Path,
+) -> Option<TelegramResult<i32>> {
+    let response = handle_command(text, workspace_root, bot.parse_mode())?;
+    Some(bot.send_message(chat_id, &response).await)
+}
+
+/// Parse and execute a bot command, returning the response message
+/// formatted for `mode`.
 ///
 /// Returns `Some(response)` if the text was a recognized command,
 /// or `None` if the command was not recognized (so the caller can
 /// treat it as a regular message).
-pub fn handle_command(text: &str, workspace_root: &Path) -> Option<String> {
+pub fn handle_command(text: &str, workspace_root: &Path, mode: ParseMode) -> Option<String> {
+    if let Some(control) = parse_control_command(text) {
+        return Some(match control {
+            ControlCommand::Status => cmd_status(workspace_root, mode),
+            ControlCommand::Pause => cmd_pause(workspace_root, mode),
+            ControlCommand::Resume => cmd_resume(workspace_root, mode),
+            ControlCommand::Stop => cmd_stop(workspace_root, mode),
+        });
+    }
+
     let (command, _args) = parse_command(text);
     match command {
-        "/help" => Some(cmd_help()),
-        "/status" => Some(cmd_status(workspace_root)),
-        "/tasks" => Some(cmd_tasks(workspace_root)),
-        "/memories" => Some(cmd_memories(workspace_root)),
-        "/tail" => Some(cmd_tail(workspace_root)),
-        "/restart" => Some(cmd_restart(workspace_root)),
-        "/stop" => Some(cmd_stop(workspace_root)),
+        "/help" => Some(cmd_help(mode)),
+        "/tasks" => Some(cmd_tasks(workspace_root, mode)),
+        "/memories" => Some(cmd_memories(workspace_root, mode)),
+        "/tail" => Some(cmd_tail(workspace_root, mode)),
+        "/restart" => Some(cmd_restart(workspace_root, mode)),
         _ => None,
     }
 }
@@ -50,51 +104,52 @@ fn truncate_with_ellipsis(input: &str, max_chars: usize) -> String {
 }
 
 /// `/help` — List available commands.
-fn cmd_help() -> String {
-    [
-        "<b>Ralph Bot Commands</b>",
-        "",
-        "/status — Current loop status",
-        "/tasks — Open tasks",
-        "/memories — Recent memories",
-        "/tail — Last 20 events",
-        "/restart — Restart the orchestration loop",
-        "/stop — Stop the orchestration loop",
-        "/help — This message",
-    ]
-    .join("\n")
+fn cmd_help(mode: ParseMode) -> String {
+    let mut lines = vec![mode.bold("Ralph Bot Commands"), String::new()];
+    lines.extend(
+        [
+            "/status — Current loop status",
+            "/tasks — Open tasks",
+            "/memories — Recent memories",
+            "/tail — Last 20 events",
+            "/pause — Pause the loop at the next iteration boundary",
+            "/resume — Resume a paused loop",
+            "/restart — Restart the orchestration loop",
+            "/stop — Stop the orchestration loop",
+            "/help — This message",
+        ]
+        .map(|line| mode.escape(line)),
+    );
+    lines.join("\n")
 }
 
 /// `/status` — Current iteration, hat, elapsed time, loop ID.
-fn cmd_status(workspace_root: &Path) -> String {
+fn cmd_status(workspace_root: &Path, mode: ParseMode) -> String {
     let state = match lock_state(workspace_root) {
         Ok(state) => state,
         Err(e) => {
-            return format!(
-                "Failed to check lock state: {}",
-                escape_html(&e.to_string())
-            );
+            return mode.escape(&format!("Failed to check lock state: {e}"));
         }
     };
 
     if state == LockState::Inactive {
-        return "No active loop (no lock file found).".to_string();
+        return mode.escape("No active loop (no lock file found).");
     }
 
     if state == LockState::Stale {
-        return "No active loop (stale lock file found).".to_string();
+        return mode.escape("No active loop (stale lock file found).");
     }
 
     let lock_path = lock_path(workspace_root);
     let lock_content = match std::fs::read_to_string(&lock_path) {
         Ok(c) => c,
-        Err(e) => return format!("Failed to read lock file: {}", escape_html(&e.to_string())),
+        Err(e) => return mode.escape(&format!("Failed to read lock file: {e}")),
     };
 
     let lock: serde_json::Value = match serde_json::from_str(&lock_content) {
         Ok(v) => v,
         Err(e) => {
-            return format!("Failed to parse lock file: {}", escape_html(&e.to_string()));
+            return mode.escape(&format!("Failed to parse lock file: {e}"));
         }
     };
 
@@ -134,16 +189,24 @@ fn cmd_status(workspace_root: &Path) -> String {
         .unwrap_or_else(|| "none".to_string());
 
     let mut lines = vec![
-        "<b>Loop Status</b>".to_string(),
+        mode.bold("Loop Status"),
         String::new(),
-        format!("PID: <code>{}</code>", pid),
-        format!("Elapsed: <code>{}</code>", elapsed_str),
-        format!("Iterations: <code>{}</code>", iteration_count),
-        format!("Started: <code>{}</code>", escape_html(started)),
+        format!("PID: {}", mode.code(&pid.to_string())),
+        format!("Elapsed: {}", mode.code(&elapsed_str)),
+        format!("Iterations: {}", mode.code(&iteration_count.to_string())),
+        format!("Started: {}", mode.code(started)),
     ];
 
+    if workspace_root.join(PAUSE_REQUESTED_FILE).exists() {
+        lines.push(format!(
+            "State: {} {}",
+            mode.bold("paused"),
+            mode.escape("(send /resume to continue)")
+        ));
+    }
+
     lines.push(String::new());
-    lines.push(format!("Prompt: {}", escape_html(&prompt_preview)));
+    lines.push(format!("Prompt: {}", mode.escape(&prompt_preview)));
 
     lines.join("\n")
 }
@@ -186,16 +249,16 @@ fn count_iterations(workspace_root: &Path) -> usize {
 }
 
 /// `/tasks` — List open tasks from `.ralph/agent/tasks.jsonl`.
-fn cmd_tasks(workspace_root: &Path) -> String {
+fn cmd_tasks(workspace_root: &Path, mode: ParseMode) -> String {
     let tasks_path = workspace_root.join(".ralph/agent/tasks.jsonl");
 
     if !tasks_path.exists() {
-        return "No tasks file found.".to_string();
+        return mode.escape("No tasks file found.");
     }
 
     let content = match std::fs::read_to_string(&tasks_path) {
         Ok(c) => c,
-        Err(e) => return format!("Failed to read tasks: {}", escape_html(&e.to_string())),
+        Err(e) => return mode.escape(&format!("Failed to read tasks: {e}")),
     };
 
     let mut open_tasks: Vec<(String, String, u64)> = Vec::new(); // (id, title, priority)
@@ -230,13 +293,17 @@ fn cmd_tasks(workspace_root: &Path) -> String {
     open_tasks.sort_by_key(|t| t.2);
 
     if open_tasks.is_empty() {
-        return format!("No open tasks. ({} completed)", closed_count);
+        return mode.escape(&format!("No open tasks. ({} completed)", closed_count));
     }
 
     let mut lines = vec![format!(
-        "<b>Open Tasks</b> ({} open, {} closed)",
-        open_tasks.len(),
-        closed_count
+        "{} {}",
+        mode.bold("Open Tasks"),
+        mode.escape(&format!(
+            "({} open, {} closed)",
+            open_tasks.len(),
+            closed_count
+        ))
     )];
     lines.push(String::new());
 
@@ -247,10 +314,10 @@ fn cmd_tasks(workspace_root: &Path) -> String {
             _ => "P3",
         };
         lines.push(format!(
-            "{}  <code>{}</code>\n    {}",
+            "{}  {}\n    {}",
             priority_label,
-            escape_html(id),
-            escape_html(title)
+            mode.code(id),
+            mode.escape(title)
         ));
     }
 
@@ -258,16 +325,16 @@ fn cmd_tasks(workspace_root: &Path) -> String {
 }
 
 /// `/memories` — Last N memories from `.ralph/agent/memories.md`.
-fn cmd_memories(workspace_root: &Path) -> String {
+fn cmd_memories(workspace_root: &Path, mode: ParseMode) -> String {
     let memories_path = workspace_root.join(".ralph/agent/memories.md");
 
     if !memories_path.exists() {
-        return "No memories file found.".to_string();
+        return mode.escape("No memories file found.");
     }
 
     let content = match std::fs::read_to_string(&memories_path) {
         Ok(c) => c,
-        Err(e) => return format!("Failed to read memories: {}", escape_html(&e.to_string())),
+        Err(e) => return mode.escape(&format!("Failed to read memories: {e}")),
     };
 
     // Extract memory blocks (### mem-... sections)
@@ -296,7 +363,7 @@ fn cmd_memories(workspace_root: &Path) -> String {
     }
 
     if memories.is_empty() {
-        return "No memories found.".to_string();
+        return mode.escape("No memories found.");
     }
 
     // Show last 5 memories
@@ -305,9 +372,9 @@ fn cmd_memories(workspace_root: &Path) -> String {
     let shown = &memories[start..];
 
     let mut lines = vec![format!(
-        "<b>Recent Memories</b> (showing {}/{})",
-        shown.len(),
-        memories.len()
+        "{} {}",
+        mode.bold("Recent Memories"),
+        mode.escape(&format!("(showing {}/{})", shown.len(), memories.len()))
     )];
     lines.push(String::new());
 
@@ -325,11 +392,7 @@ fn cmd_memories(workspace_root: &Path) -> String {
 
         let preview = truncate_with_ellipsis(&preview, 120);
 
-        lines.push(format!(
-            "<code>{}</code>\n  {}",
-            escape_html(id),
-            escape_html(&preview)
-        ));
+        lines.push(format!("{}\n  {}", mode.code(id), mode.escape(&preview)));
     }
 
     lines.join("\n")
@@ -340,31 +403,72 @@ fn cmd_memories(workspace_root: &Path) -> String {
 /// Writes a signal file (`.ralph/restart-requested`) that the event loop
 /// checks at each iteration boundary. When detected, the loop terminates
 /// and the process exec-replaces itself with the same CLI arguments.
-fn cmd_restart(workspace_root: &Path) -> String {
+fn cmd_restart(workspace_root: &Path, mode: ParseMode) -> String {
     let restart_path = workspace_root.join(".ralph/restart-requested");
 
     // Check if a loop is actually running
     let state = match lock_state(workspace_root) {
         Ok(state) => state,
         Err(e) => {
-            return format!(
-                "Failed to check lock state: {}",
-                escape_html(&e.to_string())
-            );
+            return mode.escape(&format!("Failed to check lock state: {e}"));
         }
     };
     if state != LockState::Active {
-        return "No active loop to restart.".to_string();
+        return mode.escape("No active loop to restart.");
     }
 
     match std::fs::write(&restart_path, "") {
         Ok(()) => {
-            "Restart requested. The loop will restart at the next iteration boundary.".to_string()
+            let _ = clear_pause(workspace_root);
+            mode.escape("Restart requested. The loop will restart at the next iteration boundary.")
         }
-        Err(e) => format!(
-            "Failed to write restart signal: {}",
-            escape_html(&e.to_string())
-        ),
+        Err(e) => mode.escape(&format!("Failed to write restart signal: {e}")),
+    }
+}
+
+/// `/pause` — Hold the loop at its next iteration boundary.
+///
+/// Writes a signal file (`.ralph/pause-requested`). The loop runner waits
+/// between iterations while it exists; `/resume`, `/stop`, and `/restart`
+/// remove it.
+fn cmd_pause(workspace_root: &Path, mode: ParseMode) -> String {
+    let state = match lock_state(workspace_root) {
+        Ok(state) => state,
+        Err(e) => {
+            return mode.escape(&format!("Failed to check lock state: {e}"));
+        }
+    };
+    if state != LockState::Active {
+        return mode.escape("No active loop to pause.");
+    }
+
+    let pause_path = workspace_root.join(PAUSE_REQUESTED_FILE);
+    if pause_path.exists() {
+        return mode.escape("Loop is already paused. Send /resume to continue.");
+    }
+    match std::fs::write(&pause_path, "") {
+        Ok(()) => {
+            mode.escape("Pause requested. The loop will pause at the next iteration boundary; send /resume to continue.")
+        }
+        Err(e) => mode.escape(&format!("Failed to write pause signal: {e}")),
+    }
+}
+
+/// `/resume` — Let a paused loop continue.
+fn cmd_resume(workspace_root: &Path, mode: ParseMode) -> String {
+    match clear_pause(workspace_root) {
+        Ok(true) => mode.escape("Resumed. The loop will continue with its next iteration."),
+        Ok(false) => mode.escape("Loop is not paused."),
+        Err(e) => mode.escape(&format!("Failed to remove pause signal: {e}")),
+    }
+}
+
+/// Removes the pause signal, returning whether the loop was paused.
+fn clear_pause(workspace_root: &Path) -> std::io::Result<bool> {
+    match std::fs::remove_file(workspace_root.join(PAUSE_REQUESTED_FILE)) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
     }
 }
 
@@ -372,45 +476,40 @@ fn cmd_restart(workspace_root: &Path) -> String {
 ///
 /// Writes a signal file (`.ralph/stop-requested`) that the event loop
 /// checks at each iteration boundary. When detected, the loop terminates
-/// gracefully with `TerminationReason::Stopped`.
-fn cmd_stop(workspace_root: &Path) -> String {
+/// gracefully with `TerminationReason::Stopped`. Also lifts a pause so a
+/// paused loop reaches that boundary.
+fn cmd_stop(workspace_root: &Path, mode: ParseMode) -> String {
     let stop_path = workspace_root.join(".ralph/stop-requested");
 
     // Check if a loop is actually running
     let state = match lock_state(workspace_root) {
         Ok(state) => state,
         Err(e) => {
-            return format!(
-                "Failed to check lock state: {}",
-                escape_html(&e.to_string())
-            );
+            return mode.escape(&format!("Failed to check lock state: {e}"));
         }
     };
     if state != LockState::Active {
-        return "No active loop to stop.".to_string();
+        return mode.escape("No active loop to stop.");
     }
 
     match std::fs::write(&stop_path, "") {
-        Ok(()) => "Stop requested. The loop will stop at the next iteration boundary.".to_string(),
-        Err(e) => format!(
-            "Failed to write stop signal: {}",
-            escape_html(&e.to_string())
-        ),
+        Ok(()) => {
+            let _ = clear_pause(workspace_root);
+            mode.escape("Stop requested. The loop will stop at the next iteration boundary.")
+        }
+        Err(e) => mode.escape(&format!("Failed to write stop signal: {e}")),
     }
 }
 
 /// `/tail` — Last 20 lines of the current events file.
-fn cmd_tail(workspace_root: &Path) -> String {
+fn cmd_tail(workspace_root: &Path, mode: ParseMode) -> String {
     // Find current events file
     let pointer_path = workspace_root.join(".ralph/current-events");
     let events_path = if pointer_path.exists() {
         match std::fs::read_to_string(&pointer_path) {
             Ok(p) => workspace_root.join(p.trim()),
             Err(e) => {
-                return format!(
-                    "Failed to read current-events pointer: {}",
-                    escape_html(&e.to_string())
-                );
+                return mode.escape(&format!("Failed to read current-events pointer: {e}"));
             }
         }
     } else {
@@ -418,18 +517,18 @@ fn cmd_tail(workspace_root: &Path) -> String {
     };
 
     if !events_path.exists() {
-        return "No events file found.".to_string();
+        return mode.escape("No events file found.");
     }
 
     let content = match std::fs::read_to_string(&events_path) {
         Ok(c) => c,
-        Err(e) => return format!("Failed to read events: {}", escape_html(&e.to_string())),
+        Err(e) => return mode.escape(&format!("Failed to read events: {e}")),
     };
 
     let all_lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
 
     if all_lines.is_empty() {
-        return "Events file is empty.".to_string();
+        return mode.escape("Events file is empty.");
     }
 
     let tail_count = 20;
@@ -437,9 +536,9 @@ fn cmd_tail(workspace_root: &Path) -> String {
     let tail = &all_lines[start..];
 
     let mut lines = vec![format!(
-        "<b>Last {} Events</b> ({} total)",
-        tail.len(),
-        all_lines.len()
+        "{} {}",
+        mode.bold(&format!("Last {} Events", tail.len())),
+        mode.escape(&format!("({} total)", all_lines.len()))
     )];
     lines.push(String::new());
 
@@ -463,20 +562,19 @@ fn cmd_tail(workspace_root: &Path) -> String {
             };
 
             lines.push(format!(
-                "<code>{}{}</code> {}{}",
-                escape_html(topic),
-                iteration,
-                hat_str,
+                "{} {}{}",
+                mode.code(&format!("{topic}{iteration}")),
+                mode.escape(&hat_str),
                 if payload_preview.is_empty() {
                     String::new()
                 } else {
-                    format!("\n  {}", escape_html(&payload_preview))
+                    format!("\n  {}", mode.escape(&payload_preview))
                 }
             ));
         } else {
             // Non-JSON line, show raw (truncated)
             let preview = truncate_with_ellipsis(event_line, 80);
-            lines.push(escape_html(&preview));
+            lines.push(mode.escape(&preview));
         }
     }
 
@@ -493,6 +591,112 @@ mod tests {
         std::fs::create_dir_all(dir.path().join(".ralph/agent")).unwrap();
     }
 
+    /// Holds `.ralph/loop.lock` so the workspace reports an active loop.
+    #[cfg(unix)]
+    fn hold_loop_lock(dir: &TempDir) -> nix::fcntl::Flock<std::fs::File> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .read(true)
+            .write(true)
+            .open(lock_path(dir.path()))
+            .unwrap();
+        nix::fcntl::Flock::lock(file, nix::fcntl::FlockArg::LockExclusiveNonblock)
+            .map_err(|(_, errno)| errno)
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_control_command_maps_known_commands() {
+        assert_eq!(parse_control_command("/pause"), Some(ControlCommand::Pause));
+        assert_eq!(
+            parse_control_command("/pause@ralph_bot"),
+            Some(ControlCommand::Pause)
+        );
+        assert_eq!(
+            parse_control_command("/resume"),
+            Some(ControlCommand::Resume)
+        );
+        assert_eq!(
+            parse_control_command("/status"),
+            Some(ControlCommand::Status)
+        );
+        assert_eq!(
+            parse_control_command(" /stop now "),
+            Some(ControlCommand::Stop)
+        );
+    }
+
+    #[test]
+    fn parse_control_command_ignores_unknown_messages() {
+        assert_eq!(parse_control_command("/frobnicate"), None);
+        assert_eq!(parse_control_command("/help"), None);
+        assert_eq!(parse_control_command("pause"), None);
+        assert_eq!(parse_control_command("please /pause"), None);
+        assert_eq!(parse_control_command(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pause_and_resume_toggle_signal_file() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        let _lock = hold_loop_lock(&dir);
+        let pause_path = dir.path().join(PAUSE_REQUESTED_FILE);
+
+        assert!(cmd_pause(dir.path(), ParseMode::Html).contains("Pause requested"));
+        assert!(pause_path.exists());
+        assert!(cmd_pause(dir.path(), ParseMode::Html).contains("already paused"));
+
+        assert!(cmd_resume(dir.path(), ParseMode::Html).contains("Resumed"));
+        assert!(!pause_path.exists());
+        assert!(cmd_resume(dir.path(), ParseMode::Html).contains("not paused"));
+    }
+
+    #[test]
+    fn cmd_pause_no_active_loop() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        assert!(cmd_pause(dir.path(), ParseMode::Html).contains("No active loop"));
+        assert!(!dir.path().join(PAUSE_REQUESTED_FILE).exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stop_command_via_bot_stops_event_loop() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        let _lock = hold_loop_lock(&dir);
+        std::fs::write(dir.path().join(PAUSE_REQUESTED_FILE), "").unwrap();
+
+        let bot = crate::bot::mock::MockBot::new();
+        let sent = respond_to_command(&bot, 42, "/stop", dir.path()).await;
+        assert!(sent.unwrap().is_ok());
+
+        let messages = bot.sent_messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].0, 42);
+        assert!(messages[0].1.contains("Stop requested"));
+        assert!(
+            !dir.path().join(PAUSE_REQUESTED_FILE).exists(),
+            "/stop should lift a pause"
+        );
+        assert!(dir.path().join(".ralph/stop-requested").exists());
+    }
+
+    #[tokio::test]
+    async fn respond_to_command_ignores_non_commands() {
+        let dir = TempDir::new().unwrap();
+        let bot = crate::bot::mock::MockBot::new();
+
+        assert!(
+            respond_to_command(&bot, 42, "/frobnicate", dir.path())
+                .await
+                .is_none()
+        );
+        assert!(bot.sent_messages().is_empty());
+    }
+
     #[test]
     fn is_command_recognizes_slash() {
         assert!(is_command("/help"));
@@ -521,18 +725,18 @@ mod tests {
     #[test]
     fn handle_command_returns_none_for_unknown() {
         let dir = TempDir::new().unwrap();
-        assert!(handle_command("/unknown", dir.path()).is_none());
+        assert!(handle_command("/unknown", dir.path(), ParseMode::Html).is_none());
     }
 
     #[test]
     fn handle_command_returns_some_for_known() {
         let dir = TempDir::new().unwrap();
-        assert!(handle_command("/help", dir.path()).is_some());
+        assert!(handle_command("/help", dir.path(), ParseMode::Html).is_some());
     }
 
     #[test]
     fn cmd_help_lists_commands() {
-        let result = cmd_help();
+        let result = cmd_help(ParseMode::Html);
         assert!(result.contains("/status"));
         assert!(result.contains("/tasks"));
         assert!(result.contains("/memories"));
@@ -540,11 +744,41 @@ mod tests {
         assert!(result.contains("/help"));
     }
 
+    #[test]
+    fn command_replies_follow_parse_mode() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        std::fs::write(
+            dir.path().join(".ralph/agent/tasks.jsonl"),
+            r#"{"id":"task-1","title":"Fix <b> & (parens)","status":"open","priority":1}"#,
+        )
+        .unwrap();
+
+        let help = cmd_help(ParseMode::MarkdownV2);
+        assert!(help.starts_with("*Ralph Bot Commands*\n"), "{help}");
+        assert!(!help.contains("<b>"), "{help}");
+
+        let tasks = cmd_tasks(dir.path(), ParseMode::MarkdownV2);
+        assert!(
+            tasks.contains("*Open Tasks* \\(1 open, 0 closed\\)"),
+            "{tasks}"
+        );
+        assert!(tasks.contains("`task-1`"), "{tasks}");
+        assert!(tasks.contains("Fix <b\\> & \\(parens\\)"), "{tasks}");
+
+        let tasks = cmd_tasks(dir.path(), ParseMode::Html);
+        assert!(tasks.contains("<b>Open Tasks</b>"), "{tasks}");
+        assert!(tasks.contains("Fix &lt;b&gt; &amp; (parens)"), "{tasks}");
+
+        let reply = cmd_pause(dir.path(), ParseMode::MarkdownV2);
+        assert_eq!(reply, "No active loop to pause\\.");
+    }
+
     #[test]
     fn cmd_status_no_lock_file() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_status(dir.path());
+        let result = cmd_status(dir.path(), ParseMode::Html);
         assert!(result.contains("No active loop"));
     }
 
@@ -561,7 +795,7 @@ mod tests {
         let lock_path = dir.path().join(".ralph/loop.lock");
         std::fs::write(&lock_path, serde_json::to_string(&lock).unwrap()).unwrap();
 
-        let result = cmd_status(dir.path());
+        let result = cmd_status(dir.path(), ParseMode::Html);
         assert!(result.contains("No active loop"));
         assert!(result.contains("stale lock"));
     }
@@ -589,7 +823,7 @@ mod tests {
             .unwrap();
         let _flock = Flock::lock(file, FlockArg::LockExclusiveNonblock).unwrap();
 
-        let result = cmd_status(dir.path());
+        let result = cmd_status(dir.path(), ParseMode::Html);
         assert!(result.contains("12345"));
         assert!(result.contains("Build a feature"));
     }
@@ -598,7 +832,7 @@ mod tests {
     fn cmd_tasks_no_file() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_tasks(dir.path());
+        let result = cmd_tasks(dir.path(), ParseMode::Html);
         assert!(result.contains("No tasks file"));
     }
 
@@ -625,7 +859,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = cmd_tasks(dir.path());
+        let result = cmd_tasks(dir.path(), ParseMode::Html);
         assert!(result.contains("2 open"));
         assert!(result.contains("1 closed"));
         assert!(result.contains("Add auth"));
@@ -646,7 +880,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = cmd_tasks(dir.path());
+        let result = cmd_tasks(dir.path(), ParseMode::Html);
         assert!(result.contains("No open tasks"));
         assert!(result.contains("1 completed"));
     }
@@ -655,7 +889,7 @@ mod tests {
     fn cmd_memories_no_file() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_memories(dir.path());
+        let result = cmd_memories(dir.path(), ParseMode::Html);
         assert!(result.contains("No memories file"));
     }
 
@@ -671,7 +905,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = cmd_memories(dir.path());
+        let result = cmd_memories(dir.path(), ParseMode::Html);
         assert!(result.contains("mem-001"));
         assert!(result.contains("mem-002"));
         assert!(result.contains("First memory"));
@@ -682,7 +916,7 @@ mod tests {
     fn cmd_tail_no_events() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_tail(dir.path());
+        let result = cmd_tail(dir.path(), ParseMode::Html);
         assert!(result.contains("No events file"));
     }
 
@@ -702,7 +936,7 @@ mod tests {
             .unwrap();
         }
 
-        let result = cmd_tail(dir.path());
+        let result = cmd_tail(dir.path(), ParseMode::Html);
         assert!(result.contains("Last 5 Events"));
         assert!(result.contains("5 total"));
         assert!(result.contains("work.start"));
@@ -727,7 +961,7 @@ mod tests {
         let pointer_path = dir.path().join(".ralph/current-events");
         std::fs::write(&pointer_path, events_file).unwrap();
 
-        let result = cmd_tail(dir.path());
+        let result = cmd_tail(dir.path(), ParseMode::Html);
         assert!(result.contains("plan.start"));
     }
 
@@ -746,7 +980,7 @@ mod tests {
         )
         .unwrap();
 
-        let result = cmd_tail(dir.path());
+        let result = cmd_tail(dir.path(), ParseMode::Html);
         assert!(result.contains("..."));
         assert!(result.len() < 300); // Truncated, not full 200 chars
     }
@@ -755,7 +989,7 @@ mod tests {
     fn cmd_restart_no_active_loop() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_restart(dir.path());
+        let result = cmd_restart(dir.path(), ParseMode::Html);
         assert!(result.contains("No active loop"));
     }
 
@@ -763,7 +997,7 @@ mod tests {
     fn cmd_stop_no_active_loop() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        let result = cmd_stop(dir.path());
+        let result = cmd_stop(dir.path(), ParseMode::Html);
         assert!(result.contains("No active loop"));
     }
 
@@ -791,7 +1025,7 @@ mod tests {
             .unwrap();
         let _flock = Flock::lock(file, FlockArg::LockExclusiveNonblock).unwrap();
 
-        let result = cmd_restart(dir.path());
+        let result = cmd_restart(dir.path(), ParseMode::Html);
         assert!(result.contains("Restart requested"));
 
         // Verify signal file was created
@@ -823,7 +1057,7 @@ mod tests {
             .unwrap();
         let _flock = Flock::lock(file, FlockArg::LockExclusiveNonblock).unwrap();
 
-        let result = cmd_stop(dir.path());
+        let result = cmd_stop(dir.path(), ParseMode::Html);
         assert!(result.contains("Stop requested"));
 
         // Verify signal file was created
@@ -835,25 +1069,25 @@ mod tests {
     fn handle_command_recognizes_restart() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        assert!(handle_command("/restart", dir.path()).is_some());
+        assert!(handle_command("/restart", dir.path(), ParseMode::Html).is_some());
     }
 
     #[test]
     fn handle_command_recognizes_stop() {
         let dir = TempDir::new().unwrap();
         setup_workspace(&dir);
-        assert!(handle_command("/stop", dir.path()).is_some());
+        assert!(handle_command("/stop", dir.path(), ParseMode::Html).is_some());
     }
 
     #[test]
     fn cmd_help_lists_restart() {
-        let result = cmd_help();
+        let result = cmd_help(ParseMode::Html);
         assert!(result.contains("/restart"));
     }
 
     #[test]
     fn cmd_help_lists_stop() {
-        let result = cmd_help();
+        let result = cmd_help(ParseMode::Html);
         assert!(result.contains("/stop"));
     }
 }
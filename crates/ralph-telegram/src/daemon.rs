@@ -17,7 +17,7 @@ use tracing::{error, info, warn};
 
 use ralph_proto::daemon::{DaemonAdapter, StartLoopFn};
 
-use crate::bot::{BotApi, TelegramBot, escape_html};
+use crate::bot::{BotApi, ParseMode, TelegramBot};
 use crate::loop_lock::{LockState, lock_path, lock_state};
 use crate::state::StateManager;
 
@@ -35,6 +35,7 @@ async fn wait_for_shutdown(shutdown: Arc<AtomicBool>) {
 pub struct TelegramDaemon {
     bot_token: String,
     chat_id: i64,
+    parse_mode: ParseMode,
 }
 
 impl TelegramDaemon {
@@ -43,7 +44,17 @@ impl TelegramDaemon {
     /// `bot_token` — Telegram Bot API token.
     /// `chat_id` — The Telegram chat to communicate with.
     pub fn new(bot_token: String, chat_id: i64) -> Self {
-        Self { bot_token, chat_id }
+        Self {
+            bot_token,
+            chat_id,
+            parse_mode: ParseMode::Html,
+        }
+    }
+
+    /// Format and send messages with `parse_mode` instead of HTML.
+    pub fn with_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
     }
 }
 
@@ -54,13 +65,16 @@ impl DaemonAdapter for TelegramDaemon {
         workspace_root: PathBuf,
         start_loop: StartLoopFn,
     ) -> anyhow::Result<()> {
-        let bot = TelegramBot::new(&self.bot_token);
+        let bot = TelegramBot::new(&self.bot_token).with_parse_mode(self.parse_mode);
+        let mode = self.parse_mode;
         let chat_id = self.chat_id;
 
         let state_manager = StateManager::new(workspace_root.join(".ralph/telegram-state.json"));
 
         // Send greeting
-        let _ = bot.send_message(chat_id, "Ralph daemon online 🤖").await;
+        let _ = bot
+            .send_message(chat_id, &mode.escape("Ralph daemon online 🤖"))
+            .await;
 
         // Install signal handlers for graceful shutdown
         let shutdown = Arc::new(AtomicBool::new(false));
@@ -144,13 +158,15 @@ impl DaemonAdapter for TelegramDaemon {
                                 }
                                 Err(e) => format!("Failed to check lock state: {}", e),
                             };
-                            let _ = bot.send_message(chat_id, &msg).await;
+                            let _ = bot.send_message(chat_id, &mode.escape(&msg)).await;
                         }
                         _ => {
                             let _ = bot
                                 .send_message(
                                     chat_id,
-                                    "Unknown command. I only handle /status while idle.",
+                                    &mode.escape(
+                                        "Unknown command. I only handle /status while idle.",
+                                    ),
                                 )
                                 .await;
                         }
@@ -167,7 +183,7 @@ impl DaemonAdapter for TelegramDaemon {
                         let _ = bot
                             .send_message(
                                 chat_id,
-                                "Failed to check loop state; try again in a moment.",
+                                &mode.escape("Failed to check loop state; try again in a moment."),
                             )
                             .await;
                         continue;
@@ -177,7 +193,9 @@ impl DaemonAdapter for TelegramDaemon {
                     let _ = bot
                         .send_message(
                             chat_id,
-                            "A loop is already running — it will receive your messages directly.",
+                            &mode.escape(
+                                "A loop is already running — it will receive your messages directly.",
+                            ),
                         )
                         .await;
                     continue;
@@ -191,8 +209,7 @@ impl DaemonAdapter for TelegramDaemon {
                 }
 
                 // No loop running — start one with this message as prompt
-                let escaped = escape_html(text);
-                let ack = format!("Starting loop: <i>{}</i>", escaped);
+                let ack = format!("Starting loop: {}", mode.italic(text));
                 let _ = bot.send_message(chat_id, &ack).await;
 
                 // ── Loop Running: hand off Telegram to the loop ──
@@ -213,15 +230,15 @@ impl DaemonAdapter for TelegramDaemon {
                 match result {
                     Ok(Ok(description)) => {
                         let notification =
-                            format!("Loop complete ({}).", escape_html(&description));
+                            mode.escape(&format!("Loop complete ({}).", description));
                         let _ = bot.send_message(chat_id, &notification).await;
                     }
                     Ok(Err(e)) => {
-                        let notification = format!("Loop failed: {}", escape_html(&e.to_string()));
+                        let notification = mode.escape(&format!("Loop failed: {}", e));
                         let _ = bot.send_message(chat_id, &notification).await;
                     }
                     Err(e) => {
-                        let notification = format!("Loop failed: {}", escape_html(&e.to_string()));
+                        let notification = mode.escape(&format!("Loop failed: {}", e));
                         let _ = bot.send_message(chat_id, &notification).await;
                     }
                 }
@@ -229,7 +246,10 @@ impl DaemonAdapter for TelegramDaemon {
         }
 
         // Farewell
-        let _ = bot.send_message(chat_id, "Ralph daemon offline 👋").await;
+        let _ = bot
+            .send_message(chat_id, &mode.escape("Ralph daemon offline 👋"))
+            .await;
+        bot.flush().await;
 
         Ok(())
     }
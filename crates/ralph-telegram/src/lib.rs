@@ -21,14 +21,17 @@ pub mod daemon;
 mod error;
 mod handler;
 mod loop_lock;
+mod rate_limit;
 mod service;
 mod state;
 
-pub use bot::{BotApi, TelegramBot, escape_html, markdown_to_telegram_html};
+pub use bot::{
+    BotApi, DEFAULT_MESSAGES_PER_SECOND, DEFAULT_SEND_ATTEMPTS, DEFAULT_SEND_BASE_DELAY,
+    MAX_SEND_RETRY_DELAY, ParseMode, TelegramBot, escape_html, escape_markdown_v2,
+    markdown_to_telegram_html,
+};
 pub use daemon::TelegramDaemon;
 pub use error::{TelegramError, TelegramResult};
 pub use handler::MessageHandler;
-pub use service::{
-    BASE_RETRY_DELAY, CheckinContext, MAX_SEND_RETRIES, TelegramService, retry_with_backoff,
-};
+pub use service::{BASE_RETRY_DELAY, CheckinContext, MAX_SEND_RETRIES, TelegramService};
 pub use state::{PendingQuestion, StateManager, TelegramState};
@@ -0,0 +1,256 @@
+//! Per-chat token-bucket rate limiting for outgoing Telegram messages.
+//!
+//! Telegram answers bursts to a single chat with `429 Too Many Requests`.
+//! [`RateLimiter`] spaces sends so each chat gets at most N messages per
+//! second. Sends to the same chat queue up in FIFO order, so they go out in
+//! the order they were issued.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+/// Source of time for the rate limiter, so tests can run on a fake clock.
+#[async_trait]
+pub(crate) trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Real time, backed by tokio's timer.
+pub(crate) struct TokioClock;
+
+#[async_trait]
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Tokens left for one chat. Holds up to `messages_per_second` tokens and
+/// refills continuously at that rate.
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token-bucket rate limiter keyed by chat ID.
+pub(crate) struct RateLimiter {
+    messages_per_second: u32,
+    clock: Arc<dyn Clock>,
+    chats: Mutex<HashMap<i64, Arc<AsyncMutex<Bucket>>>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `messages_per_second` sends per chat.
+    ///
+    /// Zero disables throttling; sends are still queued in order.
+    pub(crate) fn new(messages_per_second: u32) -> Self {
+        Self::with_clock(messages_per_second, Arc::new(TokioClock))
+    }
+
+    pub(crate) fn with_clock(messages_per_second: u32, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            messages_per_second,
+            clock,
+            chats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for this chat's turn in the send queue.
+    ///
+    /// Hold the returned slot for the whole send, retries included, and call
+    /// [`ChatSlot::take`] before each request.
+    pub(crate) async fn queue(&self, chat_id: i64) -> ChatSlot<'_> {
+        let bucket = {
+            let mut chats = self.chats.lock().unwrap();
+            Arc::clone(chats.entry(chat_id).or_insert_with(|| {
+                Arc::new(AsyncMutex::new(Bucket {
+                    tokens: f64::from(self.messages_per_second),
+                    refilled_at: self.clock.now(),
+                }))
+            }))
+        };
+        ChatSlot {
+            limiter: self,
+            bucket: bucket.lock_owned().await,
+        }
+    }
+
+    /// Wait until every send queued so far has gone out.
+    pub(crate) async fn flush(&self) {
+        let buckets: Vec<_> = self.chats.lock().unwrap().values().cloned().collect();
+        for bucket in buckets {
+            drop(bucket.lock().await);
+        }
+    }
+}
+
+/// Exclusive turn to send to one chat; other sends to it wait until dropped.
+pub(crate) struct ChatSlot<'a> {
+    limiter: &'a RateLimiter,
+    bucket: OwnedMutexGuard<Bucket>,
+}
+
+impl ChatSlot<'_> {
+    /// Take one token, sleeping until the bucket refills if it is empty.
+    pub(crate) async fn take(&mut self) {
+        let rate = f64::from(self.limiter.messages_per_second);
+        if rate == 0.0 {
+            return;
+        }
+
+        let now = self.limiter.clock.now();
+        let elapsed = now.duration_since(self.bucket.refilled_at).as_secs_f64();
+        self.bucket.tokens = (self.bucket.tokens + elapsed * rate).min(rate);
+        self.bucket.refilled_at = now;
+
+        if self.bucket.tokens >= 1.0 {
+            self.bucket.tokens -= 1.0;
+            return;
+        }
+
+        // Sleep until exactly one token has accrued, then spend it.
+        let wait = Duration::from_secs_f64((1.0 - self.bucket.tokens) / rate);
+        self.limiter.clock.sleep(wait).await;
+        self.bucket.tokens = 0.0;
+        self.bucket.refilled_at = now + wait;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A clock whose `sleep` advances time instantly.
+    struct FakeClock {
+        start: Instant,
+        now: Mutex<Instant>,
+    }
+
+    impl FakeClock {
+        fn new() -> Arc<Self> {
+            let start = Instant::now();
+            Arc::new(Self {
+                start,
+                now: Mutex::new(start),
+            })
+        }
+
+        fn elapsed(&self) -> Duration {
+            *self.now.lock().unwrap() - self.start
+        }
+    }
+
+    #[async_trait]
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            *self.now.lock().unwrap() += duration;
+            tokio::task::yield_now().await;
+        }
+    }
+
+    async fn send(limiter: &RateLimiter, chat_id: i64) {
+        limiter.queue(chat_id).await.take().await;
+    }
+
+    #[tokio::test]
+    async fn burst_is_throttled_to_rate_per_chat() {
+        let clock = FakeClock::new();
+        let limiter = RateLimiter::with_clock(2, clock.clone());
+
+        let mut sent_at = Vec::new();
+        for _ in 0..5 {
+            send(&limiter, 1).await;
+            sent_at.push(clock.elapsed().as_millis());
+        }
+        assert_eq!(sent_at, vec![0, 0, 500, 1000, 1500]);
+
+        // Another chat has its own bucket.
+        send(&limiter, 2).await;
+        assert_eq!(clock.elapsed().as_millis(), 1500);
+    }
+
+    #[tokio::test]
+    async fn idle_time_refills_the_bucket_up_to_capacity() {
+        let clock = FakeClock::new();
+        let limiter = RateLimiter::with_clock(2, clock.clone());
+
+        for _ in 0..2 {
+            send(&limiter, 1).await;
+        }
+        clock.sleep(Duration::from_secs(10)).await;
+
+        for _ in 0..3 {
+            send(&limiter, 1).await;
+        }
+        // Two tokens banked (not twenty), then one wait of 500ms.
+        assert_eq!(clock.elapsed().as_millis(), 10_500);
+    }
+
+    #[tokio::test]
+    async fn zero_rate_disables_throttling() {
+        let clock = FakeClock::new();
+        let limiter = RateLimiter::with_clock(0, clock.clone());
+
+        for _ in 0..10 {
+            send(&limiter, 1).await;
+        }
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn queued_sends_keep_their_order() {
+        let clock = FakeClock::new();
+        let limiter = RateLimiter::with_clock(1, clock.clone());
+        let order = Mutex::new(Vec::new());
+
+        let sender = |n: u32| {
+            let (limiter, order) = (&limiter, &order);
+            async move {
+                let mut slot = limiter.queue(1).await;
+                slot.take().await;
+                order.lock().unwrap().push(n);
+            }
+        };
+        tokio::join!(sender(1), sender(2), sender(3), sender(4));
+
+        assert_eq!(*order.lock().unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn flush_waits_for_queued_sends() {
+        let clock = FakeClock::new();
+        let limiter = RateLimiter::with_clock(1, clock.clone());
+        let done = Mutex::new(0);
+
+        let sender = || {
+            let (limiter, done) = (&limiter, &done);
+            async move {
+                send(limiter, 1).await;
+                *done.lock().unwrap() += 1;
+            }
+        };
+        let flushed = async {
+            tokio::task::yield_now().await;
+            limiter.flush().await;
+            *done.lock().unwrap()
+        };
+        let (_, _, _, sent_before_flush_returned) =
+            tokio::join!(sender(), sender(), sender(), flushed);
+
+        assert_eq!(sent_before_flush_returned, 3);
+    }
+}
@@ -7,10 +7,11 @@ use std::time::{Duration, Instant};
 use chrono::Utc;
 use tracing::{debug, info, warn};
 
-use crate::bot::TelegramBot;
+use crate::bot::{BotApi, ParseMode, TelegramBot};
 use crate::error::{TelegramError, TelegramResult};
 use crate::handler::MessageHandler;
 use crate::state::StateManager;
+use ralph_proto::TerminationContext;
 
 /// Maximum number of retry attempts for sending messages.
 pub const MAX_SEND_RETRIES: u32 = 3;
@@ -18,50 +19,6 @@ pub const MAX_SEND_RETRIES: u32 = 3;
 /// Base delay for exponential backoff (1 second).
 pub const BASE_RETRY_DELAY: Duration = Duration::from_secs(1);
 
-/// Execute a fallible send operation with exponential backoff retry.
-///
-/// Retries up to [`MAX_SEND_RETRIES`] times with delays of 1s, 2s, 4s.
-/// Returns the result on success, or `TelegramError::Send` after all
-/// retries are exhausted.
-///
-/// The `sleep_fn` parameter allows tests to substitute a no-op sleep.
-pub fn retry_with_backoff<F, S>(mut send_fn: F, mut sleep_fn: S) -> TelegramResult<i32>
-where
-    F: FnMut(u32) -> TelegramResult<i32>,
-    S: FnMut(Duration),
-{
-    let mut last_error = String::new();
-
-    for attempt in 1..=MAX_SEND_RETRIES {
-        match send_fn(attempt) {
-            Ok(msg_id) => return Ok(msg_id),
-            Err(e) => {
-                last_error = e.to_string();
-                warn!(
-                    attempt = attempt,
-                    max_retries = MAX_SEND_RETRIES,
-                    error = %last_error,
-                    "Telegram send failed, {}",
-                    if attempt < MAX_SEND_RETRIES {
-                        "retrying with backoff"
-                    } else {
-                        "all retries exhausted"
-                    }
-                );
-                if attempt < MAX_SEND_RETRIES {
-                    let delay = BASE_RETRY_DELAY * 2u32.pow(attempt - 1);
-                    sleep_fn(delay);
-                }
-            }
-        }
-    }
-
-    Err(TelegramError::Send {
-        attempts: MAX_SEND_RETRIES,
-        reason: last_error,
-    })
-}
-
 /// Additional context for enhanced check-in messages.
 ///
 /// Provides richer information than the basic iteration + elapsed time,
@@ -111,7 +68,7 @@ impl TelegramService {
         let state_manager = StateManager::new(&state_path);
         let handler_state_manager = StateManager::new(&state_path);
         let handler = MessageHandler::new(handler_state_manager, &workspace_root);
-        let bot = TelegramBot::new(&resolved_token);
+        let bot = TelegramBot::with_retry(&resolved_token, MAX_SEND_RETRIES, BASE_RETRY_DELAY);
         let shutdown = Arc::new(AtomicBool::new(false));
 
         Ok(Self {
@@ -126,6 +83,12 @@ impl TelegramService {
         })
     }
 
+    /// Format and send messages with `parse_mode` instead of HTML.
+    pub fn with_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.bot = self.bot.with_parse_mode(parse_mode);
+        self
+    }
+
     /// Get a reference to the workspace root.
     pub fn workspace_root(&self) -> &PathBuf {
         &self.workspace_root
@@ -190,20 +153,21 @@ impl TelegramService {
         })?;
 
         let raw_bot = teloxide::Bot::new(&self.bot_token);
+        let api = self.bot.clone();
         let workspace_root = self.workspace_root.clone();
         let state_path = self.workspace_root.join(".ralph/telegram-state.json");
         let shutdown = self.shutdown.clone();
         let loop_id = self.loop_id.clone();
 
         handle.spawn(async move {
-            Self::poll_updates(raw_bot, workspace_root, state_path, shutdown, loop_id).await;
+            Self::poll_updates(raw_bot, api, workspace_root, state_path, shutdown, loop_id).await;
         });
 
         // Send greeting if we already know the chat ID
         if let Ok(state) = self.state_manager.load_or_default()
             && let Some(chat_id) = state.chat_id
         {
-            let greeting = crate::bot::TelegramBot::format_greeting(&self.loop_id);
+            let greeting = self.bot.format_greeting(&self.loop_id);
             match self.send_with_retry(chat_id, &greeting) {
                 Ok(_) => info!("Sent greeting to chat {}", chat_id),
                 Err(e) => warn!(error = %e, "Failed to send greeting"),
@@ -218,8 +182,10 @@ impl TelegramService {
     ///
     /// Uses long polling (`getUpdates`) to receive messages, then routes them
     /// through `MessageHandler` to write events to the correct loop's JSONL.
+    /// Replies go through `api`, which shares the service's rate limiter.
     async fn poll_updates(
         bot: teloxide::Bot,
+        api: TelegramBot,
         workspace_root: PathBuf,
         state_path: PathBuf,
         shutdown: Arc<AtomicBool>,
@@ -277,14 +243,14 @@ impl TelegramService {
 
                         // Handle bot commands before routing to handler
                         if crate::commands::is_command(text)
-                            && let Some(response) =
-                                crate::commands::handle_command(text, &workspace_root)
+                            && let Some(send_result) = crate::commands::respond_to_command(
+                                &api,
+                                chat_id,
+                                text,
+                                &workspace_root,
+                            )
+                            .await
                         {
-                            use teloxide::payloads::SendMessageSetters;
-                            let send_result = bot
-                                .send_message(teloxide::types::ChatId(chat_id), &response)
-                                .parse_mode(teloxide::types::ParseMode::Html)
-                                .await;
                             if let Err(e) = send_result {
                                 warn!(error = %e, "Failed to send command response");
                             }
@@ -318,12 +284,15 @@ impl TelegramService {
 
                                 // For guidance, also send a short text reply
                                 if topic == "human.guidance" {
-                                    let _ = bot
-                                        .send_message(
-                                            teloxide::types::ChatId(chat_id),
-                                            "📝 <b>Guidance received</b> — will apply next iteration.",
-                                        )
-                                        .await;
+                                    let mode = api.parse_mode();
+                                    let ack = format!(
+                                        "📝 {} {}",
+                                        mode.bold("Guidance received"),
+                                        mode.escape("— will apply next iteration.")
+                                    );
+                                    if let Err(e) = api.send_message(chat_id, &ack).await {
+                                        warn!(error = %e, "Failed to acknowledge guidance");
+                                    }
                                 }
                             }
                             Err(e) => {
@@ -364,6 +333,8 @@ impl TelegramService {
             BotCommand::new("tasks", "Open tasks"),
             BotCommand::new("memories", "Recent memories"),
             BotCommand::new("tail", "Last 20 events"),
+            BotCommand::new("pause", "Pause the loop"),
+            BotCommand::new("resume", "Resume a paused loop"),
             BotCommand::new("stop", "Stop the loop"),
             BotCommand::new("help", "List available commands"),
         ];
@@ -376,19 +347,24 @@ impl TelegramService {
 
     /// Stop the Telegram service gracefully.
     ///
-    /// Signals the background polling task to shut down.
+    /// Sends the farewell, flushes queued sends, then signals the background
+    /// polling task to shut down.
     pub fn stop(self) {
         // Send farewell if we know the chat ID
         if let Ok(state) = self.state_manager.load_or_default()
             && let Some(chat_id) = state.chat_id
         {
-            let farewell = crate::bot::TelegramBot::format_farewell(&self.loop_id);
+            let farewell = self.bot.format_farewell(&self.loop_id);
             match self.send_with_retry(chat_id, &farewell) {
                 Ok(_) => info!("Sent farewell to chat {}", chat_id),
                 Err(e) => warn!(error = %e, "Failed to send farewell"),
             }
         }
 
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            tokio::task::block_in_place(|| handle.block_on(self.bot.flush()));
+        }
+
         self.shutdown.store(true, Ordering::Relaxed);
         info!(
             workspace = %self.workspace_root.display(),
@@ -402,14 +378,16 @@ impl TelegramService {
     /// question is stored in the state manager so that incoming replies can be
     /// routed back to the correct loop.
     ///
-    /// On send failure, retries up to 3 times with exponential backoff (1s, 2s, 4s).
+    /// The payload is rendered as markdown in the bot's parse mode. On send
+    /// failure, retries up to 3 times with exponential backoff (1s, 2s, 4s).
     /// Returns the message ID of the sent Telegram message, or 0 if no chat ID
     /// is configured (question is logged but not sent).
     pub fn send_question(&self, payload: &str) -> TelegramResult<i32> {
         let mut state = self.state_manager.load_or_default()?;
 
         let message_id = if let Some(chat_id) = state.chat_id {
-            self.send_with_retry(chat_id, payload)?
+            let question = self.bot.parse_mode().from_markdown(payload);
+            self.send_with_retry(chat_id, &question)?
         } else {
             warn!(
                 loop_id = %self.loop_id,
@@ -455,48 +433,57 @@ impl TelegramService {
             return Ok(0);
         };
 
-        let elapsed_secs = elapsed.as_secs();
-        let minutes = elapsed_secs / 60;
-        let seconds = elapsed_secs % 60;
-        let elapsed_str = if minutes > 0 {
-            format!("{}m {}s", minutes, seconds)
-        } else {
-            format!("{}s", seconds)
-        };
-
-        let msg = match context {
-            Some(ctx) => {
-                let mut lines = vec![format!(
-                    "Still working — iteration <b>{}</b>, <code>{}</code> elapsed.",
-                    iteration, elapsed_str
-                )];
-
-                if let Some(hat) = &ctx.current_hat {
-                    lines.push(format!(
-                        "Hat: <code>{}</code>",
-                        crate::bot::escape_html(hat)
-                    ));
-                }
-
-                if ctx.open_tasks > 0 || ctx.closed_tasks > 0 {
-                    lines.push(format!(
-                        "Tasks: <b>{}</b> open, {} closed",
-                        ctx.open_tasks, ctx.closed_tasks
-                    ));
-                }
-
-                if ctx.cumulative_cost > 0.0 {
-                    lines.push(format!("Cost: <code>${:.4}</code>", ctx.cumulative_cost));
-                }
+        let msg = format_checkin(self.bot.parse_mode(), iteration, elapsed, context);
+        self.send_with_retry(chat_id, &msg)
+    }
 
-                lines.join("\n")
-            }
-            None => format!(
-                "Still working — iteration <b>{}</b>, <code>{}</code> elapsed.",
-                iteration, elapsed_str
-            ),
+    /// Send the loop's termination summary, then its scratchpad.
+    ///
+    /// Goes through the service's bot, so the sends share its retry policy
+    /// and per-chat rate limit. Skips silently if no chat ID is configured.
+    /// Failures are logged; one failed send doesn't prevent the other.
+    pub fn send_termination(&self, context: &TerminationContext) -> TelegramResult<()> {
+        let state = self.state_manager.load_or_default()?;
+        let Some(chat_id) = state.chat_id else {
+            debug!(
+                loop_id = %self.loop_id,
+                "No chat ID configured — skipping termination notice"
+            );
+            return Ok(());
         };
-        self.send_with_retry(chat_id, &msg)
+        let handle = tokio::runtime::Handle::try_current().map_err(|_| TelegramError::Send {
+            attempts: 0,
+            reason: "no tokio runtime available for sending".to_string(),
+        })?;
+
+        let message = self.bot.format_termination(
+            &self.loop_id,
+            &context.reason,
+            context.iterations,
+            context.elapsed,
+            context.cumulative_cost,
+        );
+        let caption =
+            self.bot
+                .format_scratchpad_caption(&self.loop_id, &context.reason, context.iterations);
+        let (summary, scratchpad) = tokio::task::block_in_place(|| {
+            handle.block_on(async {
+                (
+                    self.bot.send_message(chat_id, &message).await,
+                    self.bot
+                        .send_scratchpad(chat_id, &context.scratchpad, &caption)
+                        .await,
+                )
+            })
+        });
+        match summary {
+            Ok(_) => info!("Sent termination notice to chat {}", chat_id),
+            Err(e) => warn!(error = %e, "Failed to send termination notice"),
+        }
+        if let Err(e) = scratchpad {
+            warn!(error = %e, "Failed to send scratchpad");
+        }
+        Ok(())
     }
 
     /// Send a document (file) to the human via Telegram.
@@ -537,24 +524,17 @@ impl TelegramService {
 
     /// Attempt to send a message with exponential backoff retries.
     ///
-    /// Uses the host tokio runtime via `block_in_place` + `Handle::block_on`
-    /// to bridge the sync event loop to the async BotApi.
+    /// Retries happen inside [`TelegramBot`], which the service builds with
+    /// [`MAX_SEND_RETRIES`] and [`BASE_RETRY_DELAY`]. Uses the host tokio
+    /// runtime via `block_in_place` + `Handle::block_on` to bridge the sync
+    /// event loop to the async BotApi.
     fn send_with_retry(&self, chat_id: i64, payload: &str) -> TelegramResult<i32> {
-        use crate::bot::BotApi;
-
         let handle = tokio::runtime::Handle::try_current().map_err(|_| TelegramError::Send {
             attempts: 0,
             reason: "no tokio runtime available for sending".to_string(),
         })?;
 
-        retry_with_backoff(
-            |_attempt| {
-                tokio::task::block_in_place(|| {
-                    handle.block_on(self.bot.send_message(chat_id, payload))
-                })
-            },
-            |delay| std::thread::sleep(delay),
-        )
+        tokio::task::block_in_place(|| handle.block_on(self.bot.send_message(chat_id, payload)))
     }
 
     /// Attempt to send a document with exponential backoff retries.
@@ -564,21 +544,14 @@ impl TelegramService {
         file_path: &Path,
         caption: Option<&str>,
     ) -> TelegramResult<i32> {
-        use crate::bot::BotApi;
-
         let handle = tokio::runtime::Handle::try_current().map_err(|_| TelegramError::Send {
             attempts: 0,
             reason: "no tokio runtime available for sending".to_string(),
         })?;
 
-        retry_with_backoff(
-            |_attempt| {
-                tokio::task::block_in_place(|| {
-                    handle.block_on(self.bot.send_document(chat_id, file_path, caption))
-                })
-            },
-            |delay| std::thread::sleep(delay),
-        )
+        tokio::task::block_in_place(|| {
+            handle.block_on(self.bot.send_document(chat_id, file_path, caption))
+        })
     }
 
     /// Attempt to send a photo with exponential backoff retries.
@@ -588,21 +561,14 @@ impl TelegramService {
         file_path: &Path,
         caption: Option<&str>,
     ) -> TelegramResult<i32> {
-        use crate::bot::BotApi;
-
         let handle = tokio::runtime::Handle::try_current().map_err(|_| TelegramError::Send {
             attempts: 0,
             reason: "no tokio runtime available for sending".to_string(),
         })?;
 
-        retry_with_backoff(
-            |_attempt| {
-                tokio::task::block_in_place(|| {
-                    handle.block_on(self.bot.send_photo(chat_id, file_path, caption))
-                })
-            },
-            |delay| std::thread::sleep(delay),
-        )
+        tokio::task::block_in_place(|| {
+            handle.block_on(self.bot.send_photo(chat_id, file_path, caption))
+        })
     }
 
     /// Poll the events file for a `human.response` event, blocking until one
@@ -741,6 +707,57 @@ impl TelegramService {
     }
 }
 
+/// Formats a check-in message for `mode`.
+fn format_checkin(
+    mode: ParseMode,
+    iteration: u32,
+    elapsed: Duration,
+    context: Option<&CheckinContext>,
+) -> String {
+    let elapsed_secs = elapsed.as_secs();
+    let minutes = elapsed_secs / 60;
+    let seconds = elapsed_secs % 60;
+    let elapsed_str = if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    };
+
+    let headline = format!(
+        "Still working — iteration {}, {} {}",
+        mode.bold(&iteration.to_string()),
+        mode.code(&elapsed_str),
+        mode.escape("elapsed.")
+    );
+    match context {
+        Some(ctx) => {
+            let mut lines = vec![headline];
+
+            if let Some(hat) = &ctx.current_hat {
+                lines.push(format!("Hat: {}", mode.code(hat)));
+            }
+
+            if ctx.open_tasks > 0 || ctx.closed_tasks > 0 {
+                lines.push(format!(
+                    "Tasks: {} open, {} closed",
+                    mode.bold(&ctx.open_tasks.to_string()),
+                    ctx.closed_tasks
+                ));
+            }
+
+            if ctx.cumulative_cost > 0.0 {
+                lines.push(format!(
+                    "Cost: {}",
+                    mode.code(&format!("${:.4}", ctx.cumulative_cost))
+                ));
+            }
+
+            lines.join("\n")
+        }
+        None => headline,
+    }
+}
+
 impl ralph_proto::RobotService for TelegramService {
     fn send_question(&self, payload: &str) -> anyhow::Result<i32> {
         Ok(TelegramService::send_question(self, payload)?)
@@ -771,6 +788,10 @@ impl ralph_proto::RobotService for TelegramService {
         )?)
     }
 
+    fn send_termination(&self, context: &TerminationContext) -> anyhow::Result<()> {
+        Ok(TelegramService::send_termination(self, context)?)
+    }
+
     fn timeout_secs(&self) -> u64 {
         self.timeout_secs
     }
@@ -1088,160 +1109,38 @@ mod tests {
     }
 
     #[test]
-    fn retry_with_backoff_succeeds_on_first_attempt() {
-        let attempts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let attempts_clone = attempts.clone();
-
-        let result = retry_with_backoff(
-            |attempt| {
-                attempts_clone.lock().unwrap().push(attempt);
-                Ok(42)
-            },
-            |_delay| {},
-        );
-
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 42);
-        assert_eq!(*attempts.lock().unwrap(), vec![1]);
-    }
-
-    #[test]
-    fn retry_with_backoff_succeeds_on_second_attempt() {
-        let attempts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let attempts_clone = attempts.clone();
-        let delays = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let delays_clone = delays.clone();
-
-        let result = retry_with_backoff(
-            |attempt| {
-                attempts_clone.lock().unwrap().push(attempt);
-                if attempt < 2 {
-                    Err(TelegramError::Send {
-                        attempts: attempt,
-                        reason: "transient failure".to_string(),
-                    })
-                } else {
-                    Ok(99)
-                }
-            },
-            |delay| {
-                delays_clone.lock().unwrap().push(delay);
-            },
-        );
-
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 99);
-        assert_eq!(*attempts.lock().unwrap(), vec![1, 2]);
-        // First retry delay: 1s * 2^0 = 1s
-        assert_eq!(*delays.lock().unwrap(), vec![Duration::from_secs(1)]);
+    fn checkin_context_default() {
+        let ctx = CheckinContext::default();
+        assert!(ctx.current_hat.is_none());
+        assert_eq!(ctx.open_tasks, 0);
+        assert_eq!(ctx.closed_tasks, 0);
+        assert!(ctx.cumulative_cost.abs() < f64::EPSILON);
     }
 
     #[test]
-    fn retry_with_backoff_succeeds_on_third_attempt() {
-        let attempts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let attempts_clone = attempts.clone();
-        let delays = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let delays_clone = delays.clone();
-
-        let result = retry_with_backoff(
-            |attempt| {
-                attempts_clone.lock().unwrap().push(attempt);
-                if attempt < 3 {
-                    Err(TelegramError::Send {
-                        attempts: attempt,
-                        reason: "transient failure".to_string(),
-                    })
-                } else {
-                    Ok(7)
-                }
-            },
-            |delay| {
-                delays_clone.lock().unwrap().push(delay);
-            },
-        );
+    fn format_checkin_follows_parse_mode() {
+        let ctx = CheckinContext {
+            current_hat: Some("code_reviewer".to_string()),
+            open_tasks: 2,
+            closed_tasks: 1,
+            cumulative_cost: 0.5,
+        };
+        let elapsed = Duration::from_secs(75);
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 7);
-        assert_eq!(*attempts.lock().unwrap(), vec![1, 2, 3]);
-        // Delays: 1s * 2^0 = 1s, 1s * 2^1 = 2s
+        let html = format_checkin(ParseMode::Html, 4, elapsed, Some(&ctx));
         assert_eq!(
-            *delays.lock().unwrap(),
-            vec![Duration::from_secs(1), Duration::from_secs(2)]
+            html,
+            "Still working — iteration <b>4</b>, <code>1m 15s</code> elapsed.\n\
+             Hat: <code>code_reviewer</code>\n\
+             Tasks: <b>2</b> open, 1 closed\n\
+             Cost: <code>$0.5000</code>"
         );
-    }
 
-    #[test]
-    fn retry_with_backoff_fails_after_all_retries() {
-        let attempts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let attempts_clone = attempts.clone();
-        let delays = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let delays_clone = delays.clone();
-
-        let result = retry_with_backoff(
-            |attempt| {
-                attempts_clone.lock().unwrap().push(attempt);
-                Err(TelegramError::Send {
-                    attempts: attempt,
-                    reason: format!("failure on attempt {}", attempt),
-                })
-            },
-            |delay| {
-                delays_clone.lock().unwrap().push(delay);
-            },
-        );
-
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert!(matches!(
-            err,
-            TelegramError::Send {
-                attempts: 3,
-                reason: _
-            }
-        ));
-        // Should report the last error message
-        if let TelegramError::Send { reason, .. } = &err {
-            assert!(reason.contains("failure on attempt 3"));
-        }
-        assert_eq!(*attempts.lock().unwrap(), vec![1, 2, 3]);
-        // Delays: 1s, 2s (no delay after final attempt)
+        let markdown = format_checkin(ParseMode::MarkdownV2, 4, elapsed, None);
         assert_eq!(
-            *delays.lock().unwrap(),
-            vec![Duration::from_secs(1), Duration::from_secs(2)]
-        );
-    }
-
-    #[test]
-    fn retry_with_backoff_exponential_delays_are_correct() {
-        let delays = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let delays_clone = delays.clone();
-
-        let _ = retry_with_backoff(
-            |_attempt| {
-                Err(TelegramError::Send {
-                    attempts: 1,
-                    reason: "always fail".to_string(),
-                })
-            },
-            |delay| {
-                delays_clone.lock().unwrap().push(delay);
-            },
+            markdown,
+            "Still working — iteration *4*, `1m 15s` elapsed\\."
         );
-
-        let recorded = delays.lock().unwrap().clone();
-        // Backoff: 1s * 2^0 = 1s, 1s * 2^1 = 2s (no sleep after 3rd attempt)
-        assert_eq!(recorded.len(), 2);
-        assert_eq!(recorded[0], Duration::from_secs(1));
-        assert_eq!(recorded[1], Duration::from_secs(2));
-    }
-
-    #[test]
-    fn checkin_context_default() {
-        let ctx = CheckinContext::default();
-        assert!(ctx.current_hat.is_none());
-        assert_eq!(ctx.open_tasks, 0);
-        assert_eq!(ctx.closed_tasks, 0);
-        assert!(ctx.cumulative_cost.abs() < f64::EPSILON);
     }
 
     #[test]
@@ -35,6 +35,10 @@ use tracing::info;
 /// Returns `true` if the action signals to quit the application.
 pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: usize) -> bool {
     match action {
+        // In scroll mode, q leaves scroll mode instead of quitting
+        Action::Quit if state.in_scroll_mode => {
+            state.exit_scroll_mode(viewport_height);
+        }
         Action::Quit => return true,
         Action::ScrollDown => {
             if let Some(buffer) = state.current_iteration_mut() {
@@ -56,6 +60,21 @@ pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: us
                 buffer.scroll_bottom(viewport_height);
             }
         }
+        Action::ScrollPageUp => {
+            if let Some(buffer) = state.current_iteration_mut() {
+                buffer.page_up(viewport_height);
+            }
+        }
+        Action::ScrollPageDown => {
+            if let Some(buffer) = state.current_iteration_mut() {
+                buffer.page_down(viewport_height);
+            }
+        }
+        Action::EnterScrollMode => {
+            if let Some(buffer) = state.current_iteration_mut() {
+                buffer.following_bottom = false;
+            }
+        }
         Action::NextIteration => {
             state.navigate_next();
         }
@@ -66,6 +85,9 @@ pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: us
             state.show_help = true;
         }
         Action::DismissHelp => {
+            if state.in_scroll_mode {
+                state.exit_scroll_mode(viewport_height);
+            }
             state.show_help = false;
             state.clear_search();
         }
@@ -86,6 +108,7 @@ pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: us
         }
         Action::None => {}
     }
+    state.sync_scroll_mode();
     false
 }
 
@@ -173,6 +196,7 @@ impl App {
                                                     buffer.scroll_up();
                                                 }
                                             }
+                                            state.sync_scroll_mode();
                                         }
                                         MouseEventKind::ScrollDown => {
                                             let mut state = self.state.lock().unwrap();
@@ -181,6 +205,7 @@ impl App {
                                                     buffer.scroll_down(viewport_height);
                                                 }
                                             }
+                                            state.sync_scroll_mode();
                                         }
                                         _ => {}
                                     }
@@ -416,6 +441,93 @@ mod tests {
         assert_eq!(state.current_iteration().unwrap().scroll_offset, 10);
     }
 
+    #[test]
+    fn dispatch_action_page_down_clamps_to_bottom() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        let buffer = state.current_iteration_mut().unwrap();
+        for _ in 0..25 {
+            buffer.append_line(Line::from("line"));
+        }
+        buffer.following_bottom = false;
+
+        dispatch_action(Action::ScrollPageDown, &mut state, 10);
+        assert_eq!(state.current_iteration().unwrap().scroll_offset, 10);
+        assert!(state.in_scroll_mode);
+
+        // max_scroll = 25 - 10 = 15
+        dispatch_action(Action::ScrollPageDown, &mut state, 10);
+        assert_eq!(state.current_iteration().unwrap().scroll_offset, 15);
+        assert!(!state.in_scroll_mode, "reaching bottom leaves scroll mode");
+    }
+
+    #[test]
+    fn dispatch_action_page_up_enters_scroll_mode() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        let buffer = state.current_iteration_mut().unwrap();
+        for _ in 0..25 {
+            buffer.append_line(Line::from("line"));
+        }
+        buffer.scroll_offset = 15;
+
+        dispatch_action(Action::ScrollPageUp, &mut state, 10);
+
+        assert_eq!(state.current_iteration().unwrap().scroll_offset, 5);
+        assert!(state.in_scroll_mode);
+    }
+
+    #[test]
+    fn dispatch_action_left_bracket_enters_scroll_mode() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+
+        let action = map_key(KeyEvent::new(KeyCode::Char('['), KeyModifiers::NONE));
+        dispatch_action(action, &mut state, 10);
+
+        assert!(state.in_scroll_mode);
+        assert!(!state.current_iteration().unwrap().following_bottom);
+    }
+
+    #[test]
+    fn q_in_scroll_mode_returns_to_bottom_instead_of_quitting() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        let buffer = state.current_iteration_mut().unwrap();
+        for _ in 0..25 {
+            buffer.append_line(Line::from("line"));
+        }
+        dispatch_action(Action::ScrollTop, &mut state, 10);
+        assert!(state.in_scroll_mode);
+
+        let quit = dispatch_action(Action::Quit, &mut state, 10);
+
+        assert!(!quit, "q should leave scroll mode, not quit");
+        assert!(!state.in_scroll_mode);
+        let buffer = state.current_iteration().unwrap();
+        assert_eq!(buffer.scroll_offset, 15);
+        assert!(buffer.following_bottom);
+
+        // Outside scroll mode, q quits as usual
+        assert!(dispatch_action(Action::Quit, &mut state, 10));
+    }
+
+    #[test]
+    fn esc_in_scroll_mode_returns_to_bottom() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        let buffer = state.current_iteration_mut().unwrap();
+        for _ in 0..25 {
+            buffer.append_line(Line::from("line"));
+        }
+        dispatch_action(Action::ScrollPageUp, &mut state, 10);
+
+        dispatch_action(Action::DismissHelp, &mut state, 10);
+
+        assert!(!state.in_scroll_mode);
+        assert_eq!(state.current_iteration().unwrap().scroll_offset, 15);
+    }
+
     #[test]
     fn dispatch_action_next_iteration_navigates_forward() {
         let mut state = TuiState::new();
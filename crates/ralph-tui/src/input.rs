@@ -26,6 +26,12 @@ pub enum Action {
     ScrollTop,
     /// Jump to bottom of content
     ScrollBottom,
+    /// Scroll up one page
+    ScrollPageUp,
+    /// Scroll down one page
+    ScrollPageDown,
+    /// Enter scroll mode, pausing auto-scroll
+    EnterScrollMode,
     /// Enter search mode
     StartSearch,
     /// Jump to next search match
@@ -54,6 +60,8 @@ pub enum Action {
 /// - `↑`/`k`: Scroll up
 /// - `g`: Scroll to top
 /// - `G`: Scroll to bottom
+/// - `PgUp`/`PgDn`: Scroll one page
+/// - `[`: Enter scroll mode (`q`/`Esc` leave it)
 /// - `/`: Start search
 /// - `n`: Next search match
 /// - `N`: Previous search match
@@ -73,6 +81,9 @@ pub fn map_key(key: KeyEvent) -> Action {
         KeyCode::Up | KeyCode::Char('k') => Action::ScrollUp,
         KeyCode::Char('g') => Action::ScrollTop,
         KeyCode::Char('G') => Action::ScrollBottom,
+        KeyCode::PageUp => Action::ScrollPageUp,
+        KeyCode::PageDown => Action::ScrollPageDown,
+        KeyCode::Char('[') => Action::EnterScrollMode,
 
         // Search
         KeyCode::Char('/') => Action::StartSearch,
@@ -228,4 +239,22 @@ mod tests {
         let key = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
         assert_eq!(map_key(key), Action::ScrollUp);
     }
+
+    #[test]
+    fn page_up_returns_scroll_page_up() {
+        let key = KeyEvent::new(KeyCode::PageUp, KeyModifiers::NONE);
+        assert_eq!(map_key(key), Action::ScrollPageUp);
+    }
+
+    #[test]
+    fn page_down_returns_scroll_page_down() {
+        let key = KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE);
+        assert_eq!(map_key(key), Action::ScrollPageDown);
+    }
+
+    #[test]
+    fn left_bracket_returns_enter_scroll_mode() {
+        let key = KeyEvent::new(KeyCode::Char('['), KeyModifiers::NONE);
+        assert_eq!(map_key(key), Action::EnterScrollMode);
+    }
 }
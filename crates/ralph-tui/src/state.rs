@@ -544,6 +544,22 @@ impl TuiState {
         self.iterations.get(self.current_view)
     }
 
+    /// Syncs `in_scroll_mode` with the current buffer: the user is in scroll
+    /// mode whenever they have scrolled away from the live bottom.
+    pub fn sync_scroll_mode(&mut self) {
+        self.in_scroll_mode = self
+            .current_iteration()
+            .is_some_and(|buffer| !buffer.following_bottom);
+    }
+
+    /// Leaves scroll mode, jumping back to the bottom of the current buffer.
+    pub fn exit_scroll_mode(&mut self, viewport_height: usize) {
+        if let Some(buffer) = self.current_iteration_mut() {
+            buffer.scroll_bottom(viewport_height);
+        }
+        self.in_scroll_mode = false;
+    }
+
     /// Returns a mutable reference to the currently viewed iteration buffer.
     pub fn current_iteration_mut(&mut self) -> Option<&mut IterationBuffer> {
         self.iterations.get_mut(self.current_view)
@@ -949,6 +965,30 @@ impl IterationBuffer {
         self.following_bottom = true;
     }
 
+    /// Scrolls up by one viewport page.
+    /// Disables auto-scroll since user is moving away from bottom.
+    pub fn page_up(&mut self, viewport_height: usize) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(viewport_height.max(1));
+        self.following_bottom = false;
+    }
+
+    /// Scrolls down by one viewport page, clamped to the bottom of the buffer.
+    /// Re-enables auto-scroll if user reaches the bottom.
+    pub fn page_down(&mut self, viewport_height: usize) {
+        let max_scroll = self.max_scroll_offset(viewport_height);
+        self.scroll_offset = (self.scroll_offset + viewport_height.max(1)).min(max_scroll);
+        if self.scroll_offset >= max_scroll {
+            self.following_bottom = true;
+        }
+    }
+
+    /// Returns the 1-indexed top visible line and the total line count,
+    /// for the `[scroll N/M]` indicator.
+    pub fn scroll_position(&self) -> (usize, usize) {
+        let total = self.line_count();
+        ((self.scroll_offset + 1).min(total), total)
+    }
+
     /// Calculates the maximum scroll offset for the given viewport height.
     fn max_scroll_offset(&self, viewport_height: usize) -> usize {
         self.lines
@@ -1239,6 +1279,82 @@ mod tests {
             );
         }
 
+        #[test]
+        fn page_down_clamps_to_max_offset() {
+            let mut buffer = IterationBuffer::new(1);
+            for _ in 0..25 {
+                buffer.append_line(Line::from("line"));
+            }
+            buffer.following_bottom = false;
+
+            buffer.page_down(10);
+            assert_eq!(buffer.scroll_offset, 10);
+            assert!(!buffer.following_bottom);
+
+            buffer.page_down(10); // Would be 20, max is 15
+            assert_eq!(buffer.scroll_offset, 15);
+            assert!(buffer.following_bottom);
+        }
+
+        #[test]
+        fn page_up_clamps_to_zero() {
+            let mut buffer = IterationBuffer::new(1);
+            for _ in 0..25 {
+                buffer.append_line(Line::from("line"));
+            }
+            buffer.scroll_offset = 15;
+
+            buffer.page_up(10);
+            assert_eq!(buffer.scroll_offset, 5);
+            assert!(!buffer.following_bottom);
+
+            buffer.page_up(10);
+            assert_eq!(buffer.scroll_offset, 0);
+        }
+
+        #[test]
+        fn page_down_stays_at_zero_when_content_fits_viewport() {
+            let mut buffer = IterationBuffer::new(1);
+            for _ in 0..3 {
+                buffer.append_line(Line::from("line"));
+            }
+
+            buffer.page_down(10);
+            assert_eq!(buffer.scroll_offset, 0);
+            assert!(buffer.following_bottom);
+        }
+
+        #[test]
+        fn page_scroll_with_zero_viewport_moves_one_line() {
+            let mut buffer = IterationBuffer::new(1);
+            for _ in 0..5 {
+                buffer.append_line(Line::from("line"));
+            }
+            buffer.scroll_offset = 2;
+
+            buffer.page_up(0);
+            assert_eq!(buffer.scroll_offset, 1);
+        }
+
+        #[test]
+        fn scroll_position_reports_top_line_and_total() {
+            let mut buffer = IterationBuffer::new(1);
+            assert_eq!(buffer.scroll_position(), (0, 0));
+
+            for _ in 0..25 {
+                buffer.append_line(Line::from("line"));
+            }
+            buffer.scroll_offset = 0;
+            assert_eq!(buffer.scroll_position(), (1, 25));
+
+            buffer.scroll_offset = 15;
+            assert_eq!(buffer.scroll_position(), (16, 25));
+
+            // A stale offset past the end is clamped to the last line
+            buffer.scroll_offset = 40;
+            assert_eq!(buffer.scroll_position(), (25, 25));
+        }
+
         #[test]
         fn autoscroll_scenario_content_grows_past_viewport() {
             // This tests the core bug fix: content growing from small to large
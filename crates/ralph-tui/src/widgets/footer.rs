@@ -119,6 +119,18 @@ impl Widget for Footer<'_> {
             left_spans.push(Span::raw("│ "));
         }
 
+        // Show scrollback position while the user is scrolled away from live output
+        if self.state.in_scroll_mode
+            && let Some(buffer) = self.state.current_iteration()
+        {
+            let (line, total) = buffer.scroll_position();
+            left_spans.push(Span::styled(
+                format!("[scroll {line}/{total}] "),
+                Style::default().fg(Color::Cyan),
+            ));
+            left_spans.push(Span::raw("│ "));
+        }
+
         // Show total elapsed time (default to 00:00 if loop hasn't started)
         let elapsed_display = if let Some(elapsed) = self.state.get_loop_elapsed() {
             let total_secs = elapsed.as_secs();
@@ -241,6 +253,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn footer_shows_scroll_position_in_scroll_mode() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        let buffer = state.current_iteration_mut().unwrap();
+        for i in 0..40 {
+            buffer.append_line(ratatui::text::Line::from(format!("line {i}")));
+        }
+        buffer.scroll_offset = 11;
+        buffer.following_bottom = false;
+        state.sync_scroll_mode();
+
+        let text = render_to_string(&state);
+        assert!(
+            text.contains("[scroll 12/40]"),
+            "should show scroll position, got: {}",
+            text
+        );
+    }
+
+    #[test]
+    fn footer_hides_scroll_position_when_following() {
+        let mut state = TuiState::new();
+        state.start_new_iteration();
+        state
+            .current_iteration_mut()
+            .unwrap()
+            .append_line(ratatui::text::Line::from("line"));
+        state.sync_scroll_mode();
+
+        let text = render_to_string(&state);
+        assert!(
+            !text.contains("[scroll"),
+            "should not show scroll position when following, got: {}",
+            text
+        );
+    }
+
     #[test]
     fn footer_shows_elapsed_time() {
         // Given loop_started is set (simulating 2 minutes 30 seconds elapsed)
@@ -699,7 +699,7 @@ mod tests {
         let event = Event::new("task.start", "");
         state.update(&event);
         if let Some(iteration) = state.iterations.get_mut(0) {
-            iteration.elapsed = Some(Duration::from_secs(300));
+            iteration.elapsed = Some(Duration::from_mins(5));
         }
 
         let text = render_to_string(&state);
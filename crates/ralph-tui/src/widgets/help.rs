@@ -49,6 +49,14 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("  G", Style::default().fg(Color::Cyan)),
             Span::raw("      Scroll to bottom"),
         ]),
+        Line::from(vec![
+            Span::styled("  PgUp/PgDn", Style::default().fg(Color::Cyan)),
+            Span::raw(" Scroll one page"),
+        ]),
+        Line::from(vec![
+            Span::styled("  [", Style::default().fg(Color::Cyan)),
+            Span::raw("      Enter scroll mode (q/Esc to leave)"),
+        ]),
         Line::from(""),
         Line::from(Span::styled("Search:", Style::default().fg(Color::Yellow))),
         Line::from(vec![